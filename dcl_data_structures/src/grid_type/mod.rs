@@ -103,6 +103,18 @@ where
         }
     }
 
+    /// Returns true if the point lies within the bounds of the grid,
+    /// i.e. get and set do not panic for the point.
+    #[inline(always)]
+    pub fn contains(&self, p: PointIndex) -> bool {
+        match self {
+            ArrayGrid::ArrayGrid1D(_) => p.x < H,
+            ArrayGrid::ArrayGrid2D(_) => p.y < H && p.x < W,
+            ArrayGrid::ArrayGrid3D(_) => p.y < D && p.x < H && p.z < W,
+            ArrayGrid::ArrayGrid4D(_) => p.y < C && p.x < D && p.z < H && p.t < W,
+        }
+    }
+
     #[inline(always)]
     pub fn set(&self, p: PointIndex, value: T) {
         match self {
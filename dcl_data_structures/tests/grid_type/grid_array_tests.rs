@@ -160,6 +160,43 @@ fn test_array_grid_4d() {
     assert_eq!(res, 23);
 }
 
+#[test]
+fn test_array_grid_contains() {
+    // Distinct sizes so that every dimension is checked against its own bound.
+    fn check<const W: usize, const H: usize, const D: usize, const C: usize>(
+        ag: &ArrayGrid<u8, W, H, D, C>,
+        points: &[PointIndex],
+    ) {
+        for p in points {
+            assert!(ag.contains(*p), "{:?}", p);
+            assert_eq!(ag.get(*p), 0);
+        }
+    }
+
+    let ag: ArrayGrid<u8, 2, 3, 4, 5> = ArrayGrid::new(ArrayType::Array1D);
+    check(&ag, &[PointIndex::new1d(2)]);
+    assert!(!ag.contains(PointIndex::new1d(3)));
+
+    let ag: ArrayGrid<u8, 2, 3, 4, 5> = ArrayGrid::new(ArrayType::Array2D);
+    check(&ag, &[PointIndex::new2d(1, 2)]);
+    assert!(!ag.contains(PointIndex::new2d(2, 0)));
+    assert!(!ag.contains(PointIndex::new2d(0, 3)));
+
+    let ag: ArrayGrid<u8, 2, 3, 4, 5> = ArrayGrid::new(ArrayType::Array3D);
+    check(&ag, &[PointIndex::new3d(2, 3, 1)]);
+    assert!(!ag.contains(PointIndex::new3d(3, 0, 0)));
+    assert!(!ag.contains(PointIndex::new3d(0, 4, 0)));
+    assert!(!ag.contains(PointIndex::new3d(0, 0, 2)));
+
+    let ag: ArrayGrid<u8, 2, 3, 4, 5> = ArrayGrid::new(ArrayType::Array4D);
+    check(&ag, &[PointIndex::new4d(3, 4, 2, 1)]);
+    assert!(!ag.contains(PointIndex::new4d(4, 0, 0, 0)));
+    assert!(!ag.contains(PointIndex::new4d(0, 5, 0, 0)));
+    assert!(!ag.contains(PointIndex::new4d(0, 0, 3, 0)));
+    assert!(!ag.contains(PointIndex::new4d(0, 0, 0, 2)));
+    assert!(!ag.contains(PointIndex::new4d(usize::MAX, 0, 0, 0)));
+}
+
 #[test]
 fn test_array_grid_display() {
     const W: usize = 2;
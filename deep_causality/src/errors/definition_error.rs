@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::Constructor;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Constructor, Debug)]
+pub struct DefinitionError(pub String);
+
+impl Error for DefinitionError {}
+
+impl fmt::Display for DefinitionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DefinitionError: {}", self.0)
+    }
+}
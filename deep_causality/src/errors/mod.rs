@@ -3,18 +3,34 @@
 
 mod action_error;
 mod adjustment_error;
+mod assumption_error;
+mod audit_error;
 mod build_error;
 mod causal_graph_index_error;
 mod causality_error;
 mod causality_graph_error;
 mod context_index_error;
+mod definition_error;
+mod model_error;
+mod registry_error;
+mod serialization_error;
+mod time_error;
+mod uncertainty_error;
 mod update_error;
 
 pub use action_error::*;
 pub use adjustment_error::*;
+pub use assumption_error::*;
+pub use audit_error::*;
 pub use build_error::*;
 pub use causal_graph_index_error::*;
 pub use causality_error::*;
 pub use causality_graph_error::*;
 pub use context_index_error::*;
+pub use definition_error::*;
+pub use model_error::*;
+pub use registry_error::*;
+pub use serialization_error::*;
+pub use time_error::*;
+pub use uncertainty_error::*;
 pub use update_error::*;
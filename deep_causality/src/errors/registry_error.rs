@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::Constructor;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Constructor, Debug)]
+pub struct RegistryError(pub String);
+
+impl Error for RegistryError {}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RegistryError: {}", self.0)
+    }
+}
@@ -17,6 +17,8 @@ pub use crate::protocols::adjustable::Adjustable;
 // Assumeable protocols
 pub use crate::protocols::assumable::Assumable;
 pub use crate::protocols::assumable::AssumableReasoning;
+// Audit sink protocol
+pub use crate::protocols::audit_sink::AuditSink;
 // Causable protocols
 pub use crate::protocols::causable::Causable;
 pub use crate::protocols::causable::CausableReasoning;
@@ -52,7 +54,15 @@ pub use crate::types::alias_types::*;
 // Context types
 pub use crate::types::context_types::context_graph;
 // Context graph types
+pub use crate::types::context_types::context_event::*;
 pub use crate::types::context_types::context_graph::Context;
+#[cfg(feature = "serde")]
+pub use crate::types::context_types::context_graph::ContextSpec;
+pub use crate::types::context_types::context_graph::{
+    ContextEdge, ContextHyperedge, ContextNodeRef, ContextSnapshot, ContextSubscriber,
+    EdgeAttributes, ExtraContextHandle, ExtraContextView, ExtraContextViewMut, HyperedgeAttributes,
+    NodeAdjustment,
+};
 pub use crate::types::context_types::contextoid::*;
 // Graph types
 pub use crate::types::context_types::contextoid::contextoid_type::*;
@@ -67,21 +77,69 @@ pub use crate::types::context_types::node_types_adjustable::adjustable_data::*;
 pub use crate::types::context_types::node_types_adjustable::adjustable_space::*;
 pub use crate::types::context_types::node_types_adjustable::adjustable_space_time::*;
 pub use crate::types::context_types::node_types_adjustable::adjustable_time::*;
+pub use crate::types::context_types::node_types_adjustable::adjustment_mapping::*;
+pub use crate::types::context_types::node_types_uncertain::uncertain_data::*;
+pub use crate::types::context_types::node_types_uncertain::uncertain_space::*;
+pub use crate::types::context_types::node_types_uncertain::uncertain_time::*;
 pub use crate::types::context_types::relation_kind::*;
+pub use crate::types::context_types::spatial_metric::SpatialMetric;
 pub use crate::types::context_types::time_scale::TimeScale;
+pub use crate::types::context_types::uncertain_value::UncertainValue;
 pub use crate::types::csm_types::CSM;
 // CSM types
 pub use crate::types::csm_types::csm_action::CausalAction;
+pub use crate::types::csm_types::csm_audit_log::CSMAuditLog;
+pub use crate::types::csm_types::csm_audit_record::CSMAuditRecord;
+#[cfg(feature = "serde")]
+pub use crate::types::csm_types::csm_audit_sink::JsonLinesAuditSink;
+pub use crate::types::csm_types::csm_replay::{CSMReplayChange, CSMReplayReport};
+#[cfg(feature = "serde")]
+pub use crate::types::csm_types::csm_spec::{CSMSpec, CSMStateSpec};
 pub use crate::types::csm_types::csm_state::CausalState;
+pub use crate::types::csm_types::csm_versioning::CSMStateDiff;
+// Definition types
+#[cfg(feature = "serde")]
+pub use crate::types::definition_types::assumption_definition::AssumptionDefinition;
+#[cfg(feature = "serde")]
+pub use crate::types::definition_types::causaloid_definition::{
+    CausaloidDefinition, EdgeDefinition, GraphDefinition,
+};
+#[cfg(feature = "serde")]
+pub use crate::types::definition_types::context_definition::{
+    ContextDefinition, ContextEdgeDefinition,
+};
+#[cfg(feature = "serde")]
+pub use crate::types::definition_types::model_definition::ModelDefinition;
+#[cfg(feature = "serde")]
+pub use crate::types::definition_types::state_definition::StateDefinition;
+// Export types
+pub use crate::types::export_types::GraphExportOptions;
 // Model types
-pub use crate::types::model_types::Model;
+#[cfg(feature = "serde")]
+pub use crate::types::model_types::ModelSpec;
+pub use crate::types::model_types::{Model, ModelValidationReport, ValidationIssue};
 // Reasoning types
-pub use crate::types::reasoning_types::assumption::Assumption;
+pub use crate::types::reasoning_types::assumption::{
+    Assumption, AssumptionVerification, StatisticalTest, StatisticalTestResult,
+    DEFAULT_SIGNIFICANCE,
+};
+#[cfg(feature = "serde")]
+pub use crate::types::reasoning_types::assumption::{AssumptionSpec, AssumptionSpecKind};
 pub use crate::types::reasoning_types::causaloid::Causaloid;
-pub use crate::types::reasoning_types::causaloid_graph::CausaloidGraph;
+#[cfg(feature = "serde")]
+pub use crate::types::reasoning_types::causaloid::{
+    CausaloidGraphSpec, CausaloidSpec, CausaloidSpecKind,
+};
+pub use crate::types::reasoning_types::causaloid_graph::{
+    CausaloidGraph, CausaloidGraphBuilder, CausaloidHandle, CauseBuilder,
+};
 pub use crate::types::reasoning_types::inference::Inference;
 pub use crate::types::reasoning_types::observation::Observation;
+// Registry types
+pub use crate::types::registry_types::causal_function_registry::CausalFunctionRegistry;
 //
 // Utils
 //
+#[cfg(feature = "serde")]
+pub use crate::utils::serde_utils::*;
 pub use crate::utils::time_utils::*;
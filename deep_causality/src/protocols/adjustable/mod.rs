@@ -4,6 +4,7 @@
 use dcl_data_structures::prelude::ArrayGrid;
 
 use crate::errors::{AdjustmentError, UpdateError};
+use crate::prelude::AdjustmentMapping;
 
 pub trait Adjustable<T>
 where
@@ -30,4 +31,26 @@ where
     ) -> Result<(), AdjustmentError> {
         Ok(())
     }
+
+    /// Updates the node with the values at the grid positions of the mapping.
+    /// The default implementation ignores the mapping and calls update.
+    /// The adjustable node types override this method and read only the fields they have.
+    fn update_with_mapping<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<T, W, H, D, C>,
+        _mapping: &AdjustmentMapping,
+    ) -> Result<(), UpdateError> {
+        self.update(array_grid)
+    }
+
+    /// Adjusts the node with the values at the grid positions of the mapping.
+    /// The default implementation ignores the mapping and calls adjust.
+    /// The adjustable node types override this method and read only the fields they have.
+    fn adjust_with_mapping<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<T, W, H, D, C>,
+        _mapping: &AdjustmentMapping,
+    ) -> Result<(), AdjustmentError> {
+        self.adjust(array_grid)
+    }
 }
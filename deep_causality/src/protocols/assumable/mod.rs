@@ -1,7 +1,9 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
-use crate::prelude::{DescriptionValue, EvalFn, Identifiable, NumericalValue};
+use crate::prelude::{
+    AssumptionVerification, DescriptionValue, EvalFn, Identifiable, NumericalValue,
+};
 
 /// The Assumable trait defines the interface for objects that represent
 /// assumptions that can be tested and verified. Assumable types must also
@@ -17,6 +19,8 @@ use crate::prelude::{DescriptionValue, EvalFn, Identifiable, NumericalValue};
 /// * `assumption_valid` - Returns whether this assumption is valid
 /// * `verify_assumption` - Tests the assumption against the provided data and
 ///   returns whether it is valid
+/// * `confidence` - Returns the confidence that the assumption holds
+/// * `verification_history` - Returns the outcome of all verifications
 ///
 /// The AssumableReasoning trait provides default implementations for common
 /// operations over collections of Assumable types.
@@ -27,6 +31,23 @@ pub trait Assumable: Identifiable {
     fn assumption_tested(&self) -> bool;
     fn assumption_valid(&self) -> bool;
     fn verify_assumption(&self, data: &[NumericalValue]) -> bool;
+
+    /// Returns the confidence in [0, 1] that the assumption holds, based on the last
+    /// verification. The default implementation returns one if the assumption is valid
+    /// and zero otherwise.
+    fn confidence(&self) -> NumericalValue {
+        if self.assumption_valid() {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the outcome of all verifications, oldest first.
+    /// The default implementation keeps no history.
+    fn verification_history(&self) -> Vec<AssumptionVerification> {
+        Vec::new()
+    }
 }
 
 /// The AssumableReasoning trait provides default implementations for common
@@ -46,6 +67,7 @@ pub trait Assumable: Identifiable {
 /// * `all_assumptions_valid` - Checks if all assumptions are valid.
 /// * `number_assumption_valid` - Returns the number of valid assumptions.
 /// * `percent_assumption_valid` - Returns the percentage of valid assumptions.
+/// * `assumption_confidence` - Returns the average confidence of all assumptions.
 /// * `verify_all_assumptions` - Verifies all assumptions against provided data.
 /// * `get_all_invalid_assumptions` - Filters for invalid assumptions.
 /// * `get_all_valid_assumptions` - Filters for valid assumptions.
@@ -123,6 +145,31 @@ where
         (self.number_assumption_valid() / self.len() as NumericalValue) * 100.0
     }
 
+    /// Returns the degree of confidence that the assumptions in the collection hold.
+    ///
+    /// Calculates the average of `confidence()` over all items returned by
+    /// `get_all_items()`. Untested assumptions count as zero.
+    ///
+    /// Returns zero for an empty collection.
+    ///
+    fn assumption_confidence(&self) -> NumericalValue {
+        if self.is_empty() {
+            return 0.0;
+        }
+
+        self.get_all_items()
+            .iter()
+            .map(|a| {
+                if a.assumption_tested() {
+                    a.confidence()
+                } else {
+                    0.0
+                }
+            })
+            .sum::<NumericalValue>()
+            / self.len() as NumericalValue
+    }
+
     /// Verifies all assumptions in the collection against the provided data.
     ///
     /// Iterates through all items returned by `get_all_items()` and calls
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::AuditError;
+use crate::prelude::CSMAuditRecord;
+
+/// The AuditSink trait defines a persistent destination for CSM audit records.
+///
+/// Every record appended to a `CSMAuditLog` is forwarded to its sink, if any,
+/// before the record is added to the in-memory ring. Implement this trait
+/// to store decisions in a file, a database, or a remote service.
+///
+/// For a sample implementation, see `JsonLinesAuditSink`, available with the `serde` feature.
+///
+pub trait AuditSink {
+    /// Writes a single audit record to the sink.
+    /// Returns AuditError if the record could not be written.
+    fn write_record(&mut self, record: &CSMAuditRecord) -> Result<(), AuditError>;
+
+    /// The default implementation does nothing to keep flushing optional.
+    /// Override this method when the sink buffers records.
+    fn flush(&mut self) -> Result<(), AuditError> {
+        Ok(())
+    }
+}
@@ -22,8 +22,8 @@ use crate::protocols::causable_graph::CausalGraph;
 /// The get_graph() method returns the underlying CausalGraph instance.
 /// This enables default implementations for reasoning and explaining.
 ///
-/// Also includes a default implementation of shortest_path() using the
-/// underlying CausalGraph.
+/// Also includes default implementations of shortest_path() and of queries
+/// for the causes and effects of a causaloid using the underlying CausalGraph.
 ///
 /// Nodes are indexed by usize.
 ///
@@ -104,4 +104,62 @@ where
             None => Err(CausalityGraphError("No path found".to_string())),
         };
     }
+
+    /// Returns the indices of the direct causes of a causaloid, that is all causaloids
+    /// with an edge to the causaloid, sorted by index.
+    ///
+    /// Returns CausalGraphIndexError if the graph does not contain the index.
+    fn get_predecessors(&self, index: usize) -> Result<Vec<usize>, CausalGraphIndexError> {
+        self.get_graph()
+            .predecessors(index)
+            .map_err(|e| CausalGraphIndexError(e.to_string()))
+    }
+
+    /// Returns the number of direct causes of a causaloid.
+    ///
+    /// Returns CausalGraphIndexError if the graph does not contain the index.
+    fn get_in_degree(&self, index: usize) -> Result<usize, CausalGraphIndexError> {
+        self.get_graph()
+            .in_degree(index)
+            .map_err(|e| CausalGraphIndexError(e.to_string()))
+    }
+
+    /// Returns the number of direct effects of a causaloid.
+    ///
+    /// Returns CausalGraphIndexError if the graph does not contain the index.
+    fn get_out_degree(&self, index: usize) -> Result<usize, CausalGraphIndexError> {
+        self.get_graph()
+            .out_degree(index)
+            .map_err(|e| CausalGraphIndexError(e.to_string()))
+    }
+
+    /// Returns the indices of all upstream causes of a causaloid, that is all causaloids
+    /// from which the causaloid can be reached, sorted by index.
+    ///
+    /// Returns CausalGraphIndexError if the graph does not contain the index.
+    fn get_ancestors(&self, index: usize) -> Result<Vec<usize>, CausalGraphIndexError> {
+        self.get_graph()
+            .ancestors(index)
+            .map_err(|e| CausalGraphIndexError(e.to_string()))
+    }
+
+    /// Returns the indices of all downstream effects of a causaloid, that is all causaloids
+    /// that can be reached from the causaloid, sorted by index.
+    ///
+    /// Returns CausalGraphIndexError if the graph does not contain the index.
+    fn get_descendants(&self, index: usize) -> Result<Vec<usize>, CausalGraphIndexError> {
+        self.get_graph()
+            .descendants(index)
+            .map_err(|e| CausalGraphIndexError(e.to_string()))
+    }
+
+    /// Returns the indices of all causaloids without causes, sorted by index.
+    fn get_sources(&self) -> Vec<usize> {
+        self.get_graph().sources()
+    }
+
+    /// Returns the indices of all causaloids without effects, sorted by index.
+    fn get_sinks(&self) -> Vec<usize> {
+        self.get_graph().sinks()
+    }
 }
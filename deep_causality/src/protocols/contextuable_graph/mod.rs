@@ -16,10 +16,11 @@ use crate::prelude::{Contextoid, Datable, RelationKind, SpaceTemporal, Spatial,
 /// V: Numeric type for dimension values
 ///
 /// Provides methods for:
-/// - Adding/removing nodes and edges
+/// - Adding/removing/updating nodes and adding/removing edges
 /// - Checking if nodes/edges exist
 /// - Getting node references
 /// - Getting graph size and counts
+/// - Querying neighbours, node types, ids, and paths
 ///
 /// Nodes are Contextoid objects implementing required traits.
 /// Edges have a relation kind weight.
@@ -46,6 +47,11 @@ where
     fn contains_node(&self, index: usize) -> bool;
     fn get_node(&self, index: usize) -> Option<&Contextoid<D, S, T, ST, V>>;
     fn remove_node(&mut self, index: usize) -> Result<(), ContextIndexError>;
+    fn update_node(
+        &mut self,
+        index: usize,
+        value: Contextoid<D, S, T, ST, V>,
+    ) -> Result<(), ContextIndexError>;
     fn add_edge(
         &mut self,
         a: usize,
@@ -58,6 +64,24 @@ where
     fn is_empty(&self) -> bool;
     fn node_count(&self) -> usize;
     fn edge_count(&self) -> usize;
+
+    fn get_neighbors(
+        &self,
+        index: usize,
+        relation_kind: Option<RelationKind>,
+    ) -> Result<Vec<usize>, ContextIndexError>;
+    fn get_edge_relation(&self, a: usize, b: usize) -> Option<RelationKind>;
+    fn get_node_index_by_id(&self, id: u64) -> Option<usize>;
+    fn get_node_by_id(&self, id: u64) -> Option<&Contextoid<D, S, T, ST, V>>;
+    fn get_all_datoids(&self) -> Vec<(usize, &D)>;
+    fn get_all_tempoids(&self) -> Vec<(usize, &T)>;
+    fn get_all_spaceoids(&self) -> Vec<(usize, &S)>;
+    fn get_all_space_tempoids(&self) -> Vec<(usize, &ST)>;
+    fn get_shortest_path(
+        &self,
+        start_index: usize,
+        stop_index: usize,
+    ) -> Result<Vec<usize>, ContextIndexError>;
 }
 
 /// Trait for poly-contextuable causal graphs.
@@ -77,6 +101,7 @@ where
 /// - Checking if a context ID exists
 /// - Getting/setting current context ID
 /// - Context-specific node and edge methods
+/// - Context-specific query methods
 ///
 /// Nodes are Contextoid objects implementing required traits.
 /// Edges have a relation kind weight.
@@ -116,6 +141,11 @@ where
         index: usize,
     ) -> Result<&Contextoid<D, S, T, ST, V>, ContextIndexError>;
     fn extra_ctx_remove_node(&mut self, index: usize) -> Result<(), ContextIndexError>;
+    fn extra_ctx_update_node(
+        &mut self,
+        index: usize,
+        value: Contextoid<D, S, T, ST, V>,
+    ) -> Result<(), ContextIndexError>;
     fn extra_ctx_add_edge(
         &mut self,
         a: usize,
@@ -128,4 +158,25 @@ where
     fn extra_ctx_is_empty(&self) -> Result<bool, ContextIndexError>;
     fn extra_ctx_node_count(&self) -> Result<usize, ContextIndexError>;
     fn extra_ctx_edge_count(&self) -> Result<usize, ContextIndexError>;
+
+    fn extra_ctx_get_neighbors(
+        &self,
+        index: usize,
+        relation_kind: Option<RelationKind>,
+    ) -> Result<Vec<usize>, ContextIndexError>;
+    fn extra_ctx_get_edge_relation(
+        &self,
+        a: usize,
+        b: usize,
+    ) -> Result<Option<RelationKind>, ContextIndexError>;
+    fn extra_ctx_get_node_index_by_id(&self, id: u64) -> Result<Option<usize>, ContextIndexError>;
+    fn extra_ctx_get_all_datoids(&self) -> Result<Vec<(usize, &D)>, ContextIndexError>;
+    fn extra_ctx_get_all_tempoids(&self) -> Result<Vec<(usize, &T)>, ContextIndexError>;
+    fn extra_ctx_get_all_spaceoids(&self) -> Result<Vec<(usize, &S)>, ContextIndexError>;
+    fn extra_ctx_get_all_space_tempoids(&self) -> Result<Vec<(usize, &ST)>, ContextIndexError>;
+    fn extra_ctx_get_shortest_path(
+        &self,
+        start_index: usize,
+        stop_index: usize,
+    ) -> Result<Vec<usize>, ContextIndexError>;
 }
@@ -3,6 +3,7 @@
 
 pub mod adjustable;
 pub mod assumable;
+pub mod audit_sink;
 pub mod causable;
 pub mod causable_graph;
 pub mod contextuable;
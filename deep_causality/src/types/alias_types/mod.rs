@@ -2,7 +2,8 @@
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
 use crate::prelude::{
-    CausalityError, Causaloid, CausaloidGraph, Context, Contextoid, Data, Space, SpaceTime, Time,
+    CausalFunctionRegistry, CausalityError, Causaloid, CausaloidGraph, CausaloidGraphBuilder,
+    Context, Contextoid, Data, Space, SpaceTime, Time,
 };
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
@@ -17,7 +18,9 @@ pub type DescriptionValue = String;
 pub type ArcRWLock<T> = Arc<RwLock<T>>;
 
 // Fn aliases for assumable, assumption, & assumption collection
-pub type EvalFn = fn(&[NumericalValue]) -> bool;
+// Shared closure so that an assumption function can capture its parameters,
+// e.g. the significance level of a statistical test.
+pub type EvalFn = Arc<dyn Fn(&[NumericalValue]) -> bool + Send + Sync>;
 
 // Fn aliases for causal function with and without context
 pub type CausalFn = fn(NumericalValue) -> Result<bool, CausalityError>;
@@ -75,6 +78,26 @@ pub type BaseCausalGraph<'l> = CausaloidGraph<
     >,
 >;
 
+pub type BaseCausaloidGraphBuilder<'l> = CausaloidGraphBuilder<
+    Causaloid<
+        'l,
+        Data<BaseNumberType>,
+        Space<BaseNumberType>,
+        Time<BaseNumberType>,
+        SpaceTime<BaseNumberType>,
+        BaseNumberType,
+    >,
+>;
+
+pub type BaseCausalFunctionRegistry<'l> = CausalFunctionRegistry<
+    'l,
+    Data<BaseNumberType>,
+    Space<BaseNumberType>,
+    Time<BaseNumberType>,
+    SpaceTime<BaseNumberType>,
+    BaseNumberType,
+>;
+
 // Default type alias for basic context. It's used in tests
 pub type BaseContext = Context<
     Data<BaseNumberType>,
@@ -91,3 +114,52 @@ pub type BaseContextoid = Contextoid<
     SpaceTime<BaseNumberType>,
     BaseNumberType,
 >;
+
+// Default type aliases for serializable model descriptions
+#[cfg(feature = "serde")]
+pub type BaseCausaloidSpec<'l> = crate::prelude::CausaloidSpec<
+    'l,
+    Data<BaseNumberType>,
+    Space<BaseNumberType>,
+    Time<BaseNumberType>,
+    SpaceTime<BaseNumberType>,
+    BaseNumberType,
+>;
+
+#[cfg(feature = "serde")]
+pub type BaseContextSpec = crate::prelude::ContextSpec<
+    Data<BaseNumberType>,
+    Space<BaseNumberType>,
+    Time<BaseNumberType>,
+    SpaceTime<BaseNumberType>,
+    BaseNumberType,
+>;
+
+#[cfg(feature = "serde")]
+pub type BaseCSMSpec<'l> = crate::prelude::CSMSpec<
+    'l,
+    Data<BaseNumberType>,
+    Space<BaseNumberType>,
+    Time<BaseNumberType>,
+    SpaceTime<BaseNumberType>,
+    BaseNumberType,
+>;
+
+#[cfg(feature = "serde")]
+pub type BaseModelSpec<'l> = crate::prelude::ModelSpec<
+    'l,
+    Data<BaseNumberType>,
+    Space<BaseNumberType>,
+    Time<BaseNumberType>,
+    SpaceTime<BaseNumberType>,
+    BaseNumberType,
+>;
+
+#[cfg(feature = "serde")]
+pub type BaseModelDefinition = crate::prelude::ModelDefinition<
+    Data<BaseNumberType>,
+    Space<BaseNumberType>,
+    Time<BaseNumberType>,
+    SpaceTime<BaseNumberType>,
+    BaseNumberType,
+>;
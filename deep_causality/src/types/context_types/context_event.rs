@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+
+use deep_causality_macros::{Constructor, Getters};
+
+/// Kind of change to a contextoid in a context.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[repr(u8)]
+pub enum ContextEventKind {
+    Added,
+    Removed,
+    Adjusted,
+}
+
+impl Display for ContextEventKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Notification sent to context subscribers whenever a contextoid
+/// is added, removed, or adjusted.
+///
+/// version: version of the context after the change.
+/// extra_context_id: None for the base context, otherwise the id of the extra context.
+/// index: node index of the contextoid.
+/// contextoid_id: id of the contextoid.
+#[derive(Getters, Constructor, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ContextEvent {
+    kind: ContextEventKind,
+    version: u64,
+    extra_context_id: Option<u64>,
+    index: usize,
+    contextoid_id: u64,
+}
+
+impl Display for ContextEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ContextEvent: kind: {} version: {} extra context: {:?} index: {} contextoid: {}",
+            self.kind, self.version, self.extra_context_id, self.index, self.contextoid_id
+        )
+    }
+}
@@ -0,0 +1,249 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use dcl_data_structures::grid_type::ArrayGrid;
+
+use super::*;
+
+// Contextoids by node index in the order they are swapped in.
+type NodeChanges<D, S, T, ST, V> = Vec<(usize, Contextoid<D, S, T, ST, V>)>;
+
+// Contextoids as they were before the adjustments of one adjust_node or adjust_nodes call.
+type AdjustmentUndo<D, S, T, ST, V> = NodeChanges<D, S, T, ST, V>;
+
+pub(super) type AdjustmentLog<D, S, T, ST, V> = Vec<AdjustmentUndo<D, S, T, ST, V>>;
+
+/// Node index and grid of one adjustment in a batch.
+pub type NodeAdjustment<'a, V, const W: usize, const H: usize, const DEPTH: usize, const C: usize> =
+    (usize, &'a ArrayGrid<V, W, H, DEPTH, C>);
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable + Adjustable<V> + Clone,
+    S: Spatial<V> + Adjustable<V> + Clone,
+    T: Temporable<V> + Adjustable<V> + Clone,
+    ST: SpaceTemporal<V> + Adjustable<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Adjusts the contextoid at index with the values at the grid positions of the mapping
+    /// and records the previous contextoid in the adjustment log.
+    /// Returns AdjustmentError if the index is not found, the contextoid is a root,
+    /// or the adjustment fails. A failed adjustment leaves the contextoid unchanged.
+    pub fn adjust_node<const W: usize, const H: usize, const DEPTH: usize, const C: usize>(
+        &mut self,
+        index: usize,
+        array_grid: &ArrayGrid<V, W, H, DEPTH, C>,
+        mapping: &AdjustmentMapping,
+    ) -> Result<(), AdjustmentError> {
+        self.adjust_nodes(&[(index, array_grid)], mapping)
+    }
+
+    /// Adjusts all contextoids of the batch with the values at the grid positions of the mapping.
+    /// A contextoid adjusted more than once accumulates its adjustments.
+    ///
+    /// The batch is atomic: all adjusted contextoids are computed first and only swapped in
+    /// if every adjustment succeeds. The context then increases its version once and notifies
+    /// subscribers once for every adjusted contextoid. A successful batch is recorded as one
+    /// entry in the adjustment log.
+    ///
+    /// Returns AdjustmentError without changing the context if a mapped grid position lies
+    /// outside one of the grids, an index is not found, or an adjustment fails.
+    pub fn adjust_nodes<const W: usize, const H: usize, const DEPTH: usize, const C: usize>(
+        &mut self,
+        batch: &[NodeAdjustment<'_, V, W, H, DEPTH, C>],
+        mapping: &AdjustmentMapping,
+    ) -> Result<(), AdjustmentError> {
+        for (index, array_grid) in batch {
+            mapping.check_bounds(array_grid).map_err(|e| {
+                AdjustmentError(format!("Adjustment of index {} failed: {}", index, e))
+            })?;
+        }
+
+        // Adjusted contextoids in the order of their first adjustment,
+        // and the contextoids before the batch in the same order.
+        let mut staged: NodeChanges<D, S, T, ST, V> = Vec::with_capacity(batch.len());
+        let mut undo: AdjustmentUndo<D, S, T, ST, V> = Vec::with_capacity(batch.len());
+        let mut positions: HashMap<usize, usize> = HashMap::with_capacity(batch.len());
+
+        for (index, array_grid) in batch {
+            let position = positions.get(index).copied();
+            let current = match position {
+                Some(position) => staged[position].1.clone(),
+                None => match self.base_context.get_node(*index) {
+                    Some(node) => node.clone(),
+                    None => return Err(AdjustmentError(format!("index {} not found", index))),
+                },
+            };
+
+            let adjusted = adjust_contextoid(*index, &current, array_grid, mapping)?;
+
+            match position {
+                Some(position) => staged[position].1 = adjusted,
+                None => {
+                    positions.insert(*index, staged.len());
+                    staged.push((*index, adjusted));
+                    undo.push((*index, current));
+                }
+            }
+        }
+
+        self.commit(&staged)?;
+        self.adjustment_log.push(undo);
+        Ok(())
+    }
+
+    /// Reverts the last successful adjust_node or adjust_nodes call and removes it from the log.
+    /// Contextoids removed or replaced by another contextoid since then are skipped.
+    /// Like a batch, the revert increases the version once and notifies subscribers afterwards.
+    /// Returns AdjustmentError if the adjustment log is empty or the revert fails,
+    /// in which case the context and the log are unchanged.
+    pub fn undo_adjustment(&mut self) -> Result<(), AdjustmentError> {
+        let undo = match self.adjustment_log.pop() {
+            Some(undo) => undo,
+            None => return Err(AdjustmentError("Adjustment log is empty".into())),
+        };
+
+        let changes: NodeChanges<D, S, T, ST, V> = undo
+            .iter()
+            .filter(|(index, previous)| {
+                self.base_context
+                    .get_node(*index)
+                    .is_some_and(|node| node.id() == previous.id())
+            })
+            .cloned()
+            .collect();
+
+        if let Err(e) = self.commit(&changes) {
+            self.adjustment_log.push(undo);
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    // Swaps in all contextoids, then increases the version once and notifies subscribers.
+    // If a contextoid cannot be swapped in, the contextoids swapped in so far are restored
+    // and the error is returned together with any error of the rollback.
+    fn commit(&mut self, changes: &NodeChanges<D, S, T, ST, V>) -> Result<(), AdjustmentError> {
+        let mut replaced = Vec::with_capacity(changes.len());
+
+        for (index, value) in changes {
+            let result = match self.base_context.get_node(*index).cloned() {
+                Some(previous) => self
+                    .replace_node(*index, value.clone())
+                    .map(|_| previous)
+                    .map_err(|e| e.to_string()),
+                None => Err(format!("index {} not found", index)),
+            };
+
+            match result {
+                Ok(previous) => replaced.push((*index, previous)),
+                Err(e) => {
+                    let mut message = format!("Adjustment of index {} failed: {}", index, e);
+                    for (index, previous) in replaced.into_iter().rev() {
+                        if let Err(e) = self.replace_node(index, previous) {
+                            message
+                                .push_str(&format!("; rollback of index {} failed: {}", index, e));
+                        }
+                    }
+                    return Err(AdjustmentError(message));
+                }
+            }
+        }
+
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        self.increment_version();
+        for (index, value) in changes {
+            self.emit(ContextEventKind::Adjusted, None, *index, value.id());
+        }
+
+        Ok(())
+    }
+}
+
+// Returns the contextoid adjusted with the values at the grid positions of the mapping.
+fn adjust_contextoid<
+    D,
+    S,
+    T,
+    ST,
+    V,
+    const W: usize,
+    const H: usize,
+    const DEPTH: usize,
+    const C: usize,
+>(
+    index: usize,
+    contextoid: &Contextoid<D, S, T, ST, V>,
+    array_grid: &ArrayGrid<V, W, H, DEPTH, C>,
+    mapping: &AdjustmentMapping,
+) -> Result<Contextoid<D, S, T, ST, V>, AdjustmentError>
+where
+    D: Datable + Adjustable<V> + Clone,
+    S: Spatial<V> + Adjustable<V> + Clone,
+    T: Temporable<V> + Adjustable<V> + Clone,
+    ST: SpaceTemporal<V> + Adjustable<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    let mut vertex_type = contextoid.vertex_type().clone();
+    match &mut vertex_type {
+        ContextoidType::Datoid(d) => d.adjust_with_mapping(array_grid, mapping)?,
+        ContextoidType::Tempoid(t) => t.adjust_with_mapping(array_grid, mapping)?,
+        ContextoidType::Spaceoid(s) => s.adjust_with_mapping(array_grid, mapping)?,
+        ContextoidType::SpaceTempoid(st) => st.adjust_with_mapping(array_grid, mapping)?,
+        _ => {
+            return Err(AdjustmentError(format!(
+                "Contextoid at index {} is not adjustable",
+                index
+            )))
+        }
+    }
+
+    Ok(Contextoid::new(contextoid.id(), vertex_type))
+}
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Returns the number of adjustments that can be undone.
+    pub fn adjustment_log_len(&self) -> usize {
+        self.adjustment_log.len()
+    }
+
+    /// Clears the adjustment log. Previous adjustments can no longer be undone.
+    pub fn clear_adjustment_log(&mut self) {
+        self.adjustment_log.clear();
+    }
+}
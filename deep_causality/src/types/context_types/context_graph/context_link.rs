@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+
+use super::*;
+
+/// Reference to a node in either the base context or one of the extra contexts.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContextNodeRef {
+    context: Option<ExtraContextHandle>,
+    index: usize,
+}
+
+impl ContextNodeRef {
+    /// References the node at index in the base context.
+    pub fn base(index: usize) -> Self {
+        Self {
+            context: None,
+            index,
+        }
+    }
+
+    /// References the node at index in the extra context of the handle.
+    pub fn extra(handle: ExtraContextHandle, index: usize) -> Self {
+        Self {
+            context: Some(handle),
+            index,
+        }
+    }
+
+    /// Returns None for the base context, otherwise the handle of the extra context.
+    pub fn context(&self) -> Option<ExtraContextHandle> {
+        self.context
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl Display for ContextNodeRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.context {
+            None => write!(f, "ContextNodeRef: base context index: {}", self.index),
+            Some(handle) => write!(
+                f,
+                "ContextNodeRef: extra context: {} index: {}",
+                handle.id(),
+                self.index
+            ),
+        }
+    }
+}
+
+// Outgoing links per node.
+pub(super) type ContextLinks = HashMap<ContextNodeRef, Vec<(ContextNodeRef, RelationKind)>>;
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Adds a directed link between two nodes in different contexts.
+    /// Links between nodes of the same context are edges; use add_edge for those.
+    /// Links are removed together with their nodes or contexts.
+    /// Returns ContextIndexError if any node does not exist,
+    /// both nodes are in the same context, or the link already exists.
+    pub fn add_context_link(
+        &mut self,
+        from: ContextNodeRef,
+        to: ContextNodeRef,
+        relation_kind: RelationKind,
+    ) -> Result<(), ContextIndexError> {
+        if !self.contains_node_ref(from) {
+            return Err(ContextIndexError(format!("{} not found", from)));
+        }
+
+        if !self.contains_node_ref(to) {
+            return Err(ContextIndexError(format!("{} not found", to)));
+        }
+
+        if from.context == to.context {
+            return Err(ContextIndexError(format!(
+                "{} and {} are in the same context, use add_edge instead",
+                from, to
+            )));
+        }
+
+        if self.contains_context_link(from, to) {
+            return Err(ContextIndexError(format!(
+                "Link already exists between: {} and {}",
+                from, to
+            )));
+        }
+
+        self.context_links
+            .entry(from)
+            .or_default()
+            .push((to, relation_kind));
+
+        self.increment_version();
+        Ok(())
+    }
+
+    /// Removes the link between two nodes.
+    /// Returns ContextIndexError if the link does not exist.
+    pub fn remove_context_link(
+        &mut self,
+        from: ContextNodeRef,
+        to: ContextNodeRef,
+    ) -> Result<(), ContextIndexError> {
+        if !self.contains_context_link(from, to) {
+            return Err(ContextIndexError(format!(
+                "No link found between: {} and {}",
+                from, to
+            )));
+        }
+
+        if let Some(links) = self.context_links.get_mut(&from) {
+            links.retain(|(target, _)| *target != to);
+            if links.is_empty() {
+                self.context_links.remove(&from);
+            }
+        }
+
+        self.increment_version();
+        Ok(())
+    }
+
+    /// Returns true if a link exists from one node to the other.
+    pub fn contains_context_link(&self, from: ContextNodeRef, to: ContextNodeRef) -> bool {
+        self.context_links
+            .get(&from)
+            .is_some_and(|links| links.iter().any(|(target, _)| *target == to))
+    }
+
+    /// Returns all links starting at the node with their relation kind.
+    pub fn get_context_links(&self, from: ContextNodeRef) -> Vec<(ContextNodeRef, RelationKind)> {
+        self.context_links.get(&from).cloned().unwrap_or_default()
+    }
+
+    /// Returns the total number of links between contexts.
+    pub fn number_of_context_links(&self) -> usize {
+        self.context_links.values().map(|links| links.len()).sum()
+    }
+
+    fn contains_node_ref(&self, node: ContextNodeRef) -> bool {
+        match node.context {
+            None => self.base_context.contains_node(node.index),
+            Some(handle) => self
+                .get_extra_context(handle.id())
+                .is_ok_and(|ctx| ctx.contains_node(node.index)),
+        }
+    }
+
+    pub(super) fn remove_context_links_of(&mut self, node: ContextNodeRef) {
+        self.context_links.remove(&node);
+        self.context_links.retain(|_, links| {
+            links.retain(|(target, _)| *target != node);
+            !links.is_empty()
+        });
+    }
+
+    pub(super) fn remove_context_links_of_context(&mut self, context: Option<ExtraContextHandle>) {
+        self.context_links.retain(|from, links| {
+            links.retain(|(target, _)| target.context != context);
+            from.context != context && !links.is_empty()
+        });
+    }
+}
@@ -5,10 +5,10 @@ use super::*;
 
 impl<D, S, T, ST, V> ContextuableGraph<D, S, T, ST, V> for Context<D, S, T, ST, V>
 where
-    D: Datable,
-    S: Spatial<V>,
-    T: Temporable<V>,
-    ST: SpaceTemporal<V>,
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
     V: Default
         + Copy
         + Clone
@@ -23,7 +23,21 @@ where
     /// You can add the same contextoid multiple times,
     /// but each one will return a new and unique node index.
     fn add_node(&mut self, value: Contextoid<D, S, T, ST, V>) -> usize {
-        self.base_context.add_node(value)
+        let id = value.id();
+        let key = time_index::time_key(&value);
+        let point = spatial_index::space_key(&value);
+        let index = self.base_context_mut().add_node(value);
+
+        if let (Some(time_index), Some((scale, unit))) = (self.time_index.as_mut(), key) {
+            time_index.insert(scale, unit, index);
+        }
+
+        if let (Some(spatial_index), Some(point)) = (self.spatial_index.as_mut(), point) {
+            spatial_index.insert(index, point);
+        }
+
+        self.notify(ContextEventKind::Added, None, index, id);
+        index
     }
 
     /// Returns only true if the context contains the contextoid with the given index.
@@ -44,10 +58,40 @@ where
             return Err(ContextIndexError(format!("index {} not found", index)));
         };
 
-        if self.base_context.remove_node(index).is_err() {
+        let node = self.base_context.get_node(index);
+        let id = node.map(|n| n.id()).unwrap_or_default();
+        let key = node.and_then(time_index::time_key);
+        let hyperedges = self.base_context.get_hyperedges_of_node(index);
+
+        if self.base_context_mut().remove_node(index).is_err() {
             return Err(ContextIndexError(format!("index {} not found", index)));
         };
 
+        if let (Some(time_index), Some((scale, unit))) = (self.time_index.as_mut(), key) {
+            time_index.remove(scale, unit, index);
+        }
+
+        if let Some(spatial_index) = self.spatial_index.as_mut() {
+            spatial_index.remove(index);
+        }
+
+        self.remove_hyperedge_data_of(&hyperedges);
+        self.remove_context_links_of(ContextNodeRef::base(index));
+        self.notify(ContextEventKind::Removed, None, index, id);
+        Ok(())
+    }
+
+    /// Replaces the contextoid at the given index while keeping all its edges.
+    /// Returns ContextIndexError if the index is not found
+    fn update_node(
+        &mut self,
+        index: usize,
+        value: Contextoid<D, S, T, ST, V>,
+    ) -> Result<(), ContextIndexError> {
+        let id = value.id();
+        self.replace_node(index, value)?;
+
+        self.notify(ContextEventKind::Adjusted, None, index, id);
         Ok(())
     }
 
@@ -60,26 +104,7 @@ where
         b: usize,
         weight: RelationKind,
     ) -> Result<(), ContextIndexError> {
-        if !self.contains_node(a) {
-            return Err(ContextIndexError(format!("index a {} not found", a)));
-        };
-
-        if !self.contains_node(b) {
-            return Err(ContextIndexError(format!("index b {} not found", b)));
-        };
-
-        if self
-            .base_context
-            .add_edge_with_weight(a, b, weight as u64)
-            .is_err()
-        {
-            return Err(ContextIndexError(format!(
-                "Failed to add edge for index a {} and b {}",
-                a, b
-            )));
-        }
-
-        Ok(())
+        self.add_edge_with_attributes(a, b, weight, EdgeAttributes::new())
     }
 
     /// Returns only true if the context contains the edge between the two nodes.
@@ -101,13 +126,14 @@ where
             return Err(ContextIndexError("index b not found".into()));
         };
 
-        if self.base_context.remove_edge(a, b).is_err() {
+        if self.base_context_mut().remove_edge(a, b).is_err() {
             return Err(ContextIndexError(format!(
                 "Failed to remove edge for index a {} and b {}",
                 a, b
             )));
         }
 
+        self.increment_version();
         Ok(())
     }
 
@@ -130,4 +156,123 @@ where
     fn edge_count(&self) -> usize {
         self.base_context.number_edges()
     }
+
+    /// Returns the indices of all contextoids with an edge starting from index,
+    /// optionally filtered by the relation kind of the edge.
+    /// Returns ContextIndexError if the index is not found.
+    fn get_neighbors(
+        &self,
+        index: usize,
+        relation_kind: Option<RelationKind>,
+    ) -> Result<Vec<usize>, ContextIndexError> {
+        query::neighbors(&self.base_context, index, relation_kind)
+    }
+
+    /// Returns the relation kind of the edge between the two nodes.
+    /// If the context does not contain the edge it will return None.
+    fn get_edge_relation(&self, a: usize, b: usize) -> Option<RelationKind> {
+        query::edge_relation(&self.base_context, a, b)
+    }
+
+    /// Returns the index of the first contextoid with the given id.
+    /// The lookup is linear in the number of contextoids.
+    fn get_node_index_by_id(&self, id: u64) -> Option<usize> {
+        query::node_index_by_id(&self.base_context, id)
+    }
+
+    /// Returns a reference to the first contextoid with the given id.
+    /// The lookup is linear in the number of contextoids.
+    fn get_node_by_id(&self, id: u64) -> Option<&Contextoid<D, S, T, ST, V>> {
+        self.get_node_index_by_id(id)
+            .and_then(|index| self.get_node(index))
+    }
+
+    /// Returns the index and value of all Datoids in the context, ordered by index.
+    fn get_all_datoids(&self) -> Vec<(usize, &D)> {
+        query::filter_nodes(&self.base_context, ContextoidType::dataoid)
+    }
+
+    /// Returns the index and value of all Tempoids in the context, ordered by index.
+    fn get_all_tempoids(&self) -> Vec<(usize, &T)> {
+        query::filter_nodes(&self.base_context, ContextoidType::tempoid)
+    }
+
+    /// Returns the index and value of all Spaceoids in the context, ordered by index.
+    fn get_all_spaceoids(&self) -> Vec<(usize, &S)> {
+        query::filter_nodes(&self.base_context, ContextoidType::spaceiod)
+    }
+
+    /// Returns the index and value of all SpaceTempoids in the context, ordered by index.
+    fn get_all_space_tempoids(&self) -> Vec<(usize, &ST)> {
+        query::filter_nodes(&self.base_context, ContextoidType::space_tempoid)
+    }
+
+    /// Returns the path with the fewest edges from start_index to stop_index.
+    /// Returns ContextIndexError if any of the nodes are not in the context
+    /// or if there is no path between them.
+    fn get_shortest_path(
+        &self,
+        start_index: usize,
+        stop_index: usize,
+    ) -> Result<Vec<usize>, ContextIndexError> {
+        query::shortest_path(&self.base_context, start_index, stop_index)
+    }
+}
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Replaces the contextoid at the given index and keeps the time and spatial index in sync,
+    /// without changing the version or notifying subscribers.
+    /// Returns ContextIndexError if the index is not found.
+    pub(super) fn replace_node(
+        &mut self,
+        index: usize,
+        value: Contextoid<D, S, T, ST, V>,
+    ) -> Result<(), ContextIndexError> {
+        let (old_key, old_point) = match self.base_context.get_node(index) {
+            Some(node) => (time_index::time_key(node), spatial_index::space_key(node)),
+            None => return Err(ContextIndexError(format!("index {} not found", index))),
+        };
+
+        let key = time_index::time_key(&value);
+        let point = spatial_index::space_key(&value);
+
+        if self.base_context_mut().update_node(index, value).is_err() {
+            return Err(ContextIndexError(format!("index {} not found", index)));
+        };
+
+        if let Some(time_index) = self.time_index.as_mut() {
+            if let Some((scale, unit)) = old_key {
+                time_index.remove(scale, unit, index);
+            }
+            if let Some((scale, unit)) = key {
+                time_index.insert(scale, unit, index);
+            }
+        }
+
+        if let Some(spatial_index) = self.spatial_index.as_mut() {
+            if old_point.is_some() {
+                spatial_index.remove(index);
+            }
+            if let Some(point) = point {
+                spatial_index.insert(index, point);
+            }
+        }
+
+        Ok(())
+    }
 }
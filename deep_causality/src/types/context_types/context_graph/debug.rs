@@ -5,7 +5,7 @@ use std::hash::Hash;
 use std::ops::*;
 
 use crate::prelude::{Context, Datable, SpaceTemporal, Spatial, Temporable};
-use crate::protocols::contextuable_graph::ContextuableGraph;
+use ultragraph::prelude::GraphView;
 
 impl<D, S, T, ST, V> Context<D, S, T, ST, V>
 where
@@ -29,8 +29,8 @@ where
             "Context: id: {}, name: {}, node_count: {}, edge_count: {}",
             self.id,
             self.name,
-            self.node_count(),
-            self.edge_count(),
+            self.base_context.number_nodes(),
+            self.base_context.number_edges(),
         )
     }
 }
@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use super::*;
+
+/// Free-form attributes of an edge, e.g. the source or confidence of a relation.
+pub type EdgeAttributes = HashMap<String, String>;
+
+/// Payload of an edge between two contextoids of the same context.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContextEdge {
+    relation_kind: RelationKind,
+    attributes: EdgeAttributes,
+}
+
+impl ContextEdge {
+    pub fn new(relation_kind: RelationKind, attributes: EdgeAttributes) -> Self {
+        Self {
+            relation_kind,
+            attributes,
+        }
+    }
+
+    pub fn relation_kind(&self) -> RelationKind {
+        self.relation_kind
+    }
+
+    pub fn attributes(&self) -> &EdgeAttributes {
+        &self.attributes
+    }
+
+    /// Returns the attribute value for the key, if any.
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.get(key).map(|value| value.as_str())
+    }
+}
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Adds an edge with attributes between two contextoids of the base context.
+    /// Returns ContextIndexError if a node is not found or the edge already exists.
+    pub fn add_edge_with_attributes(
+        &mut self,
+        a: usize,
+        b: usize,
+        relation_kind: RelationKind,
+        attributes: EdgeAttributes,
+    ) -> Result<(), ContextIndexError> {
+        if !self.base_context.contains_node(a) {
+            return Err(ContextIndexError(format!("index a {} not found", a)));
+        };
+
+        if !self.base_context.contains_node(b) {
+            return Err(ContextIndexError(format!("index b {} not found", b)));
+        };
+
+        let edge = ContextEdge::new(relation_kind, attributes);
+        if self
+            .base_context_mut()
+            .add_edge_with_data(a, b, EDGE_WEIGHT, edge)
+            .is_err()
+        {
+            return Err(ContextIndexError(format!(
+                "Failed to add edge for index a {} and b {}",
+                a, b
+            )));
+        }
+
+        self.increment_version();
+        Ok(())
+    }
+
+    /// Returns the edge between two contextoids of the base context, if any.
+    pub fn get_edge(&self, a: usize, b: usize) -> Option<&ContextEdge> {
+        self.base_context.get_edge_data(a, b)
+    }
+}
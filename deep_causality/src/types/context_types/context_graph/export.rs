@@ -0,0 +1,294 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::Write;
+
+use crate::utils::export_utils::{escape_dot, escape_mermaid};
+
+use super::*;
+
+// Edge between two nodes of the same graph.
+type RelationEdge = (usize, usize, RelationKind);
+
+type ExtraContextEntry<'a, D, S, T, ST, V> = (u64, &'a ExtraContext<D, S, T, ST, V>);
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Renders the context in Graphviz DOT.
+    ///
+    /// Nodes show their contextoid type and id, edges their RelationKind.
+    /// Each extra context is drawn as a cluster, and links between contexts as dashed edges.
+    pub fn to_dot(&self, _options: &GraphExportOptions) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph context_{} {{", self.id).expect("Failed to write DOT");
+        writeln!(dot, "    label=\"{}\";", escape_dot(&self.name)).expect("Failed to write DOT");
+        dot.push_str("    node [shape=ellipse];\n");
+
+        write_dot_graph(&mut dot, &self.base_context, None, "    ");
+
+        for (id, graph) in self.sorted_extra_contexts() {
+            writeln!(dot, "    subgraph cluster_{} {{", id).expect("Failed to write DOT");
+            writeln!(
+                dot,
+                "        label=\"{}\";",
+                escape_dot(&self.extra_context_label(id))
+            )
+            .expect("Failed to write DOT");
+            write_dot_graph(&mut dot, graph, Some(id), "        ");
+            dot.push_str("    }\n");
+        }
+
+        for (from, to, relation_kind) in self.sorted_context_links() {
+            writeln!(
+                dot,
+                "    {} -> {} [label=\"{}\", style=dashed];",
+                node_name(&from),
+                node_name(&to),
+                relation_kind
+            )
+            .expect("Failed to write DOT");
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders the context as a Mermaid flowchart.
+    ///
+    /// Nodes show their contextoid type and id, edges their RelationKind.
+    /// Each extra context is drawn as a subgraph, and links between contexts as dotted edges.
+    pub fn to_mermaid(&self, _options: &GraphExportOptions) -> String {
+        let mut mermaid = String::from("flowchart TD\n");
+
+        write_mermaid_graph(&mut mermaid, &self.base_context, None, "    ");
+
+        for (id, graph) in self.sorted_extra_contexts() {
+            writeln!(
+                mermaid,
+                "    subgraph x{}[\"{}\"]",
+                id,
+                escape_mermaid(&self.extra_context_label(id))
+            )
+            .expect("Failed to write Mermaid");
+            write_mermaid_graph(&mut mermaid, graph, Some(id), "        ");
+            mermaid.push_str("    end\n");
+        }
+
+        for (from, to, relation_kind) in self.sorted_context_links() {
+            writeln!(
+                mermaid,
+                "    {} -.->|{}| {}",
+                node_name(&from),
+                relation_kind,
+                node_name(&to)
+            )
+            .expect("Failed to write Mermaid");
+        }
+
+        mermaid
+    }
+
+    fn sorted_extra_contexts(&self) -> Vec<ExtraContextEntry<'_, D, S, T, ST, V>> {
+        let mut extra_contexts: Vec<_> = self
+            .extra_contexts
+            .iter()
+            .flatten()
+            .map(|(id, graph)| (*id, graph))
+            .collect();
+        extra_contexts.sort_by_key(|(id, _)| *id);
+        extra_contexts
+    }
+
+    fn extra_context_label(&self, id: u64) -> String {
+        let mut names: Vec<&str> = self
+            .extra_context_names
+            .iter()
+            .filter(|(_, v)| **v == id)
+            .map(|(name, _)| name.as_str())
+            .collect();
+        names.sort();
+
+        if names.is_empty() {
+            format!("Extra context {}", id)
+        } else {
+            format!("Extra context {}: {}", id, names.join(", "))
+        }
+    }
+
+    fn sorted_context_links(&self) -> Vec<(ContextNodeRef, ContextNodeRef, RelationKind)> {
+        let mut links: Vec<_> = self
+            .context_links
+            .iter()
+            .flat_map(|(from, links)| links.iter().map(move |(to, r)| (*from, *to, *r)))
+            .collect();
+        links.sort_by_key(|(from, to, _)| (node_name(from), node_name(to)));
+        links
+    }
+}
+
+fn write_dot_graph<D, S, T, ST, V>(
+    dot: &mut String,
+    graph: &ExtraContext<D, S, T, ST, V>,
+    context: Option<u64>,
+    indent: &str,
+) where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    for (index, contextoid) in graph.get_all_indexed_nodes() {
+        writeln!(
+            dot,
+            "{}{} [label=\"{}\"];",
+            indent,
+            graph_node_name(context, index),
+            escape_dot(&node_label(contextoid))
+        )
+        .expect("Failed to write DOT");
+    }
+
+    for (a, b, relation_kind) in relation_edges(graph) {
+        writeln!(
+            dot,
+            "{}{} -> {} [label=\"{}\"];",
+            indent,
+            graph_node_name(context, a),
+            graph_node_name(context, b),
+            relation_kind
+        )
+        .expect("Failed to write DOT");
+    }
+}
+
+fn write_mermaid_graph<D, S, T, ST, V>(
+    mermaid: &mut String,
+    graph: &ExtraContext<D, S, T, ST, V>,
+    context: Option<u64>,
+    indent: &str,
+) where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    for (index, contextoid) in graph.get_all_indexed_nodes() {
+        writeln!(
+            mermaid,
+            "{}{}([\"{}\"])",
+            indent,
+            graph_node_name(context, index),
+            escape_mermaid(&node_label(contextoid))
+        )
+        .expect("Failed to write Mermaid");
+    }
+
+    for (a, b, relation_kind) in relation_edges(graph) {
+        writeln!(
+            mermaid,
+            "{}{} -->|{}| {}",
+            indent,
+            graph_node_name(context, a),
+            relation_kind,
+            graph_node_name(context, b)
+        )
+        .expect("Failed to write Mermaid");
+    }
+}
+
+// Edges sorted by their nodes so that exports are deterministic.
+fn relation_edges<D, S, T, ST, V>(graph: &ExtraContext<D, S, T, ST, V>) -> Vec<RelationEdge>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    let mut edges: Vec<_> = graph
+        .get_all_edges()
+        .into_iter()
+        .filter_map(|(a, b)| query::edge_relation(graph, a, b).map(|r| (a, b, r)))
+        .collect();
+    edges.sort_by_key(|(a, b, _)| (*a, *b));
+    edges
+}
+
+fn node_label<D, S, T, ST, V>(contextoid: &Contextoid<D, S, T, ST, V>) -> String
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    let node_type = match contextoid.vertex_type() {
+        ContextoidType::Datoid(_) => "Datoid",
+        ContextoidType::Tempoid(_) => "Tempoid",
+        ContextoidType::Root(_) => "Root",
+        ContextoidType::Spaceoid(_) => "Spaceoid",
+        ContextoidType::SpaceTempoid(_) => "SpaceTempoid",
+        ContextoidType::_Unreachable(_) => "Unknown",
+    };
+    format!("{}\nid: {}", node_type, contextoid.id())
+}
+
+// Base context nodes are named b<index>, extra context nodes x<context id>_<index>.
+fn graph_node_name(context: Option<u64>, index: usize) -> String {
+    match context {
+        None => format!("b{}", index),
+        Some(id) => format!("x{}_{}", id, index),
+    }
+}
+
+fn node_name(node: &ContextNodeRef) -> String {
+    graph_node_name(node.context().map(|handle| handle.id()), node.index())
+}
@@ -24,7 +24,7 @@ where
             self.extra_contexts = Some(HashMap::new());
         }
 
-        let new_context = ultragraph::new_with_matrix_storage(capacity);
+        let new_context = ultragraph::with_edge_data(capacity);
 
         self.number_of_extra_contexts += 1;
 
@@ -41,7 +41,9 @@ where
     }
 
     fn extra_ctx_check_exists(&self, idx: u64) -> bool {
-        idx <= self.number_of_extra_contexts
+        self.extra_contexts
+            .as_ref()
+            .is_some_and(|contexts| contexts.contains_key(&idx))
     }
 
     fn extra_ctx_get_current_id(&self) -> u64 {
@@ -68,10 +70,7 @@ where
         &mut self,
         value: Contextoid<D, S, T, ST, V>,
     ) -> Result<usize, ContextIndexError> {
-        return match self.get_current_extra_context_mut() {
-            Ok(ctx) => Ok(ctx.add_node(value)),
-            Err(e) => Err(e),
-        };
+        self.extra_ctx_add_node_to(self.extra_context_id, value)
     }
 
     fn extra_ctx_contains_node(&self, index: usize) -> bool {
@@ -98,13 +97,15 @@ where
     }
 
     fn extra_ctx_remove_node(&mut self, index: usize) -> Result<(), ContextIndexError> {
-        return match self.get_current_extra_context_mut() {
-            Ok(ctx) => match ctx.remove_node(index) {
-                Ok(()) => Ok(()),
-                Err(e) => Err(ContextIndexError::new(e.to_string())),
-            },
-            Err(e) => Err(e),
-        };
+        self.extra_ctx_remove_node_from(self.extra_context_id, index)
+    }
+
+    fn extra_ctx_update_node(
+        &mut self,
+        index: usize,
+        value: Contextoid<D, S, T, ST, V>,
+    ) -> Result<(), ContextIndexError> {
+        self.extra_ctx_update_node_in(self.extra_context_id, index, value)
     }
 
     fn extra_ctx_add_edge(
@@ -113,21 +114,7 @@ where
         b: usize,
         weight: RelationKind,
     ) -> Result<(), ContextIndexError> {
-        if !self.extra_ctx_contains_node(a) {
-            return Err(ContextIndexError(format!("index a {} not found", a)));
-        };
-
-        if !self.extra_ctx_contains_node(b) {
-            return Err(ContextIndexError(format!("index b {} not found", b)));
-        };
-
-        return match self.get_current_extra_context_mut() {
-            Ok(ctx) => match ctx.add_edge_with_weight(a, b, weight as u64) {
-                Ok(()) => Ok(()),
-                Err(e) => Err(ContextIndexError::new(e.to_string())),
-            },
-            Err(e) => Err(e),
-        };
+        self.extra_ctx_add_edge_to(self.extra_context_id, a, b, weight, EdgeAttributes::new())
     }
 
     fn extra_ctx_contains_edge(&self, a: usize, b: usize) -> bool {
@@ -146,21 +133,7 @@ where
     }
 
     fn extra_ctx_remove_edge(&mut self, a: usize, b: usize) -> Result<(), ContextIndexError> {
-        if !self.extra_ctx_contains_node(a) {
-            return Err(ContextIndexError("index a not found".into()));
-        };
-
-        if !self.extra_ctx_contains_node(b) {
-            return Err(ContextIndexError("index b not found".into()));
-        };
-
-        return match self.get_current_extra_context_mut() {
-            Ok(ctx) => match ctx.remove_edge(a, b) {
-                Ok(()) => Ok(()),
-                Err(e) => Err(ContextIndexError::new(e.to_string())),
-            },
-            Err(e) => Err(e),
-        };
+        self.extra_ctx_remove_edge_from(self.extra_context_id, a, b)
     }
 
     fn extra_ctx_size(&self) -> Result<usize, ContextIndexError> {
@@ -190,6 +163,58 @@ where
             Err(e) => Err(e),
         };
     }
+
+    fn extra_ctx_get_neighbors(
+        &self,
+        index: usize,
+        relation_kind: Option<RelationKind>,
+    ) -> Result<Vec<usize>, ContextIndexError> {
+        let ctx = self.get_current_extra_context()?;
+        query::neighbors(ctx, index, relation_kind)
+    }
+
+    fn extra_ctx_get_edge_relation(
+        &self,
+        a: usize,
+        b: usize,
+    ) -> Result<Option<RelationKind>, ContextIndexError> {
+        let ctx = self.get_current_extra_context()?;
+        Ok(query::edge_relation(ctx, a, b))
+    }
+
+    fn extra_ctx_get_node_index_by_id(&self, id: u64) -> Result<Option<usize>, ContextIndexError> {
+        let ctx = self.get_current_extra_context()?;
+        Ok(query::node_index_by_id(ctx, id))
+    }
+
+    fn extra_ctx_get_all_datoids(&self) -> Result<Vec<(usize, &D)>, ContextIndexError> {
+        let ctx = self.get_current_extra_context()?;
+        Ok(query::filter_nodes(ctx, ContextoidType::dataoid))
+    }
+
+    fn extra_ctx_get_all_tempoids(&self) -> Result<Vec<(usize, &T)>, ContextIndexError> {
+        let ctx = self.get_current_extra_context()?;
+        Ok(query::filter_nodes(ctx, ContextoidType::tempoid))
+    }
+
+    fn extra_ctx_get_all_spaceoids(&self) -> Result<Vec<(usize, &S)>, ContextIndexError> {
+        let ctx = self.get_current_extra_context()?;
+        Ok(query::filter_nodes(ctx, ContextoidType::spaceiod))
+    }
+
+    fn extra_ctx_get_all_space_tempoids(&self) -> Result<Vec<(usize, &ST)>, ContextIndexError> {
+        let ctx = self.get_current_extra_context()?;
+        Ok(query::filter_nodes(ctx, ContextoidType::space_tempoid))
+    }
+
+    fn extra_ctx_get_shortest_path(
+        &self,
+        start_index: usize,
+        stop_index: usize,
+    ) -> Result<Vec<usize>, ContextIndexError> {
+        let ctx = self.get_current_extra_context()?;
+        query::shortest_path(ctx, start_index, stop_index)
+    }
 }
 
 impl<'l, D, S, T, ST, V> Context<D, S, T, ST, V>
@@ -211,46 +236,6 @@ where
     fn get_current_extra_context(
         &self,
     ) -> Result<&ExtraContext<D, S, T, ST, V>, ContextIndexError> {
-        if self.extra_context_id == 0 {
-            return Err(ContextIndexError::new("context ID not set".into()));
-        }
-
-        if !self.extra_ctx_check_exists(self.extra_context_id) {
-            return Err(ContextIndexError::new("context does not exists".into()));
-        }
-
-        let ctx = self
-            .extra_contexts
-            .as_ref()
-            .expect("Failed to get a reference to extra_contexts")
-            .get(&self.extra_context_id);
-
-        match ctx {
-            None => Err(ContextIndexError::new("context does not exists".into())),
-            Some(ctx) => Ok(ctx),
-        }
-    }
-
-    fn get_current_extra_context_mut(
-        &mut self,
-    ) -> Result<&mut ExtraContext<D, S, T, ST, V>, ContextIndexError> {
-        if self.extra_context_id == 0 {
-            return Err(ContextIndexError::new("context ID not set".into()));
-        }
-
-        if !self.extra_ctx_check_exists(self.extra_context_id) {
-            return Err(ContextIndexError::new("context does not exists".into()));
-        }
-
-        let ctx = self
-            .extra_contexts
-            .as_mut()
-            .expect("Failed to get a reference to extra_contexts")
-            .get_mut(&self.extra_context_id);
-
-        match ctx {
-            None => Err(ContextIndexError::new("context does not exists".into())),
-            Some(ctx) => Ok(ctx),
-        }
+        self.get_extra_context(self.extra_context_id)
     }
 }
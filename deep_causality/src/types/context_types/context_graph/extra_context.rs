@@ -0,0 +1,484 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+
+use super::*;
+
+/// Handle to an extra context of a Context.
+///
+/// Unlike the current extra context id used by ExtendableContextuableGraph,
+/// a handle addresses its extra context explicitly and does not depend on mutable state.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtraContextHandle(u64);
+
+impl ExtraContextHandle {
+    /// Returns the id of the extra context.
+    pub fn id(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for ExtraContextHandle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ExtraContextHandle: {}", self.0)
+    }
+}
+
+/// Read access to one extra context of a Context.
+pub struct ExtraContextView<'a, D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    handle: ExtraContextHandle,
+    graph: &'a ExtraContext<D, S, T, ST, V>,
+}
+
+impl<'a, D, S, T, ST, V> ExtraContextView<'a, D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    pub fn handle(&self) -> ExtraContextHandle {
+        self.handle
+    }
+
+    pub fn contains_node(&self, index: usize) -> bool {
+        self.graph.contains_node(index)
+    }
+
+    pub fn get_node(&self, index: usize) -> Option<&'a Contextoid<D, S, T, ST, V>> {
+        self.graph.get_node(index)
+    }
+
+    pub fn contains_edge(&self, a: usize, b: usize) -> bool {
+        self.graph.contains_edge(a, b)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.graph.number_nodes()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.graph.number_edges()
+    }
+
+    pub fn get_neighbors(
+        &self,
+        index: usize,
+        relation_kind: Option<RelationKind>,
+    ) -> Result<Vec<usize>, ContextIndexError> {
+        query::neighbors(self.graph, index, relation_kind)
+    }
+
+    pub fn get_edge_relation(&self, a: usize, b: usize) -> Option<RelationKind> {
+        query::edge_relation(self.graph, a, b)
+    }
+
+    pub fn get_edge(&self, a: usize, b: usize) -> Option<&'a ContextEdge> {
+        self.graph.get_edge_data(a, b)
+    }
+
+    pub fn get_node_index_by_id(&self, id: u64) -> Option<usize> {
+        query::node_index_by_id(self.graph, id)
+    }
+
+    pub fn get_node_by_id(&self, id: u64) -> Option<&'a Contextoid<D, S, T, ST, V>> {
+        self.get_node_index_by_id(id)
+            .and_then(|index| self.get_node(index))
+    }
+
+    pub fn get_shortest_path(
+        &self,
+        start_index: usize,
+        stop_index: usize,
+    ) -> Result<Vec<usize>, ContextIndexError> {
+        query::shortest_path(self.graph, start_index, stop_index)
+    }
+}
+
+/// Write access to one extra context of a Context.
+/// All changes increase the version of the context and notify its subscribers.
+pub struct ExtraContextViewMut<'a, D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    handle: ExtraContextHandle,
+    context: &'a mut Context<D, S, T, ST, V>,
+}
+
+impl<D, S, T, ST, V> ExtraContextViewMut<'_, D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    pub fn handle(&self) -> ExtraContextHandle {
+        self.handle
+    }
+
+    pub fn add_node(
+        &mut self,
+        value: Contextoid<D, S, T, ST, V>,
+    ) -> Result<usize, ContextIndexError> {
+        self.context.extra_ctx_add_node_to(self.handle.0, value)
+    }
+
+    pub fn remove_node(&mut self, index: usize) -> Result<(), ContextIndexError> {
+        self.context
+            .extra_ctx_remove_node_from(self.handle.0, index)
+    }
+
+    pub fn update_node(
+        &mut self,
+        index: usize,
+        value: Contextoid<D, S, T, ST, V>,
+    ) -> Result<(), ContextIndexError> {
+        self.context
+            .extra_ctx_update_node_in(self.handle.0, index, value)
+    }
+
+    pub fn add_edge(
+        &mut self,
+        a: usize,
+        b: usize,
+        weight: RelationKind,
+    ) -> Result<(), ContextIndexError> {
+        self.add_edge_with_attributes(a, b, weight, EdgeAttributes::new())
+    }
+
+    pub fn add_edge_with_attributes(
+        &mut self,
+        a: usize,
+        b: usize,
+        relation_kind: RelationKind,
+        attributes: EdgeAttributes,
+    ) -> Result<(), ContextIndexError> {
+        self.context
+            .extra_ctx_add_edge_to(self.handle.0, a, b, relation_kind, attributes)
+    }
+
+    pub fn remove_edge(&mut self, a: usize, b: usize) -> Result<(), ContextIndexError> {
+        self.context.extra_ctx_remove_edge_from(self.handle.0, a, b)
+    }
+
+    pub fn contains_node(&self, index: usize) -> bool {
+        self.view().contains_node(index)
+    }
+
+    pub fn get_node(&self, index: usize) -> Option<&Contextoid<D, S, T, ST, V>> {
+        self.context
+            .get_extra_context(self.handle.0)
+            .ok()
+            .and_then(|graph| graph.get_node(index))
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.view().node_count()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.view().edge_count()
+    }
+
+    /// Returns read access to the extra context for queries.
+    pub fn view(&self) -> ExtraContextView<'_, D, S, T, ST, V> {
+        ExtraContextView {
+            handle: self.handle,
+            graph: self
+                .context
+                .get_extra_context(self.handle.0)
+                .expect("Extra context of a valid handle must exist"),
+        }
+    }
+}
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Creates a new extra context with a unique name and returns its handle.
+    /// The current extra context id remains unchanged.
+    /// Returns ContextIndexError if the name is already in use.
+    pub fn extra_ctx_add_named(
+        &mut self,
+        name: &str,
+        capacity: usize,
+    ) -> Result<ExtraContextHandle, ContextIndexError> {
+        if self.extra_context_names.contains_key(name) {
+            return Err(ContextIndexError(format!(
+                "extra context {} already exists",
+                name
+            )));
+        }
+
+        let id = self.extra_ctx_add_new(capacity, false);
+        self.extra_context_names.insert(name.to_string(), id);
+        Ok(ExtraContextHandle(id))
+    }
+
+    /// Returns the handle of the extra context with the given name.
+    pub fn extra_ctx_handle(&self, name: &str) -> Option<ExtraContextHandle> {
+        self.extra_context_names
+            .get(name)
+            .map(|id| ExtraContextHandle(*id))
+    }
+
+    /// Returns the handle of the extra context with the given id,
+    /// e.g. as returned by extra_ctx_add_new.
+    pub fn extra_ctx_handle_by_id(&self, id: u64) -> Option<ExtraContextHandle> {
+        if self.extra_ctx_check_exists(id) {
+            Some(ExtraContextHandle(id))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the name of the extra context, or None if it has no name.
+    pub fn extra_ctx_name(&self, handle: ExtraContextHandle) -> Option<&str> {
+        self.extra_context_names
+            .iter()
+            .find(|(_, id)| **id == handle.0)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the handles of all extra contexts, ordered by id.
+    pub fn extra_ctx_handles(&self) -> Vec<ExtraContextHandle> {
+        let mut handles: Vec<ExtraContextHandle> = match self.extra_contexts.as_ref() {
+            Some(contexts) => contexts.keys().map(|id| ExtraContextHandle(*id)).collect(),
+            None => Vec::new(),
+        };
+        handles.sort_unstable();
+        handles
+    }
+
+    /// Returns read access to the extra context of the handle.
+    /// Returns ContextIndexError if the extra context does not exist.
+    pub fn extra_ctx(
+        &self,
+        handle: ExtraContextHandle,
+    ) -> Result<ExtraContextView<'_, D, S, T, ST, V>, ContextIndexError> {
+        Ok(ExtraContextView {
+            handle,
+            graph: self.get_extra_context(handle.0)?,
+        })
+    }
+
+    /// Returns write access to the extra context of the handle.
+    /// Returns ContextIndexError if the extra context does not exist.
+    pub fn extra_ctx_mut(
+        &mut self,
+        handle: ExtraContextHandle,
+    ) -> Result<ExtraContextViewMut<'_, D, S, T, ST, V>, ContextIndexError> {
+        self.get_extra_context(handle.0)?;
+        Ok(ExtraContextViewMut {
+            handle,
+            context: self,
+        })
+    }
+
+    /// Removes the extra context of the handle together with its name and all links
+    /// from or to its nodes. If it is the current extra context, the current id is unset.
+    /// Ids of removed extra contexts are never reused.
+    /// Returns ContextIndexError if the extra context does not exist.
+    pub fn extra_ctx_remove(
+        &mut self,
+        handle: ExtraContextHandle,
+    ) -> Result<(), ContextIndexError> {
+        self.get_extra_context(handle.0)?;
+
+        if let Some(contexts) = self.extra_contexts.as_mut() {
+            contexts.remove(&handle.0);
+        }
+
+        self.extra_context_names.retain(|_, id| *id != handle.0);
+        self.remove_context_links_of_context(Some(handle));
+
+        if self.extra_context_id == handle.0 {
+            self.extra_context_id = 0;
+        }
+
+        self.increment_version();
+        Ok(())
+    }
+
+    pub(super) fn get_extra_context(
+        &self,
+        id: u64,
+    ) -> Result<&ExtraContext<D, S, T, ST, V>, ContextIndexError> {
+        if id == 0 {
+            return Err(ContextIndexError::new("context ID not set".into()));
+        }
+
+        self.extra_contexts
+            .as_ref()
+            .and_then(|contexts| contexts.get(&id))
+            .ok_or_else(|| ContextIndexError::new("context does not exists".into()))
+    }
+
+    pub(super) fn get_extra_context_mut(
+        &mut self,
+        id: u64,
+    ) -> Result<&mut ExtraContext<D, S, T, ST, V>, ContextIndexError> {
+        if id == 0 {
+            return Err(ContextIndexError::new("context ID not set".into()));
+        }
+
+        self.extra_contexts
+            .as_mut()
+            .and_then(|contexts| contexts.get_mut(&id))
+            .ok_or_else(|| ContextIndexError::new("context does not exists".into()))
+    }
+
+    pub(super) fn extra_ctx_add_node_to(
+        &mut self,
+        id: u64,
+        value: Contextoid<D, S, T, ST, V>,
+    ) -> Result<usize, ContextIndexError> {
+        let contextoid_id = value.id();
+        let index = self.get_extra_context_mut(id)?.add_node(value);
+
+        self.notify(ContextEventKind::Added, Some(id), index, contextoid_id);
+        Ok(index)
+    }
+
+    pub(super) fn extra_ctx_remove_node_from(
+        &mut self,
+        id: u64,
+        index: usize,
+    ) -> Result<(), ContextIndexError> {
+        let ctx = self.get_extra_context_mut(id)?;
+        let contextoid_id = ctx.get_node(index).map(|n| n.id()).unwrap_or_default();
+        ctx.remove_node(index)
+            .map_err(|e| ContextIndexError::new(e.to_string()))?;
+
+        self.remove_context_links_of(ContextNodeRef::extra(ExtraContextHandle(id), index));
+        self.notify(ContextEventKind::Removed, Some(id), index, contextoid_id);
+        Ok(())
+    }
+
+    pub(super) fn extra_ctx_update_node_in(
+        &mut self,
+        id: u64,
+        index: usize,
+        value: Contextoid<D, S, T, ST, V>,
+    ) -> Result<(), ContextIndexError> {
+        let contextoid_id = value.id();
+        self.get_extra_context_mut(id)?
+            .update_node(index, value)
+            .map_err(|e| ContextIndexError::new(e.to_string()))?;
+
+        self.notify(ContextEventKind::Adjusted, Some(id), index, contextoid_id);
+        Ok(())
+    }
+
+    pub(super) fn extra_ctx_add_edge_to(
+        &mut self,
+        id: u64,
+        a: usize,
+        b: usize,
+        weight: RelationKind,
+        attributes: EdgeAttributes,
+    ) -> Result<(), ContextIndexError> {
+        let ctx = self.get_extra_context_mut(id)?;
+
+        if !ctx.contains_node(a) {
+            return Err(ContextIndexError(format!("index a {} not found", a)));
+        };
+
+        if !ctx.contains_node(b) {
+            return Err(ContextIndexError(format!("index b {} not found", b)));
+        };
+
+        ctx.add_edge_with_data(a, b, EDGE_WEIGHT, ContextEdge::new(weight, attributes))
+            .map_err(|e| ContextIndexError::new(e.to_string()))?;
+
+        self.increment_version();
+        Ok(())
+    }
+
+    pub(super) fn extra_ctx_remove_edge_from(
+        &mut self,
+        id: u64,
+        a: usize,
+        b: usize,
+    ) -> Result<(), ContextIndexError> {
+        let ctx = self.get_extra_context_mut(id)?;
+
+        if !ctx.contains_node(a) {
+            return Err(ContextIndexError("index a not found".into()));
+        };
+
+        if !ctx.contains_node(b) {
+            return Err(ContextIndexError("index b not found".into()));
+        };
+
+        ctx.remove_edge(a, b)
+            .map_err(|e| ContextIndexError::new(e.to_string()))?;
+
+        self.increment_version();
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use super::*;
+
+/// Free-form attributes of a hyperedge, e.g. the name or source of an event.
+pub type HyperedgeAttributes = HashMap<String, String>;
+
+/// Relation between two or more contextoids of the base context.
+pub struct ContextHyperedge<'a> {
+    index: usize,
+    nodes: &'a [usize],
+    data: &'a ContextEdge,
+}
+
+impl<'a> ContextHyperedge<'a> {
+    /// Returns the index of the hyperedge.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the indices of all connected contextoids in insertion order.
+    pub fn nodes(&self) -> &'a [usize] {
+        self.nodes
+    }
+
+    pub fn relation_kind(&self) -> RelationKind {
+        self.data.relation_kind()
+    }
+
+    pub fn attributes(&self) -> &'a HyperedgeAttributes {
+        self.data.attributes()
+    }
+
+    /// Returns the attribute value for the key, if any.
+    pub fn attribute(&self, key: &str) -> Option<&'a str> {
+        self.data.attribute(key)
+    }
+}
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Adds a hyperedge connecting two or more distinct contextoids of the base context
+    /// and returns its index. Hyperedges are removed together with any of their contextoids.
+    /// Returns ContextIndexError if fewer than two nodes are given,
+    /// a node is not found, or a node is given more than once.
+    pub fn add_hyperedge(
+        &mut self,
+        nodes: &[usize],
+        relation_kind: RelationKind,
+        attributes: HyperedgeAttributes,
+    ) -> Result<usize, ContextIndexError> {
+        let index = self
+            .base_context_mut()
+            .add_hyperedge(nodes, EDGE_WEIGHT)
+            .map_err(|e| ContextIndexError(e.to_string()))?;
+
+        self.hyperedge_data
+            .insert(index, ContextEdge::new(relation_kind, attributes));
+        self.increment_version();
+        Ok(index)
+    }
+
+    /// Returns only true if the context contains the hyperedge with the given index.
+    pub fn contains_hyperedge(&self, index: usize) -> bool {
+        self.base_context.contains_hyperedge(index)
+    }
+
+    /// Returns the hyperedge with the given index, or None if it does not exist.
+    pub fn get_hyperedge(&self, index: usize) -> Option<ContextHyperedge<'_>> {
+        let edge = self.base_context.get_hyperedge(index)?;
+        let data = self.hyperedge_data.get(&index)?;
+
+        Some(ContextHyperedge {
+            index,
+            nodes: edge.nodes(),
+            data,
+        })
+    }
+
+    /// Removes the hyperedge with the given index.
+    /// Returns ContextIndexError if the index is not found.
+    pub fn remove_hyperedge(&mut self, index: usize) -> Result<(), ContextIndexError> {
+        self.base_context_mut()
+            .remove_hyperedge(index)
+            .map_err(|e| ContextIndexError(e.to_string()))?;
+
+        self.hyperedge_data.remove(&index);
+        self.increment_version();
+        Ok(())
+    }
+
+    /// Returns the indices of all hyperedges the contextoid takes part in,
+    /// optionally only those of the given relation kind, in ascending order.
+    /// Returns ContextIndexError if the index is not found.
+    pub fn get_hyperedges_of_node(
+        &self,
+        index: usize,
+        relation_kind: Option<RelationKind>,
+    ) -> Result<Vec<usize>, ContextIndexError> {
+        if !self.base_context.contains_node(index) {
+            return Err(ContextIndexError(format!("index {} not found", index)));
+        }
+
+        let edges = self.base_context.get_hyperedges_of_node(index);
+        Ok(match relation_kind {
+            None => edges,
+            Some(kind) => edges
+                .into_iter()
+                .filter(|e| {
+                    self.get_hyperedge(*e)
+                        .is_some_and(|edge| edge.relation_kind() == kind)
+                })
+                .collect(),
+        })
+    }
+
+    /// Returns all contextoids sharing at least one hyperedge with the contextoid,
+    /// in ascending order and without the contextoid itself.
+    /// Returns ContextIndexError if the index is not found.
+    pub fn get_hyperedge_neighbors(
+        &self,
+        index: usize,
+        relation_kind: Option<RelationKind>,
+    ) -> Result<Vec<usize>, ContextIndexError> {
+        let mut result: Vec<usize> = self
+            .get_hyperedges_of_node(index, relation_kind)?
+            .into_iter()
+            .filter_map(|e| self.base_context.get_hyperedge(e))
+            .flat_map(|edge| edge.nodes().iter().copied())
+            .filter(|n| *n != index)
+            .collect();
+
+        result.sort_unstable();
+        result.dedup();
+        Ok(result)
+    }
+
+    /// Returns the number of hyperedges in the context.
+    pub fn hyperedge_count(&self) -> usize {
+        self.base_context.number_hyperedges()
+    }
+
+    // Drops the payload of all hyperedges the storage removed together with the node.
+    pub(super) fn remove_hyperedge_data_of(&mut self, hyperedges: &[usize]) {
+        for index in hyperedges {
+            self.hyperedge_data.remove(index);
+        }
+    }
+}
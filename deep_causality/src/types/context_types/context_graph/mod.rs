@@ -4,21 +4,47 @@
 use std::collections::HashMap;
 use std::hash::Hash;
 use std::ops::*;
+use std::sync::Arc;
 
 use ultragraph::prelude::*;
 
 use crate::prelude::*;
 
+mod adjustment;
+mod context_link;
 mod contextuable_graph;
 mod debug;
+mod edge;
+mod export;
 mod extendable_contextuable_graph;
+mod extra_context;
+mod hyperedge;
 mod identifiable;
 mod indexable;
+mod query;
+mod snapshot;
+mod spatial_index;
+#[cfg(feature = "serde")]
+mod spec;
+mod time_index;
 
-type ExtraContext<D, S, T, ST, V> = UltraGraph<Contextoid<D, S, T, ST, V>>;
+pub use adjustment::NodeAdjustment;
+pub use context_link::ContextNodeRef;
+pub use edge::{ContextEdge, EdgeAttributes};
+pub use extra_context::{ExtraContextHandle, ExtraContextView, ExtraContextViewMut};
+pub use hyperedge::{ContextHyperedge, HyperedgeAttributes};
+pub use snapshot::{ContextSnapshot, ContextSubscriber};
+#[cfg(feature = "serde")]
+pub use spec::ContextSpec;
+
+// Edges carry their RelationKind in the ContextEdge payload.
+type ExtraContext<D, S, T, ST, V> = UltraGraph<Contextoid<D, S, T, ST, V>, ContextEdge>;
 
 type ExtraContextMap<D, S, T, ST, V> = HashMap<u64, ExtraContext<D, S, T, ST, V>>;
 
+// Weight of all edges and hyperedges, the same as for edges added without a weight.
+const EDGE_WEIGHT: u64 = 0;
+
 pub struct Context<D, S, T, ST, V>
 where
     D: Datable,
@@ -37,12 +63,24 @@ where
 {
     id: u64,
     name: String,
-    base_context: UltraGraph<Contextoid<D, S, T, ST, V>>,
+    // Shared with snapshots and copied on the first change after a snapshot.
+    base_context: Arc<ExtraContext<D, S, T, ST, V>>,
     extra_contexts: Option<ExtraContextMap<D, S, T, ST, V>>,
     number_of_extra_contexts: u64,
     extra_context_id: u64,
+    extra_context_names: HashMap<String, u64>,
+    context_links: context_link::ContextLinks,
+    // Payload of each hyperedge of the base context by hyperedge index.
+    hyperedge_data: HashMap<usize, ContextEdge>,
     current_index_map: HashMap<usize, usize>,
     previous_index_map: HashMap<usize, usize>,
+    time_index: Option<time_index::TimeIndex<V>>,
+    spatial_index: Option<spatial_index::SpatialIndex<V>>,
+    version: u64,
+    subscribers: snapshot::ContextSubscribers,
+    next_subscriber_id: u64,
+    snapshot_cache: snapshot::SnapshotCache<D, S, T, ST, V>,
+    adjustment_log: adjustment::AdjustmentLog<D, S, T, ST, V>,
 }
 
 impl<D, S, T, ST, V> Context<D, S, T, ST, V>
@@ -66,12 +104,22 @@ where
         Self {
             id,
             name: name.to_string(),
-            base_context: ultragraph::new_with_matrix_storage(capacity),
+            base_context: Arc::new(ultragraph::with_edge_data(capacity)),
             extra_contexts: None,
             number_of_extra_contexts: 0,
             extra_context_id: 0,
+            extra_context_names: HashMap::new(),
+            context_links: HashMap::new(),
+            hyperedge_data: HashMap::new(),
             current_index_map: HashMap::new(),
             previous_index_map: HashMap::new(),
+            time_index: None,
+            spatial_index: None,
+            version: 0,
+            subscribers: Vec::new(),
+            next_subscriber_id: 0,
+            snapshot_cache: std::sync::Mutex::new(None),
+            adjustment_log: Vec::new(),
         }
     }
 
@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::VecDeque;
+
+use super::*;
+
+// Query helpers shared by the base context and all extra contexts.
+
+pub(super) fn edge_relation<D, S, T, ST, V>(
+    graph: &ExtraContext<D, S, T, ST, V>,
+    a: usize,
+    b: usize,
+) -> Option<RelationKind>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    graph.get_edge_data(a, b).map(|edge| edge.relation_kind())
+}
+
+pub(super) fn neighbors<D, S, T, ST, V>(
+    graph: &ExtraContext<D, S, T, ST, V>,
+    index: usize,
+    relation_kind: Option<RelationKind>,
+) -> Result<Vec<usize>, ContextIndexError>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    let outgoing = match graph.outgoing_edges(index) {
+        Ok(outgoing) => outgoing,
+        Err(_) => return Err(ContextIndexError(format!("index {} not found", index))),
+    };
+
+    let mut result: Vec<usize> = match relation_kind {
+        None => outgoing.collect(),
+        Some(kind) => outgoing
+            .filter(|n| edge_relation(graph, index, *n) == Some(kind))
+            .collect(),
+    };
+
+    result.sort_unstable();
+    Ok(result)
+}
+
+pub(super) fn node_index_by_id<D, S, T, ST, V>(
+    graph: &ExtraContext<D, S, T, ST, V>,
+    id: u64,
+) -> Option<usize>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    graph
+        .get_all_indexed_nodes()
+        .into_iter()
+        .find(|(_, node)| node.id() == id)
+        .map(|(index, _)| index)
+}
+
+pub(super) fn filter_nodes<'a, D, S, T, ST, V, R, F>(
+    graph: &'a ExtraContext<D, S, T, ST, V>,
+    f: F,
+) -> Vec<(usize, &'a R)>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+    F: Fn(&'a ContextoidType<D, S, T, ST, V>) -> Option<&'a R>,
+{
+    graph
+        .get_all_indexed_nodes()
+        .into_iter()
+        .filter_map(|(index, node)| f(node.vertex_type()).map(|r| (index, r)))
+        .collect()
+}
+
+/// Breadth-first search, hence the returned path has the fewest possible edges.
+pub(super) fn shortest_path<D, S, T, ST, V>(
+    graph: &ExtraContext<D, S, T, ST, V>,
+    start_index: usize,
+    stop_index: usize,
+) -> Result<Vec<usize>, ContextIndexError>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    if !graph.contains_node(start_index) {
+        return Err(ContextIndexError(format!(
+            "start index {} not found",
+            start_index
+        )));
+    };
+
+    if !graph.contains_node(stop_index) {
+        return Err(ContextIndexError(format!(
+            "stop index {} not found",
+            stop_index
+        )));
+    };
+
+    let mut previous: HashMap<usize, usize> = HashMap::new();
+    let mut queue = VecDeque::from([start_index]);
+
+    while let Some(current) = queue.pop_front() {
+        if current == stop_index {
+            let mut path = vec![stop_index];
+            let mut node = stop_index;
+            while let Some(prev) = previous.get(&node) {
+                path.push(*prev);
+                node = *prev;
+            }
+            path.reverse();
+            return Ok(path);
+        }
+
+        let outgoing = graph
+            .outgoing_edges(current)
+            .map_err(|e| ContextIndexError(e.to_string()))?;
+
+        for next in outgoing {
+            if next != start_index && !previous.contains_key(&next) {
+                previous.insert(next, current);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Err(ContextIndexError(format!(
+        "No path found between index {} and {}",
+        start_index, stop_index
+    )))
+}
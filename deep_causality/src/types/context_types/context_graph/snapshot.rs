@@ -0,0 +1,261 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::sync::{Arc, Mutex};
+
+use super::*;
+
+/// Callback invoked for every ContextEvent.
+pub type ContextSubscriber = Box<dyn Fn(&ContextEvent) + Send + Sync>;
+
+pub(super) type ContextSubscribers = Vec<(u64, ContextSubscriber)>;
+
+pub(super) type SnapshotCache<D, S, T, ST, V> = Mutex<Option<Arc<ContextSnapshot<D, S, T, ST, V>>>>;
+
+/// Immutable view of the base context at a given version.
+///
+/// Snapshots share the graph with the context, hence taking a snapshot is O(1).
+/// The context copies the graph on the first change after a snapshot
+/// as long as the snapshot is still in use. Without changes in between,
+/// every call to Context::snapshot returns the same Arc.
+pub struct ContextSnapshot<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    id: u64,
+    name: String,
+    version: u64,
+    base_context: Arc<ExtraContext<D, S, T, ST, V>>,
+}
+
+impl<D, S, T, ST, V> ContextSnapshot<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the version of the context this snapshot was taken from.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn contains_node(&self, index: usize) -> bool {
+        self.base_context.contains_node(index)
+    }
+
+    pub fn get_node(&self, index: usize) -> Option<&Contextoid<D, S, T, ST, V>> {
+        self.base_context.get_node(index)
+    }
+
+    pub fn contains_edge(&self, a: usize, b: usize) -> bool {
+        self.base_context.contains_edge(a, b)
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.base_context.number_nodes()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.base_context.number_edges()
+    }
+
+    pub fn get_neighbors(
+        &self,
+        index: usize,
+        relation_kind: Option<RelationKind>,
+    ) -> Result<Vec<usize>, ContextIndexError> {
+        query::neighbors(&self.base_context, index, relation_kind)
+    }
+
+    pub fn get_edge_relation(&self, a: usize, b: usize) -> Option<RelationKind> {
+        query::edge_relation(&self.base_context, a, b)
+    }
+
+    pub fn get_edge(&self, a: usize, b: usize) -> Option<&ContextEdge> {
+        self.base_context.get_edge_data(a, b)
+    }
+
+    pub fn get_node_index_by_id(&self, id: u64) -> Option<usize> {
+        query::node_index_by_id(&self.base_context, id)
+    }
+
+    pub fn get_node_by_id(&self, id: u64) -> Option<&Contextoid<D, S, T, ST, V>> {
+        self.get_node_index_by_id(id)
+            .and_then(|index| self.get_node(index))
+    }
+
+    pub fn get_shortest_path(
+        &self,
+        start_index: usize,
+        stop_index: usize,
+    ) -> Result<Vec<usize>, ContextIndexError> {
+        query::shortest_path(&self.base_context, start_index, stop_index)
+    }
+}
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Returns an immutable snapshot of the base context at the current version.
+    /// Extra contexts are not part of the snapshot.
+    /// Repeated calls without changes in between return the same shared snapshot.
+    pub fn snapshot(&self) -> Arc<ContextSnapshot<D, S, T, ST, V>> {
+        let mut cache = self
+            .snapshot_cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if let Some(snapshot) = cache.as_ref() {
+            if snapshot.version == self.version {
+                return Arc::clone(snapshot);
+            }
+        }
+
+        let snapshot = Arc::new(ContextSnapshot {
+            id: self.id,
+            name: self.name.clone(),
+            version: self.version,
+            base_context: Arc::clone(&self.base_context),
+        });
+
+        *cache = Some(Arc::clone(&snapshot));
+        snapshot
+    }
+
+    /// Returns the base context for a change.
+    /// Copies the graph first if a snapshot still shares it.
+    pub(super) fn base_context_mut(&mut self) -> &mut ExtraContext<D, S, T, ST, V> {
+        // The cached snapshot is outdated by the change. Dropping it first
+        // avoids the copy when no one else holds the snapshot.
+        *self
+            .snapshot_cache
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+
+        Arc::make_mut(&mut self.base_context)
+    }
+}
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Returns the version of the context.
+    /// The version increases with every change to nodes or edges,
+    /// in the base context and in all extra contexts.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Registers a subscriber that is called whenever a contextoid is added,
+    /// removed, or adjusted. Returns the subscription id required to unsubscribe.
+    pub fn subscribe<F>(&mut self, subscriber: F) -> u64
+    where
+        F: Fn(&ContextEvent) + Send + Sync + 'static,
+    {
+        self.next_subscriber_id += 1;
+        self.subscribers
+            .push((self.next_subscriber_id, Box::new(subscriber)));
+        self.next_subscriber_id
+    }
+
+    /// Removes the subscriber with the given subscription id.
+    /// Returns true if the subscriber existed.
+    pub fn unsubscribe(&mut self, subscription_id: u64) -> bool {
+        let len = self.subscribers.len();
+        self.subscribers.retain(|(id, _)| *id != subscription_id);
+        self.subscribers.len() != len
+    }
+
+    /// Returns the number of registered subscribers.
+    pub fn number_of_subscribers(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    /// Increases the version after a change that does not concern a contextoid, i.e. an edge.
+    pub(super) fn increment_version(&mut self) {
+        self.version += 1;
+    }
+
+    /// Increases the version and notifies all subscribers.
+    pub(super) fn notify(
+        &mut self,
+        kind: ContextEventKind,
+        extra_context_id: Option<u64>,
+        index: usize,
+        contextoid_id: u64,
+    ) {
+        self.increment_version();
+        self.emit(kind, extra_context_id, index, contextoid_id);
+    }
+
+    /// Notifies all subscribers without increasing the version.
+    /// Used when several changes share a single version increase.
+    pub(super) fn emit(
+        &self,
+        kind: ContextEventKind,
+        extra_context_id: Option<u64>,
+        index: usize,
+        contextoid_id: u64,
+    ) {
+        let event = ContextEvent::new(kind, self.version, extra_context_id, index, contextoid_id);
+        for (_, subscriber) in self.subscribers.iter() {
+            subscriber(&event);
+        }
+    }
+}
@@ -0,0 +1,456 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::cmp::Ordering;
+
+use crate::utils::math_utils::{euclidean_distance, geodesic_distance, EARTH_RADIUS_METERS};
+
+use super::*;
+
+// Converts a coordinate value into a float.
+// Captured when the index is enabled so that V needs no numeric conversion bound.
+type CoordinateFn<V> = fn(V) -> f64;
+
+type Point = [f64; 3];
+
+struct KdNode {
+    // Position in the tree; the coordinates for Euclidean,
+    // and earth centered cartesian coordinates for Geodesic.
+    point: Point,
+    // Coordinates as converted from x, y, z.
+    coordinates: Point,
+    index: usize,
+    removed: bool,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+/// k-d tree over the spatial nodes of a context.
+/// Removed nodes are marked and the tree is rebuilt balanced
+/// once they outnumber the remaining nodes.
+pub(super) struct SpatialIndex<V> {
+    metric: SpatialMetric,
+    coordinate: CoordinateFn<V>,
+    nodes: Vec<KdNode>,
+    root: Option<usize>,
+    positions: HashMap<usize, usize>,
+}
+
+impl<V> SpatialIndex<V>
+where
+    V: Copy,
+{
+    pub(super) fn new(metric: SpatialMetric, coordinate: CoordinateFn<V>) -> Self {
+        Self {
+            metric,
+            coordinate,
+            nodes: Vec::new(),
+            root: None,
+            positions: HashMap::new(),
+        }
+    }
+
+    pub(super) fn metric(&self) -> SpatialMetric {
+        self.metric
+    }
+
+    pub(super) fn coordinates(&self, point: [V; 3]) -> Point {
+        [
+            (self.coordinate)(point[0]),
+            (self.coordinate)(point[1]),
+            (self.coordinate)(point[2]),
+        ]
+    }
+
+    fn tree_point(&self, coordinates: Point) -> Point {
+        match self.metric {
+            SpatialMetric::Euclidean => coordinates,
+            SpatialMetric::Geodesic => {
+                let lat = coordinates[0].to_radians();
+                let lon = coordinates[1].to_radians();
+                [
+                    EARTH_RADIUS_METERS * lat.cos() * lon.cos(),
+                    EARTH_RADIUS_METERS * lat.cos() * lon.sin(),
+                    EARTH_RADIUS_METERS * lat.sin(),
+                ]
+            }
+        }
+    }
+
+    fn distance(&self, a: &Point, b: &Point) -> f64 {
+        match self.metric {
+            SpatialMetric::Euclidean => euclidean_distance(*a, *b),
+            SpatialMetric::Geodesic => geodesic_distance(a[0], a[1], b[0], b[1]),
+        }
+    }
+
+    pub(super) fn insert(&mut self, index: usize, point: [V; 3]) {
+        let coordinates = self.coordinates(point);
+        self.insert_coordinates(index, coordinates);
+    }
+
+    fn insert_coordinates(&mut self, index: usize, coordinates: Point) {
+        let position = self.nodes.len();
+        self.nodes.push(KdNode {
+            point: self.tree_point(coordinates),
+            coordinates,
+            index,
+            removed: false,
+            left: None,
+            right: None,
+        });
+        self.positions.insert(index, position);
+
+        let mut current = match self.root {
+            Some(root) => root,
+            None => {
+                self.root = Some(position);
+                return;
+            }
+        };
+
+        let point = self.nodes[position].point;
+        let mut depth = 0;
+        loop {
+            let axis = depth % 3;
+            let node = &mut self.nodes[current];
+            let child = if point[axis] < node.point[axis] {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
+
+            match child {
+                Some(next) => current = *next,
+                None => {
+                    *child = Some(position);
+                    return;
+                }
+            }
+            depth += 1;
+        }
+    }
+
+    pub(super) fn remove(&mut self, index: usize) {
+        if let Some(position) = self.positions.remove(&index) {
+            self.nodes[position].removed = true;
+        }
+
+        if self.nodes.len() > 2 * self.positions.len() {
+            self.rebuild();
+        }
+    }
+
+    fn rebuild(&mut self) {
+        let mut live: Vec<(usize, Point)> = self
+            .nodes
+            .iter()
+            .filter(|n| !n.removed)
+            .map(|n| (n.index, n.coordinates))
+            .collect();
+
+        self.nodes.clear();
+        self.positions.clear();
+        self.root = None;
+
+        // Inserting medians first keeps the tree balanced.
+        let mut ordered = Vec::with_capacity(live.len());
+        Self::median_order(&mut live, 0, &mut ordered);
+        for (index, coordinates) in ordered {
+            self.insert_coordinates(index, coordinates);
+        }
+    }
+
+    fn median_order(points: &mut [(usize, Point)], depth: usize, out: &mut Vec<(usize, Point)>) {
+        if points.is_empty() {
+            return;
+        }
+
+        let axis = depth % 3;
+        points.sort_by(|a, b| a.1[axis].partial_cmp(&b.1[axis]).unwrap_or(Ordering::Equal));
+        let mid = points.len() / 2;
+        out.push(points[mid]);
+
+        let (left, right) = points.split_at_mut(mid);
+        Self::median_order(left, depth + 1, out);
+        Self::median_order(&mut right[1..], depth + 1, out);
+    }
+
+    /// Returns the k nearest nodes as (index, distance), closest first.
+    pub(super) fn nearest(&self, point: [V; 3], k: usize) -> Vec<(usize, f64)> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let coordinates = self.coordinates(point);
+        let target = self.tree_point(coordinates);
+
+        // Sorted by tree distance, at most k entries.
+        let mut best: Vec<(f64, usize)> = Vec::with_capacity(k + 1);
+        self.nearest_rec(self.root, &target, 0, k, &mut best);
+
+        best.into_iter()
+            .map(|(_, position)| {
+                let node = &self.nodes[position];
+                (node.index, self.distance(&coordinates, &node.coordinates))
+            })
+            .collect()
+    }
+
+    fn nearest_rec(
+        &self,
+        current: Option<usize>,
+        target: &Point,
+        depth: usize,
+        k: usize,
+        best: &mut Vec<(f64, usize)>,
+    ) {
+        let position = match current {
+            Some(position) => position,
+            None => return,
+        };
+
+        let node = &self.nodes[position];
+        if !node.removed {
+            let d = euclidean_distance(node.point, *target);
+            let pos = best.partition_point(|(b, _)| *b <= d);
+            if pos < k {
+                best.insert(pos, (d, position));
+                best.truncate(k);
+            }
+        }
+
+        let axis = depth % 3;
+        let diff = target[axis] - node.point[axis];
+        let (near, far) = if diff < 0.0 {
+            (node.left, node.right)
+        } else {
+            (node.right, node.left)
+        };
+
+        self.nearest_rec(near, target, depth + 1, k, best);
+
+        let worst = if best.len() < k {
+            f64::INFINITY
+        } else {
+            best[best.len() - 1].0
+        };
+        if diff.abs() <= worst {
+            self.nearest_rec(far, target, depth + 1, k, best);
+        }
+    }
+
+    /// Returns all nodes within the radius as (index, distance), closest first.
+    pub(super) fn within_radius(&self, point: [V; 3], radius: f64) -> Vec<(usize, f64)> {
+        let coordinates = self.coordinates(point);
+        let target = self.tree_point(coordinates);
+
+        // For Geodesic, the great circle radius translates into a chord length.
+        let tree_radius = match self.metric {
+            SpatialMetric::Euclidean => radius,
+            SpatialMetric::Geodesic => {
+                let angle = (radius / (2.0 * EARTH_RADIUS_METERS)).min(std::f64::consts::FRAC_PI_2);
+                2.0 * EARTH_RADIUS_METERS * angle.sin()
+            }
+        };
+
+        let mut found = Vec::new();
+        self.radius_rec(self.root, &target, 0, tree_radius, &mut found);
+
+        let mut result: Vec<(usize, f64)> = found
+            .into_iter()
+            .map(|position| {
+                let node = &self.nodes[position];
+                (node.index, self.distance(&coordinates, &node.coordinates))
+            })
+            .filter(|(_, d)| *d <= radius)
+            .collect();
+
+        result.sort_by(|a, b| {
+            a.1.partial_cmp(&b.1)
+                .unwrap_or(Ordering::Equal)
+                .then(a.0.cmp(&b.0))
+        });
+        result
+    }
+
+    fn radius_rec(
+        &self,
+        current: Option<usize>,
+        target: &Point,
+        depth: usize,
+        radius: f64,
+        found: &mut Vec<usize>,
+    ) {
+        let position = match current {
+            Some(position) => position,
+            None => return,
+        };
+
+        let node = &self.nodes[position];
+        // Small tolerance for rounding in the chord conversion;
+        // the exact distance is checked by the caller.
+        if !node.removed && euclidean_distance(node.point, *target) <= radius * (1.0 + 1e-9) {
+            found.push(position);
+        }
+
+        let axis = depth % 3;
+        let diff = target[axis] - node.point[axis];
+        if diff - radius < 0.0 {
+            self.radius_rec(node.left, target, depth + 1, radius, found);
+        }
+        if diff + radius >= 0.0 {
+            self.radius_rec(node.right, target, depth + 1, radius, found);
+        }
+    }
+
+    /// Returns all nodes with coordinates inside the box, both bounds inclusive,
+    /// sorted by index.
+    pub(super) fn within_box(&self, min: [V; 3], max: [V; 3]) -> Vec<usize> {
+        let min = self.coordinates(min);
+        let max = self.coordinates(max);
+
+        let mut result = Vec::new();
+        match self.metric {
+            SpatialMetric::Euclidean => self.box_rec(self.root, &min, &max, 0, &mut result),
+            // The tree does not follow latitude and longitude, hence scan all nodes.
+            SpatialMetric::Geodesic => {
+                for node in self.nodes.iter().filter(|n| !n.removed) {
+                    let c = &node.coordinates;
+                    if c[0] >= min[0] && c[0] <= max[0] && c[1] >= min[1] && c[1] <= max[1] {
+                        result.push(node.index);
+                    }
+                }
+            }
+        }
+
+        result.sort_unstable();
+        result
+    }
+
+    fn box_rec(
+        &self,
+        current: Option<usize>,
+        min: &Point,
+        max: &Point,
+        depth: usize,
+        result: &mut Vec<usize>,
+    ) {
+        let position = match current {
+            Some(position) => position,
+            None => return,
+        };
+
+        let node = &self.nodes[position];
+        let p = &node.point;
+        if !node.removed && (0..3).all(|axis| p[axis] >= min[axis] && p[axis] <= max[axis]) {
+            result.push(node.index);
+        }
+
+        let axis = depth % 3;
+        if min[axis] < p[axis] {
+            self.box_rec(node.left, min, max, depth + 1, result);
+        }
+        if max[axis] >= p[axis] {
+            self.box_rec(node.right, min, max, depth + 1, result);
+        }
+    }
+}
+
+// Returns the x, y, z coordinates of a spatial contextoid.
+pub(super) fn space_key<D, S, T, ST, V>(contextoid: &Contextoid<D, S, T, ST, V>) -> Option<[V; 3]>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    match contextoid.vertex_type() {
+        ContextoidType::Spaceoid(s) => Some([*s.x(), *s.y(), *s.z()]),
+        ContextoidType::SpaceTempoid(st) => Some([*st.x(), *st.y(), *st.z()]),
+        _ => None,
+    }
+}
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Enables the spatial index over all Spaceoid and SpaceTempoid nodes of the base context.
+    /// The coordinate function converts x, y, z into floats, for example `|v| v as f64`.
+    /// Existing nodes are indexed immediately; nodes added or removed afterwards
+    /// are kept in sync automatically. Calling this method again rebuilds the index.
+    pub fn enable_spatial_index(&mut self, metric: SpatialMetric, coordinate: fn(V) -> f64) {
+        let mut index = SpatialIndex::new(metric, coordinate);
+        for (idx, node) in self.base_context.get_all_indexed_nodes() {
+            if let Some(point) = space_key(node) {
+                index.insert(idx, point);
+            }
+        }
+        self.spatial_index = Some(index);
+    }
+
+    /// Returns the metric of the spatial index, or None if the index is not enabled.
+    pub fn spatial_metric(&self) -> Option<SpatialMetric> {
+        self.spatial_index.as_ref().map(|index| index.metric())
+    }
+
+    /// Returns the k spatial nodes nearest to the point as (index, distance), closest first.
+    /// Returns ContextIndexError if the spatial index is not enabled.
+    pub fn get_nearest_spaceoids(
+        &self,
+        point: [V; 3],
+        k: usize,
+    ) -> Result<Vec<(usize, f64)>, ContextIndexError> {
+        Ok(self.get_spatial_index()?.nearest(point, k))
+    }
+
+    /// Returns all spatial nodes within the radius around the point as (index, distance),
+    /// closest first. For SpatialMetric::Geodesic, the radius is in meters.
+    /// Returns ContextIndexError if the spatial index is not enabled.
+    pub fn get_spaceoids_within_radius(
+        &self,
+        point: [V; 3],
+        radius: f64,
+    ) -> Result<Vec<(usize, f64)>, ContextIndexError> {
+        Ok(self.get_spatial_index()?.within_radius(point, radius))
+    }
+
+    /// Returns all spatial nodes inside the bounding box, both bounds inclusive, sorted by index.
+    /// For SpatialMetric::Geodesic, only latitude and longitude are considered.
+    /// Returns ContextIndexError if the spatial index is not enabled.
+    pub fn get_spaceoids_in_bbox(
+        &self,
+        min: [V; 3],
+        max: [V; 3],
+    ) -> Result<Vec<usize>, ContextIndexError> {
+        Ok(self.get_spatial_index()?.within_box(min, max))
+    }
+
+    fn get_spatial_index(&self) -> Result<&SpatialIndex<V>, ContextIndexError> {
+        self.spatial_index
+            .as_ref()
+            .ok_or_else(|| ContextIndexError("spatial index not enabled".into()))
+    }
+}
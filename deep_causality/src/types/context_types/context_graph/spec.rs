@@ -0,0 +1,339 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::BTreeSet;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::serde_utils::index_slots;
+
+use super::*;
+
+type IndexedContextoid<D, S, T, ST, V> = (usize, Contextoid<D, S, T, ST, V>);
+
+/// Serializable description of a context.
+///
+/// Covers the base context, extra contexts, hyperedges, context links,
+/// and the index maps. Nodes keep their index. Hyperedges are renumbered
+/// in ascending order of their original index.
+///
+/// Not persisted: time and spatial indices, which must be enabled again after building,
+/// subscribers, snapshots, the adjustment log, and the version.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(bound(
+    serialize = "D: Serialize, S: Serialize, T: Serialize, ST: Serialize, V: Serialize",
+    deserialize = "D: DeserializeOwned, S: DeserializeOwned, T: DeserializeOwned, \
+                   ST: DeserializeOwned, V: DeserializeOwned"
+))]
+pub struct ContextSpec<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    id: u64,
+    name: String,
+    base_context: ContextGraphSpec<D, S, T, ST, V>,
+    hyperedges: Vec<HyperedgeSpec>,
+    extra_contexts: Vec<ExtraContextSpec<D, S, T, ST, V>>,
+    number_of_extra_contexts: u64,
+    extra_context_id: u64,
+    context_links: Vec<(ContextNodeRef, ContextNodeRef, RelationKind)>,
+    current_index_map: Vec<(usize, usize)>,
+    previous_index_map: Vec<(usize, usize)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(bound(
+    serialize = "D: Serialize, S: Serialize, T: Serialize, ST: Serialize, V: Serialize",
+    deserialize = "D: DeserializeOwned, S: DeserializeOwned, T: DeserializeOwned, \
+                   ST: DeserializeOwned, V: DeserializeOwned"
+))]
+struct ContextGraphSpec<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    nodes: Vec<IndexedContextoid<D, S, T, ST, V>>,
+    edges: Vec<(usize, usize, ContextEdge)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(bound(
+    serialize = "D: Serialize, S: Serialize, T: Serialize, ST: Serialize, V: Serialize",
+    deserialize = "D: DeserializeOwned, S: DeserializeOwned, T: DeserializeOwned, \
+                   ST: DeserializeOwned, V: DeserializeOwned"
+))]
+struct ExtraContextSpec<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    id: u64,
+    names: Vec<String>,
+    graph: ContextGraphSpec<D, S, T, ST, V>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct HyperedgeSpec {
+    nodes: Vec<usize>,
+    relation_kind: RelationKind,
+    attributes: Vec<(String, String)>,
+}
+
+impl<D, S, T, ST, V> ContextSpec<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Describes the context.
+    pub fn from_context(context: &Context<D, S, T, ST, V>) -> Self {
+        let hyperedge_indices: BTreeSet<usize> = context
+            .base_context
+            .get_all_indexed_nodes()
+            .into_iter()
+            .flat_map(|(i, _)| context.base_context.get_hyperedges_of_node(i))
+            .collect();
+
+        let hyperedges = hyperedge_indices
+            .into_iter()
+            .filter_map(|i| context.get_hyperedge(i))
+            .map(|edge| HyperedgeSpec {
+                nodes: edge.nodes().to_vec(),
+                relation_kind: edge.relation_kind(),
+                attributes: sorted(
+                    edge.attributes()
+                        .iter()
+                        .map(|(k, v)| (k.clone(), v.clone())),
+                ),
+            })
+            .collect();
+
+        let mut extra_graphs: Vec<_> = context.extra_contexts.iter().flatten().collect();
+        extra_graphs.sort_by_key(|(id, _)| **id);
+
+        let extra_contexts = extra_graphs
+            .into_iter()
+            .map(|(id, graph)| ExtraContextSpec {
+                id: *id,
+                names: sorted(
+                    context
+                        .extra_context_names
+                        .iter()
+                        .filter(|(_, v)| *v == id)
+                        .map(|(name, _)| name.clone()),
+                ),
+                graph: ContextGraphSpec::from_graph(graph),
+            })
+            .collect();
+
+        let mut context_links = Vec::new();
+        for (from, links) in &context.context_links {
+            for (to, relation_kind) in links {
+                context_links.push((*from, *to, *relation_kind));
+            }
+        }
+        context_links.sort_by_key(|(from, to, _)| (node_ref_key(from), node_ref_key(to)));
+
+        Self {
+            id: context.id,
+            name: context.name.clone(),
+            base_context: ContextGraphSpec::from_graph(&context.base_context),
+            hyperedges,
+            extra_contexts,
+            number_of_extra_contexts: context.number_of_extra_contexts,
+            extra_context_id: context.extra_context_id,
+            context_links,
+            current_index_map: sorted(context.current_index_map.iter().map(|(k, v)| (*k, *v))),
+            previous_index_map: sorted(context.previous_index_map.iter().map(|(k, v)| (*k, *v))),
+        }
+    }
+
+    /// Builds the context. The base context and all extra contexts
+    /// are allocated to fit their largest node index.
+    ///
+    /// Returns SerializationError if two nodes share an index, a node index is not less than
+    /// twice the number of nodes in its graph, or an edge, hyperedge, or context link refers
+    /// to a node or extra context that does not exist.
+    pub fn build(&self) -> Result<Context<D, S, T, ST, V>, SerializationError> {
+        let mut context = Context::with_capacity(self.id, &self.name, 0);
+        context.base_context = Arc::new(self.base_context.build()?);
+
+        for edge in &self.hyperedges {
+            let attributes = edge.attributes.iter().cloned().collect();
+            context
+                .add_hyperedge(&edge.nodes, edge.relation_kind, attributes)
+                .map_err(|e| SerializationError(format!("Invalid hyperedge: {}", e)))?;
+        }
+
+        if !self.extra_contexts.is_empty() {
+            let mut extra_contexts = HashMap::new();
+            for extra in &self.extra_contexts {
+                if extra_contexts
+                    .insert(extra.id, extra.graph.build()?)
+                    .is_some()
+                {
+                    return Err(SerializationError(format!(
+                        "Duplicate extra context id {}",
+                        extra.id
+                    )));
+                }
+                for name in &extra.names {
+                    context.extra_context_names.insert(name.clone(), extra.id);
+                }
+            }
+            context.extra_contexts = Some(extra_contexts);
+        }
+
+        context.number_of_extra_contexts = self.number_of_extra_contexts;
+        context.extra_context_id = self.extra_context_id;
+
+        for (from, to, relation_kind) in &self.context_links {
+            context
+                .add_context_link(*from, *to, *relation_kind)
+                .map_err(|e| SerializationError(format!("Invalid context link: {}", e)))?;
+        }
+
+        context.current_index_map = self.current_index_map.iter().copied().collect();
+        context.previous_index_map = self.previous_index_map.iter().copied().collect();
+        context.version = 0;
+
+        Ok(context)
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+}
+
+impl<D, S, T, ST, V> ContextGraphSpec<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    fn from_graph(graph: &ExtraContext<D, S, T, ST, V>) -> Self {
+        let nodes = graph
+            .get_all_indexed_nodes()
+            .into_iter()
+            .map(|(i, node)| (i, node.clone()))
+            .collect();
+
+        let mut edges: Vec<_> = graph
+            .get_all_edges()
+            .into_iter()
+            .filter_map(|(a, b)| graph.get_edge_data(a, b).map(|e| (a, b, e.clone())))
+            .collect();
+        edges.sort_by_key(|(a, b, _)| (*a, *b));
+
+        Self { nodes, edges }
+    }
+
+    // A new graph assigns consecutive indices, so placeholders fill the gaps
+    // between indices and are removed once all nodes are in place.
+    fn build(&self) -> Result<ExtraContext<D, S, T, ST, V>, SerializationError> {
+        let slots = index_slots(&self.nodes, "context")?;
+
+        let mut graph = ultragraph::with_edge_data(slots.len());
+        let mut gaps = Vec::new();
+        for (index, slot) in slots.iter().enumerate() {
+            match slot {
+                Some(node) => {
+                    graph.add_node((*node).clone());
+                }
+                None => {
+                    graph.add_node(Contextoid::new(0, ContextoidType::Root(Root::new(0))));
+                    gaps.push(index);
+                }
+            }
+        }
+
+        for index in gaps {
+            graph
+                .remove_node(index)
+                .map_err(|e| SerializationError(e.to_string()))?;
+        }
+
+        for (a, b, edge) in &self.edges {
+            if !graph.contains_node(*a) || !graph.contains_node(*b) {
+                return Err(SerializationError(format!(
+                    "Edge {} -> {} refers to a node that does not exist",
+                    a, b
+                )));
+            }
+            graph
+                .add_edge_with_data(*a, *b, EDGE_WEIGHT, edge.clone())
+                .map_err(|e| SerializationError(e.to_string()))?;
+        }
+
+        Ok(graph)
+    }
+}
+
+fn sorted<K: Ord, I: Iterator<Item = K>>(iter: I) -> Vec<K> {
+    let mut items: Vec<K> = iter.collect();
+    items.sort();
+    items
+}
+
+fn node_ref_key(node: &ContextNodeRef) -> (u64, usize) {
+    (node.context().map_or(0, |h| h.id()), node.index())
+}
@@ -0,0 +1,266 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::cmp::Ordering;
+
+use super::*;
+
+// The comparator is captured when the index is enabled, which is the only place
+// that requires V: Ord. This keeps the index maintainable from add_node / remove_node
+// without adding an Ord bound to every Context method.
+type TimeCmp<V> = fn(&V, &V) -> Ordering;
+
+// Sorted (time_unit, node index) entries per time scale.
+type TimeEntries<V> = Vec<(V, usize)>;
+
+pub(super) struct TimeIndex<V> {
+    cmp: TimeCmp<V>,
+    scales: HashMap<TimeScale, TimeEntries<V>>,
+}
+
+impl<V> TimeIndex<V>
+where
+    V: Copy,
+{
+    pub(super) fn new(cmp: TimeCmp<V>) -> Self {
+        Self {
+            cmp,
+            scales: HashMap::new(),
+        }
+    }
+
+    fn compare(&self, a: &(V, usize), b: &(V, usize)) -> Ordering {
+        (self.cmp)(&a.0, &b.0).then(a.1.cmp(&b.1))
+    }
+
+    pub(super) fn insert(&mut self, time_scale: TimeScale, time_unit: V, index: usize) {
+        let entry = (time_unit, index);
+        let pos = match self.scales.get(&time_scale) {
+            Some(entries) => entries
+                .binary_search_by(|e| self.compare(e, &entry))
+                .unwrap_or_else(|pos| pos),
+            None => 0,
+        };
+        self.scales
+            .entry(time_scale)
+            .or_default()
+            .insert(pos, entry);
+    }
+
+    pub(super) fn remove(&mut self, time_scale: TimeScale, time_unit: V, index: usize) {
+        let entry = (time_unit, index);
+        let pos = match self.scales.get(&time_scale) {
+            Some(entries) => entries.binary_search_by(|e| self.compare(e, &entry)),
+            None => return,
+        };
+        if let (Ok(pos), Some(entries)) = (pos, self.scales.get_mut(&time_scale)) {
+            entries.remove(pos);
+        }
+    }
+
+    fn entries(&self, time_scale: TimeScale) -> &[(V, usize)] {
+        self.scales
+            .get(&time_scale)
+            .map(|e| e.as_slice())
+            .unwrap_or(&[])
+    }
+
+    // Position of the first entry with a time unit not less than t.
+    fn lower_bound(&self, entries: &[(V, usize)], t: &V) -> usize {
+        entries.partition_point(|(u, _)| (self.cmp)(u, t) == Ordering::Less)
+    }
+
+    // Position of the first entry with a time unit greater than t.
+    fn upper_bound(&self, entries: &[(V, usize)], t: &V) -> usize {
+        entries.partition_point(|(u, _)| (self.cmp)(u, t) != Ordering::Greater)
+    }
+
+    pub(super) fn range(&self, time_scale: TimeScale, from: &V, to: &V) -> Vec<usize> {
+        let entries = self.entries(time_scale);
+        let start = self.lower_bound(entries, from);
+        let end = self.upper_bound(entries, to);
+        if start >= end {
+            return Vec::new();
+        }
+        entries[start..end].iter().map(|(_, idx)| *idx).collect()
+    }
+
+    pub(super) fn containing(&self, time_scale: TimeScale, t: &V) -> Option<usize> {
+        let entries = self.entries(time_scale);
+        let end = self.upper_bound(entries, t);
+        if end == 0 {
+            None
+        } else {
+            Some(entries[end - 1].1)
+        }
+    }
+
+    fn position(&self, time_scale: TimeScale, time_unit: V, index: usize) -> Option<usize> {
+        let entry = (time_unit, index);
+        self.entries(time_scale)
+            .binary_search_by(|e| self.compare(e, &entry))
+            .ok()
+    }
+
+    pub(super) fn previous(
+        &self,
+        time_scale: TimeScale,
+        time_unit: V,
+        index: usize,
+    ) -> Option<usize> {
+        let entries = self.entries(time_scale);
+        let pos = self.position(time_scale, time_unit, index)?;
+        // Skip entries sharing the same time unit.
+        let start = self.lower_bound(entries, &entries[pos].0);
+        if start == 0 {
+            None
+        } else {
+            Some(entries[start - 1].1)
+        }
+    }
+
+    pub(super) fn next(&self, time_scale: TimeScale, time_unit: V, index: usize) -> Option<usize> {
+        let entries = self.entries(time_scale);
+        let pos = self.position(time_scale, time_unit, index)?;
+        // Skip entries sharing the same time unit.
+        let end = self.upper_bound(entries, &entries[pos].0);
+        entries.get(end).map(|(_, idx)| *idx)
+    }
+}
+
+// Returns the time scale and time unit of a temporal contextoid.
+pub(super) fn time_key<D, S, T, ST, V>(
+    contextoid: &Contextoid<D, S, T, ST, V>,
+) -> Option<(TimeScale, V)>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    match contextoid.vertex_type() {
+        ContextoidType::Tempoid(t) => Some((t.time_scale(), *t.time_unit())),
+        ContextoidType::SpaceTempoid(st) => Some((st.time_scale(), *st.time_unit())),
+        _ => None,
+    }
+}
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Ord
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Enables the time index over all Tempoid and SpaceTempoid nodes of the base context.
+    /// Existing nodes are indexed immediately; nodes added or removed afterwards
+    /// are kept in sync automatically. Calling this method again rebuilds the index.
+    pub fn enable_time_index(&mut self) {
+        let mut index = TimeIndex::new(V::cmp);
+        for (idx, node) in self.base_context.get_all_indexed_nodes() {
+            if let Some((scale, unit)) = time_key(node) {
+                index.insert(scale, unit, idx);
+            }
+        }
+        self.time_index = Some(index);
+    }
+}
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Returns true if the time index is enabled.
+    pub fn has_time_index(&self) -> bool {
+        self.time_index.is_some()
+    }
+
+    /// Returns the indices of all temporal nodes at the given time scale
+    /// with a time unit between from and to, both inclusive, ordered by time.
+    /// Returns ContextIndexError if the time index is not enabled.
+    pub fn get_tempoids_in_range(
+        &self,
+        time_scale: TimeScale,
+        from: V,
+        to: V,
+    ) -> Result<Vec<usize>, ContextIndexError> {
+        Ok(self.get_time_index()?.range(time_scale, &from, &to))
+    }
+
+    /// Returns the index of the temporal node at the given time scale that contains t,
+    /// that is, the node with the latest time unit not after t.
+    /// Returns None if all nodes at the time scale are after t.
+    /// Returns ContextIndexError if the time index is not enabled.
+    pub fn get_tempoid_containing(
+        &self,
+        time_scale: TimeScale,
+        t: V,
+    ) -> Result<Option<usize>, ContextIndexError> {
+        Ok(self.get_time_index()?.containing(time_scale, &t))
+    }
+
+    /// Returns the index of the temporal node directly before the node at index
+    /// with the same time scale, or None if it is the first one.
+    /// Returns ContextIndexError if the time index is not enabled
+    /// or the node at index is not a temporal node.
+    pub fn get_previous_tempoid(&self, index: usize) -> Result<Option<usize>, ContextIndexError> {
+        let (scale, unit) = self.get_time_key(index)?;
+        Ok(self.get_time_index()?.previous(scale, unit, index))
+    }
+
+    /// Returns the index of the temporal node directly after the node at index
+    /// with the same time scale, or None if it is the last one.
+    /// Returns ContextIndexError if the time index is not enabled
+    /// or the node at index is not a temporal node.
+    pub fn get_next_tempoid(&self, index: usize) -> Result<Option<usize>, ContextIndexError> {
+        let (scale, unit) = self.get_time_key(index)?;
+        Ok(self.get_time_index()?.next(scale, unit, index))
+    }
+
+    fn get_time_index(&self) -> Result<&TimeIndex<V>, ContextIndexError> {
+        self.time_index
+            .as_ref()
+            .ok_or_else(|| ContextIndexError("time index not enabled".into()))
+    }
+
+    fn get_time_key(&self, index: usize) -> Result<(TimeScale, V), ContextIndexError> {
+        let node = self
+            .base_context
+            .get_node(index)
+            .ok_or_else(|| ContextIndexError(format!("index {} not found", index)))?;
+
+        time_key(node)
+            .ok_or_else(|| ContextIndexError(format!("node {} is not a temporal node", index)))
+    }
+}
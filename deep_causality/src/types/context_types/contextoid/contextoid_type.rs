@@ -20,6 +20,7 @@ use crate::prelude::*;
 
 // https://stackoverflow.com/questions/69173586/either-type-a-or-b-in-rust
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ContextoidType<D, S, T, ST, V>
 where
     D: Datable,
@@ -12,6 +12,7 @@ mod display;
 mod identifiable;
 
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Contextoid<D, S, T, ST, V>
 where
     D: Datable,
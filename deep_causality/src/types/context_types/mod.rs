@@ -1,9 +1,13 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
+pub mod context_event;
 pub mod context_graph;
 pub mod contextoid;
 pub mod node_types;
 pub mod node_types_adjustable;
+pub mod node_types_uncertain;
 pub mod relation_kind;
+pub mod spatial_metric;
 pub mod time_scale;
+pub mod uncertain_value;
@@ -7,6 +7,7 @@ use deep_causality_macros::Constructor;
 use crate::protocols::identifiable::Identifiable;
 
 #[derive(Constructor, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Root {
     id: u64,
 }
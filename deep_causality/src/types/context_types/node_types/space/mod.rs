@@ -11,6 +11,7 @@ mod identifiable;
 mod spatial;
 
 #[derive(Constructor, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Space<T>
 where
     T: Default
@@ -14,6 +14,7 @@ mod spatial;
 mod temporable;
 
 #[derive(Constructor, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpaceTime<T>
 where
     T: Default
@@ -5,13 +5,15 @@ use std::ops::*;
 
 use deep_causality_macros::Constructor;
 
-use crate::prelude::TimeScale;
+use crate::prelude::{TimeError, TimeScale};
+use crate::utils::time_utils;
 
 mod display;
 mod identifiable;
 mod temporable;
 
 #[derive(Constructor, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Time<T>
 where
     T: Default
@@ -28,3 +30,43 @@ where
     time_scale: TimeScale,
     time_unit: T,
 }
+
+impl Time<i64> {
+    /// Creates a time whose time unit is the number of whole units of the time scale
+    /// between the Unix epoch and the UTC timestamp in seconds,
+    /// e.g. the day number for TimeScale::Day or the month number for TimeScale::Month.
+    /// Returns TimeError for NoScale or if the timestamp overflows.
+    pub fn from_unix_timestamp(
+        id: u64,
+        time_scale: TimeScale,
+        seconds: i64,
+    ) -> Result<Self, TimeError> {
+        let nanos = seconds
+            .checked_mul(time_utils::NANOS_PER_SECOND)
+            .ok_or_else(|| TimeError(format!("Timestamp {} overflows", seconds)))?;
+
+        Self::from_unix_timestamp_nanos(id, time_scale, nanos)
+    }
+
+    /// Creates a time from a UTC timestamp in nanoseconds. See from_unix_timestamp.
+    pub fn from_unix_timestamp_nanos(
+        id: u64,
+        time_scale: TimeScale,
+        nanos: i64,
+    ) -> Result<Self, TimeError> {
+        let time_unit = time_utils::units_since_epoch(nanos, time_scale)?;
+        Ok(Self::new(id, time_scale, time_unit))
+    }
+
+    /// Returns the UTC timestamp in nanoseconds at which the time unit starts.
+    /// Returns TimeError for NoScale or if the timestamp overflows.
+    pub fn unix_timestamp_nanos(&self) -> Result<i64, TimeError> {
+        time_utils::unit_start(self.time_unit, self.time_scale)
+    }
+
+    /// Returns true if the UTC timestamp in nanoseconds falls within the time unit.
+    pub fn contains_unix_timestamp_nanos(&self, nanos: i64) -> bool {
+        time_utils::units_since_epoch(nanos, self.time_scale)
+            .is_ok_and(|units| units == self.time_unit)
+    }
+}
@@ -3,9 +3,10 @@
 use std::ops::*;
 
 use dcl_data_structures::grid_type::ArrayGrid;
-use dcl_data_structures::prelude::PointIndex;
 
-use crate::prelude::{Adjustable, AdjustmentError, UpdateError};
+use crate::prelude::{
+    Adjustable, AdjustmentError, AdjustmentField, AdjustmentMapping, UpdateError,
+};
 
 use super::*;
 
@@ -26,8 +27,25 @@ where
         &mut self,
         array_grid: &ArrayGrid<T, W, H, D, C>,
     ) -> Result<(), UpdateError> {
-        // Create a 1D PointIndex
-        let p = PointIndex::new1d(0);
+        self.update_with_mapping(array_grid, &AdjustmentMapping::data())
+    }
+
+    fn adjust<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<T, W, H, D, C>,
+    ) -> Result<(), AdjustmentError> {
+        self.adjust_with_mapping(array_grid, &AdjustmentMapping::data())
+    }
+
+    fn update_with_mapping<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<T, W, H, D, C>,
+        mapping: &AdjustmentMapping,
+    ) -> Result<(), UpdateError> {
+        // Look up the grid position
+        let p = mapping
+            .position(AdjustmentField::Data, array_grid)
+            .map_err(UpdateError)?;
 
         // get the data at the index position
         let update_data = array_grid.get(p);
@@ -43,12 +61,15 @@ where
         Ok(())
     }
 
-    fn adjust<const W: usize, const H: usize, const D: usize, const C: usize>(
+    fn adjust_with_mapping<const W: usize, const H: usize, const D: usize, const C: usize>(
         &mut self,
         array_grid: &ArrayGrid<T, W, H, D, C>,
+        mapping: &AdjustmentMapping,
     ) -> Result<(), AdjustmentError> {
-        // Create a 1D PointIndex
-        let p = PointIndex::new1d(0);
+        // Look up the grid position
+        let p = mapping
+            .position(AdjustmentField::Data, array_grid)
+            .map_err(AdjustmentError)?;
 
         // get the data at the index position
         let new_data = array_grid.get(p);
@@ -11,6 +11,7 @@ mod display;
 mod identifiable;
 
 #[derive(Getters, Constructor, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AdjustableData<T>
 where
     T: Default + Copy + Clone + Hash + Eq + PartialEq,
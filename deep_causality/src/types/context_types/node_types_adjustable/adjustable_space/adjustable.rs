@@ -5,9 +5,10 @@ use std::fmt::Debug;
 use std::ops::Add;
 
 use dcl_data_structures::grid_type::ArrayGrid;
-use dcl_data_structures::prelude::PointIndex;
 
-use crate::prelude::{Adjustable, AdjustmentError, UpdateError};
+use crate::prelude::{
+    Adjustable, AdjustmentError, AdjustmentField, AdjustmentMapping, UpdateError,
+};
 
 use super::*;
 
@@ -29,10 +30,31 @@ where
         &mut self,
         array_grid: &ArrayGrid<T, W, H, D, C>,
     ) -> Result<(), UpdateError> {
-        // Create a 3D PointIndex for each of the updated x,y,z coordinates
-        let p1 = PointIndex::new3d(0, 0, 0);
-        let p2 = PointIndex::new3d(0, 0, 1);
-        let p3 = PointIndex::new3d(0, 0, 2);
+        self.update_with_mapping(array_grid, &AdjustmentMapping::space())
+    }
+
+    fn adjust<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<T, W, H, D, C>,
+    ) -> Result<(), AdjustmentError> {
+        self.adjust_with_mapping(array_grid, &AdjustmentMapping::space())
+    }
+
+    fn update_with_mapping<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<T, W, H, D, C>,
+        mapping: &AdjustmentMapping,
+    ) -> Result<(), UpdateError> {
+        // Look up the grid position of each of the updated x,y,z coordinates
+        let p1 = mapping
+            .position(AdjustmentField::X, array_grid)
+            .map_err(UpdateError)?;
+        let p2 = mapping
+            .position(AdjustmentField::Y, array_grid)
+            .map_err(UpdateError)?;
+        let p3 = mapping
+            .position(AdjustmentField::Z, array_grid)
+            .map_err(UpdateError)?;
 
         // get the data at the index position
         let new_x = array_grid.get(p1);
@@ -60,14 +82,21 @@ where
         Ok(())
     }
 
-    fn adjust<const W: usize, const H: usize, const D: usize, const C: usize>(
+    fn adjust_with_mapping<const W: usize, const H: usize, const D: usize, const C: usize>(
         &mut self,
         array_grid: &ArrayGrid<T, W, H, D, C>,
+        mapping: &AdjustmentMapping,
     ) -> Result<(), AdjustmentError> {
-        // Create a 3D PointIndex for each of the updated x,y,z coordinates
-        let p1 = PointIndex::new3d(0, 0, 0);
-        let p2 = PointIndex::new3d(0, 0, 1);
-        let p3 = PointIndex::new3d(0, 0, 2);
+        // Look up the grid position of each of the updated x,y,z coordinates
+        let p1 = mapping
+            .position(AdjustmentField::X, array_grid)
+            .map_err(AdjustmentError)?;
+        let p2 = mapping
+            .position(AdjustmentField::Y, array_grid)
+            .map_err(AdjustmentError)?;
+        let p3 = mapping
+            .position(AdjustmentField::Z, array_grid)
+            .map_err(AdjustmentError)?;
 
         // Get the data at the index position
         let new_x = array_grid.get(p1);
@@ -12,6 +12,7 @@ mod identifiable;
 mod spatial;
 
 #[derive(Getters, Constructor, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AdjustableSpace<T>
 where
     T: Default
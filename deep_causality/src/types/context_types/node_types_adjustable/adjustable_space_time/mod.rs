@@ -16,6 +16,7 @@ mod spatial;
 mod temporable;
 
 #[derive(Getters, Constructor, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AdjustableSpaceTime<T>
 where
     T: Default
@@ -6,10 +6,9 @@ use std::hash::Hash;
 use std::ops::*;
 
 use dcl_data_structures::grid_type::ArrayGrid;
-use dcl_data_structures::prelude::PointIndex;
 
 use crate::errors::{AdjustmentError, UpdateError};
-use crate::prelude::{Adjustable, AdjustableTime};
+use crate::prelude::{Adjustable, AdjustableTime, AdjustmentField, AdjustmentMapping};
 
 impl<T> Adjustable<T> for AdjustableTime<T>
 where
@@ -29,8 +28,25 @@ where
         &mut self,
         array_grid: &ArrayGrid<T, W, H, D, C>,
     ) -> Result<(), UpdateError> {
-        // Create a 1D PointIndex
-        let p = PointIndex::new1d(0);
+        self.update_with_mapping(array_grid, &AdjustmentMapping::time())
+    }
+
+    fn adjust<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<T, W, H, D, C>,
+    ) -> Result<(), AdjustmentError> {
+        self.adjust_with_mapping(array_grid, &AdjustmentMapping::time())
+    }
+
+    fn update_with_mapping<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<T, W, H, D, C>,
+        mapping: &AdjustmentMapping,
+    ) -> Result<(), UpdateError> {
+        // Look up the grid position
+        let p = mapping
+            .position(AdjustmentField::Time, array_grid)
+            .map_err(UpdateError)?;
 
         // get the data at the index position
         let update_time = array_grid.get(p);
@@ -51,12 +67,15 @@ where
         Ok(())
     }
 
-    fn adjust<const W: usize, const H: usize, const D: usize, const C: usize>(
+    fn adjust_with_mapping<const W: usize, const H: usize, const D: usize, const C: usize>(
         &mut self,
         array_grid: &ArrayGrid<T, W, H, D, C>,
+        mapping: &AdjustmentMapping,
     ) -> Result<(), AdjustmentError> {
-        // Create a 1D PointIndex
-        let p = PointIndex::new1d(0);
+        // Look up the grid position
+        let p = mapping
+            .position(AdjustmentField::Time, array_grid)
+            .map_err(AdjustmentError)?;
 
         // get the data at the index position
         let time_adjustment = array_grid.get(p);
@@ -14,6 +14,7 @@ mod identifiable;
 mod temporable;
 
 #[derive(Getters, Constructor, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AdjustableTime<T>
 where
     T: Default
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Debug, Display};
+
+use dcl_data_structures::prelude::{ArrayGrid, PointIndex};
+
+/// Field of an adjustable node that can be read from an array grid.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[repr(u8)]
+pub enum AdjustmentField {
+    Data = 0,
+    X = 1,
+    Y = 2,
+    Z = 3,
+    Time = 4,
+}
+
+impl Display for AdjustmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Maps the fields of adjustable nodes to positions in an array grid.
+///
+/// A node only reads the fields it has, so one mapping can hold the
+/// positions for data, space, and time nodes at the same time.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct AdjustmentMapping {
+    positions: [Option<PointIndex>; 5],
+}
+
+impl AdjustmentMapping {
+    /// Creates an empty mapping.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the mapping with the field mapped to the grid position.
+    pub fn with(mut self, field: AdjustmentField, position: PointIndex) -> Self {
+        self.positions[field as usize] = Some(position);
+        self
+    }
+
+    /// Returns the grid position of the field, or None if the field is not mapped.
+    pub fn get(&self, field: AdjustmentField) -> Option<PointIndex> {
+        self.positions[field as usize]
+    }
+
+    // Returns the grid position of the field or an error message
+    // if the field is not mapped or the position lies outside the grid.
+    pub(crate) fn position<T, const W: usize, const H: usize, const D: usize, const C: usize>(
+        &self,
+        field: AdjustmentField,
+        array_grid: &ArrayGrid<T, W, H, D, C>,
+    ) -> Result<PointIndex, String>
+    where
+        T: Copy + Default,
+    {
+        let p = self
+            .get(field)
+            .ok_or_else(|| format!("no grid position mapped for field {}", field))?;
+
+        if !array_grid.contains(p) {
+            return Err(format!(
+                "grid position {:?} of field {} is out of bounds",
+                p, field
+            ));
+        }
+
+        Ok(p)
+    }
+
+    // Returns an error message if any mapped position lies outside the grid.
+    pub(crate) fn check_bounds<T, const W: usize, const H: usize, const D: usize, const C: usize>(
+        &self,
+        array_grid: &ArrayGrid<T, W, H, D, C>,
+    ) -> Result<(), String>
+    where
+        T: Copy + Default,
+    {
+        for field in [
+            AdjustmentField::Data,
+            AdjustmentField::X,
+            AdjustmentField::Y,
+            AdjustmentField::Z,
+            AdjustmentField::Time,
+        ] {
+            if self.get(field).is_some() {
+                self.position(field, array_grid)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Default mapping of AdjustableData: data at 1D index 0.
+    pub fn data() -> Self {
+        Self::new().with(AdjustmentField::Data, PointIndex::new1d(0))
+    }
+
+    /// Default mapping of AdjustableTime: time at 1D index 0.
+    pub fn time() -> Self {
+        Self::new().with(AdjustmentField::Time, PointIndex::new1d(0))
+    }
+
+    /// Default mapping of AdjustableSpace: x, y, z at 3D index (0,0,0..2).
+    pub fn space() -> Self {
+        Self::new()
+            .with(AdjustmentField::X, PointIndex::new3d(0, 0, 0))
+            .with(AdjustmentField::Y, PointIndex::new3d(0, 0, 1))
+            .with(AdjustmentField::Z, PointIndex::new3d(0, 0, 2))
+    }
+
+    /// Default mapping of AdjustableSpaceTime: x, y, z, t at 4D index (0,0,0,0..3).
+    pub fn space_time() -> Self {
+        Self::new()
+            .with(AdjustmentField::X, PointIndex::new4d(0, 0, 0, 0))
+            .with(AdjustmentField::Y, PointIndex::new4d(0, 0, 0, 1))
+            .with(AdjustmentField::Z, PointIndex::new4d(0, 0, 0, 2))
+            .with(AdjustmentField::Time, PointIndex::new4d(0, 0, 0, 3))
+    }
+}
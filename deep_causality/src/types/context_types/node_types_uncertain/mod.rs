@@ -0,0 +1,5 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+pub mod uncertain_data;
+pub mod uncertain_space;
+pub mod uncertain_time;
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use dcl_data_structures::grid_type::ArrayGrid;
+
+use crate::prelude::{
+    Adjustable, AdjustmentError, AdjustmentField, AdjustmentMapping, UpdateError,
+};
+
+use super::*;
+
+impl Adjustable<NumericalValue> for UncertainData {
+    fn update<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<NumericalValue, W, H, D, C>,
+    ) -> Result<(), UpdateError> {
+        self.update_with_mapping(array_grid, &AdjustmentMapping::data())
+    }
+
+    fn adjust<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<NumericalValue, W, H, D, C>,
+    ) -> Result<(), AdjustmentError> {
+        self.adjust_with_mapping(array_grid, &AdjustmentMapping::data())
+    }
+
+    fn update_with_mapping<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<NumericalValue, W, H, D, C>,
+        mapping: &AdjustmentMapping,
+    ) -> Result<(), UpdateError> {
+        // Look up the grid position
+        let p = mapping
+            .position(AdjustmentField::Data, array_grid)
+            .map_err(UpdateError)?;
+
+        // The measurement is normally distributed around the value at the index position
+        let measurement = UncertainValue::normal(array_grid.get(p), self.measurement_variance)
+            .map_err(|e| UpdateError(format!("Update failed, {}", e)))?;
+
+        // Fuse the measurement into the current estimate
+        self.data = self.data.fuse(&measurement);
+
+        Ok(())
+    }
+
+    fn adjust_with_mapping<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<NumericalValue, W, H, D, C>,
+        mapping: &AdjustmentMapping,
+    ) -> Result<(), AdjustmentError> {
+        // Look up the grid position
+        let p = mapping
+            .position(AdjustmentField::Data, array_grid)
+            .map_err(AdjustmentError)?;
+
+        // get the data at the index position
+        let delta = array_grid.get(p);
+
+        if !delta.is_finite() {
+            return Err(AdjustmentError(
+                "Adjustment failed, new data is not a finite number".into(),
+            ));
+        }
+
+        // Shift the current estimate
+        self.data = self.data.shift(delta);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Display, Formatter};
+
+use super::*;
+
+impl Display for UncertainData {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "UncertainData {{ id={:?}, data={}, measurement_variance={:?} }}",
+            self.id, self.data, self.measurement_variance
+        )
+    }
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::{Datable, NumericalValue, UncertainValue};
+
+mod adjustable;
+mod display;
+mod identifiable;
+
+/// Data value with uncertainty.
+///
+/// update fuses the measurement from the array grid, assuming the measurement variance,
+/// into the current estimate. adjust shifts the current estimate by the value from the array grid.
+#[derive(Getters, Constructor, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UncertainData {
+    #[getter(name = data_id)] // Rename ID getter to prevent conflict impl with identifiable
+    id: u64,
+    data: UncertainValue,
+    measurement_variance: NumericalValue,
+}
+
+// Type tag required for context.
+impl Datable for UncertainData {}
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use dcl_data_structures::grid_type::ArrayGrid;
+
+use crate::prelude::{
+    Adjustable, AdjustmentError, AdjustmentField, AdjustmentMapping, UpdateError,
+};
+
+use super::*;
+
+impl<T> Adjustable<NumericalValue> for UncertainSpace<T>
+where
+    T: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<T, Output = T>
+        + Sub<T, Output = T>
+        + Mul<T, Output = T>,
+{
+    fn update<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<NumericalValue, W, H, D, C>,
+    ) -> Result<(), UpdateError> {
+        self.update_with_mapping(array_grid, &AdjustmentMapping::space())
+    }
+
+    fn adjust<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<NumericalValue, W, H, D, C>,
+    ) -> Result<(), AdjustmentError> {
+        self.adjust_with_mapping(array_grid, &AdjustmentMapping::space())
+    }
+
+    fn update_with_mapping<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<NumericalValue, W, H, D, C>,
+        mapping: &AdjustmentMapping,
+    ) -> Result<(), UpdateError> {
+        // Look up the grid position of each of the measured x,y,z coordinates
+        let p1 = mapping
+            .position(AdjustmentField::X, array_grid)
+            .map_err(UpdateError)?;
+        let p2 = mapping
+            .position(AdjustmentField::Y, array_grid)
+            .map_err(UpdateError)?;
+        let p3 = mapping
+            .position(AdjustmentField::Z, array_grid)
+            .map_err(UpdateError)?;
+
+        // The measurements are normally distributed around the values at the index positions
+        let measure = |value: NumericalValue| {
+            UncertainValue::normal(value, self.measurement_variance)
+                .map_err(|e| UpdateError(format!("Update failed, {}", e)))
+        };
+
+        let new_x = measure(array_grid.get(p1))?;
+        let new_y = measure(array_grid.get(p2))?;
+        let new_z = measure(array_grid.get(p3))?;
+
+        // Fuse the measurements into the current estimate
+        self.uncertain_position[0] = self.uncertain_position[0].fuse(&new_x);
+        self.uncertain_position[1] = self.uncertain_position[1].fuse(&new_y);
+        self.uncertain_position[2] = self.uncertain_position[2].fuse(&new_z);
+
+        Ok(())
+    }
+
+    fn adjust_with_mapping<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        array_grid: &ArrayGrid<NumericalValue, W, H, D, C>,
+        mapping: &AdjustmentMapping,
+    ) -> Result<(), AdjustmentError> {
+        // Look up the grid position of each of the adjusted x,y,z coordinates
+        let p1 = mapping
+            .position(AdjustmentField::X, array_grid)
+            .map_err(AdjustmentError)?;
+        let p2 = mapping
+            .position(AdjustmentField::Y, array_grid)
+            .map_err(AdjustmentError)?;
+        let p3 = mapping
+            .position(AdjustmentField::Z, array_grid)
+            .map_err(AdjustmentError)?;
+
+        // Get the data at the index position
+        let dx = array_grid.get(p1);
+        let dy = array_grid.get(p2);
+        let dz = array_grid.get(p3);
+
+        if !dx.is_finite() || !dy.is_finite() || !dz.is_finite() {
+            return Err(AdjustmentError(
+                "Adjustment failed, new data is not a finite number".into(),
+            ));
+        }
+
+        // Shift the current estimate
+        self.uncertain_position[0] = self.uncertain_position[0].shift(dx);
+        self.uncertain_position[1] = self.uncertain_position[1].shift(dy);
+        self.uncertain_position[2] = self.uncertain_position[2].shift(dz);
+
+        Ok(())
+    }
+}
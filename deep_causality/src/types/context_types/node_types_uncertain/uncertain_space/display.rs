@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Debug, Display, Formatter};
+
+use super::*;
+
+impl<T> Display for UncertainSpace<T>
+where
+    T: Debug
+        + Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<T, Output = T>
+        + Sub<T, Output = T>
+        + Mul<T, Output = T>,
+{
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "UncertainSpace {{ id={:?}, x={:?}, y={:?}, z={:?}, uncertain_x={}, uncertain_y={}, uncertain_z={} }}",
+            self.id, self.x, self.y, self.z, self.uncertain_x(), self.uncertain_y(), self.uncertain_z()
+        )
+    }
+}
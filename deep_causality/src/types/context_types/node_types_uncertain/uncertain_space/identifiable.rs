@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::prelude::Identifiable;
+
+use super::*;
+
+impl<T> Identifiable for UncertainSpace<T>
+where
+    T: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<T, Output = T>
+        + Sub<T, Output = T>
+        + Mul<T, Output = T>,
+{
+    fn id(&self) -> u64 {
+        self.id
+    }
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::*;
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::{NumericalValue, UncertainValue};
+
+mod adjustable;
+mod display;
+mod identifiable;
+mod spatial;
+
+/// Location with uncertain coordinates.
+///
+/// x, y, z are the nominal coordinates used by the spatial index of a context
+/// and do not change. uncertain_position holds the current estimate of x, y, z.
+/// update fuses the measured coordinates from the array grid, assuming the measurement variance,
+/// into the current estimate. adjust shifts the current estimate by the values from the array grid.
+#[derive(Getters, Constructor, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UncertainSpace<T>
+where
+    T: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<T, Output = T>
+        + Sub<T, Output = T>
+        + Mul<T, Output = T>,
+{
+    #[getter(name = space_id)] // Rename ID getter to prevent conflict impl with identifiable
+    id: u64,
+    x: T,
+    y: T,
+    z: T,
+    uncertain_position: [UncertainValue; 3],
+    measurement_variance: NumericalValue,
+}
+
+impl<T> UncertainSpace<T>
+where
+    T: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<T, Output = T>
+        + Sub<T, Output = T>
+        + Mul<T, Output = T>,
+{
+    pub fn uncertain_x(&self) -> &UncertainValue {
+        &self.uncertain_position[0]
+    }
+
+    pub fn uncertain_y(&self) -> &UncertainValue {
+        &self.uncertain_position[1]
+    }
+
+    pub fn uncertain_z(&self) -> &UncertainValue {
+        &self.uncertain_position[2]
+    }
+
+    /// Returns the probability that the location lies within the box from min to max,
+    /// assuming independent coordinates.
+    pub fn probability_in_bbox(
+        &self,
+        min: [NumericalValue; 3],
+        max: [NumericalValue; 3],
+    ) -> NumericalValue {
+        self.uncertain_position
+            .iter()
+            .zip(min.iter().zip(max.iter()))
+            .map(|(value, (lower, upper))| value.probability_between(*lower, *upper))
+            .product()
+    }
+}
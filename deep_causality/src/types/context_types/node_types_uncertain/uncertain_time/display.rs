@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Debug, Display, Formatter};
+
+use super::*;
+
+impl<T> Display for UncertainTime<T>
+where
+    T: Debug
+        + Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<T, Output = T>
+        + Sub<T, Output = T>
+        + Mul<T, Output = T>,
+{
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "UncertainTime {{ id={:?}, time_scale={}, time_unit={:?}, uncertain_time={} }}",
+            self.id, self.time_scale, self.time_unit, self.uncertain_time
+        )
+    }
+}
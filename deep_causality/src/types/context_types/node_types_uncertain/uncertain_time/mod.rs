@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::*;
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::{NumericalValue, TimeScale, UncertainValue};
+
+mod adjustable;
+mod display;
+mod identifiable;
+mod temporable;
+
+/// Point in time with uncertainty.
+///
+/// time_unit is the nominal time used by the time index of a context and does not change.
+/// uncertain_time holds the current estimate. update fuses the measured time from the array grid,
+/// assuming the measurement variance, into the current estimate.
+/// adjust shifts the current estimate by the value from the array grid.
+#[derive(Getters, Constructor, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UncertainTime<T>
+where
+    T: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<T, Output = T>
+        + Sub<T, Output = T>
+        + Mul<T, Output = T>,
+{
+    #[getter(name = time_id)] // Rename ID getter to prevent conflict impl with identifiable
+    id: u64,
+    time_scale: TimeScale,
+    time_unit: T,
+    uncertain_time: UncertainValue,
+    measurement_variance: NumericalValue,
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::prelude::Temporable;
+
+use super::*;
+
+impl<T> Temporable<T> for UncertainTime<T>
+where
+    T: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<T, Output = T>
+        + Sub<T, Output = T>
+        + Mul<T, Output = T>,
+{
+    fn time_scale(&self) -> TimeScale {
+        self.time_scale
+    }
+
+    fn time_unit(&self) -> &T {
+        &self.time_unit
+    }
+}
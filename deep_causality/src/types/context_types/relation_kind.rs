@@ -3,6 +3,7 @@
 use std::fmt::{Debug, Display};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum RelationKind {
     Datial,
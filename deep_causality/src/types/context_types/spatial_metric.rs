@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Debug, Display};
+
+/// Distance metric used by the spatial index of a context.
+///
+/// Euclidean: x, y, z are cartesian coordinates and distances are in the same unit.
+///
+/// Geodesic: x is the latitude and y the longitude in degrees, z is ignored,
+/// and distances are great circle distances in meters.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[repr(u8)]
+pub enum SpatialMetric {
+    #[default]
+    Euclidean,
+    Geodesic,
+}
+
+impl Display for SpatialMetric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -3,7 +3,11 @@
 
 use std::fmt::{Display, Formatter};
 
+use crate::prelude::{NumericalValue, TimeError};
+use crate::utils::time_utils::*;
+
 #[derive(Debug, Default, Copy, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum TimeScale {
     #[default]
@@ -16,6 +20,68 @@ pub enum TimeScale {
     Month,
     Quarter,
     Year,
+    // Added after Year to keep the discriminants of the scales above.
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+}
+
+impl TimeScale {
+    /// Returns the duration of one unit in nanoseconds,
+    /// or None for calendar scales of varying length and NoScale.
+    pub fn duration_nanos(&self) -> Option<i64> {
+        match self {
+            TimeScale::Nanosecond => Some(1),
+            TimeScale::Microsecond => Some(NANOS_PER_MICROSECOND),
+            TimeScale::Millisecond => Some(NANOS_PER_MILLISECOND),
+            TimeScale::Second => Some(NANOS_PER_SECOND),
+            TimeScale::Minute => Some(NANOS_PER_MINUTE),
+            TimeScale::Hour => Some(NANOS_PER_HOUR),
+            TimeScale::Day => Some(NANOS_PER_DAY),
+            TimeScale::Week => Some(NANOS_PER_WEEK),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of months in one unit of a calendar scale, otherwise None.
+    pub fn months(&self) -> Option<i64> {
+        match self {
+            TimeScale::Month => Some(1),
+            TimeScale::Quarter => Some(3),
+            TimeScale::Year => Some(12),
+            _ => None,
+        }
+    }
+
+    /// Returns true for month, quarter, and year, whose length depends on the calendar.
+    pub fn is_calendar(&self) -> bool {
+        self.months().is_some()
+    }
+
+    /// Converts a value in units of this scale into units of the target scale,
+    /// e.g. 90 Minute to 1.5 Hour or 2 Year to 8 Quarter.
+    /// Returns TimeError if either scale is NoScale, or one scale is a calendar scale
+    /// and the other is not, because months differ in length.
+    pub fn convert(
+        &self,
+        value: NumericalValue,
+        target: TimeScale,
+    ) -> Result<NumericalValue, TimeError> {
+        let (from, to) = match (self.duration_nanos(), target.duration_nanos()) {
+            (Some(from), Some(to)) => (from, to),
+            _ => match (self.months(), target.months()) {
+                (Some(from), Some(to)) => (from, to),
+                _ => {
+                    return Err(TimeError(format!(
+                        "Cannot convert between {} and {}",
+                        self, target
+                    )))
+                }
+            },
+        };
+
+        Ok(value * from as NumericalValue / to as NumericalValue)
+    }
 }
 
 impl Display for TimeScale {
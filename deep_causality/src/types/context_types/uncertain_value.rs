@@ -0,0 +1,291 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::Display;
+
+use crate::prelude::{NumericalValue, UncertaintyError};
+use crate::utils::math_utils;
+
+// Number of steps used to integrate a condition over a continuous distribution.
+const INTEGRATION_STEPS: usize = 4096;
+
+// Normal distributions are integrated within this many standard deviations of the mean.
+const NORMAL_RANGE: NumericalValue = 8.0;
+
+/// Distribution of a value that is not known exactly, e.g. a sensor reading.
+///
+/// Normal: mean and variance of a normal distribution.
+///
+/// Interval: the value lies anywhere between lower and upper with equal probability.
+///
+/// Samples: the value is one of the samples with equal probability.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UncertainValue {
+    Normal {
+        mean: NumericalValue,
+        variance: NumericalValue,
+    },
+    Interval {
+        lower: NumericalValue,
+        upper: NumericalValue,
+    },
+    Samples(Vec<NumericalValue>),
+}
+
+impl UncertainValue {
+    /// Returns a normal distribution.
+    /// Returns UncertaintyError if the mean is not finite
+    /// or the variance is negative or not finite.
+    pub fn normal(
+        mean: NumericalValue,
+        variance: NumericalValue,
+    ) -> Result<Self, UncertaintyError> {
+        if !mean.is_finite() {
+            return Err(UncertaintyError("Mean is not a finite number".into()));
+        }
+
+        if !variance.is_finite() || variance < 0.0 {
+            return Err(UncertaintyError(
+                "Variance must be a finite, non-negative number".into(),
+            ));
+        }
+
+        Ok(Self::Normal { mean, variance })
+    }
+
+    /// Returns a uniform distribution between lower and upper.
+    /// Returns UncertaintyError if a bound is not finite or lower is greater than upper.
+    pub fn interval(
+        lower: NumericalValue,
+        upper: NumericalValue,
+    ) -> Result<Self, UncertaintyError> {
+        if !lower.is_finite() || !upper.is_finite() {
+            return Err(UncertaintyError("Bounds must be finite numbers".into()));
+        }
+
+        if lower > upper {
+            return Err(UncertaintyError(format!(
+                "Lower bound {} is greater than upper bound {}",
+                lower, upper
+            )));
+        }
+
+        Ok(Self::Interval { lower, upper })
+    }
+
+    /// Returns an empirical distribution of the samples.
+    /// Returns UncertaintyError if there are no samples or a sample is not finite.
+    pub fn samples(samples: Vec<NumericalValue>) -> Result<Self, UncertaintyError> {
+        if samples.is_empty() {
+            return Err(UncertaintyError("Samples are empty".into()));
+        }
+
+        if samples.iter().any(|s| !s.is_finite()) {
+            return Err(UncertaintyError("Samples must be finite numbers".into()));
+        }
+
+        Ok(Self::Samples(samples))
+    }
+
+    /// Returns a value known exactly as a normal distribution with a variance of zero.
+    pub fn exact(value: NumericalValue) -> Self {
+        Self::Normal {
+            mean: value,
+            variance: 0.0,
+        }
+    }
+
+    pub fn mean(&self) -> NumericalValue {
+        match self {
+            Self::Normal { mean, .. } => *mean,
+            Self::Interval { lower, upper } => (lower + upper) / 2.0,
+            Self::Samples(samples) => {
+                samples.iter().sum::<NumericalValue>() / samples.len() as NumericalValue
+            }
+        }
+    }
+
+    pub fn variance(&self) -> NumericalValue {
+        match self {
+            Self::Normal { variance, .. } => *variance,
+            Self::Interval { lower, upper } => (upper - lower).powi(2) / 12.0,
+            Self::Samples(samples) => {
+                let mean = self.mean();
+                samples
+                    .iter()
+                    .map(|s| (s - mean).powi(2))
+                    .sum::<NumericalValue>()
+                    / samples.len() as NumericalValue
+            }
+        }
+    }
+
+    pub fn std_dev(&self) -> NumericalValue {
+        self.variance().sqrt()
+    }
+
+    /// Returns the probability that the value is less than or equal to x.
+    pub fn probability_below(&self, x: NumericalValue) -> NumericalValue {
+        match self {
+            Self::Normal { mean, variance } => math_utils::normal_cdf(x, *mean, variance.sqrt()),
+            Self::Interval { lower, upper } => {
+                if x < *lower {
+                    0.0
+                } else if x >= *upper {
+                    1.0
+                } else {
+                    (x - lower) / (upper - lower)
+                }
+            }
+            Self::Samples(samples) => {
+                samples.iter().filter(|s| **s <= x).count() as NumericalValue
+                    / samples.len() as NumericalValue
+            }
+        }
+    }
+
+    /// Returns the probability that the value is greater than x.
+    pub fn probability_above(&self, x: NumericalValue) -> NumericalValue {
+        1.0 - self.probability_below(x)
+    }
+
+    /// Returns the probability that the value lies between lower and upper.
+    pub fn probability_between(
+        &self,
+        lower: NumericalValue,
+        upper: NumericalValue,
+    ) -> NumericalValue {
+        if lower > upper {
+            return 0.0;
+        }
+
+        match self {
+            // Samples equal to the lower bound count as well.
+            Self::Samples(samples) => {
+                samples
+                    .iter()
+                    .filter(|s| **s >= lower && **s <= upper)
+                    .count() as NumericalValue
+                    / samples.len() as NumericalValue
+            }
+            _ => self.probability_below(upper) - self.probability_below(lower),
+        }
+    }
+
+    /// Returns the probability that the condition holds for the value.
+    /// Samples are counted exactly; normal distributions and intervals
+    /// are integrated numerically, so the result is an approximation.
+    pub fn probability<F>(&self, condition: F) -> NumericalValue
+    where
+        F: Fn(NumericalValue) -> bool,
+    {
+        match self {
+            Self::Normal { mean, variance } => {
+                let std_dev = variance.sqrt();
+                if std_dev == 0.0 {
+                    return if condition(*mean) { 1.0 } else { 0.0 };
+                }
+
+                let lower = mean - NORMAL_RANGE * std_dev;
+                let upper = mean + NORMAL_RANGE * std_dev;
+                Self::integrate(lower, upper, condition, |a, b| {
+                    math_utils::normal_cdf(b, *mean, std_dev)
+                        - math_utils::normal_cdf(a, *mean, std_dev)
+                })
+            }
+            Self::Interval { lower, upper } => {
+                if lower == upper {
+                    return if condition(*lower) { 1.0 } else { 0.0 };
+                }
+
+                Self::integrate(*lower, *upper, condition, |a, b| (b - a) / (upper - lower))
+            }
+            Self::Samples(samples) => {
+                samples.iter().filter(|s| condition(**s)).count() as NumericalValue
+                    / samples.len() as NumericalValue
+            }
+        }
+    }
+
+    /// Returns the distribution shifted by delta, e.g. after a known movement.
+    pub fn shift(&self, delta: NumericalValue) -> Self {
+        match self {
+            Self::Normal { mean, variance } => Self::Normal {
+                mean: mean + delta,
+                variance: *variance,
+            },
+            Self::Interval { lower, upper } => Self::Interval {
+                lower: lower + delta,
+                upper: upper + delta,
+            },
+            Self::Samples(samples) => Self::Samples(samples.iter().map(|s| s + delta).collect()),
+        }
+    }
+
+    /// Fuses two independent estimates of the same value, as in the update step of a Kalman filter.
+    /// Both estimates are approximated by a normal distribution with their mean and variance,
+    /// and the result is weighted by the inverse variances.
+    ///
+    /// An estimate with a variance of zero is exact and takes precedence. This is the limit
+    /// of the Kalman update: as the variance of self goes to zero, the gain goes to zero and
+    /// the other estimate is ignored; as the variance of other goes to zero, the gain goes to one
+    /// and the result is the other estimate. If both are exact, self is returned unchanged.
+    /// Consequently, an uncertain node updated with a measurement variance of zero takes
+    /// the first measurement as exact and keeps it for all later updates.
+    pub fn fuse(&self, other: &Self) -> Self {
+        let (m1, v1) = (self.mean(), self.variance());
+        let (m2, v2) = (other.mean(), other.variance());
+
+        if v1 == 0.0 {
+            return Self::exact(m1);
+        }
+
+        if v2 == 0.0 {
+            return Self::exact(m2);
+        }
+
+        // Kalman gain
+        let gain = v1 / (v1 + v2);
+        Self::Normal {
+            mean: m1 + gain * (m2 - m1),
+            variance: (1.0 - gain) * v1,
+        }
+    }
+
+    // Sums the probability mass of all steps whose midpoint satisfies the condition.
+    fn integrate<F, M>(
+        lower: NumericalValue,
+        upper: NumericalValue,
+        condition: F,
+        mass: M,
+    ) -> NumericalValue
+    where
+        F: Fn(NumericalValue) -> bool,
+        M: Fn(NumericalValue, NumericalValue) -> NumericalValue,
+    {
+        let step = (upper - lower) / INTEGRATION_STEPS as NumericalValue;
+
+        (0..INTEGRATION_STEPS)
+            .map(|i| lower + i as NumericalValue * step)
+            .filter(|a| condition(a + step / 2.0))
+            .map(|a| mass(a, a + step))
+            .sum::<NumericalValue>()
+            .clamp(0.0, 1.0)
+    }
+}
+
+impl Display for UncertainValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Normal { mean, variance } => {
+                write!(f, "Normal: mean: {}, variance: {}", mean, variance)
+            }
+            Self::Interval { lower, upper } => {
+                write!(f, "Interval: lower: {}, upper: {}", lower, upper)
+            }
+            Self::Samples(samples) => {
+                write!(f, "Samples: n: {}, mean: {}", samples.len(), self.mean())
+            }
+        }
+    }
+}
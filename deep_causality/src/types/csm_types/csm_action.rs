@@ -9,9 +9,19 @@ pub struct CausalAction {
     action: fn() -> Result<(), ActionError>,
     descr: &'static str,
     version: usize,
+    // Name of the action in a CausalFunctionRegistry, if known.
+    #[new(default)]
+    name: Option<String>,
 }
 
 impl CausalAction {
+    /// Sets the name under which the action is registered in a CausalFunctionRegistry.
+    /// The name is required to serialize a CSM with the action.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
     pub fn fire(&self) -> Result<(), ActionError> {
         (self.action)()
     }
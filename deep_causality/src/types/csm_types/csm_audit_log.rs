@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter};
+
+use crate::errors::AuditError;
+use crate::prelude::{AuditSink, CSMAuditRecord};
+
+/// Append-only audit log of CSM decisions.
+///
+/// Keeps the most recent records in a fixed capacity in-memory ring
+/// and forwards every record to an optional sink for persistent storage.
+/// When the ring is full, the oldest record is dropped from memory,
+/// but remains in the sink.
+pub struct CSMAuditLog {
+    capacity: usize,
+    next_seq: u64,
+    records: VecDeque<CSMAuditRecord>,
+    sink: Option<Box<dyn AuditSink>>,
+}
+
+impl CSMAuditLog {
+    /// Constructs a new in-memory audit log that keeps at most capacity records.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            next_seq: 1,
+            records: VecDeque::with_capacity(capacity),
+            sink: None,
+        }
+    }
+
+    /// Constructs a new audit log that keeps at most capacity records in memory
+    /// and writes every record to the given sink.
+    pub fn with_sink(capacity: usize, sink: Box<dyn AuditSink>) -> Self {
+        Self {
+            sink: Some(sink),
+            ..Self::new(capacity)
+        }
+    }
+
+    /// Returns the maximum number of records kept in memory.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of records kept in memory.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns true if no records are kept in memory.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns true if the log writes records to a sink.
+    pub fn has_sink(&self) -> bool {
+        self.sink.is_some()
+    }
+
+    /// Returns the total number of records appended since the log was created.
+    pub fn total_appended(&self) -> u64 {
+        self.next_seq - 1
+    }
+
+    /// Returns the records kept in memory, oldest first.
+    pub fn records(&self) -> Vec<CSMAuditRecord> {
+        self.records.iter().cloned().collect()
+    }
+
+    /// Returns an iterator over the records kept in memory, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &CSMAuditRecord> {
+        self.records.iter()
+    }
+
+    /// Appends a record to the log and assigns it the next sequence number.
+    /// The record is written to the sink first and only kept in memory if that succeeded.
+    /// Returns AuditError if the sink failed to write the record.
+    pub fn append(&mut self, record: CSMAuditRecord) -> Result<(), AuditError> {
+        let record = record.with_seq(self.next_seq);
+
+        if let Some(sink) = self.sink.as_mut() {
+            sink.write_record(&record)?;
+        }
+
+        self.next_seq += 1;
+
+        if self.capacity == 0 {
+            return Ok(());
+        }
+
+        if self.records.len() == self.capacity {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+
+        Ok(())
+    }
+
+    /// Flushes the sink, if any.
+    /// Returns AuditError if the sink failed to flush.
+    pub fn flush(&mut self) -> Result<(), AuditError> {
+        match self.sink.as_mut() {
+            Some(sink) => sink.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Debug for CSMAuditLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CSMAuditLog")
+            .field("capacity", &self.capacity)
+            .field("next_seq", &self.next_seq)
+            .field("records", &self.records)
+            .field("has_sink", &self.sink.is_some())
+            .finish()
+    }
+}
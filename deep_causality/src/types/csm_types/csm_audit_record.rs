@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use deep_causality_macros::Getters;
+
+#[cfg(feature = "serde")]
+use crate::errors::AuditError;
+use crate::prelude::NumericalValue;
+
+/// A single decision made by the CSM.
+///
+/// Records the state id and version, the input data, the causaloid explanation,
+/// the decision of the state, whether the associated action actually fired,
+/// and the error, if any, that occurred while evaluating the state or firing the action.
+///
+/// The decision is Some(true) if the state evaluated to true, Some(false) if it
+/// evaluated to false, and None if the evaluation of the state failed.
+/// The action fired only if the decision is Some(true) and the action did not fail.
+#[derive(Getters, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CSMAuditRecord {
+    seq: u64,
+    timestamp_ms: u64,
+    state_id: usize,
+    state_version: usize,
+    // serde_json writes a data value that is not finite as null.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_data"))]
+    data: NumericalValue,
+    explanation: Option<String>,
+    decision: Option<bool>,
+    fired: bool,
+    error: Option<String>,
+}
+
+impl CSMAuditRecord {
+    /// Constructs a new audit record stamped with the current wall-clock time.
+    /// The sequence number is assigned when the record is appended to a `CSMAuditLog`.
+    pub fn new(
+        state_id: usize,
+        state_version: usize,
+        data: NumericalValue,
+        explanation: Option<String>,
+        decision: Option<bool>,
+        fired: bool,
+        error: Option<String>,
+    ) -> Self {
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+
+        Self {
+            seq: 0,
+            timestamp_ms,
+            state_id,
+            state_version,
+            data,
+            explanation,
+            decision,
+            fired,
+            error,
+        }
+    }
+
+    pub(crate) fn with_seq(mut self, seq: u64) -> Self {
+        self.seq = seq;
+        self
+    }
+}
+
+#[cfg(feature = "serde")]
+impl CSMAuditRecord {
+    /// Serializes the record into a single line of JSON without a trailing newline.
+    /// A data value that is not finite is written as null.
+    pub fn to_json_line(&self) -> String {
+        serde_json::to_string(self).expect("Failed to serialize audit record")
+    }
+
+    /// Parses a record from a single line of JSON as written by `to_json_line`.
+    /// Returns AuditError if the line is not a valid audit record.
+    pub fn from_json_line(line: &str) -> Result<Self, AuditError> {
+        serde_json::from_str(line).map_err(|e| AuditError(format!("Malformed audit record: {}", e)))
+    }
+}
+
+impl Display for CSMAuditRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CSMAuditRecord: seq: {} state: {} version: {} data: {} decision: {:?} fired: {}",
+            self.seq, self.state_id, self.state_version, self.data, self.decision, self.fired
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_data<'de, D>(deserializer: D) -> Result<NumericalValue, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let data: Option<NumericalValue> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(data.unwrap_or(NumericalValue::NAN))
+}
@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::errors::AuditError;
+use crate::prelude::{AuditSink, CSMAuditRecord};
+
+/// Audit sink that appends one JSON object per line to a file.
+///
+/// Each record is flushed to the file as soon as it is written
+/// so that no decision is lost when the process terminates.
+#[derive(Debug)]
+pub struct JsonLinesAuditSink {
+    path: PathBuf,
+    writer: BufWriter<File>,
+}
+
+impl JsonLinesAuditSink {
+    /// Opens the file at path in append mode, creating it if it does not exist.
+    /// Returns AuditError if the file cannot be opened.
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, AuditError> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| {
+                AuditError(format!(
+                    "Failed to open audit file {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+
+        Ok(Self {
+            path,
+            writer: BufWriter::new(file),
+        })
+    }
+
+    /// Returns the path of the audit file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads all records from a JSON lines audit file. Empty lines are skipped.
+    /// Returns AuditError if the file cannot be read or a line is not a valid record.
+    pub fn read_records(path: impl AsRef<Path>) -> Result<Vec<CSMAuditRecord>, AuditError> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| {
+            AuditError(format!(
+                "Failed to open audit file {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        let mut records = Vec::new();
+        for (i, line) in BufReader::new(file).lines().enumerate() {
+            let line = line.map_err(|e| {
+                AuditError(format!(
+                    "Failed to read line {} of audit file {}: {}",
+                    i + 1,
+                    path.display(),
+                    e
+                ))
+            })?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let record = CSMAuditRecord::from_json_line(&line)
+                .map_err(|e| AuditError(format!("Line {}: {}", i + 1, e.0)))?;
+            records.push(record);
+        }
+
+        Ok(records)
+    }
+}
+
+impl AuditSink for JsonLinesAuditSink {
+    fn write_record(&mut self, record: &CSMAuditRecord) -> Result<(), AuditError> {
+        writeln!(self.writer, "{}", record.to_json_line())
+            .and_then(|_| self.writer.flush())
+            .map_err(|e| {
+                AuditError(format!(
+                    "Failed to write audit record to {}: {}",
+                    self.path.display(),
+                    e
+                ))
+            })
+    }
+
+    fn flush(&mut self) -> Result<(), AuditError> {
+        self.writer.flush().map_err(|e| {
+            AuditError(format!(
+                "Failed to flush audit file {}: {}",
+                self.path.display(),
+                e
+            ))
+        })
+    }
+}
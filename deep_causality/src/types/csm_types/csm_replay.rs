@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+use deep_causality_macros::Getters;
+
+use crate::prelude::{CSMAuditRecord, Datable, SpaceTemporal, Spatial, Temporable, CSM};
+
+/// A recorded decision that changed when the audit log was replayed.
+#[derive(Getters, Clone, Debug, PartialEq)]
+pub struct CSMReplayChange {
+    seq: u64,
+    state_id: usize,
+    recorded_version: usize,
+    /// None if the state does not exist in the replaying CSM.
+    replayed_version: Option<usize>,
+    /// None if the evaluation failed when the decision was recorded.
+    recorded_decision: Option<bool>,
+    /// None if the state does not exist or its evaluation failed during replay.
+    replayed_decision: Option<bool>,
+    replay_error: Option<String>,
+}
+
+/// Result of replaying an audit log against a CSM.
+#[derive(Getters, Clone, Debug, PartialEq)]
+pub struct CSMReplayReport {
+    total: usize,
+    changes: Vec<CSMReplayChange>,
+}
+
+impl CSMReplayReport {
+    /// Returns the number of replayed decisions that did not change.
+    pub fn unchanged(&self) -> usize {
+        self.total - self.changes.len()
+    }
+
+    /// Returns true if at least one replayed decision changed.
+    pub fn has_changes(&self) -> bool {
+        !self.changes.is_empty()
+    }
+}
+
+impl Display for CSMReplayReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CSMReplayReport: replayed: {} unchanged: {} changed: {}",
+            self.total,
+            self.unchanged(),
+            self.changes.len()
+        )
+    }
+}
+
+impl<'l, D, S, T, ST, V> CSM<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Copy,
+    S: Spatial<V> + Clone + Copy,
+    T: Temporable<V> + Clone + Copy,
+    ST: SpaceTemporal<V> + Clone + Copy,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Re-evaluates each recorded decision with the recorded data against the states of this CSM
+    /// and reports all decisions that changed.
+    ///
+    /// Replay never fires actions and never writes to the audit log of this CSM.
+    /// A record whose state does not exist in this CSM is reported as changed.
+    pub fn replay(&self, records: &[CSMAuditRecord]) -> CSMReplayReport {
+        let binding = self.state_actions.borrow();
+        let mut changes = Vec::new();
+
+        for record in records {
+            let recorded_decision = *record.decision();
+
+            let (replayed_version, replayed_decision, replay_error) =
+                match binding.get(record.state_id()) {
+                    None => (
+                        None,
+                        None,
+                        Some(format!("State {} does not exist", record.state_id())),
+                    ),
+                    Some((state, _)) => match state.eval_with_data(record.data()) {
+                        Ok(trigger) => (Some(*state.version()), Some(trigger), None),
+                        Err(e) => (Some(*state.version()), None, Some(e.to_string())),
+                    },
+                };
+
+            if replayed_version.is_none() || replayed_decision != recorded_decision {
+                changes.push(CSMReplayChange {
+                    seq: *record.seq(),
+                    state_id: *record.state_id(),
+                    recorded_version: *record.state_version(),
+                    replayed_version,
+                    recorded_decision,
+                    replayed_decision,
+                    replay_error,
+                });
+            }
+        }
+
+        CSMReplayReport {
+            total: records.len(),
+            changes,
+        }
+    }
+}
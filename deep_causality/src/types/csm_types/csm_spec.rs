@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{
+    CausalAction, CausalFunctionRegistry, CausalState, Causaloid, CausaloidSpec, Context, Datable,
+    NumericalValue, SerializationError, SpaceTemporal, Spatial, Temporable, CSM,
+};
+use crate::types::csm_types::CSMStateAction;
+use crate::utils::serde_utils::SpecCache;
+
+type CausaloidCache<'l, D, S, T, ST, V> = SpecCache<Vec<Causaloid<'l, D, S, T, ST, V>>>;
+type StateCache<'l, D, S, T, ST, V> = SpecCache<Vec<CausalState<'l, D, S, T, ST, V>>>;
+type StateActionCache<'l, D, S, T, ST, V> = SpecCache<Vec<CSMStateAction<'l, D, S, T, ST, V>>>;
+
+/// Serializable description of a causal state machine.
+///
+/// Actions are referred to by their name in a CausalFunctionRegistry.
+/// The state history and audit log are not persisted.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(bound = "")]
+pub struct CSMSpec<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Copy,
+    S: Spatial<V> + Clone + Copy,
+    T: Temporable<V> + Clone + Copy,
+    ST: SpaceTemporal<V> + Clone + Copy,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    states: Vec<CSMStateSpec<'l, D, S, T, ST, V>>,
+    #[serde(skip)]
+    causaloids: CausaloidCache<'l, D, S, T, ST, V>,
+    #[serde(skip)]
+    causal_states: StateCache<'l, D, S, T, ST, V>,
+    #[serde(skip)]
+    actions: SpecCache<Vec<CausalAction>>,
+    #[serde(skip)]
+    state_actions: StateActionCache<'l, D, S, T, ST, V>,
+}
+
+/// Serializable description of a state and its action in a CSMSpec.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(bound = "")]
+pub struct CSMStateSpec<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Copy,
+    S: Spatial<V> + Clone + Copy,
+    T: Temporable<V> + Clone + Copy,
+    ST: SpaceTemporal<V> + Clone + Copy,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    id: usize,
+    version: usize,
+    data: NumericalValue,
+    causaloid: CausaloidSpec<'l, D, S, T, ST, V>,
+    /// Name of a registered action.
+    action: String,
+    action_version: usize,
+}
+
+impl<'l, D, S, T, ST, V> CSMStateSpec<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Copy,
+    S: Spatial<V> + Clone + Copy,
+    T: Temporable<V> + Clone + Copy,
+    ST: SpaceTemporal<V> + Clone + Copy,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    pub fn new(
+        id: usize,
+        version: usize,
+        data: NumericalValue,
+        causaloid: CausaloidSpec<'l, D, S, T, ST, V>,
+        action: &str,
+        action_version: usize,
+    ) -> Self {
+        Self {
+            id,
+            version,
+            data,
+            causaloid,
+            action: action.to_string(),
+            action_version,
+        }
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    pub fn data(&self) -> NumericalValue {
+        self.data
+    }
+
+    pub fn causaloid(&self) -> &CausaloidSpec<'l, D, S, T, ST, V> {
+        &self.causaloid
+    }
+
+    pub fn action(&self) -> &str {
+        self.action.as_str()
+    }
+
+    pub fn action_version(&self) -> usize {
+        self.action_version
+    }
+}
+
+impl<'l, D, S, T, ST, V> CSMSpec<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Copy,
+    S: Spatial<V> + Clone + Copy,
+    T: Temporable<V> + Clone + Copy,
+    ST: SpaceTemporal<V> + Clone + Copy,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    pub fn new(states: Vec<CSMStateSpec<'l, D, S, T, ST, V>>) -> Self {
+        Self {
+            states,
+            causaloids: SpecCache::default(),
+            causal_states: SpecCache::default(),
+            actions: SpecCache::default(),
+            state_actions: SpecCache::default(),
+        }
+    }
+
+    /// Describes all states and actions of the CSM, ordered by state id.
+    /// Actions are described by their registry name, which actions built from a spec
+    /// or taken from a CausalFunctionRegistry carry and others set with CausalAction::with_name.
+    /// Returns SerializationError if a causal function or action has no name
+    /// or the name is not registered.
+    pub fn from_csm<'r>(
+        csm: &CSM<'r, D, S, T, ST, V>,
+        registry: &CausalFunctionRegistry<'r, D, S, T, ST, V>,
+    ) -> Result<Self, SerializationError> {
+        let state_actions = csm.state_actions.borrow();
+        let mut ids: Vec<&usize> = state_actions.keys().collect();
+        ids.sort();
+
+        let mut states = Vec::with_capacity(ids.len());
+        for id in ids {
+            let (state, action) = state_actions[id];
+            let action_name = action.name().as_deref().ok_or_else(|| {
+                SerializationError(format!("The action of state {} is not registered", id))
+            })?;
+
+            if registry.action(action_name).is_none() {
+                return Err(SerializationError(format!(
+                    "Unknown action '{}' in state {}",
+                    action_name, id
+                )));
+            }
+
+            states.push(CSMStateSpec::new(
+                *state.id(),
+                *state.version(),
+                *state.data(),
+                CausaloidSpec::from_causaloid(state.causaloid(), registry)?,
+                action_name,
+                *action.version(),
+            ));
+        }
+
+        Ok(Self::new(states))
+    }
+
+    /// Builds the CSM with the functions and actions of the registry and the contexts.
+    /// States, causaloids, and actions are built once and kept in the spec.
+    ///
+    /// Returns SerializationError if a causaloid cannot be built or an action is not registered.
+    pub fn build(
+        &'l self,
+        registry: &CausalFunctionRegistry<'l, D, S, T, ST, V>,
+        contexts: &[&'l Context<D, S, T, ST, V>],
+    ) -> Result<CSM<'l, D, S, T, ST, V>, SerializationError> {
+        let causaloids = self.causaloids.get_or_try_init(|| {
+            self.states
+                .iter()
+                .map(|s| s.causaloid.build(registry, contexts))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+
+        let actions = self.actions.get_or_try_init(|| {
+            self.states
+                .iter()
+                .map(|s| {
+                    let action = registry.action(&s.action).ok_or_else(|| {
+                        SerializationError(format!(
+                            "Unknown action '{}' in state {}",
+                            s.action, s.id
+                        ))
+                    })?;
+                    Ok(
+                        CausalAction::new(*action.action(), action.descr(), s.action_version)
+                            .with_name(&s.action),
+                    )
+                })
+                .collect::<Result<Vec<_>, SerializationError>>()
+        })?;
+
+        let causal_states = self.causal_states.get_or_try_init(|| {
+            Ok::<_, SerializationError>(
+                self.states
+                    .iter()
+                    .zip(causaloids)
+                    .map(|(s, causaloid)| CausalState::new(s.id, s.version, s.data, causaloid))
+                    .collect(),
+            )
+        })?;
+
+        let state_actions = self.state_actions.get_or_try_init(|| {
+            Ok::<_, SerializationError>(causal_states.iter().zip(actions).collect())
+        })?;
+
+        Ok(CSM::new(state_actions))
+    }
+
+    pub fn states(&self) -> &[CSMStateSpec<'l, D, S, T, ST, V>] {
+        &self.states
+    }
+}
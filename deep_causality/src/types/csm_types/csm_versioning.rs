@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+use deep_causality_macros::Getters;
+
+use crate::errors::UpdateError;
+use crate::prelude::{
+    Datable, Identifiable, IdentificationValue, NumericalValue, SpaceTemporal, Spatial, Temporable,
+    CSM,
+};
+use crate::types::csm_types::CSMStateAction;
+
+/// Describes what changed between two versions of a causal state and its action.
+/// Each field is None if the corresponding value did not change,
+/// otherwise it holds the pair (from, to).
+#[derive(Getters, Clone, Debug, PartialEq)]
+pub struct CSMStateDiff {
+    state_id: usize,
+    from_version: usize,
+    to_version: usize,
+    data: Option<(NumericalValue, NumericalValue)>,
+    causaloid_id: Option<(IdentificationValue, IdentificationValue)>,
+    causaloid_description: Option<(String, String)>,
+    action_version: Option<(usize, usize)>,
+    action_description: Option<(String, String)>,
+}
+
+impl CSMStateDiff {
+    /// Returns true if anything other than the state version changed.
+    pub fn has_changes(&self) -> bool {
+        self.data.is_some()
+            || self.causaloid_id.is_some()
+            || self.causaloid_description.is_some()
+            || self.action_version.is_some()
+            || self.action_description.is_some()
+    }
+}
+
+impl Display for CSMStateDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CSMStateDiff: state: {} version: {} -> {}",
+            self.state_id, self.from_version, self.to_version
+        )?;
+        if let Some((from, to)) = &self.data {
+            write!(f, "\n data: {} -> {}", from, to)?;
+        }
+        if let Some((from, to)) = &self.causaloid_id {
+            write!(f, "\n causaloid id: {} -> {}", from, to)?;
+        }
+        if let Some((from, to)) = &self.causaloid_description {
+            write!(f, "\n causaloid description: {} -> {}", from, to)?;
+        }
+        if let Some((from, to)) = &self.action_version {
+            write!(f, "\n action version: {} -> {}", from, to)?;
+        }
+        if let Some((from, to)) = &self.action_description {
+            write!(f, "\n action description: {} -> {}", from, to)?;
+        }
+        Ok(())
+    }
+}
+
+fn changed<X: PartialEq>(from: X, to: X) -> Option<(X, X)> {
+    if from == to {
+        None
+    } else {
+        Some((from, to))
+    }
+}
+
+impl<'l, D, S, T, ST, V> CSM<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Copy,
+    S: Spatial<V> + Clone + Copy,
+    T: Temporable<V> + Clone + Copy,
+    ST: SpaceTemporal<V> + Clone + Copy,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Restores the previous version of the causal state at the index position idx.
+    /// The current version is discarded.
+    /// Returns UpdateError if the state does not exist or has no previous version.
+    pub fn rollback(&self, idx: usize) -> Result<(), UpdateError> {
+        if self.state_actions.borrow().get(&idx).is_none() {
+            return Err(UpdateError(format!(
+                "State {} does not exists and cannot be rolled back",
+                idx
+            )));
+        }
+
+        let previous = self
+            .state_history
+            .borrow_mut()
+            .get_mut(&idx)
+            .and_then(|versions| versions.pop());
+
+        match previous {
+            Some(state_action) => {
+                self.state_actions.borrow_mut().insert(idx, state_action);
+                Ok(())
+            }
+            None => Err(UpdateError(format!(
+                "State {} has no previous version to roll back to",
+                idx
+            ))),
+        }
+    }
+
+    /// Returns all versions of the causal state at the index position idx,
+    /// oldest first and ending with the current version.
+    /// Returns an empty vector if the state does not exist.
+    pub fn state_versions(&self, idx: usize) -> Vec<usize> {
+        let current = match self.state_actions.borrow().get(&idx) {
+            Some((state, _)) => *state.version(),
+            None => return Vec::new(),
+        };
+
+        let mut versions: Vec<usize> = self
+            .state_history
+            .borrow()
+            .get(&idx)
+            .map(|history| history.iter().map(|(state, _)| *state.version()).collect())
+            .unwrap_or_default();
+        versions.push(current);
+        versions
+    }
+
+    /// Returns what changed between two versions of the causal state at the index position idx.
+    /// Both versions must be either the current version or a previous version of the state.
+    /// Returns UpdateError if the state or any of the versions does not exist.
+    pub fn state_diff(
+        &self,
+        idx: usize,
+        from_version: usize,
+        to_version: usize,
+    ) -> Result<CSMStateDiff, UpdateError> {
+        let (from_state, from_action) = self.find_version(idx, from_version)?;
+        let (to_state, to_action) = self.find_version(idx, to_version)?;
+
+        Ok(CSMStateDiff {
+            state_id: idx,
+            from_version,
+            to_version,
+            data: changed(*from_state.data(), *to_state.data()),
+            causaloid_id: changed(from_state.causaloid().id(), to_state.causaloid().id()),
+            causaloid_description: changed(
+                from_state.causaloid().description().to_string(),
+                to_state.causaloid().description().to_string(),
+            ),
+            action_version: changed(*from_action.version(), *to_action.version()),
+            action_description: changed(
+                from_action.descr().to_string(),
+                to_action.descr().to_string(),
+            ),
+        })
+    }
+
+    fn find_version(
+        &self,
+        idx: usize,
+        version: usize,
+    ) -> Result<CSMStateAction<'l, D, S, T, ST, V>, UpdateError> {
+        let current = match self.state_actions.borrow().get(&idx) {
+            Some(current) => *current,
+            None => return Err(UpdateError(format!("State {} does not exists", idx))),
+        };
+
+        if *current.0.version() == version {
+            return Ok(current);
+        }
+
+        self.state_history
+            .borrow()
+            .get(&idx)
+            .and_then(|history| {
+                history
+                    .iter()
+                    .find(|(state, _)| *state.version() == version)
+                    .copied()
+            })
+            .ok_or_else(|| UpdateError(format!("State {} has no version {}", idx, version)))
+    }
+}
@@ -6,18 +6,31 @@ use std::collections::HashMap;
 use std::hash::Hash;
 use std::ops::{Add, Mul, Sub};
 
-use crate::errors::{ActionError, UpdateError};
+use crate::errors::{ActionError, AuditError, UpdateError};
 use crate::prelude::{
-    CausalAction, CausalState, Datable, NumericalValue, SpaceTemporal, Spatial, Temporable,
+    CSMAuditLog, CSMAuditRecord, Causable, CausalAction, CausalState, Datable, NumericalValue,
+    SpaceTemporal, Spatial, Temporable,
 };
 
 pub mod csm_action;
+pub mod csm_audit_log;
+pub mod csm_audit_record;
+#[cfg(feature = "serde")]
+pub mod csm_audit_sink;
+pub mod csm_replay;
+#[cfg(feature = "serde")]
+pub mod csm_spec;
 pub mod csm_state;
+pub mod csm_versioning;
 
 pub type CSMMap<'l, D, S, T, ST, V> =
     HashMap<usize, (&'l CausalState<'l, D, S, T, ST, V>, &'l CausalAction)>;
 pub type CSMStateActions<'l, D, S, T, ST, V> =
     [(&'l CausalState<'l, D, S, T, ST, V>, &'l CausalAction)];
+pub type CSMStateAction<'l, D, S, T, ST, V> =
+    (&'l CausalState<'l, D, S, T, ST, V>, &'l CausalAction);
+pub type CSMStateHistory<'l, D, S, T, ST, V> =
+    HashMap<usize, Vec<CSMStateAction<'l, D, S, T, ST, V>>>;
 
 pub struct CSM<'l, D, S, T, ST, V>
 where
@@ -36,6 +49,8 @@ where
         + Mul<V, Output = V>,
 {
     state_actions: RefCell<CSMMap<'l, D, S, T, ST, V>>,
+    state_history: RefCell<CSMStateHistory<'l, D, S, T, ST, V>>,
+    audit_log: Option<RefCell<CSMAuditLog>>,
 }
 
 impl<'l, D, S, T, ST, V> CSM<'l, D, S, T, ST, V>
@@ -64,6 +79,19 @@ where
 
         Self {
             state_actions: RefCell::new(state_map),
+            state_history: RefCell::new(HashMap::new()),
+            audit_log: None,
+        }
+    }
+
+    /// Constructs a new CSM that records every decision in the given audit log.
+    pub fn new_with_audit_log(
+        state_actions: &'l CSMStateActions<'l, D, S, T, ST, V>,
+        audit_log: CSMAuditLog,
+    ) -> Self {
+        Self {
+            audit_log: Some(RefCell::new(audit_log)),
+            ..Self::new(state_actions)
         }
     }
 
@@ -76,6 +104,20 @@ where
     pub fn is_empty(&self) -> bool {
         self.state_actions.borrow().is_empty()
     }
+
+    /// Returns true if the CSM records its decisions in an audit log.
+    pub fn has_audit_log(&self) -> bool {
+        self.audit_log.is_some()
+    }
+
+    /// Returns the audit records kept in memory, oldest first.
+    /// Returns an empty vector if the CSM has no audit log.
+    pub fn audit_records(&self) -> Vec<CSMAuditRecord> {
+        match &self.audit_log {
+            Some(log) => log.borrow().records(),
+            None => Vec::new(),
+        }
+    }
 }
 
 impl<'l, D, S, T, ST, V> CSM<'l, D, S, T, ST, V>
@@ -129,6 +171,8 @@ where
 
         // remove the new state/action at the idx position
         binding.remove(&id);
+        // and all its previous versions
+        self.state_history.borrow_mut().remove(&id);
 
         Ok(())
     }
@@ -152,6 +196,10 @@ where
 {
     /// Evaluates a single causal state at the index position idx.
     /// Returns ActionError if the evaluation failed.
+    ///
+    /// If the CSM has an audit log, the decision is recorded after the action fired.
+    /// When the record cannot be written, the returned ActionError states whether
+    /// the action had fired or failed.
     pub fn eval_single_state(&self, id: usize, data: NumericalValue) -> Result<(), ActionError> {
         // Need binding to prevent dropped tmp value warnings
         let binding = self.state_actions.borrow();
@@ -168,49 +216,74 @@ where
         // State exists, extract it.
         let (state, action) = state_action.unwrap();
 
-        // Apply data and evaluate causal state
-        let eval = state.eval_with_data(&data);
-
-        // Check if the causal state evaluation returned an error
-        if eval.is_err() {
-            return Err(ActionError(format!(
-                "CSM[eval]: Error evaluating causal state: {}",
-                state
-            )));
-        }
-
-        // Unpack the bool result that triggers the action
-        let trigger =
-            eval.expect("CSM[eval]: Failed to unwrap evaluation result from causal state}");
-
-        // If the state evaluated to true, fire the associated action.
-        if trigger && action.fire().is_err() {
-            return Err(ActionError(format!(
-                "CSM[eval]: Failed to fire action associated with causal state {}",
-                state
-            )));
-        }
-
-        Ok(())
+        self.eval_state_action(state, action, data)
     }
 
     /// Updates a causal state with a new state at the index position idx.
-    /// Returns UpdateError if the update operation failed.
+    /// The replaced state is kept as previous version and can be restored with `rollback`.
+    /// Returns UpdateError if the state does not exist, if the id of the new state differs
+    /// from idx, if the version of the new state is not newer than the current version,
+    /// or if the version of the new action is older than the current action version.
     pub fn update_single_state(
         &self,
         idx: usize,
         state_action: (&'l CausalState<'l, D, S, T, ST, V>, &'l CausalAction),
     ) -> Result<(), UpdateError> {
+        // Need binding to prevent dropped tmp value warnings
+        let mut binding = self.state_actions.borrow_mut();
+
         // Check if the key exists, if not return error
-        if self.state_actions.borrow().get(&idx).is_none() {
+        let current = match binding.get(&idx) {
+            Some(current) => *current,
+            None => {
+                return Err(UpdateError(format!(
+                    "State {} does not exists. Add it first before evaluating",
+                    idx
+                )))
+            }
+        };
+
+        let (current_state, current_action) = current;
+        let (new_state, new_action) = state_action;
+
+        // Check that the new state replaces the state stored at idx
+        if *new_state.id() != idx {
             return Err(UpdateError(format!(
-                "State {} does not exists. Add it first before evaluating",
-                idx
+                "State {}: id {} of the new state does not match",
+                idx,
+                new_state.id()
+            )));
+        }
+
+        // Check that the new state is a newer version
+        if new_state.version() <= current_state.version() {
+            return Err(UpdateError(format!(
+                "State {}: version {} is not newer than current version {}",
+                idx,
+                new_state.version(),
+                current_state.version()
+            )));
+        }
+
+        // Check that the new action is not an older version
+        if new_action.version() < current_action.version() {
+            return Err(UpdateError(format!(
+                "State {}: action version {} is older than current action version {}",
+                idx,
+                new_action.version(),
+                current_action.version()
             )));
         }
 
+        // Keep the current state/action as previous version
+        self.state_history
+            .borrow_mut()
+            .entry(idx)
+            .or_default()
+            .push(current);
+
         // Update state/action at the idx position
-        self.state_actions.borrow_mut().insert(idx, state_action);
+        binding.insert(idx, state_action);
 
         Ok(())
     }
@@ -234,36 +307,20 @@ where
 {
     /// Evaluates all causal states in the CSM.
     /// Returns ActionError if the evaluation failed.
+    ///
+    /// As with `eval_single_state`, a failure to record a decision is reported
+    /// after the action of that state fired, and the remaining states are not evaluated.
     pub fn eval_all_states(&self) -> Result<(), ActionError> {
         for (_, (state, action)) in self.state_actions.borrow().iter() {
-            let eval = state.eval();
-
-            // check if the causal state evaluation returned an error
-            if eval.is_err() {
-                return Err(ActionError(format!(
-                    "CSM[eval]: Error evaluating causal state: {}",
-                    state
-                )));
-            }
-
-            // Unpack the bool result
-            let trigger =
-                eval.expect("CSM[eval]: Failed to unwrap evaluation result from causal state}");
-
-            // If the state evaluated to true, fire the associated action.
-            if trigger && action.fire().is_err() {
-                return Err(ActionError(format!(
-                    "CSM[eval]: Failed to fire action associated with causal state {}",
-                    state
-                )));
-            }
+            self.eval_state_action(state, action, *state.data())?;
         }
 
         Ok(())
     }
 
     /// Updates all causal state with a new state collection.
-    /// Note, this operation erases all previous states in the CSM by generating a new collection.
+    /// Note, this operation erases all previous states and their versions
+    /// in the CSM by generating a new collection.
     /// Returns UpdateError if the update operation failed.
     pub fn update_all_states(&self, state_actions: &'l CSMStateActions<'l, D, S, T, ST, V>) {
         // Generate a new HashMap from the collection
@@ -273,6 +330,101 @@ where
         }
 
         // Replace the existing map with the newly generated one.
-        *self.state_actions.borrow_mut() = state_map
+        *self.state_actions.borrow_mut() = state_map;
+        self.state_history.borrow_mut().clear();
+    }
+
+    /// Evaluates the causal state with the given data and fires the associated action
+    /// if the state evaluated to true. Records the decision if the CSM has an audit log.
+    ///
+    /// The decision is recorded after the action fired, because the record contains
+    /// the result of the action. If the record cannot be written, the action has already
+    /// fired and the returned ActionError states whether it fired or failed.
+    fn eval_state_action(
+        &self,
+        state: &CausalState<'l, D, S, T, ST, V>,
+        action: &CausalAction,
+        data: NumericalValue,
+    ) -> Result<(), ActionError> {
+        // Apply data and evaluate causal state
+        let trigger = match state.eval_with_data(&data) {
+            Ok(trigger) => trigger,
+            Err(e) => {
+                self.audit(state, data, None, false, Some(e.to_string()))
+                    .map_err(|e| {
+                        ActionError(format!(
+                            "CSM[audit]: State {} failed to evaluate and the decision could not be recorded: {}",
+                            state.id(),
+                            e
+                        ))
+                    })?;
+                return Err(ActionError(format!(
+                    "CSM[eval]: Error evaluating causal state: {}",
+                    state
+                )));
+            }
+        };
+
+        // If the state evaluated to true, fire the associated action.
+        let action_error = if trigger {
+            action.fire().err().map(|e| e.to_string())
+        } else {
+            None
+        };
+        let fired = trigger && action_error.is_none();
+
+        if let Err(e) = self.audit(state, data, Some(trigger), fired, action_error.clone()) {
+            let outcome = match (trigger, &action_error) {
+                (false, _) => "no action fired".to_string(),
+                (true, None) => "action fired".to_string(),
+                (true, Some(action_error)) => format!("action failed: {}", action_error),
+            };
+            return Err(ActionError(format!(
+                "CSM[audit]: State {}: {}, but the decision could not be recorded: {}",
+                state.id(),
+                outcome,
+                e
+            )));
+        }
+
+        if action_error.is_some() {
+            return Err(ActionError(format!(
+                "CSM[eval]: Failed to fire action associated with causal state {}",
+                state
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn audit(
+        &self,
+        state: &CausalState<'l, D, S, T, ST, V>,
+        data: NumericalValue,
+        decision: Option<bool>,
+        fired: bool,
+        error: Option<String>,
+    ) -> Result<(), AuditError> {
+        let Some(log) = &self.audit_log else {
+            return Ok(());
+        };
+
+        let explanation = if decision == Some(true) {
+            state.causaloid().explain().ok()
+        } else {
+            None
+        };
+
+        let record = CSMAuditRecord::new(
+            *state.id(),
+            *state.version(),
+            data,
+            explanation,
+            decision,
+            fired,
+            error,
+        );
+
+        log.borrow_mut().append(record)
     }
 }
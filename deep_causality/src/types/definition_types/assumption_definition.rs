@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{DescriptionValue, IdentificationValue, NumericalValue, StatisticalTest};
+
+/// Declarative definition of an assumption.
+///
+/// An assumption either names a registered assumption function
+/// or a statistical test with an optional significance level.
+#[derive(Getters, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct AssumptionDefinition {
+    id: IdentificationValue,
+    #[serde(default)]
+    description: DescriptionValue,
+    /// Name of a registered assumption function.
+    #[serde(default)]
+    function: Option<String>,
+    #[serde(default)]
+    test: Option<StatisticalTest>,
+    /// Significance level of the test; DEFAULT_SIGNIFICANCE if not set.
+    #[serde(default)]
+    significance: Option<NumericalValue>,
+}
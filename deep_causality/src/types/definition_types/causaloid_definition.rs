@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::IdentificationValue;
+
+/// Declarative definition of a causaloid.
+///
+/// A causaloid has a unique name other causaloids and states refer to,
+/// and exactly one of a causal function, a contextual causal function,
+/// a collection, or a graph of other causaloids.
+#[derive(Getters, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct CausaloidDefinition {
+    name: String,
+    id: IdentificationValue,
+    #[serde(default)]
+    description: String,
+    /// Name of a registered causal function.
+    #[serde(default)]
+    function: Option<String>,
+    /// Name of a registered contextual causal function; the causaloid uses the model context.
+    #[serde(default)]
+    contextual_function: Option<String>,
+    /// Names of the causaloids of the collection.
+    #[serde(default)]
+    collection: Option<Vec<String>>,
+    #[serde(default)]
+    graph: Option<GraphDefinition>,
+    /// Index of the data value of the causaloid when reasoning over a graph.
+    #[serde(default)]
+    data_index: Option<IdentificationValue>,
+}
+
+/// Declarative definition of a causaloid graph.
+///
+/// The root is inserted first, followed by the nodes in the given order.
+/// Edges refer to the root or the nodes by name.
+#[derive(Getters, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct GraphDefinition {
+    /// Name of the root causaloid.
+    root: String,
+    /// Names of all other causaloids of the graph.
+    #[serde(default)]
+    nodes: Vec<String>,
+    #[serde(default)]
+    edges: Vec<EdgeDefinition>,
+}
+
+/// Declarative definition of a weighted edge between two causaloids of a graph.
+#[derive(Getters, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct EdgeDefinition {
+    from: String,
+    to: String,
+    #[serde(default)]
+    weight: u64,
+}
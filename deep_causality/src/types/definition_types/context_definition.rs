@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+use deep_causality_macros::Getters;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{Contextoid, Datable, RelationKind, SpaceTemporal, Spatial, Temporable};
+
+/// Declarative definition of a context.
+///
+/// Edges refer to contextoids by their id.
+#[derive(Getters, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(
+    deny_unknown_fields,
+    bound(
+        serialize = "D: Serialize, S: Serialize, T: Serialize, ST: Serialize, V: Serialize",
+        deserialize = "D: DeserializeOwned, S: DeserializeOwned, T: DeserializeOwned, \
+                       ST: DeserializeOwned, V: DeserializeOwned"
+    )
+)]
+pub struct ContextDefinition<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    id: u64,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    nodes: Vec<Contextoid<D, S, T, ST, V>>,
+    #[serde(default)]
+    edges: Vec<ContextEdgeDefinition>,
+}
+
+/// Declarative definition of an edge between two contextoids, referred to by id.
+#[derive(Getters, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ContextEdgeDefinition {
+    from: u64,
+    to: u64,
+    relation: RelationKind,
+}
@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+pub mod assumption_definition;
+pub mod causaloid_definition;
+pub mod context_definition;
+pub mod model_definition;
+pub mod state_definition;
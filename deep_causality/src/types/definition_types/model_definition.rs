@@ -0,0 +1,576 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+use deep_causality_macros::Getters;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{
+    AssumptionDefinition, AssumptionSpec, AssumptionSpecKind, CSMSpec, CSMStateSpec,
+    CausalFunctionRegistry, CausaloidDefinition, CausaloidGraphSpec, CausaloidSpec,
+    CausaloidSpecKind, Context, ContextDefinition, ContextSpec, ContextuableGraph, Datable,
+    DefinitionError, Identifiable, IdentificationValue, ModelSpec, SpaceTemporal, Spatial,
+    StateDefinition, Temporable, DEFAULT_SIGNIFICANCE,
+};
+
+type OptionalContextSpec<D, S, T, ST, V> = Option<ContextSpec<D, S, T, ST, V>>;
+
+/// Declarative definition of a model, i.e. written in JSON, TOML, or YAML.
+///
+/// Causaloids refer to each other by name, and functions and actions are referred to
+/// by their name in a CausalFunctionRegistry. Resolve the definition into specs with
+/// model_spec and csm_spec, then build the model and CSM from the specs.
+///
+/// ```toml
+/// id = 1
+/// author = "ops"
+/// description = "Detects overheating"
+/// causaloid = "overheating"
+///
+/// [[causaloids]]
+/// name = "high_temperature"
+/// id = 1
+/// function = "threshold"
+/// data_index = 0
+///
+/// [[causaloids]]
+/// name = "overheating"
+/// id = 2
+/// graph = { root = "high_temperature" }
+/// ```
+#[derive(Getters, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(
+    deny_unknown_fields,
+    bound(
+        serialize = "D: Serialize, S: Serialize, T: Serialize, ST: Serialize, V: Serialize",
+        deserialize = "D: DeserializeOwned, S: DeserializeOwned, T: DeserializeOwned, \
+                       ST: DeserializeOwned, V: DeserializeOwned"
+    )
+)]
+pub struct ModelDefinition<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    id: u64,
+    #[serde(default)]
+    author: String,
+    #[serde(default)]
+    description: String,
+    /// Name of the causaloid of the model.
+    causaloid: String,
+    #[serde(default)]
+    causaloids: Vec<CausaloidDefinition>,
+    #[serde(default)]
+    assumptions: Vec<AssumptionDefinition>,
+    #[serde(default)]
+    context: Option<ContextDefinition<D, S, T, ST, V>>,
+    #[serde(default)]
+    states: Vec<StateDefinition>,
+}
+
+impl<D, S, T, ST, V> ModelDefinition<D, S, T, ST, V>
+where
+    D: Datable + Clone + DeserializeOwned,
+    S: Spatial<V> + Clone + DeserializeOwned,
+    T: Temporable<V> + Clone + DeserializeOwned,
+    ST: SpaceTemporal<V> + Clone + DeserializeOwned,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>
+        + DeserializeOwned,
+{
+    /// Parses a model definition from JSON.
+    pub fn from_json(json: &str) -> Result<Self, DefinitionError> {
+        serde_json::from_str(json).map_err(invalid)
+    }
+
+    /// Parses a model definition from TOML.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self, DefinitionError> {
+        toml::from_str(toml).map_err(invalid)
+    }
+
+    /// Parses a model definition from YAML.
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(yaml: &str) -> Result<Self, DefinitionError> {
+        serde_yaml_ng::from_str(yaml).map_err(invalid)
+    }
+}
+
+impl<D, S, T, ST, V> ModelDefinition<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Resolves the definition into a model spec.
+    ///
+    /// Checks every causaloid and assumption of the definition, including those the
+    /// model does not use, and returns DefinitionError for the first problem found,
+    /// i.e. an unknown function, an unknown causaloid name, a dangling graph edge,
+    /// or a causaloid that contains itself.
+    pub fn model_spec<'l>(
+        &self,
+        registry: &CausalFunctionRegistry<'_, D, S, T, ST, V>,
+    ) -> Result<ModelSpec<'l, D, S, T, ST, V>, DefinitionError> {
+        let context = self.context_spec()?;
+        let resolver = self.resolver(registry, context.as_ref().map(|c| c.id()))?;
+
+        for definition in &self.causaloids {
+            resolver.causaloid_spec(definition.name(), &mut Vec::new())?;
+        }
+
+        let assumptions = self
+            .assumptions
+            .iter()
+            .map(|a| assumption_spec(a, registry))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let causaloid = resolver.resolve(&self.causaloid, "the model")?;
+
+        Ok(ModelSpec::new(
+            self.id,
+            &self.author,
+            &self.description,
+            if assumptions.is_empty() {
+                None
+            } else {
+                Some(assumptions)
+            },
+            causaloid,
+            context,
+        ))
+    }
+
+    /// Returns the map from causaloid id to data index of all causaloids with a data index,
+    /// for use with reason_all_causes, validate, and evaluate.
+    /// Returns DefinitionError if causaloids with the same id have different data indices.
+    pub fn data_index(
+        &self,
+    ) -> Result<HashMap<IdentificationValue, IdentificationValue>, DefinitionError> {
+        let mut data_index = HashMap::new();
+        for definition in &self.causaloids {
+            if let Some(index) = definition.data_index() {
+                if let Some(other) = data_index.insert(*definition.id(), *index) {
+                    if other != *index {
+                        return Err(DefinitionError(format!(
+                            "Causaloid id {} has the data indices {} and {}",
+                            definition.id(),
+                            other,
+                            index
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(data_index)
+    }
+
+    fn context_spec(&self) -> Result<OptionalContextSpec<D, S, T, ST, V>, DefinitionError> {
+        let definition = match &self.context {
+            None => return Ok(None),
+            Some(definition) => definition,
+        };
+
+        let mut context = Context::with_capacity(
+            *definition.id(),
+            definition.name(),
+            definition.nodes().len(),
+        );
+
+        let mut indices = HashMap::new();
+        for node in definition.nodes() {
+            if indices.contains_key(&node.id()) {
+                return Err(DefinitionError(format!(
+                    "Duplicate contextoid id {} in context {}",
+                    node.id(),
+                    definition.id()
+                )));
+            }
+            indices.insert(node.id(), context.add_node(node.clone()));
+        }
+
+        for edge in definition.edges() {
+            let index = |id: &u64| {
+                indices.get(id).copied().ok_or_else(|| {
+                    DefinitionError(format!(
+                        "Context edge {} -> {} refers to unknown contextoid {}",
+                        edge.from(),
+                        edge.to(),
+                        id
+                    ))
+                })
+            };
+            context
+                .add_edge(index(edge.from())?, index(edge.to())?, *edge.relation())
+                .map_err(|e| DefinitionError(e.to_string()))?;
+        }
+
+        Ok(Some(ContextSpec::from_context(&context)))
+    }
+
+    fn resolver<'a, 'r>(
+        &'a self,
+        registry: &'a CausalFunctionRegistry<'r, D, S, T, ST, V>,
+        context: Option<u64>,
+    ) -> Result<Resolver<'a, 'r, D, S, T, ST, V>, DefinitionError> {
+        let mut causaloids = HashMap::new();
+        for definition in &self.causaloids {
+            if causaloids
+                .insert(definition.name().as_str(), definition)
+                .is_some()
+            {
+                return Err(DefinitionError(format!(
+                    "Duplicate causaloid name '{}'",
+                    definition.name()
+                )));
+            }
+        }
+
+        Ok(Resolver {
+            causaloids,
+            registry,
+            context,
+        })
+    }
+}
+
+impl<D, S, T, ST, V> ModelDefinition<D, S, T, ST, V>
+where
+    D: Datable + Clone + Copy,
+    S: Spatial<V> + Clone + Copy,
+    T: Temporable<V> + Clone + Copy,
+    ST: SpaceTemporal<V> + Clone + Copy,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Resolves the states of the definition into a CSM spec.
+    /// Contextual causaloids of the states refer to the context of the model,
+    /// so build the CSM with the context of the model built from model_spec.
+    ///
+    /// Returns DefinitionError if a state refers to an unknown causaloid or action.
+    pub fn csm_spec<'l>(
+        &self,
+        registry: &CausalFunctionRegistry<'_, D, S, T, ST, V>,
+    ) -> Result<CSMSpec<'l, D, S, T, ST, V>, DefinitionError> {
+        let resolver = self.resolver(registry, self.context.as_ref().map(|c| *c.id()))?;
+
+        let mut states = Vec::with_capacity(self.states.len());
+        for state in &self.states {
+            let causaloid =
+                resolver.resolve(state.causaloid(), &format!("state {}", state.id()))?;
+
+            let action = registry.action(state.action()).ok_or_else(|| {
+                DefinitionError(format!(
+                    "State {} uses unknown action '{}'; registered actions: {:?}",
+                    state.id(),
+                    state.action(),
+                    registry.action_names()
+                ))
+            })?;
+
+            states.push(CSMStateSpec::new(
+                *state.id(),
+                *state.version(),
+                *state.data(),
+                causaloid,
+                state.action(),
+                state.action_version().unwrap_or(*action.version()),
+            ));
+        }
+
+        Ok(CSMSpec::new(states))
+    }
+}
+
+struct Resolver<'a, 'r, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    causaloids: HashMap<&'a str, &'a CausaloidDefinition>,
+    registry: &'a CausalFunctionRegistry<'r, D, S, T, ST, V>,
+    /// Id of the model context, if any.
+    context: Option<u64>,
+}
+
+impl<'a, D, S, T, ST, V> Resolver<'a, '_, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    // Resolves a causaloid referenced by name from outside of any causaloid.
+    fn resolve<'l>(
+        &self,
+        name: &str,
+        referrer: &str,
+    ) -> Result<CausaloidSpec<'l, D, S, T, ST, V>, DefinitionError> {
+        if !self.causaloids.contains_key(name) {
+            return Err(DefinitionError(format!(
+                "Unknown causaloid '{}' referenced by {}",
+                name, referrer
+            )));
+        }
+        self.causaloid_spec(name, &mut Vec::new())
+    }
+
+    // path holds the names of the enclosing causaloids to detect causaloids containing themselves.
+    fn causaloid_spec<'l>(
+        &self,
+        name: &'a str,
+        path: &mut Vec<&'a str>,
+    ) -> Result<CausaloidSpec<'l, D, S, T, ST, V>, DefinitionError> {
+        let definition = self.causaloids[name];
+
+        if path.contains(&name) {
+            path.push(name);
+            return Err(DefinitionError(format!(
+                "Causaloid '{}' contains itself: {}",
+                name,
+                path.join(" -> ")
+            )));
+        }
+
+        let kinds = [
+            definition.function().is_some(),
+            definition.contextual_function().is_some(),
+            definition.collection().is_some(),
+            definition.graph().is_some(),
+        ];
+        if kinds.iter().filter(|k| **k).count() != 1 {
+            return Err(DefinitionError(format!(
+                "Causaloid '{}' must have exactly one of function, contextual_function, \
+                 collection, or graph",
+                name
+            )));
+        }
+
+        path.push(name);
+        let mut context = None;
+
+        let kind = if let Some(function) = definition.function() {
+            if self.registry.causal_fn(function).is_none() {
+                return Err(DefinitionError(format!(
+                    "Causaloid '{}' uses unknown causal function '{}'; \
+                     registered causal functions: {:?}",
+                    name,
+                    function,
+                    self.registry.causal_fn_names()
+                )));
+            }
+            CausaloidSpecKind::Singleton(function.clone())
+        } else if let Some(function) = definition.contextual_function() {
+            if self.registry.contextual_causal_fn(function).is_none() {
+                return Err(DefinitionError(format!(
+                    "Causaloid '{}' uses unknown contextual causal function '{}'; \
+                     registered contextual causal functions: {:?}",
+                    name,
+                    function,
+                    self.registry.contextual_causal_fn_names()
+                )));
+            }
+            if self.context.is_none() {
+                return Err(DefinitionError(format!(
+                    "Causaloid '{}' uses a contextual causal function, but the model has no context",
+                    name
+                )));
+            }
+            context = self.context;
+            CausaloidSpecKind::Contextual(function.clone())
+        } else if let Some(collection) = definition.collection() {
+            if collection.is_empty() {
+                return Err(DefinitionError(format!(
+                    "Causaloid '{}' has an empty collection",
+                    name
+                )));
+            }
+            let specs = collection
+                .iter()
+                .map(|member| self.member_spec(name, member, path))
+                .collect::<Result<Vec<_>, _>>()?;
+            CausaloidSpecKind::Collection(specs)
+        } else {
+            let graph = definition.graph().as_ref().expect("checked above");
+
+            let names: Vec<&String> = std::iter::once(graph.root())
+                .chain(graph.nodes().iter())
+                .collect();
+
+            let mut indices = HashMap::new();
+            for (index, node) in names.iter().enumerate() {
+                if indices.insert(node.as_str(), index).is_some() {
+                    return Err(DefinitionError(format!(
+                        "Causaloid '{}' lists the graph node '{}' more than once",
+                        name, node
+                    )));
+                }
+            }
+
+            let mut edges = Vec::with_capacity(graph.edges().len());
+            for edge in graph.edges() {
+                let index = |node: &String| {
+                    indices.get(node.as_str()).copied().ok_or_else(|| {
+                        DefinitionError(format!(
+                            "Edge '{}' -> '{}' of causaloid '{}' refers to '{}', \
+                             which is not a node of the graph",
+                            edge.from(),
+                            edge.to(),
+                            name,
+                            node
+                        ))
+                    })
+                };
+                edges.push((index(edge.from())?, index(edge.to())?, *edge.weight()));
+            }
+
+            let nodes = names
+                .iter()
+                .enumerate()
+                .map(|(index, node)| Ok((index, self.member_spec(name, node, path)?)))
+                .collect::<Result<Vec<_>, DefinitionError>>()?;
+
+            CausaloidSpecKind::Graph(CausaloidGraphSpec::new(Some(0), nodes, edges))
+        };
+
+        path.pop();
+
+        Ok(CausaloidSpec::new(
+            *definition.id(),
+            definition.description(),
+            context,
+            kind,
+        ))
+    }
+
+    fn member_spec<'l>(
+        &self,
+        parent: &str,
+        member: &'a str,
+        path: &mut Vec<&'a str>,
+    ) -> Result<CausaloidSpec<'l, D, S, T, ST, V>, DefinitionError> {
+        if !self.causaloids.contains_key(member) {
+            return Err(DefinitionError(format!(
+                "Unknown causaloid '{}' referenced by causaloid '{}'",
+                member, parent
+            )));
+        }
+        self.causaloid_spec(member, path)
+    }
+}
+
+fn assumption_spec<D, S, T, ST, V>(
+    definition: &AssumptionDefinition,
+    registry: &CausalFunctionRegistry<'_, D, S, T, ST, V>,
+) -> Result<AssumptionSpec, DefinitionError>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    let kind = match (definition.function(), definition.test()) {
+        (Some(function), None) => {
+            if registry.eval_fn(function).is_none() {
+                return Err(DefinitionError(format!(
+                    "Assumption {} uses unknown assumption function '{}'; \
+                     registered assumption functions: {:?}",
+                    definition.id(),
+                    function,
+                    registry.eval_fn_names()
+                )));
+            }
+            AssumptionSpecKind::Function(function.clone())
+        }
+        (None, Some(test)) => AssumptionSpecKind::Statistical {
+            test: *test,
+            significance: definition.significance().unwrap_or(DEFAULT_SIGNIFICANCE),
+        },
+        _ => {
+            return Err(DefinitionError(format!(
+                "Assumption {} must have exactly one of function or test",
+                definition.id()
+            )))
+        }
+    };
+
+    Ok(AssumptionSpec::new(
+        *definition.id(),
+        definition.description(),
+        kind,
+    ))
+}
+
+fn invalid<E: std::fmt::Display>(e: E) -> DefinitionError {
+    DefinitionError(format!("Invalid model definition: {}", e))
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::NumericalValue;
+
+/// Declarative definition of a state and its action of a causal state machine.
+#[derive(Getters, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct StateDefinition {
+    id: usize,
+    #[serde(default = "default_version")]
+    version: usize,
+    data: NumericalValue,
+    /// Name of the causaloid of the state.
+    causaloid: String,
+    /// Name of a registered action.
+    action: String,
+    /// Version of the action; the version of the registered action if not set.
+    #[serde(default)]
+    action_version: Option<usize>,
+}
+
+fn default_version() -> usize {
+    1
+}
@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::{Constructor, Getters};
+
+/// Options for exporting causaloid graphs and contexts to Graphviz DOT and Mermaid.
+///
+/// highlight_reasoning_path highlights the causaloids that were active after the last
+/// reasoning run and the edges between them. Contexts have no reasoning path and ignore it.
+#[derive(Getters, Constructor, Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct GraphExportOptions {
+    highlight_reasoning_path: bool,
+}
@@ -4,5 +4,9 @@
 pub mod alias_types;
 pub mod context_types;
 pub mod csm_types;
+#[cfg(feature = "serde")]
+pub mod definition_types;
+pub mod export_types;
 pub mod model_types;
 pub mod reasoning_types;
+pub mod registry_types;
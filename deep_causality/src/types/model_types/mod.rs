@@ -3,6 +3,13 @@ use std::hash::Hash;
 // Copyright (c) "2023" . Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
 use std::ops::*;
 
+#[cfg(feature = "serde")]
+mod model_spec;
+mod model_validation;
+#[cfg(feature = "serde")]
+pub use model_spec::ModelSpec;
+pub use model_validation::{ModelValidationReport, ValidationIssue};
+
 use deep_causality_macros::{Constructor, Getters};
 
 use crate::prelude::{
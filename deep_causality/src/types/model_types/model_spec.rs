@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::*;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{
+    Assumption, AssumptionSpec, CausalFunctionRegistry, Causaloid, CausaloidSpec, Context,
+    ContextSpec, Datable, Identifiable, SerializationError, SpaceTemporal, Spatial, Temporable,
+};
+use crate::types::model_types::Model;
+use crate::utils::serde_utils::SpecCache;
+
+/// Serializable description of a model with its assumptions, causaloid, and context.
+///
+/// Build the model with ModelSpec::build. Contextual causaloids
+/// of the model refer to the context of the model by its id.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(bound(
+    serialize = "D: Serialize, S: Serialize, T: Serialize, ST: Serialize, V: Serialize",
+    deserialize = "D: DeserializeOwned, S: DeserializeOwned, T: DeserializeOwned, \
+                   ST: DeserializeOwned, V: DeserializeOwned"
+))]
+pub struct ModelSpec<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    id: u64,
+    author: String,
+    description: String,
+    assumptions: Option<Vec<AssumptionSpec>>,
+    causaloid: CausaloidSpec<'l, D, S, T, ST, V>,
+    context: Option<ContextSpec<D, S, T, ST, V>>,
+    #[serde(skip)]
+    built_assumptions: SpecCache<Vec<Assumption>>,
+    #[serde(skip)]
+    assumption_refs: SpecCache<Vec<&'l Assumption>>,
+    #[serde(skip)]
+    built_causaloid: SpecCache<Causaloid<'l, D, S, T, ST, V>>,
+    #[serde(skip)]
+    built_context: SpecCache<Context<D, S, T, ST, V>>,
+}
+
+impl<'l, D, S, T, ST, V> ModelSpec<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    pub fn new(
+        id: u64,
+        author: &str,
+        description: &str,
+        assumptions: Option<Vec<AssumptionSpec>>,
+        causaloid: CausaloidSpec<'l, D, S, T, ST, V>,
+        context: Option<ContextSpec<D, S, T, ST, V>>,
+    ) -> Self {
+        Self {
+            id,
+            author: author.to_string(),
+            description: description.to_string(),
+            assumptions,
+            causaloid,
+            context,
+            built_assumptions: SpecCache::default(),
+            assumption_refs: SpecCache::default(),
+            built_causaloid: SpecCache::default(),
+            built_context: SpecCache::default(),
+        }
+    }
+
+    /// Describes the model, including its assumptions, causaloid, and context.
+    /// Returns SerializationError if a function is not registered.
+    pub fn from_model<'r>(
+        model: &Model<'r, D, S, T, ST, V>,
+        registry: &CausalFunctionRegistry<'r, D, S, T, ST, V>,
+    ) -> Result<Self, SerializationError> {
+        let assumptions = match model.assumptions() {
+            None => None,
+            Some(assumptions) => Some(
+                assumptions
+                    .iter()
+                    .map(|a| AssumptionSpec::from_assumption(a, registry))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        };
+
+        Ok(Self::new(
+            model.id(),
+            model.author(),
+            model.description(),
+            assumptions,
+            CausaloidSpec::from_causaloid(model.causaloid(), registry)?,
+            model.context().map(|c| ContextSpec::from_context(c)),
+        ))
+    }
+
+    /// Builds the model with the functions of the registry.
+    /// The assumptions, causaloid, and context are built once and kept in the spec.
+    ///
+    /// Returns SerializationError if an assumption, the causaloid, or the context cannot be built.
+    pub fn build(
+        &'l self,
+        registry: &CausalFunctionRegistry<'l, D, S, T, ST, V>,
+    ) -> Result<Model<'l, D, S, T, ST, V>, SerializationError> {
+        let context = match &self.context {
+            None => None,
+            Some(spec) => Some(self.built_context.get_or_try_init(|| spec.build())?),
+        };
+
+        let causaloid = self.built_causaloid.get_or_try_init(|| {
+            let contexts: Vec<&Context<D, S, T, ST, V>> = context.into_iter().collect();
+            self.causaloid.build(registry, &contexts)
+        })?;
+
+        let assumptions = match &self.assumptions {
+            None => None,
+            Some(specs) => {
+                let built = self.built_assumptions.get_or_try_init(|| {
+                    specs
+                        .iter()
+                        .map(|spec| spec.build(registry))
+                        .collect::<Result<Vec<_>, _>>()
+                })?;
+                Some(
+                    self.assumption_refs
+                        .get_or_try_init(|| Ok::<_, SerializationError>(built.iter().collect()))?,
+                )
+            }
+        };
+
+        Ok(Model::new(
+            self.id,
+            self.author.as_str(),
+            self.description.as_str(),
+            assumptions,
+            causaloid,
+            context,
+        ))
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn author(&self) -> &str {
+        self.author.as_str()
+    }
+
+    pub fn description(&self) -> &str {
+        self.description.as_str()
+    }
+
+    pub fn assumptions(&self) -> Option<&[AssumptionSpec]> {
+        self.assumptions.as_deref()
+    }
+
+    pub fn causaloid(&self) -> &CausaloidSpec<'l, D, S, T, ST, V> {
+        &self.causaloid
+    }
+
+    pub fn context(&self) -> Option<&ContextSpec<D, S, T, ST, V>> {
+        self.context.as_ref()
+    }
+}
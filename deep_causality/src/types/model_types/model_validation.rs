@@ -0,0 +1,310 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+use deep_causality_macros::Getters;
+use ultragraph::prelude::*;
+
+use crate::prelude::{
+    Assumable, Causable, CausableGraph, CausalityError, Causaloid, Datable, Identifiable,
+    IdentificationValue, ModelError, NumericalValue, SpaceTemporal, Spatial, Temporable,
+};
+use crate::types::model_types::Model;
+
+/// A problem found while validating a model.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidationIssue {
+    /// The assumption with this id does not hold for the validation data.
+    InvalidAssumption(IdentificationValue),
+    /// The contextual causaloid with this id has no context.
+    MissingContext(IdentificationValue),
+    /// The collection causaloid with this id contains no causaloids.
+    EmptyCollection(IdentificationValue),
+    /// The graph causaloid with this id has no root causaloid.
+    MissingRoot(IdentificationValue),
+    /// The graph causaloid with this id contains a cycle.
+    CyclicGraph(IdentificationValue),
+    /// No data value can be found for the causaloid with this id.
+    UnresolvedData(IdentificationValue),
+    /// The smoke evaluation of the causaloid failed with this error.
+    EvaluationFailed(String),
+}
+
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidAssumption(id) => write!(f, "Assumption {} is not valid", id),
+            Self::MissingContext(id) => write!(f, "Causaloid {} has no context", id),
+            Self::EmptyCollection(id) => write!(f, "Causaloid {} has an empty collection", id),
+            Self::MissingRoot(id) => write!(f, "Causaloid {} has a graph without root", id),
+            Self::CyclicGraph(id) => write!(f, "Causaloid {} has a cyclic graph", id),
+            Self::UnresolvedData(id) => write!(f, "Causaloid {} has no data", id),
+            Self::EvaluationFailed(e) => write!(f, "Evaluation failed: {}", e),
+        }
+    }
+}
+
+/// Result of validating a model against data.
+#[derive(Getters, Clone, Debug, PartialEq)]
+pub struct ModelValidationReport {
+    model_id: u64,
+    assumptions_verified: usize,
+    /// Mean confidence of all verified assumptions; 1.0 if the model has no assumptions.
+    assumption_confidence: NumericalValue,
+    issues: Vec<ValidationIssue>,
+    /// None if the smoke evaluation failed or was skipped because the causaloid is malformed.
+    evaluation: Option<bool>,
+}
+
+impl ModelValidationReport {
+    /// Returns true if validation found no issues.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl Display for ModelValidationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ModelValidationReport: model: {} valid: {} assumptions verified: {} confidence: {} issues: {}",
+            self.model_id,
+            self.is_valid(),
+            self.assumptions_verified,
+            self.assumption_confidence,
+            self.issues.len()
+        )
+    }
+}
+
+impl<'l, D, S, T, ST, V> Model<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Validates the model against the data and reports all issues found.
+    ///
+    /// Verifies all assumptions with the data, checks that the causaloid is well-formed,
+    /// i.e. graphs are acyclic and have a root, collections are not empty,
+    /// contextual causaloids have a context, and every causaloid resolves its data,
+    /// and finally evaluates the causaloid with the data as a smoke test.
+    /// The smoke evaluation is skipped if the causaloid is malformed.
+    ///
+    /// data_index: optional map from causaloid id to data index, as in reason_all_causes.
+    ///
+    /// Note, verifying the assumptions and the smoke evaluation both update
+    /// the state of the assumptions and causaloids, the same as a regular evaluation.
+    pub fn validate(
+        &self,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> ModelValidationReport {
+        let mut issues = Vec::new();
+
+        let assumptions = self.assumptions().map_or(&[][..], |a| a.as_slice());
+        for assumption in assumptions {
+            if !assumption.verify_assumption(data) {
+                issues.push(ValidationIssue::InvalidAssumption(assumption.id()));
+            }
+        }
+
+        let assumption_confidence = if assumptions.is_empty() {
+            1.0
+        } else {
+            assumptions
+                .iter()
+                .map(|a| a.confidence())
+                .sum::<NumericalValue>()
+                / assumptions.len() as NumericalValue
+        };
+
+        let structural = structural_issues(self.causaloid(), data.len(), data_index);
+        let evaluation = if structural.is_empty() {
+            match self.evaluate_causaloid(data, data_index) {
+                Ok(res) => Some(res),
+                Err(e) => {
+                    issues.push(ValidationIssue::EvaluationFailed(e.to_string()));
+                    None
+                }
+            }
+        } else {
+            issues.extend(structural);
+            None
+        };
+
+        ModelValidationReport {
+            model_id: self.id(),
+            assumptions_verified: assumptions.len(),
+            assumption_confidence,
+            issues,
+            evaluation,
+        }
+    }
+
+    /// Evaluates the causaloid of the model with the data.
+    ///
+    /// A singleton causaloid evaluates to true if it holds for all observations;
+    /// collections and graphs are evaluated with verify_all_causes.
+    ///
+    /// Returns ModelError without evaluating if an assumption has not been verified
+    /// or is not valid, or if the causaloid is malformed (see validate).
+    /// Returns ModelError if the evaluation fails.
+    pub fn evaluate(
+        &self,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> Result<bool, ModelError> {
+        let invalid: Vec<IdentificationValue> = self
+            .assumptions()
+            .map_or(&[][..], |a| a.as_slice())
+            .iter()
+            .filter(|a| !a.assumption_tested() || !a.assumption_valid())
+            .map(|a| a.id())
+            .collect();
+
+        if !invalid.is_empty() {
+            return Err(ModelError(format!(
+                "Model {} has untested or invalid assumptions: {:?}",
+                self.id(),
+                invalid
+            )));
+        }
+
+        if let Some(issue) = structural_issues(self.causaloid(), data.len(), data_index).first() {
+            return Err(ModelError(format!(
+                "Model {} is malformed: {}",
+                self.id(),
+                issue
+            )));
+        }
+
+        self.evaluate_causaloid(data, data_index)
+            .map_err(|e| ModelError(e.to_string()))
+    }
+
+    fn evaluate_causaloid(
+        &self,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> Result<bool, CausalityError> {
+        if data.is_empty() {
+            return Err(CausalityError("Data are empty (len ==0).".into()));
+        }
+
+        let causaloid = self.causaloid();
+        if !causaloid.is_singleton() {
+            return causaloid.verify_all_causes(data, data_index);
+        }
+
+        for obs in data {
+            if !causaloid.verify_single_cause(obs)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+// Collects the structural issues of the causaloid and all nested causaloids.
+fn structural_issues<D, S, T, ST, V>(
+    causaloid: &Causaloid<D, S, T, ST, V>,
+    data_len: usize,
+    data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+) -> Vec<ValidationIssue>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    let mut issues = Vec::new();
+    collect_structural_issues(causaloid, data_len, data_index, &mut issues);
+    issues
+}
+
+fn collect_structural_issues<D, S, T, ST, V>(
+    causaloid: &Causaloid<D, S, T, ST, V>,
+    data_len: usize,
+    data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    issues: &mut Vec<ValidationIssue>,
+) where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    if causaloid.has_context() && causaloid.context().is_none() {
+        issues.push(ValidationIssue::MissingContext(causaloid.id()));
+    }
+
+    if let Some(coll) = causaloid.causal_collection() {
+        if coll.is_empty() {
+            issues.push(ValidationIssue::EmptyCollection(causaloid.id()));
+        }
+
+        // Collections apply data by position and pass no data index to nested causaloids.
+        for (i, cause) in coll.iter().enumerate() {
+            if cause.is_singleton() && i >= data_len {
+                issues.push(ValidationIssue::UnresolvedData(cause.id()));
+            }
+            collect_structural_issues(cause, data_len, None, issues);
+        }
+    }
+
+    if let Some(graph) = causaloid.causal_graph() {
+        if !graph.contains_root_causaloid() {
+            issues.push(ValidationIssue::MissingRoot(causaloid.id()));
+        }
+
+        if !graph.get_graph().is_acyclic() {
+            issues.push(ValidationIssue::CyclicGraph(causaloid.id()));
+        }
+
+        // Graphs look up the data of every causaloid by its id.
+        for (_, cause) in graph.get_graph().get_all_indexed_nodes() {
+            let index = match data_index {
+                Some(map) => map.get(&cause.id()).copied(),
+                None => Some(cause.id()),
+            };
+
+            if index.map_or(true, |i| i as usize >= data_len) {
+                issues.push(ValidationIssue::UnresolvedData(cause.id()));
+            }
+            collect_structural_issues(cause, data_len, data_index, issues);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
-use crate::prelude::{Assumable, DescriptionValue, EvalFn, NumericalValue};
+use crate::prelude::{Assumable, AssumptionVerification, DescriptionValue, EvalFn, NumericalValue};
 use crate::types::reasoning_types::assumption::Assumption;
 
 impl Assumable for Assumption {
@@ -9,7 +9,7 @@ impl Assumable for Assumption {
     }
 
     fn assumption_fn(&self) -> EvalFn {
-        self.assumption_fn
+        self.assumption_fn.clone()
     }
 
     fn assumption_tested(&self) -> bool {
@@ -21,14 +21,46 @@ impl Assumable for Assumption {
     }
 
     fn verify_assumption(&self, data: &[NumericalValue]) -> bool {
-        let res = (self.assumption_fn)(data);
+        let verification = match self.statistical_test {
+            Some((test, significance)) => match test.run(data) {
+                Ok(result) => AssumptionVerification::new(
+                    test.holds(&result, significance),
+                    test.confidence(&result),
+                    Some(result),
+                ),
+                Err(_) => AssumptionVerification::new(false, 0.0, None),
+            },
+            None => {
+                let res = (self.assumption_fn)(data);
+                AssumptionVerification::new(res, if res { 1.0 } else { 0.0 }, None)
+            }
+        };
+
+        let res = *verification.valid();
+
         let mut guard_tested = self.assumption_tested.write().unwrap();
         *guard_tested = true;
 
-        if res {
-            let mut guard_valid = self.assumption_valid.write().unwrap();
-            *guard_valid = true;
-        }
+        // A failed verification invalidates an assumption that held before.
+        let mut guard_valid = self.assumption_valid.write().unwrap();
+        *guard_valid = res;
+
+        self.verification_history
+            .write()
+            .unwrap()
+            .push(verification);
         res
     }
+
+    fn confidence(&self) -> NumericalValue {
+        self.verification_history
+            .read()
+            .unwrap()
+            .last()
+            .map_or(0.0, |v| *v.confidence())
+    }
+
+    fn verification_history(&self) -> Vec<AssumptionVerification> {
+        self.verification_history.read().unwrap().clone()
+    }
 }
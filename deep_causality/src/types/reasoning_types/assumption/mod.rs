@@ -3,11 +3,20 @@
 
 use std::sync::{Arc, RwLock};
 
-use crate::prelude::{DescriptionValue, EvalFn, IdentificationValue};
+use crate::prelude::{DescriptionValue, EvalFn, IdentificationValue, NumericalValue};
 
 mod assumable;
 mod debug;
 mod identifiable;
+#[cfg(feature = "serde")]
+mod spec;
+pub mod statistical_test;
+pub mod verification;
+
+#[cfg(feature = "serde")]
+pub use spec::{AssumptionSpec, AssumptionSpecKind};
+pub use statistical_test::{StatisticalTest, StatisticalTestResult, DEFAULT_SIGNIFICANCE};
+pub use verification::AssumptionVerification;
 
 // Interior mutability in Rust, part 2: thread safety
 // https://ricardomartins.cc/2016/06/25/interior-mutability-thread-safety
@@ -18,13 +27,38 @@ pub struct Assumption {
     id: IdentificationValue,
     description: DescriptionValue,
     assumption_fn: EvalFn,
+    // Name of the assumption function in a CausalFunctionRegistry, if known.
+    fn_name: Option<String>,
+    statistical_test: Option<(StatisticalTest, NumericalValue)>,
     assumption_tested: ArcRWLock<bool>,
     assumption_valid: ArcRWLock<bool>,
+    verification_history: ArcRWLock<Vec<AssumptionVerification>>,
 }
 
 // Constructor
 impl Assumption {
-    pub fn new(
+    pub fn new<F>(id: IdentificationValue, description: DescriptionValue, assumption_fn: F) -> Self
+    where
+        F: Fn(&[NumericalValue]) -> bool + Send + Sync + 'static,
+    {
+        Self::from_eval_fn(id, description, Arc::new(assumption_fn))
+    }
+
+    /// Creates an assumption verified by the statistical test at the significance level,
+    /// e.g. 0.05. The assumption function runs the test at the same significance level.
+    pub fn new_statistical(
+        id: IdentificationValue,
+        description: DescriptionValue,
+        statistical_test: StatisticalTest,
+        significance: NumericalValue,
+    ) -> Self {
+        Self {
+            statistical_test: Some((statistical_test, significance)),
+            ..Self::from_eval_fn(id, description, statistical_test.eval_fn(significance))
+        }
+    }
+
+    fn from_eval_fn(
         id: IdentificationValue,
         description: DescriptionValue,
         assumption_fn: EvalFn,
@@ -33,8 +67,29 @@ impl Assumption {
             id,
             description,
             assumption_fn,
+            fn_name: None,
+            statistical_test: None,
             assumption_tested: Arc::new(RwLock::new(false)),
             assumption_valid: Arc::new(RwLock::new(false)),
+            verification_history: Arc::new(RwLock::new(Vec::new())),
         }
     }
+
+    /// Sets the name under which the assumption function is registered
+    /// in a CausalFunctionRegistry. The name is required to serialize the assumption
+    /// unless it is verified by a statistical test.
+    pub fn with_fn_name(mut self, fn_name: &str) -> Self {
+        self.fn_name = Some(fn_name.to_string());
+        self
+    }
+
+    /// Returns the statistical test and significance level, if any.
+    pub fn statistical_test(&self) -> Option<(StatisticalTest, NumericalValue)> {
+        self.statistical_test
+    }
+
+    /// Returns the registry name of the assumption function, if set.
+    pub fn fn_name(&self) -> Option<&str> {
+        self.fn_name.as_deref()
+    }
 }
@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{
+    CausalFunctionRegistry, Datable, SerializationError, SpaceTemporal, Spatial, Temporable,
+};
+
+use super::*;
+
+/// Serializable description of an assumption.
+///
+/// The verification state and history are not persisted;
+/// a built assumption is untested.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AssumptionSpec {
+    id: IdentificationValue,
+    description: DescriptionValue,
+    kind: AssumptionSpecKind,
+}
+
+/// How an AssumptionSpec is verified.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum AssumptionSpecKind {
+    /// Name of a registered assumption function.
+    Function(String),
+    Statistical {
+        test: StatisticalTest,
+        significance: NumericalValue,
+    },
+}
+
+impl AssumptionSpec {
+    pub fn new(id: IdentificationValue, description: &str, kind: AssumptionSpecKind) -> Self {
+        Self {
+            id,
+            description: description.to_string(),
+            kind,
+        }
+    }
+
+    /// Describes the assumption. Assumptions without a statistical test are described
+    /// by the registry name of their assumption function, which assumptions built from a spec
+    /// carry and others set with Assumption::with_fn_name.
+    /// Returns SerializationError if the assumption function has no name
+    /// or the name is not registered.
+    pub fn from_assumption<D, S, T, ST, V>(
+        assumption: &Assumption,
+        registry: &CausalFunctionRegistry<'_, D, S, T, ST, V>,
+    ) -> Result<Self, SerializationError>
+    where
+        D: Datable + Clone,
+        S: Spatial<V> + Clone,
+        T: Temporable<V> + Clone,
+        ST: SpaceTemporal<V> + Clone,
+        V: Default
+            + Copy
+            + Clone
+            + Hash
+            + Eq
+            + PartialEq
+            + Add<V, Output = V>
+            + Sub<V, Output = V>
+            + Mul<V, Output = V>,
+    {
+        let kind = match assumption.statistical_test {
+            Some((test, significance)) => AssumptionSpecKind::Statistical { test, significance },
+            None => {
+                let name = assumption.fn_name.as_deref().ok_or_else(|| {
+                    SerializationError(format!(
+                        "The assumption function of assumption {} is not registered",
+                        assumption.id
+                    ))
+                })?;
+
+                if registry.eval_fn(name).is_none() {
+                    return Err(SerializationError(format!(
+                        "Unknown assumption function '{}' in assumption {}",
+                        name, assumption.id
+                    )));
+                }
+
+                AssumptionSpecKind::Function(name.to_string())
+            }
+        };
+
+        Ok(Self::new(assumption.id, &assumption.description, kind))
+    }
+
+    /// Builds the assumption with the functions of the registry.
+    /// Returns SerializationError if the assumption function is not registered.
+    pub fn build<D, S, T, ST, V>(
+        &self,
+        registry: &CausalFunctionRegistry<'_, D, S, T, ST, V>,
+    ) -> Result<Assumption, SerializationError>
+    where
+        D: Datable + Clone,
+        S: Spatial<V> + Clone,
+        T: Temporable<V> + Clone,
+        ST: SpaceTemporal<V> + Clone,
+        V: Default
+            + Copy
+            + Clone
+            + Hash
+            + Eq
+            + PartialEq
+            + Add<V, Output = V>
+            + Sub<V, Output = V>
+            + Mul<V, Output = V>,
+    {
+        match &self.kind {
+            AssumptionSpecKind::Function(name) => {
+                let f = registry.eval_fn(name).ok_or_else(|| {
+                    SerializationError(format!(
+                        "Unknown assumption function '{}' in assumption {}",
+                        name, self.id
+                    ))
+                })?;
+                Ok(
+                    Assumption::from_eval_fn(self.id, self.description.clone(), f)
+                        .with_fn_name(name),
+                )
+            }
+            AssumptionSpecKind::Statistical { test, significance } => {
+                Ok(Assumption::new_statistical(
+                    self.id,
+                    self.description.clone(),
+                    *test,
+                    *significance,
+                ))
+            }
+        }
+    }
+
+    pub fn id(&self) -> IdentificationValue {
+        self.id
+    }
+
+    pub fn description(&self) -> &str {
+        self.description.as_str()
+    }
+
+    pub fn kind(&self) -> &AssumptionSpecKind {
+        &self.kind
+    }
+}
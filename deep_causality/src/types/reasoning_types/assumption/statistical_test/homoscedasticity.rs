@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::utils::math_utils;
+
+use super::*;
+
+pub(super) fn test(data: &[NumericalValue]) -> Result<StatisticalTestResult, AssumptionError> {
+    check_sample_size(StatisticalTest::Homoscedasticity, data, 8)?;
+
+    let (mean, variance) = mean_variance(data);
+    check_not_constant(StatisticalTest::Homoscedasticity, variance)?;
+
+    // Regress the squared deviations on time.
+    let squared: Vec<NumericalValue> = data.iter().map(|x| (x - mean).powi(2)).collect();
+    let rows: Vec<Vec<NumericalValue>> = (0..data.len())
+        .map(|t| vec![1.0, t as NumericalValue])
+        .collect();
+
+    let fit = regression::ols(&rows, &squared).ok_or_else(|| {
+        AssumptionError("Homoscedasticity test failed, regression is singular".into())
+    })?;
+
+    let r_squared = fit.r_squared(&squared);
+    let statistic = data.len() as NumericalValue * r_squared;
+    let p_value = math_utils::chi_squared_sf(statistic, 1.0);
+
+    Ok(StatisticalTestResult::new(
+        statistic,
+        p_value,
+        r_squared,
+        data.len(),
+    ))
+}
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::utils::math_utils;
+
+use super::*;
+
+// Maximum number of lags tested.
+const MAX_LAGS: usize = 10;
+
+pub(super) fn test(data: &[NumericalValue]) -> Result<StatisticalTestResult, AssumptionError> {
+    check_sample_size(StatisticalTest::Independence, data, 8)?;
+
+    let (mean, variance) = mean_variance(data);
+    check_not_constant(StatisticalTest::Independence, variance)?;
+
+    let n = data.len();
+    let lags = MAX_LAGS.min(n / 4);
+    let denominator: NumericalValue = data.iter().map(|x| (x - mean).powi(2)).sum();
+
+    let mut statistic = 0.0;
+    let mut max_autocorrelation: NumericalValue = 0.0;
+
+    for lag in 1..=lags {
+        let autocorrelation = (lag..n)
+            .map(|t| (data[t] - mean) * (data[t - lag] - mean))
+            .sum::<NumericalValue>()
+            / denominator;
+
+        statistic += autocorrelation.powi(2) / (n - lag) as NumericalValue;
+        max_autocorrelation = max_autocorrelation.max(autocorrelation.abs());
+    }
+
+    let n = n as NumericalValue;
+    let statistic = n * (n + 2.0) * statistic;
+    let p_value = math_utils::chi_squared_sf(statistic, lags as NumericalValue);
+
+    Ok(StatisticalTestResult::new(
+        statistic,
+        p_value,
+        max_autocorrelation,
+        data.len(),
+    ))
+}
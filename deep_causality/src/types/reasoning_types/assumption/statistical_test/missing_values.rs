@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use super::*;
+
+// The statistic is the number of missing values.
+pub(super) fn test(data: &[NumericalValue]) -> StatisticalTestResult {
+    let missing = data.iter().filter(|x| !x.is_finite()).count();
+
+    let fraction = if data.is_empty() {
+        0.0
+    } else {
+        missing as NumericalValue / data.len() as NumericalValue
+    };
+
+    let p_value = if missing == 0 { 1.0 } else { 0.0 };
+
+    StatisticalTestResult::new(missing as NumericalValue, p_value, fraction, data.len())
+}
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Debug, Display};
+use std::sync::Arc;
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::{AssumptionError, EvalFn, NumericalValue};
+
+mod homoscedasticity;
+mod independence;
+mod missing_values;
+mod normality;
+mod regression;
+mod stationarity;
+
+/// Significance level of statistical assumptions whose definition does not set one.
+pub const DEFAULT_SIGNIFICANCE: NumericalValue = 0.05;
+
+/// Statistical test of a common modelling assumption about a series of observations.
+///
+/// Normality: Jarque-Bera test. The effect size is the combined deviation
+/// of skewness and excess kurtosis from the normal distribution.
+///
+/// Stationarity: augmented Dickey-Fuller test with a constant and MacKinnon p-values.
+/// The effect size is the absolute mean reversion coefficient.
+///
+/// Independence: Ljung-Box test for autocorrelation.
+/// The effect size is the largest absolute autocorrelation.
+///
+/// Homoscedasticity: Breusch-Pagan test of the squared deviations against time.
+/// The effect size is the R squared of that regression.
+///
+/// NoMissingValues: checks for NaN and infinite values.
+/// The effect size is the fraction of missing values.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatisticalTest {
+    Normality,
+    Stationarity,
+    Independence,
+    Homoscedasticity,
+    NoMissingValues,
+}
+
+/// Result of a statistical test.
+#[derive(Getters, Constructor, Copy, Clone, Debug, PartialEq)]
+pub struct StatisticalTestResult {
+    statistic: NumericalValue,
+    p_value: NumericalValue,
+    effect_size: NumericalValue,
+    sample_size: usize,
+}
+
+impl StatisticalTest {
+    /// Runs the test on the data.
+    /// Returns AssumptionError if there are too few observations,
+    /// the data contain missing values, or the data are constant.
+    pub fn run(&self, data: &[NumericalValue]) -> Result<StatisticalTestResult, AssumptionError> {
+        if *self == StatisticalTest::NoMissingValues {
+            return Ok(missing_values::test(data));
+        }
+
+        if data.iter().any(|x| !x.is_finite()) {
+            return Err(AssumptionError(format!(
+                "{} test failed, data contain missing values",
+                self
+            )));
+        }
+
+        match self {
+            StatisticalTest::Normality => normality::test(data),
+            StatisticalTest::Stationarity => stationarity::test(data),
+            StatisticalTest::Independence => independence::test(data),
+            StatisticalTest::Homoscedasticity => homoscedasticity::test(data),
+            StatisticalTest::NoMissingValues => Ok(missing_values::test(data)),
+        }
+    }
+
+    /// Runs the test and returns true if the result supports the assumption
+    /// at the significance level. Returns false if the test fails to run.
+    pub fn verify(&self, data: &[NumericalValue], significance: NumericalValue) -> bool {
+        self.run(data)
+            .is_ok_and(|result| self.holds(&result, significance))
+    }
+
+    /// Returns an EvalFn that verifies the test at the significance level.
+    pub fn eval_fn(&self, significance: NumericalValue) -> EvalFn {
+        let test = *self;
+        Arc::new(move |data| test.verify(data, significance))
+    }
+
+    /// Returns true if the result supports the assumption at the significance level.
+    /// The null hypothesis of the stationarity test is a unit root, so stationarity
+    /// holds if the null is rejected. For all other tests, the null hypothesis is
+    /// the assumption, which holds unless the null is rejected.
+    pub fn holds(&self, result: &StatisticalTestResult, significance: NumericalValue) -> bool {
+        match self {
+            StatisticalTest::Stationarity => result.p_value < significance,
+            StatisticalTest::NoMissingValues => result.statistic == 0.0,
+            _ => result.p_value >= significance,
+        }
+    }
+
+    /// Returns the confidence in [0, 1] that the assumption holds:
+    /// the p-value if the null hypothesis is the assumption and one minus the p-value otherwise.
+    pub fn confidence(&self, result: &StatisticalTestResult) -> NumericalValue {
+        match self {
+            StatisticalTest::Stationarity => 1.0 - result.p_value,
+            _ => result.p_value,
+        }
+    }
+}
+
+impl Display for StatisticalTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+// Returns mean and population variance.
+fn mean_variance(data: &[NumericalValue]) -> (NumericalValue, NumericalValue) {
+    let n = data.len() as NumericalValue;
+    let mean = data.iter().sum::<NumericalValue>() / n;
+    let variance = data
+        .iter()
+        .map(|x| (x - mean).powi(2))
+        .sum::<NumericalValue>()
+        / n;
+    (mean, variance)
+}
+
+fn check_sample_size(
+    test: StatisticalTest,
+    data: &[NumericalValue],
+    min: usize,
+) -> Result<(), AssumptionError> {
+    if data.len() < min {
+        return Err(AssumptionError(format!(
+            "{} test requires at least {} observations, got {}",
+            test,
+            min,
+            data.len()
+        )));
+    }
+    Ok(())
+}
+
+fn check_not_constant(
+    test: StatisticalTest,
+    variance: NumericalValue,
+) -> Result<(), AssumptionError> {
+    if variance <= 0.0 {
+        return Err(AssumptionError(format!(
+            "{} test failed, data are constant",
+            test
+        )));
+    }
+    Ok(())
+}
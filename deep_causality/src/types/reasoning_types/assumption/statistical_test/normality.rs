@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::utils::math_utils;
+
+use super::*;
+
+pub(super) fn test(data: &[NumericalValue]) -> Result<StatisticalTestResult, AssumptionError> {
+    check_sample_size(StatisticalTest::Normality, data, 8)?;
+
+    let (mean, variance) = mean_variance(data);
+    check_not_constant(StatisticalTest::Normality, variance)?;
+
+    let n = data.len() as NumericalValue;
+    let m3 = data
+        .iter()
+        .map(|x| (x - mean).powi(3))
+        .sum::<NumericalValue>()
+        / n;
+    let m4 = data
+        .iter()
+        .map(|x| (x - mean).powi(4))
+        .sum::<NumericalValue>()
+        / n;
+
+    let skewness = m3 / variance.powf(1.5);
+    let excess_kurtosis = m4 / variance.powi(2) - 3.0;
+
+    let deviation = skewness.powi(2) + excess_kurtosis.powi(2) / 4.0;
+    let statistic = n / 6.0 * deviation;
+    let p_value = math_utils::chi_squared_sf(statistic, 2.0);
+
+    Ok(StatisticalTestResult::new(
+        statistic,
+        p_value,
+        deviation.sqrt(),
+        data.len(),
+    ))
+}
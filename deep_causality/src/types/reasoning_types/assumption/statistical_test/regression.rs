@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use super::*;
+
+// Ordinary least squares fit.
+pub(super) struct Fit {
+    coefficients: Vec<NumericalValue>,
+    fitted: Vec<NumericalValue>,
+    // Inverse of X'X
+    inverse: Vec<Vec<NumericalValue>>,
+}
+
+impl Fit {
+    pub(super) fn coefficients(&self) -> &[NumericalValue] {
+        &self.coefficients
+    }
+
+    fn residual_sum_of_squares(&self, targets: &[NumericalValue]) -> NumericalValue {
+        targets
+            .iter()
+            .zip(self.fitted.iter())
+            .map(|(y, f)| (y - f).powi(2))
+            .sum()
+    }
+
+    pub(super) fn r_squared(&self, targets: &[NumericalValue]) -> NumericalValue {
+        let (_, variance) = mean_variance(targets);
+        let total = variance * targets.len() as NumericalValue;
+        if total <= 0.0 {
+            return 0.0;
+        }
+        (1.0 - self.residual_sum_of_squares(targets) / total).max(0.0)
+    }
+
+    // Standard error of the coefficient at the index.
+    pub(super) fn standard_error(
+        &self,
+        targets: &[NumericalValue],
+        index: usize,
+    ) -> NumericalValue {
+        let degrees_of_freedom =
+            targets.len() as NumericalValue - self.coefficients.len() as NumericalValue;
+        let sigma_squared = self.residual_sum_of_squares(targets) / degrees_of_freedom;
+        (sigma_squared * self.inverse[index][index]).sqrt()
+    }
+}
+
+// Returns None if X'X is singular or there are fewer rows than coefficients.
+pub(super) fn ols(rows: &[Vec<NumericalValue>], targets: &[NumericalValue]) -> Option<Fit> {
+    let k = rows.first()?.len();
+    if rows.len() <= k {
+        return None;
+    }
+
+    // Augmented matrix [X'X | I]
+    let mut matrix = vec![vec![0.0; 2 * k]; k];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        for j in 0..k {
+            row[j] = rows.iter().map(|r| r[i] * r[j]).sum();
+        }
+        row[k + i] = 1.0;
+    }
+
+    // Gauss-Jordan elimination with partial pivoting
+    for col in 0..k {
+        let pivot = (col..k).max_by(|a, b| {
+            matrix[*a][col]
+                .abs()
+                .partial_cmp(&matrix[*b][col].abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+
+        if matrix[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        matrix.swap(col, pivot);
+
+        let divisor = matrix[col][col];
+        matrix[col].iter_mut().for_each(|v| *v /= divisor);
+
+        let pivot_row = matrix[col].clone();
+        for (i, row) in matrix.iter_mut().enumerate() {
+            if i != col {
+                let factor = row[col];
+                row.iter_mut()
+                    .zip(pivot_row.iter())
+                    .for_each(|(v, p)| *v -= factor * p);
+            }
+        }
+    }
+
+    let inverse: Vec<Vec<NumericalValue>> =
+        matrix.into_iter().map(|row| row[k..].to_vec()).collect();
+
+    // X'y
+    let xty: Vec<NumericalValue> = (0..k)
+        .map(|i| rows.iter().zip(targets).map(|(r, y)| r[i] * y).sum())
+        .collect();
+
+    let coefficients: Vec<NumericalValue> = inverse
+        .iter()
+        .map(|row| row.iter().zip(xty.iter()).map(|(a, b)| a * b).sum())
+        .collect();
+
+    let fitted = rows
+        .iter()
+        .map(|r| r.iter().zip(coefficients.iter()).map(|(x, c)| x * c).sum())
+        .collect();
+
+    Some(Fit {
+        coefficients,
+        fitted,
+        inverse,
+    })
+}
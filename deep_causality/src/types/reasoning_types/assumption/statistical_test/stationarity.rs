@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::utils::math_utils;
+
+use super::*;
+
+pub(super) fn test(data: &[NumericalValue]) -> Result<StatisticalTestResult, AssumptionError> {
+    check_sample_size(StatisticalTest::Stationarity, data, 12)?;
+
+    let (_, variance) = mean_variance(data);
+    check_not_constant(StatisticalTest::Stationarity, variance)?;
+
+    // Lag order (n - 1)^(1/3) after Said and Dickey (1984), as in adf.test of the R package tseries.
+    let lags = ((data.len() - 1) as NumericalValue).cbrt() as usize;
+
+    let diff: Vec<NumericalValue> = data.windows(2).map(|w| w[1] - w[0]).collect();
+
+    // diff[t] = a + g * y[t] + sum(d_i * diff[t - i]) + e
+    let mut rows = Vec::with_capacity(diff.len() - lags);
+    let mut targets = Vec::with_capacity(diff.len() - lags);
+    for t in lags..diff.len() {
+        let mut row = vec![1.0, data[t]];
+        row.extend((1..=lags).map(|i| diff[t - i]));
+        rows.push(row);
+        targets.push(diff[t]);
+    }
+
+    let fit = regression::ols(&rows, &targets).ok_or_else(|| {
+        AssumptionError("Stationarity test failed, regression is singular".into())
+    })?;
+
+    let gamma = fit.coefficients()[1];
+    let standard_error = fit.standard_error(&targets, 1);
+    if standard_error <= 0.0 || !standard_error.is_finite() {
+        return Err(AssumptionError(
+            "Stationarity test failed, standard error is zero".into(),
+        ));
+    }
+
+    let statistic = gamma / standard_error;
+
+    Ok(StatisticalTestResult::new(
+        statistic,
+        math_utils::dickey_fuller_p_value(statistic),
+        gamma.abs(),
+        data.len(),
+    ))
+}
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::NumericalValue;
+
+use super::StatisticalTestResult;
+
+/// Outcome of a single verification of an assumption.
+///
+/// The confidence is in [0, 1]. Assumptions based on an EvalFn have a confidence of
+/// either one or zero; statistical assumptions derive the confidence from the p-value.
+/// The test result is None for assumptions based on an EvalFn and for statistical
+/// tests that could not run, i.e. because of too few observations.
+#[derive(Getters, Constructor, Copy, Clone, Debug, PartialEq)]
+pub struct AssumptionVerification {
+    valid: bool,
+    confidence: NumericalValue,
+    test_result: Option<StatisticalTestResult>,
+}
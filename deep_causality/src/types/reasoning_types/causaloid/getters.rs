@@ -33,7 +33,15 @@ where
     pub fn description(&self) -> &'l str {
         self.description
     }
+    /// Returns the registry name of the causal function, if set.
+    pub fn fn_name(&self) -> Option<&'l str> {
+        self.fn_name
+    }
     pub fn context(&self) -> Option<&'l Context<D, S, T, ST, V>> {
         self.context
     }
+    /// Returns true if the causaloid evaluates a contextual causal function.
+    pub fn has_context(&self) -> bool {
+        self.has_context
+    }
 }
@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use serde::{Deserialize, Serialize};
+
+use ultragraph::prelude::*;
+
+use crate::utils::serde_utils::index_slots;
+
+use super::*;
+
+type IndexedCausaloidSpec<'l, D, S, T, ST, V> = (usize, CausaloidSpec<'l, D, S, T, ST, V>);
+
+/// Serializable description of a causaloid graph.
+///
+/// Nodes keep their index so that edges, the root, and data indices
+/// refer to the same causaloids after a round trip.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(bound = "")]
+pub struct CausaloidGraphSpec<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    root: Option<usize>,
+    nodes: Vec<IndexedCausaloidSpec<'l, D, S, T, ST, V>>,
+    /// Edges as (source, target, weight).
+    edges: Vec<(usize, usize, u64)>,
+}
+
+impl<'l, D, S, T, ST, V> CausaloidGraphSpec<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    pub fn new(
+        root: Option<usize>,
+        nodes: Vec<IndexedCausaloidSpec<'l, D, S, T, ST, V>>,
+        edges: Vec<(usize, usize, u64)>,
+    ) -> Self {
+        Self { root, nodes, edges }
+    }
+
+    /// Describes the graph, including all nested causaloids.
+    /// Returns SerializationError if a causal function is not registered.
+    pub fn from_graph<'r>(
+        graph: &CausalGraph<'r, D, S, T, ST, V>,
+        registry: &CausalFunctionRegistry<'r, D, S, T, ST, V>,
+    ) -> Result<Self, SerializationError> {
+        let g = graph.get_graph();
+
+        let nodes = g
+            .get_all_indexed_nodes()
+            .into_iter()
+            .map(|(i, c)| CausaloidSpec::from_causaloid(c, registry).map(|spec| (i, spec)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut edges: Vec<_> = g
+            .get_all_edges()
+            .into_iter()
+            .map(|(a, b)| (a, b, g.get_edge_weight(a, b).copied().unwrap_or(0)))
+            .collect();
+        edges.sort();
+
+        Ok(Self::new(graph.get_root_index(), nodes, edges))
+    }
+
+    /// Builds the graph with the functions of the registry and the contexts.
+    /// Every causaloid is inserted at its original index.
+    ///
+    /// Returns SerializationError if a causaloid cannot be built, two nodes share an index,
+    /// a node index is not less than twice the number of nodes, the root is not a node,
+    /// or an edge refers to a node that does not exist.
+    pub fn build(
+        &'l self,
+        registry: &CausalFunctionRegistry<'l, D, S, T, ST, V>,
+        contexts: &[&'l Context<D, S, T, ST, V>],
+    ) -> Result<CausalGraph<'l, D, S, T, ST, V>, SerializationError> {
+        let slots = index_slots(&self.nodes, "causaloid graph")?;
+
+        if let Some(root) = self.root {
+            if slots.get(root).map_or(true, |s| s.is_none()) {
+                return Err(SerializationError(format!(
+                    "Root index {} of the causaloid graph is not a node",
+                    root
+                )));
+            }
+        }
+
+        // A new graph assigns consecutive indices, so placeholders fill the gaps
+        // between indices and are removed once all nodes are in place.
+        let mut graph = CausaloidGraph::new_with_capacity(slots.len().max(1));
+        let mut gaps = Vec::new();
+        for (index, slot) in slots.iter().enumerate() {
+            match slot {
+                Some(spec) => {
+                    let causaloid = spec.build(registry, contexts)?;
+                    if self.root == Some(index) {
+                        graph.add_root_causaloid(causaloid);
+                    } else {
+                        graph.add_causaloid(causaloid);
+                    }
+                }
+                None => {
+                    graph.add_causaloid(Causaloid::new(0, placeholder_fn, ""));
+                    gaps.push(index);
+                }
+            }
+        }
+
+        for index in gaps {
+            graph
+                .remove_causaloid(index)
+                .map_err(|e| SerializationError(e.to_string()))?;
+        }
+
+        for (a, b, weight) in &self.edges {
+            if !graph.contains_causaloid(*a) || !graph.contains_causaloid(*b) {
+                return Err(SerializationError(format!(
+                    "Edge {} -> {} refers to a node that does not exist",
+                    a, b
+                )));
+            }
+            graph
+                .add_edg_with_weight(*a, *b, *weight)
+                .map_err(|e| SerializationError(e.to_string()))?;
+        }
+
+        Ok(graph)
+    }
+
+    pub fn root(&self) -> Option<usize> {
+        self.root
+    }
+
+    pub fn nodes(&self) -> &[IndexedCausaloidSpec<'l, D, S, T, ST, V>] {
+        &self.nodes
+    }
+
+    pub fn edges(&self) -> &[(usize, usize, u64)] {
+        &self.edges
+    }
+}
+
+fn placeholder_fn(_obs: NumericalValue) -> Result<bool, CausalityError> {
+    Ok(false)
+}
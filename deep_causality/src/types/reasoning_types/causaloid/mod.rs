@@ -15,8 +15,17 @@ mod causal_type;
 mod debug;
 mod display;
 mod getters;
+#[cfg(feature = "serde")]
+mod graph_spec;
 mod identifiable;
 mod part_eq;
+#[cfg(feature = "serde")]
+mod spec;
+
+#[cfg(feature = "serde")]
+pub use graph_spec::CausaloidGraphSpec;
+#[cfg(feature = "serde")]
+pub use spec::{CausaloidSpec, CausaloidSpecKind};
 
 pub type CausalVec<'l, D, S, T, ST, V> = Vec<Causaloid<'l, D, S, T, ST, V>>;
 pub type CausalGraph<'l, D, S, T, ST, V> = CausaloidGraph<Causaloid<'l, D, S, T, ST, V>>;
@@ -44,6 +53,8 @@ where
     causal_type: CausalType,
     causal_fn: Option<CausalFn>,
     context_causal_fn: Option<ContextualCausalDataFn<'l, D, S, T, ST, V>>,
+    // Name of the causal function in a CausalFunctionRegistry, if known.
+    fn_name: Option<&'l str>,
     context: Option<&'l Context<D, S, T, ST, V>>,
     has_context: bool,
     causal_coll: Option<&'l CausalVec<'l, D, S, T, ST, V>>,
@@ -84,6 +95,7 @@ where
             causal_coll: None,
             causal_graph: None,
             description,
+            fn_name: None,
             ty: PhantomData,
         }
     }
@@ -105,6 +117,7 @@ where
             causal_coll: None,
             causal_graph: None,
             description,
+            fn_name: None,
             ty: PhantomData,
         }
     }
@@ -132,6 +145,7 @@ where
             context: None,
             has_context: false,
             context_causal_fn: None,
+            fn_name: None,
             ty: PhantomData,
         }
     }
@@ -157,6 +171,7 @@ where
             context,
             has_context: true,
             context_causal_fn: None,
+            fn_name: None,
             ty: PhantomData,
         }
     }
@@ -184,6 +199,7 @@ where
             context: None,
             has_context: false,
             context_causal_fn: None,
+            fn_name: None,
             ty: PhantomData,
         }
     }
@@ -209,7 +225,15 @@ where
             context,
             has_context: true,
             context_causal_fn: None,
+            fn_name: None,
             ty: PhantomData,
         }
     }
+
+    /// Sets the name under which the causal function of a singleton causaloid is registered
+    /// in a CausalFunctionRegistry. The name is required to serialize the causaloid.
+    pub fn with_fn_name(mut self, fn_name: &'l str) -> Self {
+        self.fn_name = Some(fn_name);
+        self
+    }
 }
@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::serde_utils::SpecCache;
+
+use super::*;
+
+/// Serializable description of a causaloid.
+///
+/// Causal functions are referred to by their name in a CausalFunctionRegistry
+/// and contexts by their id. Build the causaloid with CausaloidSpec::build.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(bound = "")]
+pub struct CausaloidSpec<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    id: IdentificationValue,
+    description: String,
+    /// Id of the context of the causaloid, if any.
+    context: Option<u64>,
+    kind: CausaloidSpecKind<'l, D, S, T, ST, V>,
+    #[serde(skip)]
+    collection: SpecCache<CausalVec<'l, D, S, T, ST, V>>,
+    #[serde(skip)]
+    graph: SpecCache<CausalGraph<'l, D, S, T, ST, V>>,
+}
+
+/// Causal function or nested causaloids of a CausaloidSpec.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(bound = "")]
+pub enum CausaloidSpecKind<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Name of a registered causal function.
+    Singleton(String),
+    /// Name of a registered contextual causal function.
+    Contextual(String),
+    Collection(Vec<CausaloidSpec<'l, D, S, T, ST, V>>),
+    Graph(CausaloidGraphSpec<'l, D, S, T, ST, V>),
+}
+
+impl<'l, D, S, T, ST, V> CausaloidSpec<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    pub fn new(
+        id: IdentificationValue,
+        description: &str,
+        context: Option<u64>,
+        kind: CausaloidSpecKind<'l, D, S, T, ST, V>,
+    ) -> Self {
+        Self {
+            id,
+            description: description.to_string(),
+            context,
+            kind,
+            collection: SpecCache::default(),
+            graph: SpecCache::default(),
+        }
+    }
+
+    /// Describes the causaloid, including all nested causaloids.
+    /// Singleton causaloids are described by the registry name of their causal function,
+    /// which causaloids built from a spec carry and others set with Causaloid::with_fn_name.
+    /// Returns SerializationError if a causal function has no name or the name is not registered.
+    pub fn from_causaloid<'r>(
+        causaloid: &Causaloid<'r, D, S, T, ST, V>,
+        registry: &CausalFunctionRegistry<'r, D, S, T, ST, V>,
+    ) -> Result<Self, SerializationError> {
+        let kind = match causaloid.causal_type {
+            CausalType::Singleton => {
+                let kind = if causaloid.has_context {
+                    "contextual causal function"
+                } else {
+                    "causal function"
+                };
+
+                let name = causaloid
+                    .fn_name
+                    .ok_or_else(|| unregistered(kind, causaloid.id))?;
+
+                let registered = if causaloid.has_context {
+                    registry.contextual_causal_fn(name).is_some()
+                } else {
+                    registry.causal_fn(name).is_some()
+                };
+
+                if !registered {
+                    return Err(unknown(kind, name, causaloid.id));
+                }
+
+                if causaloid.has_context {
+                    CausaloidSpecKind::Contextual(name.to_string())
+                } else {
+                    CausaloidSpecKind::Singleton(name.to_string())
+                }
+            }
+            CausalType::Collection => CausaloidSpecKind::Collection(
+                causaloid
+                    .causal_coll
+                    .map_or(&[][..], |coll| coll.as_slice())
+                    .iter()
+                    .map(|c| Self::from_causaloid(c, registry))
+                    .collect::<Result<_, _>>()?,
+            ),
+            CausalType::Graph => match causaloid.causal_graph {
+                Some(graph) => {
+                    CausaloidSpecKind::Graph(CausaloidGraphSpec::from_graph(graph, registry)?)
+                }
+                None => {
+                    return Err(SerializationError(format!(
+                        "Causaloid {} has no causal graph",
+                        causaloid.id
+                    )))
+                }
+            },
+        };
+
+        Ok(Self::new(
+            causaloid.id,
+            causaloid.description,
+            causaloid.context.map(|c| c.id()),
+            kind,
+        ))
+    }
+
+    /// Builds the causaloid with the functions of the registry and the contexts.
+    /// Nested collections and graphs are built once, kept in the spec,
+    /// and shared by all causaloids built from the spec.
+    ///
+    /// Returns SerializationError if a function is not registered,
+    /// the context of a causaloid is not among the contexts, or a graph is invalid.
+    pub fn build(
+        &'l self,
+        registry: &CausalFunctionRegistry<'l, D, S, T, ST, V>,
+        contexts: &[&'l Context<D, S, T, ST, V>],
+    ) -> Result<Causaloid<'l, D, S, T, ST, V>, SerializationError> {
+        let context =
+            match self.context {
+                None => None,
+                Some(id) => Some(contexts.iter().find(|c| c.id() == id).copied().ok_or_else(
+                    || {
+                        SerializationError(format!(
+                            "Causaloid {} refers to unknown context {}",
+                            self.id, id
+                        ))
+                    },
+                )?),
+            };
+
+        let description = self.description.as_str();
+
+        let causaloid = match &self.kind {
+            CausaloidSpecKind::Singleton(name) => {
+                let f = registry
+                    .causal_fn(name)
+                    .ok_or_else(|| unknown("causal function", name, self.id))?;
+                // Only contextual causal functions use a context.
+                Causaloid::new(self.id, f, description).with_fn_name(name)
+            }
+            CausaloidSpecKind::Contextual(name) => {
+                let f = registry
+                    .contextual_causal_fn(name)
+                    .ok_or_else(|| unknown("contextual causal function", name, self.id))?;
+                Causaloid::new_with_context(self.id, f, context, description).with_fn_name(name)
+            }
+            CausaloidSpecKind::Collection(specs) => {
+                let coll = self.collection.get_or_try_init(|| {
+                    specs
+                        .iter()
+                        .map(|spec| spec.build(registry, contexts))
+                        .collect::<Result<Vec<_>, _>>()
+                })?;
+                match context {
+                    None => Causaloid::from_causal_collection(self.id, coll, description),
+                    Some(_) => Causaloid::from_causal_collection_with_context(
+                        self.id,
+                        coll,
+                        context,
+                        description,
+                    ),
+                }
+            }
+            CausaloidSpecKind::Graph(spec) => {
+                let graph = self
+                    .graph
+                    .get_or_try_init(|| spec.build(registry, contexts))?;
+                match context {
+                    None => Causaloid::from_causal_graph(self.id, graph, description),
+                    Some(_) => Causaloid::from_causal_graph_with_context(
+                        self.id,
+                        graph,
+                        context,
+                        description,
+                    ),
+                }
+            }
+        };
+
+        Ok(causaloid)
+    }
+
+    pub fn id(&self) -> IdentificationValue {
+        self.id
+    }
+
+    pub fn description(&self) -> &str {
+        self.description.as_str()
+    }
+
+    pub fn context(&self) -> Option<u64> {
+        self.context
+    }
+
+    pub fn kind(&self) -> &CausaloidSpecKind<'l, D, S, T, ST, V> {
+        &self.kind
+    }
+}
+
+fn unregistered(kind: &str, id: IdentificationValue) -> SerializationError {
+    SerializationError(format!(
+        "The {} of causaloid {} is not registered",
+        kind, id
+    ))
+}
+
+fn unknown(kind: &str, name: &str, id: IdentificationValue) -> SerializationError {
+    SerializationError(format!("Unknown {} '{}' in causaloid {}", kind, name, id))
+}
@@ -0,0 +1,370 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::errors::BuildError;
+use crate::prelude::IdentificationValue;
+
+use super::*;
+
+// Distinguishes builders so that handles of one builder are rejected by another.
+static NEXT_BUILDER_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Handle of a causaloid added to a CausaloidGraphBuilder.
+///
+/// Handles are typed by the causaloid type of their builder, so handles of builders
+/// for different causaloid types cannot be mixed. index returns the index of the
+/// causaloid in the graph returned by build.
+#[derive(Debug)]
+pub struct CausaloidHandle<T> {
+    builder: u64,
+    index: usize,
+    ty: PhantomData<fn() -> T>,
+}
+
+impl<T> CausaloidHandle<T> {
+    /// Returns the index of the causaloid in the built graph.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+// Implemented by hand because derive would require T: Clone, Copy, PartialEq.
+impl<T> Clone for CausaloidHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for CausaloidHandle<T> {}
+
+impl<T> PartialEq for CausaloidHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.builder == other.builder && self.index == other.index
+    }
+}
+
+impl<T> Eq for CausaloidHandle<T> {}
+
+/// Builds a CausaloidGraph from typed handles instead of raw indices.
+///
+/// ```text
+/// let mut builder = CausaloidGraphBuilder::new();
+/// let root = builder.root(root_causaloid);
+/// let a = builder.add(causaloid_a);
+/// let b = builder.add(causaloid_b);
+/// builder.cause(root).leads_to(a).cause(a).leads_to(b);
+/// let graph = builder.build()?;
+/// ```
+///
+/// build validates the graph and returns a BuildError that lists every problem found:
+/// a missing or repeated root, cycles, causaloids not reachable from the root,
+/// handles of another builder, repeated edges, and, if a data index is set,
+/// causaloids without data.
+pub struct CausaloidGraphBuilder<T>
+where
+    T: Causable + PartialEq,
+{
+    id: u64,
+    causaloids: Vec<T>,
+    roots: Vec<usize>,
+    edges: Vec<(usize, usize, u64)>,
+    data_index: Option<HashMap<IdentificationValue, IdentificationValue>>,
+    problems: Vec<String>,
+}
+
+/// Adds edges from a cause, see CausaloidGraphBuilder::cause.
+pub struct CauseBuilder<'b, T>
+where
+    T: Causable + PartialEq,
+{
+    builder: &'b mut CausaloidGraphBuilder<T>,
+    cause: CausaloidHandle<T>,
+}
+
+impl<T> CausaloidGraphBuilder<T>
+where
+    T: Causable + PartialEq,
+{
+    pub fn new() -> Self {
+        Self {
+            id: NEXT_BUILDER_ID.fetch_add(1, Ordering::Relaxed),
+            causaloids: Vec::new(),
+            roots: Vec::new(),
+            edges: Vec::new(),
+            data_index: None,
+            problems: Vec::new(),
+        }
+    }
+
+    /// Adds the root causaloid, where reasoning over the entire graph starts.
+    pub fn root(&mut self, causaloid: T) -> CausaloidHandle<T> {
+        let handle = self.add(causaloid);
+        self.roots.push(handle.index);
+        handle
+    }
+
+    /// Adds a causaloid.
+    pub fn add(&mut self, causaloid: T) -> CausaloidHandle<T> {
+        self.causaloids.push(causaloid);
+        CausaloidHandle {
+            builder: self.id,
+            index: self.causaloids.len() - 1,
+            ty: PhantomData,
+        }
+    }
+
+    /// Adds all causaloids and returns their handles in the same order.
+    pub fn add_all(&mut self, causaloids: Vec<T>) -> Vec<CausaloidHandle<T>> {
+        causaloids.into_iter().map(|c| self.add(c)).collect()
+    }
+
+    /// Starts wiring edges from the cause, i.e. builder.cause(a).leads_to(b).
+    pub fn cause(&mut self, cause: CausaloidHandle<T>) -> CauseBuilder<'_, T> {
+        CauseBuilder {
+            builder: self,
+            cause,
+        }
+    }
+
+    /// Adds an edge for each pair of cause and effect.
+    pub fn add_edges(&mut self, edges: &[(CausaloidHandle<T>, CausaloidHandle<T>)]) -> &mut Self {
+        for (cause, effect) in edges {
+            self.add_edge(*cause, *effect, 0);
+        }
+        self
+    }
+
+    /// Adds an edge with the given weight for each triple of cause, effect, and weight.
+    pub fn add_weighted_edges(
+        &mut self,
+        edges: &[(CausaloidHandle<T>, CausaloidHandle<T>, u64)],
+    ) -> &mut Self {
+        for (cause, effect, weight) in edges {
+            self.add_edge(*cause, *effect, *weight);
+        }
+        self
+    }
+
+    /// Sets the data index used to reason over the graph.
+    /// build then checks that the data index covers the id of every causaloid.
+    pub fn data_index(
+        &mut self,
+        data_index: HashMap<IdentificationValue, IdentificationValue>,
+    ) -> &mut Self {
+        self.data_index = Some(data_index);
+        self
+    }
+
+    /// Validates and builds the graph. The index of each handle is the index
+    /// of its causaloid in the graph.
+    ///
+    /// Returns BuildError listing every problem if the graph is not valid.
+    pub fn build(self) -> Result<CausaloidGraph<T>, BuildError> {
+        let problems = self.validate();
+        if !problems.is_empty() {
+            return Err(BuildError(format!(
+                "Invalid causaloid graph:\n{}",
+                problems
+                    .iter()
+                    .map(|p| format!("  - {}", p))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )));
+        }
+
+        let root = self.roots[0];
+        let mut graph = CausaloidGraph::new_with_capacity(self.causaloids.len());
+        for (index, causaloid) in self.causaloids.into_iter().enumerate() {
+            if index == root {
+                graph.add_root_causaloid(causaloid);
+            } else {
+                graph.add_causaloid(causaloid);
+            }
+        }
+
+        for (a, b, weight) in self.edges {
+            graph
+                .add_edg_with_weight(a, b, weight)
+                .map_err(|e| BuildError(e.to_string()))?;
+        }
+
+        Ok(graph)
+    }
+
+    fn add_edge(&mut self, cause: CausaloidHandle<T>, effect: CausaloidHandle<T>, weight: u64) {
+        if cause.builder != self.id || effect.builder != self.id {
+            self.problems.push(format!(
+                "Edge {} -> {} uses a handle of another builder",
+                cause.index, effect.index
+            ));
+        } else if self
+            .edges
+            .iter()
+            .any(|(a, b, _)| (*a, *b) == (cause.index, effect.index))
+        {
+            self.problems.push(format!(
+                "Edge {} -> {} is added more than once",
+                self.name(cause.index),
+                self.name(effect.index)
+            ));
+        } else {
+            self.edges.push((cause.index, effect.index, weight));
+        }
+    }
+
+    fn validate(&self) -> Vec<String> {
+        let mut problems = self.problems.clone();
+
+        match self.roots.as_slice() {
+            [] => problems.push("The graph has no root causaloid".to_string()),
+            [_] => {}
+            roots => problems.push(format!(
+                "The graph has {} root causaloids: {}",
+                roots.len(),
+                self.names(roots)
+            )),
+        }
+
+        let mut children = vec![Vec::new(); self.causaloids.len()];
+        for (a, b, _) in &self.edges {
+            children[*a].push(*b);
+        }
+
+        if let Some(cycle) = find_cycle(&children) {
+            problems.push(format!("The graph has a cycle: {}", self.names(&cycle)));
+        }
+
+        if let Some(root) = self.roots.first() {
+            let mut reachable = vec![false; self.causaloids.len()];
+            let mut stack = vec![*root];
+            while let Some(index) = stack.pop() {
+                if !reachable[index] {
+                    reachable[index] = true;
+                    stack.extend(&children[index]);
+                }
+            }
+
+            let unreachable: Vec<usize> = (0..self.causaloids.len())
+                .filter(|i| !reachable[*i])
+                .collect();
+            if !unreachable.is_empty() {
+                problems.push(format!(
+                    "Causaloids not reachable from the root: {}",
+                    self.names(&unreachable)
+                ));
+            }
+        }
+
+        if let Some(data_index) = &self.data_index {
+            let uncovered: Vec<usize> = (0..self.causaloids.len())
+                .filter(|i| !data_index.contains_key(&self.causaloids[*i].id()))
+                .collect();
+            if !uncovered.is_empty() {
+                problems.push(format!(
+                    "Causaloids without data in the data index: {}",
+                    self.names(&uncovered)
+                ));
+            }
+        }
+
+        problems
+    }
+
+    fn name(&self, index: usize) -> String {
+        format!("{} (id {})", index, self.causaloids[index].id())
+    }
+
+    fn names(&self, indices: &[usize]) -> String {
+        let names: Vec<String> = indices.iter().map(|i| self.name(*i)).collect();
+        names.join(", ")
+    }
+}
+
+impl<T> Default for CausaloidGraphBuilder<T>
+where
+    T: Causable + PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'b, T> CauseBuilder<'b, T>
+where
+    T: Causable + PartialEq,
+{
+    /// Adds an edge from the cause to the effect.
+    pub fn leads_to(self, effect: CausaloidHandle<T>) -> &'b mut CausaloidGraphBuilder<T> {
+        self.leads_to_with_weight(effect, 0)
+    }
+
+    /// Adds an edge with the given weight from the cause to the effect.
+    pub fn leads_to_with_weight(
+        self,
+        effect: CausaloidHandle<T>,
+        weight: u64,
+    ) -> &'b mut CausaloidGraphBuilder<T> {
+        self.builder.add_edge(self.cause, effect, weight);
+        self.builder
+    }
+
+    /// Adds an edge from the cause to each effect.
+    pub fn leads_to_all(self, effects: &[CausaloidHandle<T>]) -> &'b mut CausaloidGraphBuilder<T> {
+        for effect in effects {
+            self.builder.add_edge(self.cause, *effect, 0);
+        }
+        self.builder
+    }
+}
+
+// Returns the nodes of a cycle, starting and ending with the same node, if there is one.
+fn find_cycle(children: &[Vec<usize>]) -> Option<Vec<usize>> {
+    // 0: not visited, 1: on the current path, 2: done
+    let mut state = vec![0u8; children.len()];
+
+    for start in 0..children.len() {
+        if state[start] != 0 {
+            continue;
+        }
+
+        let mut path = vec![start];
+        let mut next_child = vec![0];
+        state[start] = 1;
+
+        while let Some(&node) = path.last() {
+            let position = next_child
+                .last_mut()
+                .expect("Path and positions have equal length");
+            match children[node].get(*position) {
+                Some(&child) => {
+                    *position += 1;
+                    match state[child] {
+                        0 => {
+                            state[child] = 1;
+                            path.push(child);
+                            next_child.push(0);
+                        }
+                        1 => {
+                            let from = path.iter().position(|n| *n == child).expect("On path");
+                            let mut cycle = path[from..].to_vec();
+                            cycle.push(child);
+                            return Some(cycle);
+                        }
+                        _ => {}
+                    }
+                }
+                None => {
+                    state[node] = 2;
+                    path.pop();
+                    next_child.pop();
+                }
+            }
+        }
+    }
+
+    None
+}
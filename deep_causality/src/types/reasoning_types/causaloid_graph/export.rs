@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::Write;
+use std::hash::Hash;
+use std::ops::*;
+
+use crate::prelude::{
+    Causable, Causaloid, Datable, GraphExportOptions, Identifiable, SpaceTemporal, Spatial,
+    Temporable,
+};
+use crate::utils::export_utils::{escape_dot, escape_mermaid};
+
+use super::*;
+
+impl<'l, D, S, T, ST, V> CausaloidGraph<Causaloid<'l, D, S, T, ST, V>>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Renders the graph in Graphviz DOT.
+    ///
+    /// Nodes show the causaloid id, description, and whether the causaloid was active
+    /// after the last reasoning run; edges show their weight. The root node has a double border.
+    pub fn to_dot(&self, options: &GraphExportOptions) -> String {
+        let mut dot = String::from("digraph causaloid_graph {\n    node [shape=box];\n");
+
+        for (index, causaloid) in self.graph.get_all_indexed_nodes() {
+            let mut attributes = format!("label=\"{}\"", escape_dot(&node_label(causaloid)));
+            if self.graph.get_root_index() == Some(index) {
+                attributes.push_str(", peripheries=2");
+            }
+            if *options.highlight_reasoning_path() && causaloid.is_active() {
+                attributes.push_str(", style=filled, fillcolor=palegreen");
+            }
+            writeln!(dot, "    {} [{}];", index, attributes).expect("Failed to write DOT");
+        }
+
+        for (a, b, weight) in self.weighted_edges() {
+            let mut attributes = format!("label=\"{}\"", weight);
+            if *options.highlight_reasoning_path() && self.on_reasoning_path(a, b) {
+                attributes.push_str(", color=darkgreen, penwidth=2");
+            }
+            writeln!(dot, "    {} -> {} [{}];", a, b, attributes).expect("Failed to write DOT");
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders the graph as a Mermaid flowchart.
+    ///
+    /// Nodes show the causaloid id, description, and whether the causaloid was active
+    /// after the last reasoning run; edges show their weight. The root node is drawn as a stadium.
+    pub fn to_mermaid(&self, options: &GraphExportOptions) -> String {
+        let mut mermaid = String::from("flowchart TD\n");
+        let mut active = Vec::new();
+
+        for (index, causaloid) in self.graph.get_all_indexed_nodes() {
+            let label = escape_mermaid(&node_label(causaloid));
+            if self.graph.get_root_index() == Some(index) {
+                writeln!(mermaid, "    n{}([\"{}\"])", index, label)
+            } else {
+                writeln!(mermaid, "    n{}[\"{}\"]", index, label)
+            }
+            .expect("Failed to write Mermaid");
+
+            if causaloid.is_active() {
+                active.push(format!("n{}", index));
+            }
+        }
+
+        let mut path = Vec::new();
+        for (position, (a, b, weight)) in self.weighted_edges().into_iter().enumerate() {
+            writeln!(mermaid, "    n{} -->|{}| n{}", a, weight, b)
+                .expect("Failed to write Mermaid");
+            if self.on_reasoning_path(a, b) {
+                path.push(position.to_string());
+            }
+        }
+
+        if *options.highlight_reasoning_path() {
+            if !active.is_empty() {
+                mermaid.push_str("    classDef active fill:#98fb98,stroke:#006400;\n");
+                writeln!(mermaid, "    class {} active;", active.join(","))
+                    .expect("Failed to write Mermaid");
+            }
+            if !path.is_empty() {
+                writeln!(
+                    mermaid,
+                    "    linkStyle {} stroke:#006400,stroke-width:3px;",
+                    path.join(",")
+                )
+                .expect("Failed to write Mermaid");
+            }
+        }
+
+        mermaid
+    }
+
+    // Edges sorted by their nodes so that exports are deterministic.
+    fn weighted_edges(&self) -> Vec<(usize, usize, u64)> {
+        let mut edges: Vec<_> = self
+            .graph
+            .get_all_edges()
+            .into_iter()
+            .map(|(a, b)| (a, b, *self.graph.get_edge_weight(a, b).unwrap_or(&0)))
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    // Reasoning only proceeds from active causaloids, so an edge between
+    // two active causaloids is part of the path taken by the last reasoning run.
+    fn on_reasoning_path(&self, a: usize, b: usize) -> bool {
+        let is_active = |index| {
+            self.graph
+                .get_node(index)
+                .is_some_and(|causaloid| causaloid.is_active())
+        };
+        is_active(a) && is_active(b)
+    }
+}
+
+fn node_label<D, S, T, ST, V>(causaloid: &Causaloid<'_, D, S, T, ST, V>) -> String
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    format!(
+        "id: {}\n{}\nactive: {}",
+        causaloid.id(),
+        causaloid.description(),
+        causaloid.is_active()
+    )
+}
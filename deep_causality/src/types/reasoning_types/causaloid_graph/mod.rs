@@ -9,8 +9,12 @@ use crate::prelude::{
     NumericalValue,
 };
 
+mod builder;
 mod causable_graph;
 mod default;
+mod export;
+
+pub use builder::{CausaloidGraphBuilder, CausaloidHandle, CauseBuilder};
 
 #[derive(Clone)]
 pub struct CausaloidGraph<T>
@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::BTreeMap;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+use std::sync::Arc;
+
+use crate::prelude::{
+    CausalAction, CausalFn, ContextualCausalDataFn, Datable, EvalFn, NumericalValue, RegistryError,
+    SpaceTemporal, Spatial, Temporable,
+};
+
+/// Maps names to causal functions, contextual causal functions,
+/// assumption functions, and actions.
+///
+/// Function pointers cannot be serialized, so models refer to their functions by name
+/// and the registry resolves the names when a model is loaded.
+/// Names are unique per kind of function.
+///
+/// Causaloids, assumptions, and actions built from a spec keep the names of their functions,
+/// which are written back when they are serialized.
+pub struct CausalFunctionRegistry<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    causal_fns: BTreeMap<String, CausalFn>,
+    contextual_causal_fns: BTreeMap<String, ContextualCausalDataFn<'l, D, S, T, ST, V>>,
+    eval_fns: BTreeMap<String, EvalFn>,
+    actions: BTreeMap<String, CausalAction>,
+}
+
+impl<'l, D, S, T, ST, V> CausalFunctionRegistry<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    pub fn new() -> Self {
+        Self {
+            causal_fns: BTreeMap::new(),
+            contextual_causal_fns: BTreeMap::new(),
+            eval_fns: BTreeMap::new(),
+            actions: BTreeMap::new(),
+        }
+    }
+
+    /// Registers a causal function under the name.
+    /// Returns RegistryError if the name is already taken by another causal function.
+    pub fn register_causal_fn(&mut self, name: &str, f: CausalFn) -> Result<(), RegistryError> {
+        insert_unique(&mut self.causal_fns, "causal function", name, f)
+    }
+
+    /// Registers a contextual causal function under the name.
+    /// Returns RegistryError if the name is already taken by another contextual causal function.
+    pub fn register_contextual_causal_fn(
+        &mut self,
+        name: &str,
+        f: ContextualCausalDataFn<'l, D, S, T, ST, V>,
+    ) -> Result<(), RegistryError> {
+        insert_unique(
+            &mut self.contextual_causal_fns,
+            "contextual causal function",
+            name,
+            f,
+        )
+    }
+
+    /// Registers an assumption function under the name.
+    /// Returns RegistryError if the name is already taken by another assumption function.
+    pub fn register_eval_fn<F>(&mut self, name: &str, f: F) -> Result<(), RegistryError>
+    where
+        F: Fn(&[NumericalValue]) -> bool + Send + Sync + 'static,
+    {
+        insert_unique(&mut self.eval_fns, "assumption function", name, Arc::new(f))
+    }
+
+    /// Registers an action under the name and sets the name of the action.
+    /// Returns RegistryError if the name is already taken by another action.
+    pub fn register_action(
+        &mut self,
+        name: &str,
+        action: CausalAction,
+    ) -> Result<(), RegistryError> {
+        insert_unique(&mut self.actions, "action", name, action.with_name(name))
+    }
+
+    pub fn causal_fn(&self, name: &str) -> Option<CausalFn> {
+        self.causal_fns.get(name).copied()
+    }
+
+    pub fn contextual_causal_fn(
+        &self,
+        name: &str,
+    ) -> Option<ContextualCausalDataFn<'l, D, S, T, ST, V>> {
+        self.contextual_causal_fns.get(name).copied()
+    }
+
+    pub fn eval_fn(&self, name: &str) -> Option<EvalFn> {
+        self.eval_fns.get(name).cloned()
+    }
+
+    pub fn action(&self, name: &str) -> Option<&CausalAction> {
+        self.actions.get(name)
+    }
+
+    /// Returns the names of all causal functions in alphabetical order.
+    pub fn causal_fn_names(&self) -> Vec<&str> {
+        self.causal_fns.keys().map(|name| name.as_str()).collect()
+    }
+
+    /// Returns the names of all contextual causal functions in alphabetical order.
+    pub fn contextual_causal_fn_names(&self) -> Vec<&str> {
+        self.contextual_causal_fns
+            .keys()
+            .map(|name| name.as_str())
+            .collect()
+    }
+
+    /// Returns the names of all assumption functions in alphabetical order.
+    pub fn eval_fn_names(&self) -> Vec<&str> {
+        self.eval_fns.keys().map(|name| name.as_str()).collect()
+    }
+
+    /// Returns the names of all actions in alphabetical order.
+    pub fn action_names(&self) -> Vec<&str> {
+        self.actions.keys().map(|name| name.as_str()).collect()
+    }
+
+    /// Returns the number of registered functions and actions.
+    pub fn len(&self) -> usize {
+        self.causal_fns.len()
+            + self.contextual_causal_fns.len()
+            + self.eval_fns.len()
+            + self.actions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<'l, D, S, T, ST, V> Default for CausalFunctionRegistry<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn insert_unique<F>(
+    map: &mut BTreeMap<String, F>,
+    kind: &str,
+    name: &str,
+    f: F,
+) -> Result<(), RegistryError> {
+    if map.contains_key(name) {
+        return Err(RegistryError(format!(
+            "A {} named '{}' is already registered",
+            kind, name
+        )));
+    }
+
+    map.insert(name.to_string(), f);
+    Ok(())
+}
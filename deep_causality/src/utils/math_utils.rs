@@ -27,3 +27,176 @@ where
 {
     iterable.into_iter().sum()
 }
+
+/// Mean earth radius in meters, as used by geodesic_distance.
+pub const EARTH_RADIUS_METERS: NumericalValue = 6_371_008.8;
+
+/// Returns the euclidean distance between two points in three dimensional space.
+pub fn euclidean_distance(a: [NumericalValue; 3], b: [NumericalValue; 3]) -> NumericalValue {
+    let dx = a[0] - b[0];
+    let dy = a[1] - b[1];
+    let dz = a[2] - b[2];
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
+
+/// Returns the great circle distance in meters between two points
+/// given as latitude and longitude in degrees, using the haversine formula.
+pub fn geodesic_distance(
+    lat_a: NumericalValue,
+    lon_a: NumericalValue,
+    lat_b: NumericalValue,
+    lon_b: NumericalValue,
+) -> NumericalValue {
+    let d_lat = (lat_b - lat_a).to_radians();
+    let d_lon = (lon_b - lon_a).to_radians();
+
+    let h = (d_lat / 2.0).sin().powi(2)
+        + lat_a.to_radians().cos() * lat_b.to_radians().cos() * (d_lon / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_METERS * h.sqrt().min(1.0).asin()
+}
+
+/// Returns the error function of x, computed with the
+/// Abramowitz and Stegun approximation 7.1.26 (maximum error 1.5e-7).
+pub fn erf(x: NumericalValue) -> NumericalValue {
+    let sign = if x < ZERO { MINUS_ONE } else { 1.0 };
+    let x = abs_num(x);
+
+    let t = 1.0 / (1.0 + 0.3275911 * x);
+    let poly = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+
+    sign * (1.0 - poly * (-x * x).exp())
+}
+
+/// Returns the probability that a normally distributed value
+/// with the given mean and standard deviation is less than or equal to x.
+/// A standard deviation of zero yields a step function at the mean.
+pub fn normal_cdf(
+    x: NumericalValue,
+    mean: NumericalValue,
+    std_dev: NumericalValue,
+) -> NumericalValue {
+    if std_dev <= ZERO {
+        return if x < mean { ZERO } else { 1.0 };
+    }
+
+    0.5 * (1.0 + erf((x - mean) / (std_dev * std::f64::consts::SQRT_2)))
+}
+
+/// Returns the natural logarithm of the gamma function for x > 0,
+/// computed with the Lanczos approximation.
+pub fn ln_gamma(x: NumericalValue) -> NumericalValue {
+    const COEFFICIENTS: [NumericalValue; 6] = [
+        76.18009172947146,
+        -86.50532032941677,
+        24.01409824083091,
+        -1.231739572450155,
+        0.1208650973866179e-2,
+        -0.5395239384953e-5,
+    ];
+
+    let tmp = x + 5.5;
+    let tmp = tmp - (x + 0.5) * tmp.ln();
+
+    let mut y = x;
+    let mut series = 1.000000000190015;
+    for c in COEFFICIENTS {
+        y += 1.0;
+        series += c / y;
+    }
+
+    -tmp + (2.5066282746310005 * series / x).ln()
+}
+
+/// Returns the regularized upper incomplete gamma function Q(a, x) for a > 0 and x >= 0.
+pub fn upper_regularized_gamma(a: NumericalValue, x: NumericalValue) -> NumericalValue {
+    const MAX_ITERATIONS: usize = 500;
+    const EPSILON: NumericalValue = 1e-14;
+    const TINY: NumericalValue = 1e-300;
+
+    if x <= ZERO {
+        return 1.0;
+    }
+
+    let ln_prefix = -x + a * x.ln() - ln_gamma(a);
+
+    if x < a + 1.0 {
+        // Series representation of the lower function P(a, x)
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        let mut n = a;
+        for _ in 0..MAX_ITERATIONS {
+            n += 1.0;
+            term *= x / n;
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+        return (1.0 - sum * ln_prefix.exp()).clamp(ZERO, 1.0);
+    }
+
+    // Continued fraction representation of Q(a, x), modified Lentz's method
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / TINY;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..=MAX_ITERATIONS {
+        let an = -(i as NumericalValue) * (i as NumericalValue - a);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = b + an / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+
+    (ln_prefix.exp() * h).clamp(ZERO, 1.0)
+}
+
+/// Returns the probability that a chi-squared distributed value
+/// with the given degrees of freedom is greater than x.
+pub fn chi_squared_sf(x: NumericalValue, degrees_of_freedom: NumericalValue) -> NumericalValue {
+    upper_regularized_gamma(degrees_of_freedom / 2.0, x / 2.0)
+}
+
+// MacKinnon (1994) response surface of the Dickey-Fuller distribution
+// for a regression with a constant, as used by statsmodels (tau_c_smallp and
+// tau_c_largep, the latter scaled by 1, 1e-1, 1e-1, 1e-2).
+const TAU_MIN: NumericalValue = -18.83;
+const TAU_MAX: NumericalValue = 2.74;
+const TAU_STAR: NumericalValue = -1.61;
+const TAU_SMALL_P: [NumericalValue; 3] = [2.1659, 1.4412, 0.038269];
+const TAU_LARGE_P: [NumericalValue; 4] = [1.7339, 0.93202, -0.12745, -0.010368];
+
+/// Returns the MacKinnon approximate p-value of the augmented Dickey-Fuller
+/// statistic tau for a regression with a constant.
+pub fn dickey_fuller_p_value(tau: NumericalValue) -> NumericalValue {
+    if tau > TAU_MAX {
+        return 1.0;
+    }
+    if tau < TAU_MIN {
+        return ZERO;
+    }
+
+    let coefficients: &[NumericalValue] = if tau <= TAU_STAR {
+        &TAU_SMALL_P
+    } else {
+        &TAU_LARGE_P
+    };
+
+    let z = coefficients.iter().rev().fold(ZERO, |acc, c| acc * tau + c);
+
+    normal_cdf(z, ZERO, 1.0)
+}
@@ -1,5 +1,8 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
+pub(crate) mod export_utils;
 pub mod math_utils;
+#[cfg(feature = "serde")]
+pub mod serde_utils;
 pub mod time_utils;
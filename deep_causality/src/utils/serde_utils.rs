@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::cell::OnceCell;
+use std::fmt::{Debug, Formatter};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::prelude::SerializationError;
+
+/// Serializes the value to JSON.
+pub fn to_json<T: Serialize>(value: &T) -> Result<String, SerializationError> {
+    serde_json::to_string(value).map_err(|e| SerializationError(e.to_string()))
+}
+
+/// Serializes the value to indented JSON.
+pub fn to_json_pretty<T: Serialize>(value: &T) -> Result<String, SerializationError> {
+    serde_json::to_string_pretty(value).map_err(|e| SerializationError(e.to_string()))
+}
+
+/// Deserializes a value from JSON.
+pub fn from_json<T: DeserializeOwned>(json: &str) -> Result<T, SerializationError> {
+    serde_json::from_str(json).map_err(|e| SerializationError(e.to_string()))
+}
+
+/// Serializes the value to the compact binary format of bincode.
+pub fn to_binary<T: Serialize>(value: &T) -> Result<Vec<u8>, SerializationError> {
+    bincode::serialize(value).map_err(|e| SerializationError(e.to_string()))
+}
+
+/// Deserializes a value from the compact binary format of bincode.
+pub fn from_binary<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, SerializationError> {
+    bincode::deserialize(bytes).map_err(|e| SerializationError(e.to_string()))
+}
+
+/// Serializes the value to TOML.
+#[cfg(feature = "toml")]
+pub fn to_toml<T: Serialize>(value: &T) -> Result<String, SerializationError> {
+    toml::to_string(value).map_err(|e| SerializationError(e.to_string()))
+}
+
+/// Deserializes a value from TOML.
+#[cfg(feature = "toml")]
+pub fn from_toml<T: DeserializeOwned>(toml: &str) -> Result<T, SerializationError> {
+    toml::from_str(toml).map_err(|e| SerializationError(e.to_string()))
+}
+
+/// Serializes the value to YAML.
+#[cfg(feature = "yaml")]
+pub fn to_yaml<T: Serialize>(value: &T) -> Result<String, SerializationError> {
+    serde_yaml_ng::to_string(value).map_err(|e| SerializationError(e.to_string()))
+}
+
+/// Deserializes a value from YAML.
+#[cfg(feature = "yaml")]
+pub fn from_yaml<T: DeserializeOwned>(yaml: &str) -> Result<T, SerializationError> {
+    serde_yaml_ng::from_str(yaml).map_err(|e| SerializationError(e.to_string()))
+}
+
+// Holds what a spec built from itself, i.e. the nested collections a causaloid refers to,
+// so that the built types can borrow it for as long as the spec lives.
+// A cache is never serialized, compares equal to any other cache, and clones empty.
+pub(crate) struct SpecCache<T>(OnceCell<T>);
+
+impl<T> SpecCache<T> {
+    pub(crate) fn get_or_try_init<E, F>(&self, f: F) -> Result<&T, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+    {
+        if let Some(value) = self.0.get() {
+            return Ok(value);
+        }
+
+        let value = f()?;
+        Ok(self.0.get_or_init(|| value))
+    }
+}
+
+impl<T> Default for SpecCache<T> {
+    fn default() -> Self {
+        Self(OnceCell::new())
+    }
+}
+
+impl<T> Clone for SpecCache<T> {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl<T> PartialEq for SpecCache<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> Debug for SpecCache<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SpecCache")
+    }
+}
+
+// Places each node at its index; missing indices are None.
+// Gaps left by removed nodes become placeholder nodes of the new graph, so to bound
+// its memory, an index must be less than twice the number of nodes.
+// Returns SerializationError if an index exceeds that limit or two nodes share an index.
+pub(crate) fn index_slots<'a, N>(
+    nodes: &'a [(usize, N)],
+    kind: &str,
+) -> Result<Vec<Option<&'a N>>, SerializationError> {
+    let limit = nodes.len().saturating_mul(2);
+
+    let mut slots = Vec::new();
+    for (index, node) in nodes {
+        if *index >= limit {
+            return Err(SerializationError(format!(
+                "{} node index {} exceeds the limit of {} for {} nodes",
+                kind,
+                index,
+                limit,
+                nodes.len()
+            )));
+        }
+        if *index >= slots.len() {
+            slots.resize(index + 1, None);
+        }
+        if slots[*index].replace(node).is_some() {
+            return Err(SerializationError(format!(
+                "Duplicate {} node index {}",
+                kind, index
+            )));
+        }
+    }
+    Ok(slots)
+}
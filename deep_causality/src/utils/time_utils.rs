@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
+use crate::prelude::{TimeError, TimeScale};
+
 pub fn time_execution<T, F: FnOnce() -> T>(f: F, f_name: &str) -> T {
     let start = std::time::Instant::now();
     let res = f();
@@ -11,3 +13,127 @@ pub fn time_execution<T, F: FnOnce() -> T>(f: F, f_name: &str) -> T {
     );
     res
 }
+
+pub const NANOS_PER_MICROSECOND: i64 = 1_000;
+pub const NANOS_PER_MILLISECOND: i64 = 1_000_000;
+pub const NANOS_PER_SECOND: i64 = 1_000_000_000;
+pub const NANOS_PER_MINUTE: i64 = 60 * NANOS_PER_SECOND;
+pub const NANOS_PER_HOUR: i64 = 60 * NANOS_PER_MINUTE;
+pub const NANOS_PER_DAY: i64 = 24 * NANOS_PER_HOUR;
+pub const NANOS_PER_WEEK: i64 = 7 * NANOS_PER_DAY;
+
+// The Unix epoch, 1970-01-01, is a Thursday, three days after the Monday starting its week.
+const EPOCH_WEEKDAY: i64 = 3;
+
+/// Returns the number of days between the Unix epoch and the date of the proleptic
+/// Gregorian calendar. Month and day start at one.
+/// Returns None if the number of days overflows.
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> Option<i64> {
+    // See http://howardhinnant.github.io/date_algorithms.html
+    let month = month as i64;
+    let year = if month <= 2 {
+        year.checked_sub(1)?
+    } else {
+        year
+    };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era.checked_mul(146097)?
+        .checked_add(doe)?
+        .checked_sub(719468)
+}
+
+/// Returns year, month, and day of the proleptic Gregorian calendar
+/// for the number of days since the Unix epoch.
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let doe = days - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+/// Returns the number of whole units of the time scale between the Unix epoch
+/// and the UTC timestamp in nanoseconds. Negative for timestamps before the epoch.
+/// Weeks start on Monday; months, quarters, and years follow the calendar.
+/// Returns TimeError for NoScale.
+pub fn units_since_epoch(unix_nanos: i64, time_scale: TimeScale) -> Result<i64, TimeError> {
+    if time_scale == TimeScale::Week {
+        let days = unix_nanos.div_euclid(NANOS_PER_DAY);
+        return Ok((days + EPOCH_WEEKDAY).div_euclid(7));
+    }
+
+    if let Some(duration) = time_scale.duration_nanos() {
+        return Ok(unix_nanos.div_euclid(duration));
+    }
+
+    match time_scale.months() {
+        Some(months) => {
+            let (year, month, _) = civil_from_days(unix_nanos.div_euclid(NANOS_PER_DAY));
+            Ok(((year - 1970) * 12 + month as i64 - 1).div_euclid(months))
+        }
+        None => Err(TimeError(format!("Time scale {} has no units", time_scale))),
+    }
+}
+
+/// Returns the UTC timestamp in nanoseconds at which the unit of the time scale starts.
+/// Inverse of units_since_epoch.
+/// Returns TimeError for NoScale or if the timestamp overflows.
+pub fn unit_start(units: i64, time_scale: TimeScale) -> Result<i64, TimeError> {
+    let overflow = || TimeError(format!("Unit {} of {} overflows", units, time_scale));
+
+    if time_scale == TimeScale::Week {
+        return units
+            .checked_mul(7)
+            .and_then(|days| days.checked_sub(EPOCH_WEEKDAY))
+            .and_then(|days| days.checked_mul(NANOS_PER_DAY))
+            .ok_or_else(overflow);
+    }
+
+    if let Some(duration) = time_scale.duration_nanos() {
+        return units.checked_mul(duration).ok_or_else(overflow);
+    }
+
+    match time_scale.months() {
+        Some(months) => {
+            let months = units.checked_mul(months).ok_or_else(overflow)?;
+            let year = months
+                .div_euclid(12)
+                .checked_add(1970)
+                .ok_or_else(overflow)?;
+            let month = months.rem_euclid(12) as u32 + 1;
+            days_from_civil(year, month, 1)
+                .and_then(|days| days.checked_mul(NANOS_PER_DAY))
+                .ok_or_else(overflow)
+        }
+        None => Err(TimeError(format!("Time scale {} has no units", time_scale))),
+    }
+}
+
+/// Returns the start of the calendar bucket of the time scale that contains
+/// the UTC timestamp, i.e. the Monday of the week or the first day of the quarter.
+/// Timestamps are in nanoseconds since the Unix epoch.
+pub fn bucket_start(unix_nanos: i64, time_scale: TimeScale) -> Result<i64, TimeError> {
+    unit_start(units_since_epoch(unix_nanos, time_scale)?, time_scale)
+}
+
+/// Returns the start of the bucket following the one that contains the UTC timestamp,
+/// which is the exclusive end of the bucket.
+/// Returns TimeError for NoScale or if the end overflows.
+pub fn bucket_end(unix_nanos: i64, time_scale: TimeScale) -> Result<i64, TimeError> {
+    let units = units_since_epoch(unix_nanos, time_scale)?;
+    let next = units
+        .checked_add(1)
+        .ok_or_else(|| TimeError(format!("Unit {} of {} overflows", units, time_scale)))?;
+
+    unit_start(next, time_scale)
+}
@@ -6,6 +6,10 @@ mod action_error_tests;
 #[cfg(test)]
 mod adjustment_error_tests;
 #[cfg(test)]
+mod assumption_error_tests;
+#[cfg(test)]
+mod audit_error_tests;
+#[cfg(test)]
 mod build_error_tests;
 #[cfg(test)]
 mod causal_graph_index_error_tests;
@@ -16,4 +20,16 @@ mod causality_graph_error_tests;
 #[cfg(test)]
 mod context_index_error_tests;
 #[cfg(test)]
+mod definition_error_tests;
+#[cfg(test)]
+mod model_error_tests;
+#[cfg(test)]
+mod registry_error_tests;
+#[cfg(test)]
+mod serialization_error_tests;
+#[cfg(test)]
+mod time_error_tests;
+#[cfg(test)]
+mod uncertainty_error_tests;
+#[cfg(test)]
 mod update_error_tests;
@@ -220,3 +220,16 @@ fn test_is_empty() {
     let col = get_test_assumption_vec();
     assert!(!col.is_empty());
 }
+
+#[test]
+fn test_assumption_confidence() {
+    let col = get_test_assumption_vec();
+    assert_eq!(col.assumption_confidence(), 0.0);
+
+    let data = get_test_num_array();
+    col.verify_all_assumptions(&data);
+    assert_eq!(col.assumption_confidence(), 1.0);
+
+    let empty: Vec<Assumption> = Vec::new();
+    assert_eq!(empty.assumption_confidence(), 0.0);
+}
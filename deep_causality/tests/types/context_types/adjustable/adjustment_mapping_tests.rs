@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use dcl_data_structures::prelude::PointIndex;
+use deep_causality::prelude::{
+    Adjustable, AdjustableData, AdjustableSpace, AdjustmentField, AdjustmentMapping,
+};
+
+use crate::types::context_types::adjustable::utils;
+
+#[test]
+fn test_default_mappings() {
+    let mapping = AdjustmentMapping::new();
+    assert!(mapping.get(AdjustmentField::Data).is_none());
+
+    let mapping = AdjustmentMapping::data();
+    assert_eq!(mapping.get(AdjustmentField::Data).unwrap().x, 0);
+    assert!(mapping.get(AdjustmentField::Time).is_none());
+
+    let mapping = AdjustmentMapping::space();
+    assert_eq!(mapping.get(AdjustmentField::Z).unwrap().z, 2);
+    assert!(mapping.get(AdjustmentField::Data).is_none());
+
+    let mapping = AdjustmentMapping::space_time();
+    assert_eq!(mapping.get(AdjustmentField::Time).unwrap().t, 3);
+}
+
+#[test]
+fn test_adjust_with_custom_mapping() {
+    let mut d = AdjustableSpace::new(0, 1, 2, 3);
+
+    // Reversed order of the coordinates in the grid.
+    let mapping = AdjustmentMapping::new()
+        .with(AdjustmentField::X, PointIndex::new3d(0, 0, 2))
+        .with(AdjustmentField::Y, PointIndex::new3d(0, 0, 1))
+        .with(AdjustmentField::Z, PointIndex::new3d(0, 0, 0));
+
+    let array_grid = utils::get_3d_array_grid(10, 20, 30);
+    let res = d.adjust_with_mapping(&array_grid, &mapping);
+    assert!(res.is_ok());
+
+    assert_eq!(d.x(), &31);
+    assert_eq!(d.y(), &22);
+    assert_eq!(d.z(), &13);
+}
+
+#[test]
+fn test_adjust_with_missing_field() {
+    let mut d = AdjustableData::new(0, 1);
+
+    let array_grid = utils::get_1d_array_grid(42);
+    let res = d.adjust_with_mapping(&array_grid, &AdjustmentMapping::space());
+    assert!(res.is_err());
+
+    let res = d.update_with_mapping(&array_grid, &AdjustmentMapping::time());
+    assert!(res.is_err());
+    assert_eq!(d.data(), &1);
+
+    let res = d.update_with_mapping(&array_grid, &AdjustmentMapping::data());
+    assert!(res.is_ok());
+    assert_eq!(d.data(), &42);
+}
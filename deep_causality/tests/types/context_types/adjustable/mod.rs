@@ -10,4 +10,6 @@ mod adjustable_space_tests;
 mod adjustable_space_time_tests;
 #[cfg(test)]
 mod adjustable_time_tests;
-mod utils;
+#[cfg(test)]
+mod adjustment_mapping_tests;
+pub(crate) mod utils;
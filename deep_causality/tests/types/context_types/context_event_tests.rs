@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::{ContextEvent, ContextEventKind};
+
+#[test]
+fn test_context_event_kind() {
+    assert_eq!(ContextEventKind::Added.to_string(), format!("Added"));
+    assert_eq!(ContextEventKind::Removed.to_string(), format!("Removed"));
+    assert_eq!(ContextEventKind::Adjusted.to_string(), format!("Adjusted"));
+}
+
+#[test]
+fn test_context_event() {
+    let event = ContextEvent::new(ContextEventKind::Added, 3, Some(1), 7, 42);
+    assert_eq!(*event.kind(), ContextEventKind::Added);
+    assert_eq!(*event.version(), 3);
+    assert_eq!(*event.extra_context_id(), Some(1));
+    assert_eq!(*event.index(), 7);
+    assert_eq!(*event.contextoid_id(), 42);
+
+    let expected =
+        "ContextEvent: kind: Added version: 3 extra context: Some(1) index: 7 contextoid: 42";
+    assert_eq!(event.to_string(), expected);
+}
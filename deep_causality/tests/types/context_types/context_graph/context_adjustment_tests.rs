@@ -0,0 +1,270 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use dcl_data_structures::prelude::PointIndex;
+use deep_causality::prelude::*;
+
+use crate::types::context_types::adjustable::utils;
+
+type AdjustableContext = Context<
+    AdjustableData<i32>,
+    AdjustableSpace<i32>,
+    AdjustableTime<i32>,
+    AdjustableSpaceTime<i32>,
+    i32,
+>;
+
+type AdjustableContextoid = Contextoid<
+    AdjustableData<i32>,
+    AdjustableSpace<i32>,
+    AdjustableTime<i32>,
+    AdjustableSpaceTime<i32>,
+    i32,
+>;
+
+fn get_data(context: &AdjustableContext, index: usize) -> i32 {
+    match context.get_node(index).unwrap().vertex_type() {
+        ContextoidType::Datoid(d) => *d.data(),
+        _ => panic!("not a datoid"),
+    }
+}
+
+fn get_time(context: &AdjustableContext, index: usize) -> i32 {
+    match context.get_node(index).unwrap().vertex_type() {
+        ContextoidType::Tempoid(t) => *t.time_unit(),
+        _ => panic!("not a tempoid"),
+    }
+}
+
+// Adds a datoid, a tempoid, a spaceoid, and a root.
+fn get_context() -> (AdjustableContext, Vec<usize>) {
+    let mut context = Context::with_capacity(1, "adjustable context", 10);
+
+    let nodes: Vec<AdjustableContextoid> = vec![
+        Contextoid::new(1, ContextoidType::Datoid(AdjustableData::new(1, 10))),
+        Contextoid::new(
+            2,
+            ContextoidType::Tempoid(AdjustableTime::new(2, TimeScale::Minute, 5)),
+        ),
+        Contextoid::new(
+            3,
+            ContextoidType::Spaceoid(AdjustableSpace::new(3, 1, 2, 3)),
+        ),
+        Contextoid::new(4, ContextoidType::Root(Root::new(4))),
+    ];
+
+    let idx = nodes.into_iter().map(|n| context.add_node(n)).collect();
+    (context, idx)
+}
+
+#[test]
+fn test_adjust_node() {
+    let (mut context, idx) = get_context();
+    let version = context.version();
+
+    let array_grid = utils::get_1d_array_grid(32);
+    let res = context.adjust_node(idx[0], &array_grid, &AdjustmentMapping::data());
+    assert!(res.is_ok());
+    assert_eq!(get_data(&context, idx[0]), 42);
+    assert!(context.version() > version);
+    assert_eq!(context.adjustment_log_len(), 1);
+
+    let res = context.adjust_node(idx[1], &array_grid, &AdjustmentMapping::time());
+    assert!(res.is_ok());
+    assert_eq!(get_time(&context, idx[1]), 37);
+    assert_eq!(context.adjustment_log_len(), 2);
+}
+
+#[test]
+fn test_adjust_node_with_custom_mapping() {
+    let (mut context, idx) = get_context();
+
+    let array_grid = utils::get_3d_array_grid(10, 20, 30);
+    let mapping = AdjustmentMapping::new()
+        .with(AdjustmentField::X, PointIndex::new3d(0, 0, 2))
+        .with(AdjustmentField::Y, PointIndex::new3d(0, 0, 1))
+        .with(AdjustmentField::Z, PointIndex::new3d(0, 0, 0));
+
+    let res = context.adjust_node(idx[2], &array_grid, &mapping);
+    assert!(res.is_ok());
+
+    match context.get_node(idx[2]).unwrap().vertex_type() {
+        ContextoidType::Spaceoid(s) => {
+            assert_eq!((*s.x(), *s.y(), *s.z()), (31, 22, 13));
+        }
+        _ => panic!("not a spaceoid"),
+    }
+}
+
+#[test]
+fn test_adjust_node_err() {
+    let (mut context, idx) = get_context();
+    let array_grid = utils::get_1d_array_grid(-20);
+
+    // Adjustment would result in negative data
+    let res = context.adjust_node(idx[0], &array_grid, &AdjustmentMapping::data());
+    assert!(res.is_err());
+    assert_eq!(get_data(&context, idx[0]), 10);
+
+    // Root is not adjustable
+    let res = context.adjust_node(idx[3], &array_grid, &AdjustmentMapping::data());
+    assert!(res.is_err());
+
+    // Node not found
+    let res = context.adjust_node(99, &array_grid, &AdjustmentMapping::data());
+    assert!(res.is_err());
+
+    // Field not mapped
+    let res = context.adjust_node(idx[0], &array_grid, &AdjustmentMapping::time());
+    assert!(res.is_err());
+
+    // Position out of bounds
+    let mapping = AdjustmentMapping::new().with(AdjustmentField::Data, PointIndex::new1d(99));
+    let res = context.adjust_node(idx[0], &array_grid, &mapping);
+    assert!(res.is_err());
+
+    assert_eq!(context.adjustment_log_len(), 0);
+}
+
+#[test]
+fn test_adjust_nodes() {
+    let (mut context, idx) = get_context();
+    let array_grid = utils::get_1d_array_grid(5);
+
+    let version = context.version();
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&events);
+    context.subscribe(move |event| {
+        recorded.lock().unwrap().push(*event);
+    });
+
+    let mapping = AdjustmentMapping::data().with(AdjustmentField::Time, PointIndex::new1d(0));
+    let res = context.adjust_nodes(&[(idx[0], &array_grid), (idx[1], &array_grid)], &mapping);
+    assert!(res.is_ok());
+
+    assert_eq!(get_data(&context, idx[0]), 15);
+    assert_eq!(get_time(&context, idx[1]), 10);
+    assert_eq!(context.adjustment_log_len(), 1);
+
+    // The whole batch shares one version increase and notifies once per node.
+    assert_eq!(context.version(), version + 1);
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 2);
+    assert!(events
+        .iter()
+        .all(|e| *e.kind() == ContextEventKind::Adjusted));
+    assert!(events.iter().all(|e| *e.version() == version + 1));
+    assert_eq!(*events[0].index(), idx[0]);
+    assert_eq!(*events[1].index(), idx[1]);
+}
+
+#[test]
+fn test_adjust_nodes_rollback() {
+    let (mut context, idx) = get_context();
+    let add = utils::get_1d_array_grid(5);
+    let subtract = utils::get_1d_array_grid(-12);
+    let version = context.version();
+
+    let events = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&events);
+    context.subscribe(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+
+    // The last adjustment fails because the time of the tempoid would become negative.
+    let mapping = AdjustmentMapping::data().with(AdjustmentField::Time, PointIndex::new1d(0));
+    let res = context.adjust_nodes(
+        &[
+            (idx[0], &add),
+            (idx[0], &add),
+            (idx[1], &add),
+            (idx[1], &subtract),
+        ],
+        &mapping,
+    );
+    assert!(res.is_err());
+
+    // All nodes are back to their state before the batch.
+    assert_eq!(get_data(&context, idx[0]), 10);
+    assert_eq!(get_time(&context, idx[1]), 5);
+    assert_eq!(context.adjustment_log_len(), 0);
+
+    // Nothing was swapped in, so the version is unchanged and no subscriber was notified.
+    assert_eq!(context.version(), version);
+    assert_eq!(events.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_adjust_nodes_out_of_bounds() {
+    let (mut context, idx) = get_context();
+    let add = utils::get_1d_array_grid(5);
+    let version = context.version();
+
+    let events = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&events);
+    context.subscribe(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+
+    // The time position lies outside the 1D grid of height 5.
+    let mapping =
+        AdjustmentMapping::data().with(AdjustmentField::Time, PointIndex::new1d(utils::HEIGHT));
+    let res = context.adjust_nodes(&[(idx[0], &add), (idx[1], &add)], &mapping);
+
+    let err = res.unwrap_err();
+    assert!(err.to_string().contains("out of bounds"));
+
+    // The batch is rejected before any node is adjusted.
+    assert_eq!(get_data(&context, idx[0]), 10);
+    assert_eq!(get_time(&context, idx[1]), 5);
+    assert_eq!(context.version(), version);
+    assert_eq!(context.adjustment_log_len(), 0);
+    assert_eq!(events.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_undo_adjustment() {
+    let (mut context, idx) = get_context();
+    let array_grid = utils::get_1d_array_grid(5);
+
+    assert!(context.undo_adjustment().is_err());
+
+    context
+        .adjust_node(idx[0], &array_grid, &AdjustmentMapping::data())
+        .expect("Failed to adjust node");
+    context
+        .adjust_nodes(
+            &[(idx[0], &array_grid), (idx[0], &array_grid)],
+            &AdjustmentMapping::data(),
+        )
+        .expect("Failed to adjust nodes");
+    assert_eq!(get_data(&context, idx[0]), 25);
+
+    let version = context.version();
+    assert!(context.undo_adjustment().is_ok());
+    assert_eq!(get_data(&context, idx[0]), 15);
+    assert_eq!(context.version(), version + 1);
+
+    assert!(context.undo_adjustment().is_ok());
+    assert_eq!(get_data(&context, idx[0]), 10);
+    assert_eq!(context.adjustment_log_len(), 0);
+
+    // Removed nodes are skipped
+    context
+        .adjust_node(idx[0], &array_grid, &AdjustmentMapping::data())
+        .expect("Failed to adjust node");
+    context.remove_node(idx[0]).expect("Failed to remove node");
+    assert!(context.undo_adjustment().is_ok());
+    assert!(!context.contains_node(idx[0]));
+
+    context
+        .adjust_node(idx[1], &array_grid, &AdjustmentMapping::time())
+        .expect("Failed to adjust node");
+    context.clear_adjustment_log();
+    assert!(context.undo_adjustment().is_err());
+    assert_eq!(get_time(&context, idx[1]), 10);
+}
@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+// Base context: root -Temporal-> tempoid; extra context "weather": datoid -Datial-> datoid,
+// linked from the base tempoid.
+fn get_test_context() -> BaseContext {
+    let mut context = Context::with_capacity(1, "Sensors", 10);
+    let root = context.add_node(Contextoid::new(1, ContextoidType::Root(Root::new(1))));
+    let time = context.add_node(Contextoid::new(
+        2,
+        ContextoidType::Tempoid(Time::new(2, TimeScale::Minute, 10)),
+    ));
+    context
+        .add_edge(root, time, RelationKind::Temporal)
+        .unwrap();
+
+    let weather = context.extra_ctx_add_named("weather", 10).unwrap();
+    let mut extra = context.extra_ctx_mut(weather).unwrap();
+    let a = extra
+        .add_node(Contextoid::new(3, ContextoidType::Datoid(Data::new(3, 20))))
+        .unwrap();
+    let b = extra
+        .add_node(Contextoid::new(4, ContextoidType::Datoid(Data::new(4, 21))))
+        .unwrap();
+    extra.add_edge(a, b, RelationKind::Datial).unwrap();
+
+    context
+        .add_context_link(
+            ContextNodeRef::base(time),
+            ContextNodeRef::extra(weather, a),
+            RelationKind::Temporal,
+        )
+        .unwrap();
+
+    context
+}
+
+#[test]
+fn test_to_dot() {
+    let context = get_test_context();
+    let expected = r#"digraph context_1 {
+    label="Sensors";
+    node [shape=ellipse];
+    b0 [label="Root\nid: 1"];
+    b1 [label="Tempoid\nid: 2"];
+    b0 -> b1 [label="Temporal"];
+    subgraph cluster_1 {
+        label="Extra context 1: weather";
+        x1_0 [label="Datoid\nid: 3"];
+        x1_1 [label="Datoid\nid: 4"];
+        x1_0 -> x1_1 [label="Datial"];
+    }
+    b1 -> x1_0 [label="Temporal", style=dashed];
+}
+"#;
+    assert_eq!(context.to_dot(&GraphExportOptions::default()), expected);
+}
+
+#[test]
+fn test_to_mermaid() {
+    let context = get_test_context();
+    let expected = r#"flowchart TD
+    b0(["Root<br/>id: 1"])
+    b1(["Tempoid<br/>id: 2"])
+    b0 -->|Temporal| b1
+    subgraph x1["Extra context 1: weather"]
+        x1_0(["Datoid<br/>id: 3"])
+        x1_1(["Datoid<br/>id: 4"])
+        x1_0 -->|Datial| x1_1
+    end
+    b1 -.->|Temporal| x1_0
+"#;
+    assert_eq!(context.to_mermaid(&GraphExportOptions::default()), expected);
+}
+
+#[test]
+fn test_highlighting_is_ignored() {
+    let context = get_test_context();
+    let options = GraphExportOptions::new(true);
+    assert_eq!(
+        context.to_dot(&options),
+        context.to_dot(&GraphExportOptions::default())
+    );
+    assert_eq!(
+        context.to_mermaid(&options),
+        context.to_mermaid(&GraphExportOptions::default())
+    );
+}
+
+#[test]
+fn test_unnamed_extra_context() {
+    let mut context: BaseContext = Context::with_capacity(2, "\"quoted\"", 10);
+    context.extra_ctx_add_new(10, true);
+    context
+        .extra_ctx_add_node(Contextoid::new(1, ContextoidType::Root(Root::new(1))))
+        .unwrap();
+
+    let dot = context.to_dot(&GraphExportOptions::default());
+    assert!(dot.contains("label=\"\\\"quoted\\\"\";"));
+    assert!(dot.contains("label=\"Extra context 1\";"));
+    assert!(dot.contains("x1_0 [label=\"Root\\nid: 1\"];"));
+
+    let mermaid = context.to_mermaid(&GraphExportOptions::default());
+    assert!(mermaid.contains("subgraph x1[\"Extra context 1\"]"));
+}
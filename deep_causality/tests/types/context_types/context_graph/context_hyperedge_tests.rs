@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+fn get_context() -> BaseContext {
+    Context::with_capacity(1, "hyper context", 10)
+}
+
+fn get_attributes(name: &str) -> HyperedgeAttributes {
+    HyperedgeAttributes::from([("event".to_string(), name.to_string())])
+}
+
+// An event relating a location, a time window and three data points.
+fn get_populated_context() -> (BaseContext, Vec<usize>) {
+    let mut context = get_context();
+    let nodes = vec![
+        context.add_node(Contextoid::new(
+            1,
+            ContextoidType::Spaceoid(Space::new(1, 1, 2, 3)),
+        )),
+        context.add_node(Contextoid::new(
+            2,
+            ContextoidType::Tempoid(Time::new(2, TimeScale::Hour, 9)),
+        )),
+        context.add_node(Contextoid::new(3, ContextoidType::Datoid(Data::new(3, 10)))),
+        context.add_node(Contextoid::new(4, ContextoidType::Datoid(Data::new(4, 20)))),
+        context.add_node(Contextoid::new(5, ContextoidType::Datoid(Data::new(5, 30)))),
+    ];
+    (context, nodes)
+}
+
+#[test]
+fn test_add_hyperedge() {
+    let (mut context, n) = get_populated_context();
+    let version = context.version();
+
+    let e = context
+        .add_hyperedge(&n, RelationKind::SpaceTemporal, get_attributes("storm"))
+        .unwrap();
+    assert!(context.contains_hyperedge(e));
+    assert_eq!(context.hyperedge_count(), 1);
+    assert_eq!(context.version(), version + 1);
+
+    let edge = context.get_hyperedge(e).unwrap();
+    assert_eq!(edge.index(), e);
+    assert_eq!(edge.nodes(), n.as_slice());
+    assert_eq!(edge.relation_kind(), RelationKind::SpaceTemporal);
+    assert_eq!(edge.attribute("event"), Some("storm"));
+    assert_eq!(edge.attribute("unknown"), None);
+    assert_eq!(edge.attributes().len(), 1);
+
+    // Pairwise edges are not affected
+    assert_eq!(context.edge_count(), 0);
+}
+
+#[test]
+fn test_add_hyperedge_error() {
+    let (mut context, n) = get_populated_context();
+
+    let res = context.add_hyperedge(&[n[0]], RelationKind::Datial, HyperedgeAttributes::new());
+    assert!(res.is_err());
+
+    let res = context.add_hyperedge(
+        &[n[0], 99],
+        RelationKind::Datial,
+        HyperedgeAttributes::new(),
+    );
+    assert!(res.is_err());
+
+    assert_eq!(context.hyperedge_count(), 0);
+    assert!(context.get_hyperedge(0).is_none());
+}
+
+#[test]
+fn test_get_hyperedges_of_node() {
+    let (mut context, n) = get_populated_context();
+
+    let a = context
+        .add_hyperedge(&n, RelationKind::SpaceTemporal, get_attributes("storm"))
+        .unwrap();
+    let b = context
+        .add_hyperedge(&[n[2], n[3]], RelationKind::Datial, get_attributes("trade"))
+        .unwrap();
+
+    // Queryable from any side
+    assert_eq!(context.get_hyperedges_of_node(n[0], None).unwrap(), vec![a]);
+    assert_eq!(context.get_hyperedges_of_node(n[4], None).unwrap(), vec![a]);
+    assert_eq!(
+        context.get_hyperedges_of_node(n[3], None).unwrap(),
+        vec![a, b]
+    );
+    assert_eq!(
+        context
+            .get_hyperedges_of_node(n[3], Some(RelationKind::Datial))
+            .unwrap(),
+        vec![b]
+    );
+    assert!(context.get_hyperedges_of_node(99, None).is_err());
+
+    let neighbors = context.get_hyperedge_neighbors(n[3], None).unwrap();
+    assert_eq!(neighbors, vec![n[0], n[1], n[2], n[4]]);
+
+    let neighbors = context
+        .get_hyperedge_neighbors(n[3], Some(RelationKind::Datial))
+        .unwrap();
+    assert_eq!(neighbors, vec![n[2]]);
+}
+
+#[test]
+fn test_remove_hyperedge() {
+    let (mut context, n) = get_populated_context();
+
+    let a = context
+        .add_hyperedge(&n, RelationKind::SpaceTemporal, get_attributes("storm"))
+        .unwrap();
+    context.remove_hyperedge(a).unwrap();
+    assert!(!context.contains_hyperedge(a));
+    assert!(context.get_hyperedge(a).is_none());
+    assert!(context.remove_hyperedge(a).is_err());
+}
+
+#[test]
+fn test_remove_node_removes_hyperedges() {
+    let (mut context, n) = get_populated_context();
+
+    let a = context
+        .add_hyperedge(&n, RelationKind::SpaceTemporal, get_attributes("storm"))
+        .unwrap();
+    let b = context
+        .add_hyperedge(
+            &[n[0], n[1]],
+            RelationKind::SpaceTemporal,
+            get_attributes("rain"),
+        )
+        .unwrap();
+
+    context.remove_node(n[4]).unwrap();
+    assert!(!context.contains_hyperedge(a));
+    assert!(context.contains_hyperedge(b));
+    assert_eq!(context.hyperedge_count(), 1);
+}
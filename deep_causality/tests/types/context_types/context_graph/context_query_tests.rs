@@ -0,0 +1,275 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+fn get_context() -> BaseContext {
+    Context::with_capacity(1, "query context", 10)
+}
+
+// Root -> Time(2) -Temporal-> Time(3)
+// Root -> Space(4)
+// Root -> Data(5)
+// Time(2) -SpaceTemporal-> SpaceTime(6)
+fn build_graph<G>(add_node: &mut G) -> Vec<usize>
+where
+    G: FnMut(BaseContextoid) -> usize,
+{
+    let nodes = [
+        Contextoid::new(1, ContextoidType::Root(Root::new(1))),
+        Contextoid::new(
+            2,
+            ContextoidType::Tempoid(Time::new(2, TimeScale::Year, 2023)),
+        ),
+        Contextoid::new(
+            3,
+            ContextoidType::Tempoid(Time::new(3, TimeScale::Month, 1)),
+        ),
+        Contextoid::new(4, ContextoidType::Spaceoid(Space::new(4, 1, 2, 3))),
+        Contextoid::new(5, ContextoidType::Datoid(Data::new(5, 42))),
+        Contextoid::new(
+            6,
+            ContextoidType::SpaceTempoid(SpaceTime::new(6, TimeScale::Day, 7, 1, 2, 3)),
+        ),
+    ];
+
+    nodes.into_iter().map(add_node).collect()
+}
+
+fn get_populated_context() -> (BaseContext, Vec<usize>) {
+    let mut context = get_context();
+    let idx = build_graph(&mut |c| context.add_node(c));
+
+    context
+        .add_edge(idx[0], idx[1], RelationKind::Temporal)
+        .expect("Failed to add edge");
+    context
+        .add_edge(idx[1], idx[2], RelationKind::Temporal)
+        .expect("Failed to add edge");
+    context
+        .add_edge(idx[0], idx[3], RelationKind::Spatial)
+        .expect("Failed to add edge");
+    context
+        .add_edge(idx[0], idx[4], RelationKind::Datial)
+        .expect("Failed to add edge");
+    context
+        .add_edge(idx[1], idx[5], RelationKind::SpaceTemporal)
+        .expect("Failed to add edge");
+
+    (context, idx)
+}
+
+#[test]
+fn test_get_neighbors() {
+    let (context, idx) = get_populated_context();
+
+    let all = context.get_neighbors(idx[0], None).unwrap();
+    assert_eq!(all, vec![idx[1], idx[3], idx[4]]);
+
+    let temporal = context
+        .get_neighbors(idx[0], Some(RelationKind::Temporal))
+        .unwrap();
+    assert_eq!(temporal, vec![idx[1]]);
+
+    let space_temporal = context
+        .get_neighbors(idx[1], Some(RelationKind::SpaceTemporal))
+        .unwrap();
+    assert_eq!(space_temporal, vec![idx[5]]);
+
+    let none = context
+        .get_neighbors(idx[4], Some(RelationKind::Datial))
+        .unwrap();
+    assert!(none.is_empty());
+
+    assert!(context.get_neighbors(99, None).is_err());
+}
+
+#[test]
+fn test_get_edge_relation() {
+    let (context, idx) = get_populated_context();
+
+    assert_eq!(
+        context.get_edge_relation(idx[0], idx[3]),
+        Some(RelationKind::Spatial)
+    );
+    assert_eq!(
+        context.get_edge_relation(idx[0], idx[4]),
+        Some(RelationKind::Datial)
+    );
+    assert_eq!(context.get_edge_relation(idx[3], idx[0]), None);
+}
+
+#[test]
+fn test_get_edge() {
+    let (mut context, idx) = get_populated_context();
+    let version = context.version();
+
+    let edge = context.get_edge(idx[0], idx[3]).unwrap();
+    assert_eq!(edge.relation_kind(), RelationKind::Spatial);
+    assert!(edge.attributes().is_empty());
+    assert!(context.get_edge(idx[3], idx[0]).is_none());
+
+    let attributes = EdgeAttributes::from([("source".to_string(), "gps".to_string())]);
+    context
+        .add_edge_with_attributes(idx[3], idx[4], RelationKind::Spatial, attributes)
+        .unwrap();
+    assert_eq!(context.version(), version + 1);
+
+    let edge = context.get_edge(idx[3], idx[4]).unwrap();
+    assert_eq!(edge.relation_kind(), RelationKind::Spatial);
+    assert_eq!(edge.attribute("source"), Some("gps"));
+    assert_eq!(edge.attribute("unknown"), None);
+    assert_eq!(
+        context.get_edge_relation(idx[3], idx[4]),
+        Some(RelationKind::Spatial)
+    );
+    assert_eq!(
+        context
+            .get_neighbors(idx[3], Some(RelationKind::Spatial))
+            .unwrap(),
+        vec![idx[4]]
+    );
+
+    // Edges are unique and need existing nodes
+    assert!(context
+        .add_edge_with_attributes(idx[3], idx[4], RelationKind::Datial, EdgeAttributes::new())
+        .is_err());
+    assert!(context
+        .add_edge_with_attributes(idx[3], 99, RelationKind::Datial, EdgeAttributes::new())
+        .is_err());
+    assert_eq!(context.version(), version + 1);
+
+    // Removing an edge removes its attributes
+    context.remove_edge(idx[3], idx[4]).unwrap();
+    context
+        .add_edge(idx[3], idx[4], RelationKind::Datial)
+        .unwrap();
+    assert!(context
+        .get_edge(idx[3], idx[4])
+        .unwrap()
+        .attributes()
+        .is_empty());
+}
+
+#[test]
+fn test_extra_context_get_edge() {
+    let mut context = get_context();
+    let handle = context.extra_ctx_add_named("weather", 10).unwrap();
+
+    let mut ctx = context.extra_ctx_mut(handle).unwrap();
+    let a = ctx
+        .add_node(Contextoid::new(1, ContextoidType::Datoid(Data::new(1, 1))))
+        .unwrap();
+    let b = ctx
+        .add_node(Contextoid::new(2, ContextoidType::Datoid(Data::new(2, 2))))
+        .unwrap();
+    let attributes = EdgeAttributes::from([("unit".to_string(), "mm".to_string())]);
+    ctx.add_edge_with_attributes(a, b, RelationKind::Datial, attributes)
+        .unwrap();
+
+    let view = context.extra_ctx(handle).unwrap();
+    let edge = view.get_edge(a, b).unwrap();
+    assert_eq!(edge.relation_kind(), RelationKind::Datial);
+    assert_eq!(edge.attribute("unit"), Some("mm"));
+    assert!(view.get_edge(b, a).is_none());
+}
+
+#[test]
+fn test_get_node_by_id() {
+    let (context, idx) = get_populated_context();
+
+    assert_eq!(context.get_node_index_by_id(4), Some(idx[3]));
+    assert_eq!(context.get_node_index_by_id(99), None);
+
+    let node = context.get_node_by_id(5).expect("Failed to find node");
+    assert_eq!(node.id(), 5);
+    assert!(context.get_node_by_id(99).is_none());
+}
+
+#[test]
+fn test_get_all_by_type() {
+    let (context, idx) = get_populated_context();
+
+    let tempoids = context.get_all_tempoids();
+    assert_eq!(tempoids.len(), 2);
+    assert_eq!(tempoids[0].0, idx[1]);
+    assert_eq!(*tempoids[0].1.time_unit(), 2023);
+    assert_eq!(tempoids[1].1.time_scale(), TimeScale::Month);
+
+    let spaceoids = context.get_all_spaceoids();
+    assert_eq!(spaceoids.len(), 1);
+    assert_eq!(*spaceoids[0].1.x(), 1);
+
+    let datoids = context.get_all_datoids();
+    assert_eq!(datoids.len(), 1);
+    assert_eq!(datoids[0].0, idx[4]);
+
+    let space_tempoids = context.get_all_space_tempoids();
+    assert_eq!(space_tempoids.len(), 1);
+    assert_eq!(*space_tempoids[0].1.t(), 7);
+
+    assert!(get_context().get_all_tempoids().is_empty());
+}
+
+#[test]
+fn test_get_shortest_path() {
+    let (context, idx) = get_populated_context();
+
+    let path = context.get_shortest_path(idx[0], idx[5]).unwrap();
+    assert_eq!(path, vec![idx[0], idx[1], idx[5]]);
+
+    let path = context.get_shortest_path(idx[0], idx[0]).unwrap();
+    assert_eq!(path, vec![idx[0]]);
+
+    // Edges are directed
+    assert!(context.get_shortest_path(idx[5], idx[0]).is_err());
+    assert!(context.get_shortest_path(idx[0], 99).is_err());
+    assert!(context.get_shortest_path(99, idx[0]).is_err());
+}
+
+#[test]
+fn test_extra_ctx_queries() {
+    let mut context = get_context();
+
+    // No extra context set
+    assert!(context.extra_ctx_get_all_tempoids().is_err());
+    assert!(context.extra_ctx_get_neighbors(0, None).is_err());
+
+    context.extra_ctx_add_new(10, true);
+    let idx = build_graph(&mut |c| context.extra_ctx_add_node(c).unwrap());
+
+    context
+        .extra_ctx_add_edge(idx[0], idx[1], RelationKind::Temporal)
+        .unwrap();
+    context
+        .extra_ctx_add_edge(idx[1], idx[5], RelationKind::SpaceTemporal)
+        .unwrap();
+    context
+        .extra_ctx_add_edge(idx[0], idx[3], RelationKind::Spatial)
+        .unwrap();
+
+    let temporal = context
+        .extra_ctx_get_neighbors(idx[0], Some(RelationKind::Temporal))
+        .unwrap();
+    assert_eq!(temporal, vec![idx[1]]);
+
+    assert_eq!(
+        context.extra_ctx_get_edge_relation(idx[0], idx[3]).unwrap(),
+        Some(RelationKind::Spatial)
+    );
+    assert_eq!(
+        context.extra_ctx_get_node_index_by_id(6).unwrap(),
+        Some(idx[5])
+    );
+
+    assert_eq!(context.extra_ctx_get_all_tempoids().unwrap().len(), 2);
+    assert_eq!(context.extra_ctx_get_all_spaceoids().unwrap().len(), 1);
+    assert_eq!(context.extra_ctx_get_all_datoids().unwrap().len(), 1);
+    assert_eq!(context.extra_ctx_get_all_space_tempoids().unwrap().len(), 1);
+
+    let path = context.extra_ctx_get_shortest_path(idx[0], idx[5]).unwrap();
+    assert_eq!(path, vec![idx[0], idx[1], idx[5]]);
+
+    // The base context is not affected
+    assert!(context.get_all_tempoids().is_empty());
+}
@@ -0,0 +1,240 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::sync::{Arc, Mutex};
+
+use deep_causality::prelude::*;
+
+fn get_context() -> BaseContext {
+    Context::with_capacity(1, "snapshot context", 10)
+}
+
+fn get_data_node(id: u64, data: u64) -> BaseContextoid {
+    Contextoid::new(id, ContextoidType::Datoid(Data::new(id, data)))
+}
+
+fn record_events(context: &mut BaseContext) -> (u64, Arc<Mutex<Vec<ContextEvent>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&events);
+    let id = context.subscribe(move |event| sink.lock().unwrap().push(*event));
+    (id, events)
+}
+
+#[test]
+fn test_version() {
+    let mut context = get_context();
+    assert_eq!(context.version(), 0);
+
+    let a = context.add_node(get_data_node(1, 1));
+    let b = context.add_node(get_data_node(2, 2));
+    assert_eq!(context.version(), 2);
+
+    context
+        .add_edge(a, b, RelationKind::Datial)
+        .expect("Failed to add edge");
+    assert_eq!(context.version(), 3);
+
+    context.remove_edge(a, b).expect("Failed to remove edge");
+    assert_eq!(context.version(), 4);
+
+    // Failed changes do not increase the version.
+    assert!(context.remove_node(99).is_err());
+    assert_eq!(context.version(), 4);
+}
+
+#[test]
+fn test_update_node() {
+    let mut context = get_context();
+    let a = context.add_node(get_data_node(1, 1));
+    let b = context.add_node(get_data_node(2, 2));
+    context
+        .add_edge(a, b, RelationKind::Datial)
+        .expect("Failed to add edge");
+
+    context
+        .update_node(a, get_data_node(3, 42))
+        .expect("Failed to update node");
+
+    let node = context.get_node(a).expect("Failed to get node");
+    assert_eq!(node.id(), 3);
+    assert!(context.contains_edge(a, b));
+
+    assert!(context.update_node(99, get_data_node(4, 0)).is_err());
+}
+
+#[test]
+fn test_update_node_keeps_indices_in_sync() {
+    let mut context = get_context();
+    context.enable_time_index();
+    context.enable_spatial_index(SpatialMetric::Euclidean, |v| v as f64);
+
+    let t = context.add_node(Contextoid::new(
+        1,
+        ContextoidType::Tempoid(Time::new(1, TimeScale::Day, 5)),
+    ));
+    context
+        .update_node(
+            t,
+            Contextoid::new(1, ContextoidType::Spaceoid(Space::new(1, 1, 1, 1))),
+        )
+        .expect("Failed to update node");
+
+    assert!(context
+        .get_tempoids_in_range(TimeScale::Day, 0, 10)
+        .unwrap()
+        .is_empty());
+    let nearest = context.get_nearest_spaceoids([0, 0, 0], 1).unwrap();
+    assert_eq!(nearest[0].0, t);
+}
+
+#[test]
+fn test_subscribe() {
+    let mut context = get_context();
+    let (_, events) = record_events(&mut context);
+    assert_eq!(context.number_of_subscribers(), 1);
+
+    let a = context.add_node(get_data_node(1, 1));
+    context
+        .update_node(a, get_data_node(1, 2))
+        .expect("Failed to update node");
+    context.remove_node(a).expect("Failed to remove node");
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 3);
+
+    assert_eq!(*events[0].kind(), ContextEventKind::Added);
+    assert_eq!(*events[1].kind(), ContextEventKind::Adjusted);
+    assert_eq!(*events[2].kind(), ContextEventKind::Removed);
+
+    assert!(events.iter().all(|e| *e.index() == a));
+    assert!(events.iter().all(|e| *e.contextoid_id() == 1));
+    assert!(events.iter().all(|e| e.extra_context_id().is_none()));
+    assert_eq!(*events[2].version(), 3);
+}
+
+#[test]
+fn test_subscribe_extra_context() {
+    let mut context = get_context();
+    let (_, events) = record_events(&mut context);
+
+    let ctx_id = context.extra_ctx_add_new(10, true);
+    let a = context
+        .extra_ctx_add_node(get_data_node(1, 1))
+        .expect("Failed to add node");
+    context
+        .extra_ctx_update_node(a, get_data_node(2, 2))
+        .expect("Failed to update node");
+    context
+        .extra_ctx_remove_node(a)
+        .expect("Failed to remove node");
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 3);
+    assert!(events.iter().all(|e| *e.extra_context_id() == Some(ctx_id)));
+    assert_eq!(*events[1].contextoid_id(), 2);
+    assert_eq!(*events[2].kind(), ContextEventKind::Removed);
+}
+
+#[test]
+fn test_unsubscribe() {
+    let mut context = get_context();
+    let (id, events) = record_events(&mut context);
+
+    context.add_node(get_data_node(1, 1));
+    assert!(context.unsubscribe(id));
+    assert!(!context.unsubscribe(id));
+    assert_eq!(context.number_of_subscribers(), 0);
+
+    context.add_node(get_data_node(2, 2));
+    assert_eq!(events.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn test_snapshot() {
+    let mut context = get_context();
+    let a = context.add_node(get_data_node(1, 1));
+    let b = context.add_node(get_data_node(2, 2));
+    context
+        .add_edge(a, b, RelationKind::Datial)
+        .expect("Failed to add edge");
+
+    let snapshot = context.snapshot();
+    assert_eq!(snapshot.id(), 1);
+    assert_eq!(snapshot.name(), "snapshot context");
+    assert_eq!(snapshot.version(), context.version());
+    assert_eq!(snapshot.node_count(), 2);
+    assert_eq!(snapshot.edge_count(), 1);
+    assert!(snapshot.contains_node(a));
+    assert!(snapshot.contains_edge(a, b));
+    assert_eq!(snapshot.get_edge_relation(a, b), Some(RelationKind::Datial));
+    assert_eq!(snapshot.get_neighbors(a, None).unwrap(), vec![b]);
+    assert_eq!(snapshot.get_node_index_by_id(2), Some(b));
+    assert_eq!(snapshot.get_node_by_id(2).map(|n| n.id()), Some(2));
+    assert_eq!(snapshot.get_shortest_path(a, b).unwrap(), vec![a, b]);
+
+    // Unchanged context shares the snapshot.
+    assert!(Arc::ptr_eq(&snapshot, &context.snapshot()));
+
+    // Changes do not affect existing snapshots.
+    context.remove_node(b).expect("Failed to remove node");
+    context.add_node(get_data_node(3, 3));
+
+    assert_eq!(snapshot.node_count(), 2);
+    assert!(snapshot.get_node(b).is_some());
+
+    let latest = context.snapshot();
+    assert!(!Arc::ptr_eq(&snapshot, &latest));
+    assert_eq!(latest.version(), snapshot.version() + 2);
+    assert!(latest.get_node_by_id(2).is_none());
+    assert!(latest.get_node_by_id(3).is_some());
+}
+
+#[test]
+fn test_snapshot_copy_on_write() {
+    let mut context = get_context();
+    let a = context.add_node(get_data_node(1, 1));
+
+    let first = context.snapshot();
+    let b = context.add_node(get_data_node(2, 2));
+    context
+        .add_edge(a, b, RelationKind::Datial)
+        .expect("Failed to add edge");
+
+    let second = context.snapshot();
+    context
+        .update_node(a, get_data_node(1, 10))
+        .expect("Failed to update node");
+
+    // Dropped snapshots do not keep the context from changing in place.
+    drop(context.snapshot());
+    context.remove_edge(a, b).expect("Failed to remove edge");
+
+    assert_eq!(first.node_count(), 1);
+    assert_eq!(first.edge_count(), 0);
+    assert_eq!(second.node_count(), 2);
+    assert!(second.contains_edge(a, b));
+    assert_eq!(
+        second.get_node(a).unwrap().vertex_type(),
+        &ContextoidType::Datoid(Data::new(1, 1))
+    );
+
+    assert_eq!(context.node_count(), 2);
+    assert!(!context.contains_edge(a, b));
+    assert_eq!(
+        context.get_node(a).unwrap().vertex_type(),
+        &ContextoidType::Datoid(Data::new(1, 10))
+    );
+}
+
+#[test]
+fn test_snapshot_send_to_thread() {
+    let mut context = get_context();
+    context.add_node(get_data_node(1, 1));
+
+    let snapshot = context.snapshot();
+    let handle = std::thread::spawn(move || snapshot.node_count());
+    context.add_node(get_data_node(2, 2));
+
+    assert_eq!(handle.join().unwrap(), 1);
+    assert_eq!(context.node_count(), 2);
+}
@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+use deep_causality::utils::math_utils;
+
+fn to_f64(v: u64) -> f64 {
+    v as f64
+}
+
+fn get_space_node(id: u64, x: u64, y: u64, z: u64) -> BaseContextoid {
+    Contextoid::new(id, ContextoidType::Spaceoid(Space::new(id, x, y, z)))
+}
+
+// Points on a 5 x 5 grid in the z = 0 plane plus a data node.
+fn get_grid_context() -> (BaseContext, Vec<usize>) {
+    let mut context = Context::with_capacity(1, "space context", 30);
+    context.enable_spatial_index(SpatialMetric::Euclidean, to_f64);
+
+    let mut idx = Vec::new();
+    for x in 0..5 {
+        for y in 0..5 {
+            idx.push(context.add_node(get_space_node(x * 5 + y, x, y, 0)));
+        }
+    }
+    context.add_node(Contextoid::new(
+        100,
+        ContextoidType::Datoid(Data::new(100, 42)),
+    ));
+
+    (context, idx)
+}
+
+#[test]
+fn test_spatial_index_disabled() {
+    let context: BaseContext = Context::with_capacity(1, "space context", 10);
+    assert_eq!(context.spatial_metric(), None);
+    assert!(context.get_nearest_spaceoids([0, 0, 0], 1).is_err());
+    assert!(context.get_spaceoids_within_radius([0, 0, 0], 1.0).is_err());
+    assert!(context.get_spaceoids_in_bbox([0, 0, 0], [1, 1, 1]).is_err());
+}
+
+#[test]
+fn test_get_nearest_spaceoids() {
+    let (context, idx) = get_grid_context();
+    assert_eq!(context.spatial_metric(), Some(SpatialMetric::Euclidean));
+
+    let nearest = context.get_nearest_spaceoids([2, 2, 0], 1).unwrap();
+    assert_eq!(nearest, vec![(idx[12], 0.0)]);
+
+    let nearest = context.get_nearest_spaceoids([2, 2, 0], 5).unwrap();
+    assert_eq!(nearest.len(), 5);
+    assert_eq!(nearest[0].0, idx[12]);
+    assert!(nearest[1..].iter().all(|(_, d)| *d == 1.0));
+
+    let nearest = context.get_nearest_spaceoids([9, 9, 0], 1).unwrap();
+    assert_eq!(nearest[0].0, idx[24]);
+    assert_eq!(
+        nearest[0].1,
+        math_utils::euclidean_distance([9.0, 9.0, 0.0], [4.0, 4.0, 0.0])
+    );
+
+    assert!(context
+        .get_nearest_spaceoids([0, 0, 0], 0)
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        context.get_nearest_spaceoids([0, 0, 0], 100).unwrap().len(),
+        25
+    );
+}
+
+#[test]
+fn test_get_spaceoids_within_radius() {
+    let (context, idx) = get_grid_context();
+
+    let found = context.get_spaceoids_within_radius([0, 0, 0], 1.0).unwrap();
+    assert_eq!(found, vec![(idx[0], 0.0), (idx[1], 1.0), (idx[5], 1.0)]);
+
+    let found = context.get_spaceoids_within_radius([2, 2, 0], 1.5).unwrap();
+    assert_eq!(found.len(), 9);
+
+    let found = context.get_spaceoids_within_radius([2, 2, 9], 1.0).unwrap();
+    assert!(found.is_empty());
+}
+
+#[test]
+fn test_get_spaceoids_in_bbox() {
+    let (context, idx) = get_grid_context();
+
+    let found = context.get_spaceoids_in_bbox([1, 1, 0], [2, 3, 0]).unwrap();
+    assert_eq!(
+        found,
+        vec![idx[6], idx[7], idx[8], idx[11], idx[12], idx[13]]
+    );
+
+    let found = context.get_spaceoids_in_bbox([0, 0, 1], [4, 4, 1]).unwrap();
+    assert!(found.is_empty());
+}
+
+#[test]
+fn test_spatial_index_remove_node() {
+    let (mut context, idx) = get_grid_context();
+
+    context.remove_node(idx[12]).expect("Failed to remove node");
+    let nearest = context.get_nearest_spaceoids([2, 2, 0], 1).unwrap();
+    assert_eq!(nearest[0].1, 1.0);
+
+    // Removing most nodes triggers a rebuild of the index.
+    for i in idx[1..24].iter().filter(|i| **i != idx[12]) {
+        context.remove_node(*i).expect("Failed to remove node");
+    }
+    let nearest = context.get_nearest_spaceoids([0, 0, 0], 3).unwrap();
+    assert_eq!(
+        nearest.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+        vec![idx[0], idx[24]]
+    );
+    assert_eq!(
+        context.get_spaceoids_in_bbox([0, 0, 0], [4, 4, 0]).unwrap(),
+        vec![idx[0], idx[24]]
+    );
+}
+
+#[test]
+fn test_spatial_index_space_tempoid() {
+    let mut context: BaseContext = Context::with_capacity(1, "space context", 10);
+    let a = context.add_node(get_space_node(1, 10, 10, 10));
+    context.enable_spatial_index(SpatialMetric::Euclidean, to_f64);
+    let b = context.add_node(Contextoid::new(
+        2,
+        ContextoidType::SpaceTempoid(SpaceTime::new(2, TimeScale::Day, 1, 1, 1, 1)),
+    ));
+
+    let nearest = context.get_nearest_spaceoids([0, 0, 0], 2).unwrap();
+    assert_eq!(
+        nearest.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+        vec![b, a]
+    );
+}
+
+#[test]
+fn test_spatial_index_matches_linear_scan() {
+    let mut context: BaseContext = Context::with_capacity(1, "space context", 200);
+    context.enable_spatial_index(SpatialMetric::Euclidean, to_f64);
+
+    // Deterministic pseudo random points
+    let mut seed: u64 = 42;
+    let mut next = || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) % 100
+    };
+
+    let mut points = Vec::new();
+    for id in 0..150 {
+        let (x, y, z) = (next(), next(), next());
+        let index = context.add_node(get_space_node(id, x, y, z));
+        points.push((index, [x as f64, y as f64, z as f64]));
+    }
+
+    let target = [50, 50, 50];
+    let t = [50.0, 50.0, 50.0];
+
+    let mut expected: Vec<(usize, f64)> = points
+        .iter()
+        .map(|(i, p)| (*i, math_utils::euclidean_distance(*p, t)))
+        .collect();
+    expected.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap().then(a.0.cmp(&b.0)));
+
+    let nearest = context.get_nearest_spaceoids(target, 10).unwrap();
+    let distances: Vec<f64> = nearest.iter().map(|(_, d)| *d).collect();
+    let expected_distances: Vec<f64> = expected.iter().take(10).map(|(_, d)| *d).collect();
+    assert_eq!(distances, expected_distances);
+
+    let within = context.get_spaceoids_within_radius(target, 30.0).unwrap();
+    let expected_within: Vec<(usize, f64)> = expected
+        .iter()
+        .filter(|(_, d)| *d <= 30.0)
+        .copied()
+        .collect();
+    assert_eq!(within, expected_within);
+}
+
+#[test]
+fn test_spatial_index_geodesic() {
+    // Coordinates in micro degrees, offset by 180 degrees to stay unsigned.
+    fn to_degrees(v: u64) -> f64 {
+        v as f64 / 1e6 - 180.0
+    }
+    fn deg(d: f64) -> u64 {
+        ((d + 180.0) * 1e6).round() as u64
+    }
+
+    let mut context: BaseContext = Context::with_capacity(1, "geo context", 10);
+    context.enable_spatial_index(SpatialMetric::Geodesic, to_degrees);
+    assert_eq!(context.spatial_metric(), Some(SpatialMetric::Geodesic));
+
+    let london = context.add_node(get_space_node(1, deg(51.5074), deg(-0.1278), 0));
+    let paris = context.add_node(get_space_node(2, deg(48.8566), deg(2.3522), 0));
+    let berlin = context.add_node(get_space_node(3, deg(52.5200), deg(13.4050), 0));
+    let sydney = context.add_node(get_space_node(4, deg(-33.8688), deg(151.2093), 0));
+
+    let nearest = context
+        .get_nearest_spaceoids([deg(50.8503), deg(4.3517), 0], 2)
+        .unwrap();
+    assert_eq!(nearest[0].0, paris);
+    assert_eq!(nearest[1].0, london);
+
+    let d = nearest[0].1;
+    let expected = math_utils::geodesic_distance(50.8503, 4.3517, 48.8566, 2.3522);
+    assert!((d - expected).abs() < 1e-3);
+
+    let within = context
+        .get_spaceoids_within_radius([deg(51.5074), deg(-0.1278), 0], 1_000_000.0)
+        .unwrap();
+    assert_eq!(
+        within.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+        vec![london, paris, berlin]
+    );
+
+    let southern = context
+        .get_spaceoids_in_bbox([deg(-90.0), deg(-180.0), 0], [deg(0.0), deg(180.0), 0])
+        .unwrap();
+    assert_eq!(southern, vec![sydney]);
+}
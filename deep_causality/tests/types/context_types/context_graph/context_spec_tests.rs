@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+// A base context with a removed node, edges, and a hyperedge,
+// plus a named extra context linked to the base context.
+fn get_populated_context() -> BaseContext {
+    let mut context = Context::with_capacity(7, "populated", 10);
+
+    let space = context.add_node(Contextoid::new(
+        1,
+        ContextoidType::Spaceoid(Space::new(1, 1, 2, 3)),
+    ));
+    let removed = context.add_node(Contextoid::new(2, ContextoidType::Datoid(Data::new(2, 5))));
+    let time = context.add_node(Contextoid::new(
+        3,
+        ContextoidType::Tempoid(Time::new(3, TimeScale::Hour, 9)),
+    ));
+    let data = context.add_node(Contextoid::new(4, ContextoidType::Datoid(Data::new(4, 20))));
+    context.remove_node(removed).unwrap();
+
+    context
+        .add_edge(space, time, RelationKind::Temporal)
+        .unwrap();
+    context
+        .add_edge_with_attributes(
+            time,
+            data,
+            RelationKind::Datial,
+            EdgeAttributes::from([("source".to_string(), "sensor".to_string())]),
+        )
+        .unwrap();
+    context
+        .add_hyperedge(
+            &[space, time, data],
+            RelationKind::SpaceTemporal,
+            HyperedgeAttributes::from([("event".to_string(), "storm".to_string())]),
+        )
+        .unwrap();
+
+    let handle = context.extra_ctx_add_named("forecast", 10).unwrap();
+    context.extra_ctx_set_current_id(handle.id()).unwrap();
+    let a = context
+        .extra_ctx_add_node(Contextoid::new(
+            10,
+            ContextoidType::Datoid(Data::new(10, 1)),
+        ))
+        .unwrap();
+    let b = context
+        .extra_ctx_add_node(Contextoid::new(
+            11,
+            ContextoidType::Datoid(Data::new(11, 2)),
+        ))
+        .unwrap();
+    context
+        .extra_ctx_add_edge(a, b, RelationKind::Datial)
+        .unwrap();
+    context
+        .add_context_link(
+            ContextNodeRef::base(data),
+            ContextNodeRef::extra(handle, a),
+            RelationKind::Datial,
+        )
+        .unwrap();
+
+    context
+}
+
+fn assert_same_context(a: &BaseContext, b: &BaseContext) {
+    assert_eq!(a.id(), b.id());
+    assert_eq!(a.name(), b.name());
+    assert_eq!(a.node_count(), b.node_count());
+    assert_eq!(a.edge_count(), b.edge_count());
+    for index in 0..4 {
+        assert_eq!(a.get_node(index), b.get_node(index));
+    }
+    assert_eq!(a.get_edge_relation(0, 2), b.get_edge_relation(0, 2));
+    assert_eq!(a.get_edge_relation(2, 3), b.get_edge_relation(2, 3));
+    assert_eq!(a.get_edge(0, 2), b.get_edge(0, 2));
+    assert_eq!(a.get_edge(2, 3), b.get_edge(2, 3));
+    assert_eq!(a.hyperedge_count(), b.hyperedge_count());
+    assert_eq!(a.extra_ctx_get_current_id(), b.extra_ctx_get_current_id());
+    assert_eq!(a.number_of_context_links(), b.number_of_context_links());
+}
+
+#[test]
+fn test_context_round_trip_json() {
+    let context = get_populated_context();
+    let spec = BaseContextSpec::from_context(&context);
+    assert_eq!(spec.id(), 7);
+    assert_eq!(spec.name(), "populated");
+
+    let spec: BaseContextSpec = from_json(&to_json(&spec).unwrap()).unwrap();
+    let built = spec.build().unwrap();
+    assert_same_context(&context, &built);
+
+    assert!(!built.contains_node(1));
+    assert_eq!(built.version(), 0);
+    assert_eq!(
+        built.get_edge(2, 3).unwrap().attribute("source"),
+        Some("sensor")
+    );
+
+    let edge = built.get_hyperedge(0).unwrap();
+    assert_eq!(edge.nodes(), &[0, 2, 3]);
+    assert_eq!(edge.relation_kind(), RelationKind::SpaceTemporal);
+    assert_eq!(edge.attribute("event"), Some("storm"));
+
+    let handle = built.extra_ctx_handle("forecast").unwrap();
+    let extra = built.extra_ctx(handle).unwrap();
+    assert_eq!(extra.node_count(), 2);
+    assert_eq!(extra.get_edge_relation(0, 1), Some(RelationKind::Datial));
+    assert_eq!(
+        built.get_context_links(ContextNodeRef::base(3)),
+        vec![(ContextNodeRef::extra(handle, 0), RelationKind::Datial)]
+    );
+}
+
+#[test]
+fn test_context_round_trip_binary() {
+    let context = get_populated_context();
+    let spec = BaseContextSpec::from_context(&context);
+
+    let bytes = to_binary(&spec).unwrap();
+    let from_bytes: BaseContextSpec = from_binary(&bytes).unwrap();
+    assert_eq!(from_bytes, spec);
+
+    let built = from_bytes.build().unwrap();
+    assert_same_context(&context, &built);
+
+    // A rebuilt context describes itself the same way.
+    assert_eq!(BaseContextSpec::from_context(&built), spec);
+}
+
+#[test]
+fn test_context_invalid_spec() {
+    let mut context = get_populated_context();
+    let spec = BaseContextSpec::from_context(&context);
+
+    // Remove the target of an edge from the serialized nodes.
+    let json = to_json(&spec).unwrap();
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["base_context"]["nodes"]
+        .as_array_mut()
+        .unwrap()
+        .retain(|node| node[0] != 3);
+    let invalid: BaseContextSpec = from_json(&value.to_string()).unwrap();
+    assert!(invalid.build().is_err());
+
+    context.remove_node(3).unwrap();
+    let spec = BaseContextSpec::from_context(&context);
+    assert!(spec.build().is_ok());
+}
+
+#[test]
+fn test_context_spec_node_index_limit() {
+    let context = get_populated_context();
+    let spec = BaseContextSpec::from_context(&context);
+    let json = to_json(&spec).unwrap();
+
+    // The base context has three nodes, so indices must be less than six.
+    for index in [6, 1 << 40, u64::MAX] {
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["base_context"]["nodes"][0][0] = serde_json::Value::from(index);
+
+        let invalid: BaseContextSpec = from_json(&value.to_string()).unwrap();
+        let err = invalid.build().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(&format!("node index {} exceeds the limit of 6", index)));
+    }
+}
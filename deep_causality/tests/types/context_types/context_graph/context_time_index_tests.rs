@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+fn get_time_node(id: u64, time_scale: TimeScale, time_unit: u64) -> BaseContextoid {
+    Contextoid::new(
+        id,
+        ContextoidType::Tempoid(Time::new(id, time_scale, time_unit)),
+    )
+}
+
+// Year 2023 with the months 1, 3, 6, 9 and 12 added out of order.
+fn get_time_context() -> (BaseContext, Vec<usize>) {
+    let mut context = Context::with_capacity(1, "time context", 20);
+    context.enable_time_index();
+
+    let mut idx = vec![context.add_node(get_time_node(1, TimeScale::Year, 2023))];
+    for (id, month) in [(2, 6), (3, 1), (4, 12), (5, 3), (6, 9)] {
+        idx.push(context.add_node(get_time_node(id, TimeScale::Month, month)));
+    }
+
+    (context, idx)
+}
+
+#[test]
+fn test_time_index_disabled() {
+    let context: BaseContext = Context::with_capacity(1, "time context", 10);
+    assert!(!context.has_time_index());
+    assert!(context
+        .get_tempoids_in_range(TimeScale::Month, 1, 12)
+        .is_err());
+    assert!(context.get_tempoid_containing(TimeScale::Month, 1).is_err());
+}
+
+#[test]
+fn test_get_tempoids_in_range() {
+    let (context, idx) = get_time_context();
+    assert!(context.has_time_index());
+
+    let months = context
+        .get_tempoids_in_range(TimeScale::Month, 2, 9)
+        .unwrap();
+    assert_eq!(months, vec![idx[4], idx[1], idx[5]]);
+
+    let all = context
+        .get_tempoids_in_range(TimeScale::Month, 1, 12)
+        .unwrap();
+    assert_eq!(all.len(), 5);
+
+    let years = context
+        .get_tempoids_in_range(TimeScale::Year, 2000, 2100)
+        .unwrap();
+    assert_eq!(years, vec![idx[0]]);
+
+    assert!(context
+        .get_tempoids_in_range(TimeScale::Month, 10, 11)
+        .unwrap()
+        .is_empty());
+    assert!(context
+        .get_tempoids_in_range(TimeScale::Day, 1, 31)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_get_tempoid_containing() {
+    let (context, idx) = get_time_context();
+
+    assert_eq!(
+        context.get_tempoid_containing(TimeScale::Month, 7).unwrap(),
+        Some(idx[1])
+    );
+    assert_eq!(
+        context.get_tempoid_containing(TimeScale::Month, 3).unwrap(),
+        Some(idx[4])
+    );
+    assert_eq!(
+        context.get_tempoid_containing(TimeScale::Month, 0).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_get_previous_next_tempoid() {
+    let (context, idx) = get_time_context();
+
+    assert_eq!(context.get_previous_tempoid(idx[1]).unwrap(), Some(idx[4]));
+    assert_eq!(context.get_next_tempoid(idx[1]).unwrap(), Some(idx[5]));
+    assert_eq!(context.get_previous_tempoid(idx[2]).unwrap(), None);
+    assert_eq!(context.get_next_tempoid(idx[3]).unwrap(), None);
+
+    // Other time scales are not considered
+    assert_eq!(context.get_previous_tempoid(idx[0]).unwrap(), None);
+    assert_eq!(context.get_next_tempoid(idx[0]).unwrap(), None);
+
+    assert!(context.get_next_tempoid(99).is_err());
+}
+
+#[test]
+fn test_time_index_not_temporal() {
+    let (mut context, _) = get_time_context();
+    let data = context.add_node(Contextoid::new(
+        10,
+        ContextoidType::Datoid(Data::new(10, 42)),
+    ));
+    assert!(context.get_next_tempoid(data).is_err());
+}
+
+#[test]
+fn test_time_index_space_tempoid() {
+    let (mut context, idx) = get_time_context();
+    let st = context.add_node(Contextoid::new(
+        10,
+        ContextoidType::SpaceTempoid(SpaceTime::new(10, TimeScale::Month, 4, 1, 2, 3)),
+    ));
+
+    assert_eq!(context.get_next_tempoid(idx[4]).unwrap(), Some(st));
+    assert_eq!(context.get_next_tempoid(st).unwrap(), Some(idx[1]));
+}
+
+#[test]
+fn test_time_index_remove_node() {
+    let (mut context, idx) = get_time_context();
+
+    context.remove_node(idx[1]).expect("Failed to remove node");
+    assert_eq!(context.get_next_tempoid(idx[4]).unwrap(), Some(idx[5]));
+    assert_eq!(
+        context.get_tempoid_containing(TimeScale::Month, 7).unwrap(),
+        Some(idx[4])
+    );
+}
+
+#[test]
+fn test_enable_time_index_existing_nodes() {
+    let mut context: BaseContext = Context::with_capacity(1, "time context", 10);
+    let a = context.add_node(get_time_node(1, TimeScale::Day, 2));
+    let b = context.add_node(get_time_node(2, TimeScale::Day, 1));
+
+    context.enable_time_index();
+    let days = context.get_tempoids_in_range(TimeScale::Day, 1, 2).unwrap();
+    assert_eq!(days, vec![b, a]);
+}
@@ -0,0 +1,218 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+fn get_context() -> BaseContext {
+    Context::with_capacity(1, "base context", 10)
+}
+
+fn get_data_node(id: u64, data: u64) -> BaseContextoid {
+    Contextoid::new(id, ContextoidType::Datoid(Data::new(id, data)))
+}
+
+#[test]
+fn test_extra_ctx_add_named() {
+    let mut context = get_context();
+
+    let weather = context.extra_ctx_add_named("weather", 10).unwrap();
+    let market = context.extra_ctx_add_named("market", 10).unwrap();
+    assert_ne!(weather, market);
+
+    assert_eq!(context.extra_ctx_handle("weather"), Some(weather));
+    assert_eq!(context.extra_ctx_handle("unknown"), None);
+    assert_eq!(context.extra_ctx_name(market), Some("market"));
+    assert_eq!(context.extra_ctx_handles(), vec![weather, market]);
+
+    // Names are unique
+    assert!(context.extra_ctx_add_named("weather", 10).is_err());
+
+    // The current extra context is not changed
+    assert_eq!(context.extra_ctx_get_current_id(), 0);
+}
+
+#[test]
+fn test_extra_ctx_handle_by_id() {
+    let mut context = get_context();
+    let id = context.extra_ctx_add_new(10, false);
+
+    let handle = context.extra_ctx_handle_by_id(id).unwrap();
+    assert_eq!(handle.id(), id);
+    assert_eq!(handle.to_string(), format!("ExtraContextHandle: {}", id));
+    assert_eq!(context.extra_ctx_name(handle), None);
+    assert!(context.extra_ctx_handle_by_id(99).is_none());
+}
+
+#[test]
+fn test_extra_ctx_mut() {
+    let mut context = get_context();
+    let weather = context.extra_ctx_add_named("weather", 10).unwrap();
+    let market = context.extra_ctx_add_named("market", 10).unwrap();
+
+    let mut ctx = context.extra_ctx_mut(weather).unwrap();
+    assert_eq!(ctx.handle(), weather);
+    let a = ctx.add_node(get_data_node(1, 1)).unwrap();
+    let b = ctx.add_node(get_data_node(2, 2)).unwrap();
+    ctx.add_edge(a, b, RelationKind::Datial).unwrap();
+    ctx.update_node(b, get_data_node(3, 3)).unwrap();
+    assert!(ctx.contains_node(a));
+    assert_eq!(ctx.get_node(b).map(|n| n.id()), Some(3));
+    assert_eq!(ctx.node_count(), 2);
+    assert_eq!(ctx.edge_count(), 1);
+    assert_eq!(ctx.view().get_neighbors(a, None).unwrap(), vec![b]);
+
+    context
+        .extra_ctx_mut(market)
+        .unwrap()
+        .add_node(get_data_node(4, 4))
+        .unwrap();
+
+    let view = context.extra_ctx(weather).unwrap();
+    assert_eq!(view.node_count(), 2);
+    assert!(view.contains_edge(a, b));
+    assert_eq!(view.get_edge_relation(a, b), Some(RelationKind::Datial));
+    assert_eq!(view.get_node_by_id(3).map(|n| n.id()), Some(3));
+    assert_eq!(view.get_shortest_path(a, b).unwrap(), vec![a, b]);
+
+    let view = context.extra_ctx(market).unwrap();
+    assert_eq!(view.node_count(), 1);
+    assert_eq!(view.get_node_index_by_id(4), Some(0));
+
+    // Neither the base context nor the current id are affected
+    assert!(context.is_empty());
+    assert_eq!(context.extra_ctx_get_current_id(), 0);
+
+    let mut ctx = context.extra_ctx_mut(weather).unwrap();
+    ctx.remove_node(a).unwrap();
+    assert_eq!(ctx.node_count(), 1);
+    assert!(ctx.remove_node(a).is_err());
+}
+
+#[test]
+fn test_extra_ctx_remove() {
+    let mut context = get_context();
+    let weather = context.extra_ctx_add_named("weather", 10).unwrap();
+    context
+        .extra_ctx_set_current_id(weather.id())
+        .expect("Failed to set current id");
+
+    context.extra_ctx_remove(weather).unwrap();
+    assert!(!context.extra_ctx_check_exists(weather.id()));
+    assert!(context.extra_ctx(weather).is_err());
+    assert!(context.extra_ctx_mut(weather).is_err());
+    assert!(context.extra_ctx_handle("weather").is_none());
+    assert_eq!(context.extra_ctx_get_current_id(), 0);
+    assert!(context.extra_ctx_remove(weather).is_err());
+
+    // Ids are not reused and the name is available again
+    let again = context.extra_ctx_add_named("weather", 10).unwrap();
+    assert_ne!(again, weather);
+}
+
+#[test]
+fn test_context_links() {
+    let mut context = get_context();
+    let base = context.add_node(get_data_node(1, 1));
+    let weather = context.extra_ctx_add_named("weather", 10).unwrap();
+    let w = context
+        .extra_ctx_mut(weather)
+        .unwrap()
+        .add_node(get_data_node(2, 2))
+        .unwrap();
+
+    let from = ContextNodeRef::base(base);
+    let to = ContextNodeRef::extra(weather, w);
+    assert_eq!(from.context(), None);
+    assert_eq!(to.context(), Some(weather));
+    assert_eq!(to.index(), w);
+
+    context
+        .add_context_link(from, to, RelationKind::Datial)
+        .unwrap();
+    assert!(context.contains_context_link(from, to));
+    assert!(!context.contains_context_link(to, from));
+    assert_eq!(
+        context.get_context_links(from),
+        vec![(to, RelationKind::Datial)]
+    );
+    assert_eq!(context.number_of_context_links(), 1);
+
+    // Duplicates, missing nodes and same context links are rejected
+    assert!(context
+        .add_context_link(from, to, RelationKind::Temporal)
+        .is_err());
+    assert!(context
+        .add_context_link(
+            from,
+            ContextNodeRef::extra(weather, 99),
+            RelationKind::Datial
+        )
+        .is_err());
+    assert!(context
+        .add_context_link(from, ContextNodeRef::base(base), RelationKind::Datial)
+        .is_err());
+
+    context.remove_context_link(from, to).unwrap();
+    assert!(!context.contains_context_link(from, to));
+    assert!(context.remove_context_link(from, to).is_err());
+    assert_eq!(context.number_of_context_links(), 0);
+}
+
+#[test]
+fn test_context_links_removed_with_nodes_and_contexts() {
+    let mut context = get_context();
+    let base = context.add_node(get_data_node(1, 1));
+    let weather = context.extra_ctx_add_named("weather", 10).unwrap();
+    let market = context.extra_ctx_add_named("market", 10).unwrap();
+    let w = context
+        .extra_ctx_mut(weather)
+        .unwrap()
+        .add_node(get_data_node(2, 2))
+        .unwrap();
+    let m = context
+        .extra_ctx_mut(market)
+        .unwrap()
+        .add_node(get_data_node(3, 3))
+        .unwrap();
+
+    let b = ContextNodeRef::base(base);
+    let w = ContextNodeRef::extra(weather, w);
+    let m = ContextNodeRef::extra(market, m);
+
+    context
+        .add_context_link(b, w, RelationKind::Datial)
+        .unwrap();
+    context
+        .add_context_link(w, m, RelationKind::Datial)
+        .unwrap();
+    context
+        .add_context_link(m, b, RelationKind::Datial)
+        .unwrap();
+    assert_eq!(context.number_of_context_links(), 3);
+
+    // Removing the weather node drops both of its links
+    context
+        .extra_ctx_mut(weather)
+        .unwrap()
+        .remove_node(w.index())
+        .unwrap();
+    assert_eq!(context.number_of_context_links(), 1);
+    assert!(context.contains_context_link(m, b));
+
+    // Removing the market context drops the remaining link
+    context.extra_ctx_remove(market).unwrap();
+    assert_eq!(context.number_of_context_links(), 0);
+
+    // Removing a base node drops its links as well
+    let w2 = context
+        .extra_ctx_mut(weather)
+        .unwrap()
+        .add_node(get_data_node(4, 4))
+        .unwrap();
+    let w2 = ContextNodeRef::extra(weather, w2);
+    context
+        .add_context_link(w2, b, RelationKind::Datial)
+        .unwrap();
+    context.remove_node(base).unwrap();
+    assert_eq!(context.number_of_context_links(), 0);
+}
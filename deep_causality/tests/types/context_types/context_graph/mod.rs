@@ -1,10 +1,28 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 #[cfg(test)]
+mod context_adjustment_tests;
+#[cfg(test)]
+mod context_export_tests;
+#[cfg(test)]
+mod context_hyperedge_tests;
+#[cfg(test)]
+mod context_query_tests;
+#[cfg(test)]
+mod context_snapshot_tests;
+#[cfg(test)]
+mod context_spatial_index_tests;
+#[cfg(all(test, feature = "serde"))]
+mod context_spec_tests;
+#[cfg(test)]
 mod context_tests;
 #[cfg(test)]
+mod context_time_index_tests;
+#[cfg(test)]
 mod extendable_context_tests;
 #[cfg(test)]
+mod extra_context_tests;
+#[cfg(test)]
 mod graph_node_tests;
 #[cfg(test)]
 mod graph_node_type_tests;
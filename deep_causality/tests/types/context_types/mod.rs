@@ -5,8 +5,16 @@ mod adjustable;
 mod context_graph;
 mod contextoid;
 mod node_types;
+mod uncertain;
+
+#[cfg(test)]
+mod context_event_tests;
 
 #[cfg(test)]
 mod relation_kind_tests;
 #[cfg(test)]
+mod spatial_metric_tests;
+#[cfg(test)]
 mod time_scale_tests;
+#[cfg(test)]
+mod uncertain_value_tests;
@@ -64,3 +64,29 @@ fn test_to_string() {
     let act = d.to_string();
     assert_eq!(act, exp);
 }
+
+#[test]
+fn test_from_unix_timestamp() {
+    // 2024-05-15T13:45:30Z
+    let seconds = 1_715_780_730;
+
+    let d = Time::from_unix_timestamp(1, TimeScale::Day, seconds).unwrap();
+    assert_eq!(d.time_scale(), TimeScale::Day);
+    assert_eq!(*d.time_unit(), 19858);
+    assert_eq!(d.unix_timestamp_nanos().unwrap(), 1_715_731_200_000_000_000);
+
+    let d = Time::from_unix_timestamp(1, TimeScale::Month, seconds).unwrap();
+    assert_eq!(*d.time_unit(), 54 * 12 + 4);
+    assert!(d.contains_unix_timestamp_nanos(1_714_521_600_000_000_000));
+    assert!(!d.contains_unix_timestamp_nanos(1_717_200_000_000_000_000));
+
+    let d = Time::from_unix_timestamp_nanos(1, TimeScale::Microsecond, 1_500).unwrap();
+    assert_eq!(*d.time_unit(), 1);
+
+    assert!(Time::from_unix_timestamp(1, TimeScale::NoScale, seconds).is_err());
+    assert!(Time::from_unix_timestamp(1, TimeScale::Day, i64::MAX).is_err());
+
+    let d = Time::new(1, TimeScale::NoScale, 3);
+    assert!(d.unix_timestamp_nanos().is_err());
+    assert!(!d.contains_unix_timestamp_nanos(0));
+}
@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::SpatialMetric;
+
+#[test]
+fn test_euclidean() {
+    let sm = SpatialMetric::Euclidean;
+    assert_eq!(sm, SpatialMetric::default());
+    assert_eq!(sm.to_string(), format!("Euclidean"));
+}
+
+#[test]
+fn test_geodesic() {
+    let sm = SpatialMetric::Geodesic;
+    assert_eq!(sm, SpatialMetric::Geodesic);
+    assert_eq!(sm.to_string(), format!("Geodesic"));
+}
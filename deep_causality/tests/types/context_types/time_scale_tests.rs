@@ -41,3 +41,61 @@ fn test_time_scale() {
     assert_eq!(ts, TimeScale::Year);
     assert_eq!(ts.to_string(), "Year");
 }
+
+#[test]
+fn test_sub_second_time_scale() {
+    assert_eq!(TimeScale::Nanosecond.to_string(), "Nanosecond");
+    assert_eq!(TimeScale::Microsecond.to_string(), "Microsecond");
+    assert_eq!(TimeScale::Millisecond.to_string(), "Millisecond");
+}
+
+#[test]
+fn test_discriminants() {
+    assert_eq!(TimeScale::NoScale as u8, 0);
+    assert_eq!(TimeScale::Second as u8, 1);
+    assert_eq!(TimeScale::Year as u8, 8);
+    assert_eq!(TimeScale::Nanosecond as u8, 9);
+    assert_eq!(TimeScale::Microsecond as u8, 10);
+    assert_eq!(TimeScale::Millisecond as u8, 11);
+}
+
+#[test]
+fn test_duration_and_months() {
+    assert_eq!(TimeScale::Nanosecond.duration_nanos(), Some(1));
+    assert_eq!(TimeScale::Millisecond.duration_nanos(), Some(1_000_000));
+    assert_eq!(TimeScale::Week.duration_nanos(), Some(604_800_000_000_000));
+    assert_eq!(TimeScale::Month.duration_nanos(), None);
+    assert_eq!(TimeScale::NoScale.duration_nanos(), None);
+
+    assert_eq!(TimeScale::Quarter.months(), Some(3));
+    assert_eq!(TimeScale::Day.months(), None);
+
+    assert!(TimeScale::Year.is_calendar());
+    assert!(!TimeScale::Week.is_calendar());
+    assert!(!TimeScale::NoScale.is_calendar());
+}
+
+#[test]
+fn test_convert() {
+    assert_eq!(
+        TimeScale::Minute.convert(90.0, TimeScale::Hour).unwrap(),
+        1.5
+    );
+    assert_eq!(
+        TimeScale::Second
+            .convert(1.5, TimeScale::Millisecond)
+            .unwrap(),
+        1500.0
+    );
+    assert_eq!(TimeScale::Week.convert(2.0, TimeScale::Day).unwrap(), 14.0);
+    assert_eq!(
+        TimeScale::Year.convert(2.0, TimeScale::Quarter).unwrap(),
+        8.0
+    );
+    assert_eq!(TimeScale::Month.convert(6.0, TimeScale::Year).unwrap(), 0.5);
+
+    assert!(TimeScale::Month.convert(1.0, TimeScale::Day).is_err());
+    assert!(TimeScale::Day.convert(1.0, TimeScale::Year).is_err());
+    assert!(TimeScale::NoScale.convert(1.0, TimeScale::NoScale).is_err());
+    assert!(TimeScale::NoScale.convert(1.0, TimeScale::Second).is_err());
+}
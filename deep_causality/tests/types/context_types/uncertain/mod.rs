@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+#[cfg(test)]
+mod uncertain_data_tests;
+#[cfg(test)]
+mod uncertain_space_tests;
+#[cfg(test)]
+mod uncertain_time_tests;
+mod utils;
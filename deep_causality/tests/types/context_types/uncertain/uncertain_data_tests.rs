@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::types::context_types::uncertain::utils;
+
+type UncertainContext = Context<UncertainData, Space<u64>, Time<u64>, SpaceTime<u64>, u64>;
+
+fn get_data(id: u64, mean: f64, variance: f64) -> UncertainData {
+    UncertainData::new(id, UncertainValue::normal(mean, variance).unwrap(), 4.0)
+}
+
+#[test]
+fn test_new() {
+    let d = get_data(1, 10.0, 4.0);
+    assert_eq!(d.id(), 1);
+    assert_eq!(*d.data_id(), 1);
+    assert_eq!(d.data().mean(), 10.0);
+    assert_eq!(*d.measurement_variance(), 4.0);
+}
+
+#[test]
+fn test_update() {
+    let mut d = get_data(1, 10.0, 4.0);
+
+    // Equal variances: the estimate moves halfway and the variance halves.
+    let res = d.update(&utils::get_1d_array_grid(20.0));
+    assert!(res.is_ok());
+    assert_eq!(d.data().mean(), 15.0);
+    assert_eq!(d.data().variance(), 2.0);
+}
+
+#[test]
+fn test_update_err() {
+    let mut d = get_data(1, 10.0, 4.0);
+
+    let res = d.update(&utils::get_1d_array_grid(f64::NAN));
+    assert!(res.is_err());
+
+    let mut d = UncertainData::new(1, UncertainValue::exact(10.0), -1.0);
+    let res = d.update(&utils::get_1d_array_grid(20.0));
+    assert!(res.is_err());
+    assert_eq!(d.data().mean(), 10.0);
+}
+
+#[test]
+fn test_adjust() {
+    let mut d = get_data(1, 10.0, 4.0);
+
+    let res = d.adjust(&utils::get_1d_array_grid(-3.0));
+    assert!(res.is_ok());
+    assert_eq!(d.data().mean(), 7.0);
+    assert_eq!(d.data().variance(), 4.0);
+
+    let res = d.adjust(&utils::get_1d_array_grid(f64::INFINITY));
+    assert!(res.is_err());
+    assert_eq!(d.data().mean(), 7.0);
+}
+
+#[test]
+fn test_display() {
+    let d = get_data(1, 10.0, 4.0);
+    assert_eq!(
+        format!("{}", d),
+        "UncertainData { id=1, data=Normal: mean: 10, variance: 4, measurement_variance=4.0 }"
+    );
+}
+
+#[test]
+fn test_probability_in_causal_fn() {
+    let mut context: UncertainContext = Context::with_capacity(1, "sensor context", 10);
+    context.add_node(Contextoid::new(
+        1,
+        ContextoidType::Datoid(get_data(1, 10.0, 4.0)),
+    ));
+
+    // Holds if the sensor value most likely exceeds the observation.
+    fn contextual_causal_fn(
+        obs: NumericalValue,
+        ctx: &UncertainContext,
+    ) -> Result<bool, CausalityError> {
+        let contextoid = ctx.get_node(0).expect("Could not find contextoid");
+
+        match contextoid.vertex_type() {
+            ContextoidType::Datoid(d) => Ok(d.data().probability_above(obs) > 0.9),
+            _ => Err(CausalityError("Contextoid is not a datoid".into())),
+        }
+    }
+
+    let causaloid = Causaloid::new_with_context(
+        1,
+        contextual_causal_fn,
+        Some(&context),
+        "tests whether the sensor value exceeds the observation",
+    );
+
+    assert!(causaloid.verify_single_cause(&5.0).unwrap());
+    assert!(!causaloid.verify_single_cause(&9.0).unwrap());
+}
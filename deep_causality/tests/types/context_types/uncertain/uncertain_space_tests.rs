@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use dcl_data_structures::prelude::PointIndex;
+use deep_causality::prelude::*;
+
+use crate::types::context_types::uncertain::utils;
+
+fn get_space() -> UncertainSpace<u64> {
+    UncertainSpace::new(
+        1,
+        1,
+        2,
+        3,
+        [
+            UncertainValue::normal(1.0, 1.0).unwrap(),
+            UncertainValue::interval(1.0, 3.0).unwrap(),
+            UncertainValue::samples(vec![2.0, 3.0, 4.0]).unwrap(),
+        ],
+        1.0,
+    )
+}
+
+#[test]
+fn test_new() {
+    let s = get_space();
+    assert_eq!(s.id(), 1);
+    assert_eq!(*s.space_id(), 1);
+    assert_eq!(*Spatial::x(&s), 1);
+    assert_eq!(*Spatial::y(&s), 2);
+    assert_eq!(*Spatial::z(&s), 3);
+    assert_eq!(s.uncertain_y().mean(), 2.0);
+    assert_eq!(s.uncertain_z().mean(), 3.0);
+}
+
+#[test]
+fn test_update() {
+    let mut s = get_space();
+
+    let res = s.update(&utils::get_3d_array_grid(3.0, 2.0, 5.0));
+    assert!(res.is_ok());
+
+    assert_eq!(s.uncertain_x().mean(), 2.0);
+    assert_eq!(s.uncertain_x().variance(), 0.5);
+    assert!(s.uncertain_y().variance() < 1.0 / 3.0);
+    assert!(s.uncertain_z().mean() > 3.0);
+
+    // Nominal coordinates do not change.
+    assert_eq!(*Spatial::x(&s), 1);
+}
+
+#[test]
+fn test_adjust_with_mapping() {
+    let mut s = get_space();
+
+    let mapping = AdjustmentMapping::new()
+        .with(AdjustmentField::X, PointIndex::new3d(0, 0, 2))
+        .with(AdjustmentField::Y, PointIndex::new3d(0, 0, 1))
+        .with(AdjustmentField::Z, PointIndex::new3d(0, 0, 0));
+
+    let res = s.adjust_with_mapping(&utils::get_3d_array_grid(1.0, 2.0, 3.0), &mapping);
+    assert!(res.is_ok());
+
+    assert_eq!(s.uncertain_x().mean(), 4.0);
+    assert_eq!(s.uncertain_y().mean(), 4.0);
+    assert_eq!(s.uncertain_z().mean(), 4.0);
+
+    let res = s.adjust_with_mapping(
+        &utils::get_3d_array_grid(1.0, 2.0, 3.0),
+        &AdjustmentMapping::data(),
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_probability_in_bbox() {
+    let s = get_space();
+
+    let p = s.probability_in_bbox([-10.0, 1.0, 2.0], [10.0, 2.0, 3.0]);
+    assert!((p - 0.5 * 2.0 / 3.0).abs() < 1e-6);
+
+    let p = s.probability_in_bbox([-10.0, 5.0, 2.0], [10.0, 6.0, 3.0]);
+    assert_eq!(p, 0.0);
+}
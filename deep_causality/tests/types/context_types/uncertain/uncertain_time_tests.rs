@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::types::context_types::uncertain::utils;
+
+fn get_time() -> UncertainTime<u64> {
+    UncertainTime::new(
+        1,
+        TimeScale::Second,
+        100,
+        UncertainValue::normal(100.0, 9.0).unwrap(),
+        1.0,
+    )
+}
+
+#[test]
+fn test_new() {
+    let t = get_time();
+    assert_eq!(t.id(), 1);
+    assert_eq!(Temporable::time_scale(&t), TimeScale::Second);
+    assert_eq!(*Temporable::time_unit(&t), 100);
+    assert_eq!(t.uncertain_time().std_dev(), 3.0);
+}
+
+#[test]
+fn test_update_and_adjust() {
+    let mut t = get_time();
+
+    let res = t.update(&utils::get_1d_array_grid(110.0));
+    assert!(res.is_ok());
+    assert!((t.uncertain_time().mean() - 109.0).abs() < 1e-9);
+    assert!((t.uncertain_time().variance() - 0.9).abs() < 1e-9);
+
+    let res = t.adjust(&utils::get_1d_array_grid(1.0));
+    assert!(res.is_ok());
+    assert!((t.uncertain_time().mean() - 110.0).abs() < 1e-9);
+
+    assert!(t.update(&utils::get_1d_array_grid(f64::NAN)).is_err());
+    assert!(t.adjust(&utils::get_1d_array_grid(f64::NAN)).is_err());
+    assert!((t.uncertain_time().mean() - 110.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_display() {
+    let t = get_time();
+    assert_eq!(
+        format!("{}", t),
+        "UncertainTime { id=1, time_scale=Second, time_unit=100, uncertain_time=Normal: mean: 100, variance: 9 }"
+    );
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use dcl_data_structures::grid_type::{ArrayGrid, ArrayType};
+use dcl_data_structures::prelude::PointIndex;
+
+pub const HEIGHT: usize = 5;
+pub const WIDTH: usize = 5;
+pub const DEPTH: usize = 5;
+pub const TIME: usize = 5;
+
+pub type MeasurementData = ArrayGrid<f64, WIDTH, HEIGHT, DEPTH, TIME>;
+
+pub fn get_1d_array_grid(val: f64) -> MeasurementData {
+    let ag: MeasurementData = ArrayGrid::new(ArrayType::Array1D);
+    ag.set(PointIndex::new1d(0), val);
+    ag
+}
+
+pub fn get_3d_array_grid(v1: f64, v2: f64, v3: f64) -> MeasurementData {
+    let ag: MeasurementData = ArrayGrid::new(ArrayType::Array3D);
+    ag.set(PointIndex::new3d(0, 0, 0), v1);
+    ag.set(PointIndex::new3d(0, 0, 1), v2);
+    ag.set(PointIndex::new3d(0, 0, 2), v3);
+    ag
+}
@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::UncertainValue;
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-4, "{} != {}", a, b);
+}
+
+#[test]
+fn test_new_err() {
+    assert!(UncertainValue::normal(f64::NAN, 1.0).is_err());
+    assert!(UncertainValue::normal(0.0, -1.0).is_err());
+    assert!(UncertainValue::interval(2.0, 1.0).is_err());
+    assert!(UncertainValue::interval(f64::NEG_INFINITY, 1.0).is_err());
+    assert!(UncertainValue::samples(vec![]).is_err());
+    assert!(UncertainValue::samples(vec![1.0, f64::NAN]).is_err());
+}
+
+#[test]
+fn test_mean_variance() {
+    let v = UncertainValue::normal(5.0, 4.0).unwrap();
+    assert_eq!(v.mean(), 5.0);
+    assert_eq!(v.variance(), 4.0);
+    assert_eq!(v.std_dev(), 2.0);
+
+    let v = UncertainValue::interval(0.0, 6.0).unwrap();
+    assert_eq!(v.mean(), 3.0);
+    assert_eq!(v.variance(), 3.0);
+
+    let v = UncertainValue::samples(vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+    assert_eq!(v.mean(), 2.5);
+    assert_eq!(v.variance(), 1.25);
+
+    let v = UncertainValue::exact(7.0);
+    assert_eq!(v.mean(), 7.0);
+    assert_eq!(v.variance(), 0.0);
+}
+
+#[test]
+fn test_probability_below_above() {
+    let v = UncertainValue::normal(0.0, 1.0).unwrap();
+    assert_close(v.probability_below(0.0), 0.5);
+    assert_close(v.probability_below(1.0), 0.841345);
+    assert_close(v.probability_above(1.96), 0.024998);
+
+    let v = UncertainValue::interval(0.0, 4.0).unwrap();
+    assert_eq!(v.probability_below(-1.0), 0.0);
+    assert_eq!(v.probability_below(1.0), 0.25);
+    assert_eq!(v.probability_below(5.0), 1.0);
+
+    let v = UncertainValue::samples(vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+    assert_eq!(v.probability_below(2.0), 0.5);
+    assert_eq!(v.probability_above(3.5), 0.25);
+
+    let v = UncertainValue::exact(1.0);
+    assert_eq!(v.probability_below(0.9), 0.0);
+    assert_eq!(v.probability_below(1.0), 1.0);
+}
+
+#[test]
+fn test_probability_between() {
+    let v = UncertainValue::normal(0.0, 1.0).unwrap();
+    assert_close(v.probability_between(-1.0, 1.0), 0.682689);
+    assert_eq!(v.probability_between(1.0, -1.0), 0.0);
+
+    let v = UncertainValue::samples(vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+    assert_eq!(v.probability_between(2.0, 3.0), 0.5);
+}
+
+#[test]
+fn test_probability_of_condition() {
+    let v = UncertainValue::normal(0.0, 1.0).unwrap();
+    let p = v.probability(|x| x.abs() <= 1.0);
+    assert_close(p, v.probability_between(-1.0, 1.0));
+
+    let p = v.probability(|x| !(-1.0..=1.0).contains(&x));
+    assert_close(p, 1.0 - 0.682689);
+
+    let v = UncertainValue::interval(0.0, 10.0).unwrap();
+    assert_close(v.probability(|x| (x as u64) % 2 == 0), 0.5);
+
+    let v = UncertainValue::samples(vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+    assert_eq!(v.probability(|x| x != 3.0), 0.75);
+
+    let v = UncertainValue::exact(2.0);
+    assert_eq!(v.probability(|x| x > 1.0), 1.0);
+}
+
+#[test]
+fn test_shift() {
+    let v = UncertainValue::normal(1.0, 2.0).unwrap().shift(3.0);
+    assert_eq!(v, UncertainValue::normal(4.0, 2.0).unwrap());
+
+    let v = UncertainValue::interval(0.0, 1.0).unwrap().shift(-1.0);
+    assert_eq!(v, UncertainValue::interval(-1.0, 0.0).unwrap());
+
+    let v = UncertainValue::samples(vec![1.0, 2.0]).unwrap().shift(1.0);
+    assert_eq!(v, UncertainValue::samples(vec![2.0, 3.0]).unwrap());
+}
+
+#[test]
+fn test_fuse() {
+    let a = UncertainValue::normal(10.0, 4.0).unwrap();
+    let b = UncertainValue::normal(16.0, 2.0).unwrap();
+
+    // Inverse variance weighting
+    let fused = a.fuse(&b);
+    assert_close(fused.mean(), 14.0);
+    assert_close(fused.variance(), 4.0 / 3.0);
+
+    // Symmetric
+    let fused = b.fuse(&a);
+    assert_close(fused.mean(), 14.0);
+
+    // Exact values take precedence
+    let exact = UncertainValue::exact(1.0);
+    assert_eq!(a.fuse(&exact), exact);
+    assert_eq!(exact.fuse(&a), exact);
+
+    // Of two exact values, self is kept
+    let other = UncertainValue::exact(2.0);
+    assert_eq!(exact.fuse(&other), exact);
+
+    // Other distributions are approximated by their mean and variance
+    let interval = UncertainValue::interval(0.0, 6.0).unwrap();
+    let fused = interval.fuse(&UncertainValue::normal(6.0, 3.0).unwrap());
+    assert_close(fused.mean(), 4.5);
+    assert_close(fused.variance(), 1.5);
+}
+
+#[test]
+fn test_display() {
+    let v = UncertainValue::normal(1.0, 2.0).unwrap();
+    assert_eq!(format!("{}", v), "Normal: mean: 1, variance: 2");
+
+    let v = UncertainValue::interval(0.0, 1.5).unwrap();
+    assert_eq!(format!("{}", v), "Interval: lower: 0, upper: 1.5");
+
+    let v = UncertainValue::samples(vec![1.0, 2.0]).unwrap();
+    assert_eq!(format!("{}", v), "Samples: n: 2, mean: 1.5");
+}
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fs;
+
+use deep_causality::prelude::{
+    ActionError, CSMAuditLog, CSMAuditRecord, CausalAction, CausalState, JsonLinesAuditSink, CSM,
+};
+
+use crate::utils::test_utils;
+
+fn state_action() -> Result<(), ActionError> {
+    Ok(())
+}
+
+fn get_test_action() -> CausalAction {
+    CausalAction::new(state_action, "Test action that does nothing", 1)
+}
+
+#[test]
+fn test_record_json_round_trip() {
+    let record = CSMAuditRecord::new(
+        42,
+        3,
+        0.89,
+        Some("Causaloid: 1 \"quoted\"\n evaluated to true".into()),
+        Some(true),
+        true,
+        None,
+    );
+
+    let line = record.to_json_line();
+    assert!(!line.contains('\n'));
+
+    let parsed = CSMAuditRecord::from_json_line(&line).expect("Failed to parse record");
+    assert_eq!(parsed, record);
+}
+
+#[test]
+fn test_record_json_errors() {
+    assert!(CSMAuditRecord::from_json_line("").is_err());
+    assert!(CSMAuditRecord::from_json_line("{\"seq\":1}").is_err());
+    assert!(CSMAuditRecord::from_json_line("{\"seq\":1,").is_err());
+
+    let line = CSMAuditRecord::new(1, 1, 0.5, None, Some(false), false, None).to_json_line();
+    assert!(CSMAuditRecord::from_json_line(&format!("{}x", line)).is_err());
+}
+
+#[test]
+fn test_record_json_nan_data() {
+    let record = CSMAuditRecord::new(1, 1, f64::NAN, None, None, false, Some("err".into()));
+
+    let line = record.to_json_line();
+    assert!(line.contains("\"data\":null"));
+
+    let parsed = CSMAuditRecord::from_json_line(&line).expect("Failed to parse record");
+    assert!(parsed.data().is_nan());
+    assert_eq!(*parsed.decision(), None);
+}
+
+#[test]
+fn test_json_lines_sink() {
+    let path = std::env::temp_dir().join(format!("csm_audit_{}.jsonl", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    {
+        let causaloid = test_utils::get_test_causaloid();
+        let cs = CausalState::new(42, 1, 0.23, &causaloid);
+        let ca = get_test_action();
+        let state_actions = &[(&cs, &ca)];
+
+        let sink = JsonLinesAuditSink::new(&path).expect("Failed to open audit file");
+        assert_eq!(sink.path(), path.as_path());
+
+        let csm = CSM::new_with_audit_log(state_actions, CSMAuditLog::with_sink(1, Box::new(sink)));
+        assert!(csm.eval_single_state(42, 0.89).is_ok());
+        assert!(csm.eval_single_state(42, 0.1).is_ok());
+        assert_eq!(csm.audit_records().len(), 1);
+    }
+
+    let records = JsonLinesAuditSink::read_records(&path).expect("Failed to read audit file");
+    assert_eq!(records.len(), 2);
+    assert!(*records[0].fired());
+    assert_eq!(*records[1].decision(), Some(false));
+    assert!(!*records[1].fired());
+    assert_eq!(*records[1].seq(), 2);
+
+    fs::remove_file(&path).expect("Failed to remove audit file");
+    assert!(JsonLinesAuditSink::read_records(&path).is_err());
+}
@@ -0,0 +1,187 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::{
+    ActionError, AuditError, AuditSink, CSMAuditLog, CSMAuditRecord, CausalAction, CausalState, CSM,
+};
+
+use crate::utils::test_utils;
+
+fn state_action() -> Result<(), ActionError> {
+    Ok(())
+}
+
+fn failing_action() -> Result<(), ActionError> {
+    Err(ActionError("Actuator offline".into()))
+}
+
+fn get_test_action() -> CausalAction {
+    CausalAction::new(state_action, "Test action that does nothing", 1)
+}
+
+struct FailingSink;
+
+impl AuditSink for FailingSink {
+    fn write_record(&mut self, _record: &CSMAuditRecord) -> Result<(), AuditError> {
+        Err(AuditError("Sink unavailable".into()))
+    }
+}
+
+#[test]
+fn test_audit_log_ring() {
+    let mut log = CSMAuditLog::new(2);
+    assert!(log.is_empty());
+    assert_eq!(log.capacity(), 2);
+    assert!(!log.has_sink());
+
+    for id in 0..3 {
+        let record = CSMAuditRecord::new(id, 1, 0.5, None, Some(false), false, None);
+        assert!(log.append(record).is_ok());
+    }
+
+    assert_eq!(log.len(), 2);
+    assert_eq!(log.total_appended(), 3);
+
+    let seqs: Vec<u64> = log.iter().map(|r| *r.seq()).collect();
+    assert_eq!(seqs, vec![2, 3]);
+    assert_eq!(*log.records()[0].state_id(), 1);
+}
+
+#[test]
+fn test_audit_log_sink_error() {
+    let mut log = CSMAuditLog::with_sink(4, Box::new(FailingSink));
+    assert!(log.has_sink());
+
+    let record = CSMAuditRecord::new(1, 1, 0.5, None, Some(false), false, None);
+    assert!(log.append(record).is_err());
+    assert!(log.is_empty());
+    assert_eq!(log.total_appended(), 0);
+}
+
+#[test]
+fn test_record_decision() {
+    let record = CSMAuditRecord::new(1, 1, 0.5, None, Some(false), false, None);
+    assert_eq!(*record.decision(), Some(false));
+    assert!(!*record.fired());
+
+    let record = CSMAuditRecord::new(
+        1,
+        1,
+        0.5,
+        Some("ok".into()),
+        Some(true),
+        false,
+        Some("err".into()),
+    );
+    assert_eq!(*record.decision(), Some(true));
+    assert!(!*record.fired());
+
+    let record = CSMAuditRecord::new(1, 1, -0.5, None, None, false, Some("err".into()));
+    assert_eq!(*record.decision(), None);
+}
+
+#[test]
+fn test_csm_records_decisions() {
+    let causaloid = test_utils::get_test_causaloid();
+    let cs = CausalState::new(42, 2, 0.23, &causaloid);
+    let ca = get_test_action();
+    let state_actions = &[(&cs, &ca)];
+
+    let csm = CSM::new_with_audit_log(state_actions, CSMAuditLog::new(10));
+    assert!(csm.has_audit_log());
+
+    assert!(csm.eval_single_state(42, 0.89).is_ok());
+    assert!(csm.eval_all_states().is_ok());
+
+    let records = csm.audit_records();
+    assert_eq!(records.len(), 2);
+
+    let first = &records[0];
+    assert_eq!(*first.seq(), 1);
+    assert_eq!(*first.state_id(), 42);
+    assert_eq!(*first.state_version(), 2);
+    assert_eq!(*first.data(), 0.89);
+    assert_eq!(*first.decision(), Some(true));
+    assert!(*first.fired());
+    assert!(first.explanation().is_some());
+    assert!(first.error().is_none());
+
+    let second = &records[1];
+    assert_eq!(*second.data(), 0.23);
+    assert_eq!(*second.decision(), Some(false));
+    assert!(!*second.fired());
+    assert!(second.explanation().is_none());
+}
+
+#[test]
+fn test_csm_records_errors() {
+    let causaloid = test_utils::get_test_causaloid();
+    let cs = CausalState::new(42, 1, 0.23, &causaloid);
+    let ca = CausalAction::new(failing_action, "Test action that fails", 1);
+    let state_actions = &[(&cs, &ca)];
+
+    let csm = CSM::new_with_audit_log(state_actions, CSMAuditLog::new(10));
+
+    assert!(csm.eval_single_state(42, 0.89).is_err());
+    assert!(csm.eval_single_state(42, -1.0).is_err());
+
+    let records = csm.audit_records();
+    assert_eq!(records.len(), 2);
+    assert_eq!(*records[0].decision(), Some(true));
+    assert!(!*records[0].fired());
+    assert!(records[0]
+        .error()
+        .as_ref()
+        .unwrap()
+        .contains("Actuator offline"));
+    assert_eq!(*records[1].decision(), None);
+    assert!(!*records[1].fired());
+}
+
+#[test]
+fn test_csm_without_audit_log() {
+    let causaloid = test_utils::get_test_causaloid();
+    let cs = CausalState::new(42, 1, 0.23, &causaloid);
+    let ca = get_test_action();
+    let state_actions = &[(&cs, &ca)];
+
+    let csm = CSM::new(state_actions);
+    assert!(!csm.has_audit_log());
+    assert!(csm.eval_single_state(42, 0.89).is_ok());
+    assert!(csm.audit_records().is_empty());
+}
+
+#[test]
+fn test_csm_audit_sink_error() {
+    let causaloid = test_utils::get_test_causaloid();
+    let cs = CausalState::new(42, 1, 0.23, &causaloid);
+    let ca = get_test_action();
+    let state_actions = &[(&cs, &ca)];
+
+    let log = CSMAuditLog::with_sink(10, Box::new(FailingSink));
+    let csm = CSM::new_with_audit_log(state_actions, log);
+
+    // The action fired before the record could be written.
+    let err = csm.eval_single_state(42, 0.89).unwrap_err();
+    assert!(err.to_string().contains("action fired"));
+    assert!(err.to_string().contains("Sink unavailable"));
+
+    let err = csm.eval_single_state(42, 0.1).unwrap_err();
+    assert!(err.to_string().contains("no action fired"));
+}
+
+#[test]
+fn test_csm_audit_sink_error_after_failed_action() {
+    let causaloid = test_utils::get_test_causaloid();
+    let cs = CausalState::new(42, 1, 0.23, &causaloid);
+    let ca = CausalAction::new(failing_action, "Test action that fails", 1);
+    let state_actions = &[(&cs, &ca)];
+
+    let log = CSMAuditLog::with_sink(10, Box::new(FailingSink));
+    let csm = CSM::new_with_audit_log(state_actions, log);
+
+    let err = csm.eval_single_state(42, 0.89).unwrap_err();
+    assert!(err.to_string().contains("action failed: "));
+    assert!(err.to_string().contains("Actuator offline"));
+    assert!(err.to_string().contains("Sink unavailable"));
+}
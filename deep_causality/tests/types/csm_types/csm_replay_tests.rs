@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils;
+
+fn state_action() -> Result<(), ActionError> {
+    Ok(())
+}
+
+fn get_test_action() -> CausalAction {
+    CausalAction::new(state_action, "Test action that does nothing", 1)
+}
+
+fn get_low_threshold_causaloid<'l>() -> BaseCausaloid<'l> {
+    fn causal_fn(obs: NumericalValue) -> Result<bool, CausalityError> {
+        if obs.is_sign_negative() {
+            return Err(CausalityError("Observation is negative".into()));
+        }
+        Ok(obs >= 0.1)
+    }
+
+    Causaloid::new(2, causal_fn, "tests whether data exceeds threshold of 0.1")
+}
+
+fn record_decisions() -> Vec<CSMAuditRecord> {
+    let causaloid = test_utils::get_test_causaloid();
+    let cs = CausalState::new(42, 1, 0.23, &causaloid);
+    let ca = get_test_action();
+    let state_actions = &[(&cs, &ca)];
+
+    let csm = CSM::new_with_audit_log(state_actions, CSMAuditLog::new(10));
+    for data in [0.89, 0.23, 0.05] {
+        csm.eval_single_state(42, data)
+            .expect("Failed to evaluate state");
+    }
+
+    csm.audit_records()
+}
+
+#[test]
+fn test_replay_unchanged() {
+    let records = record_decisions();
+
+    let causaloid = test_utils::get_test_causaloid();
+    let cs = CausalState::new(42, 2, 0.0, &causaloid);
+    let ca = get_test_action();
+    let state_actions = &[(&cs, &ca)];
+    let csm = CSM::new(state_actions);
+
+    let report = csm.replay(&records);
+    assert_eq!(*report.total(), 3);
+    assert_eq!(report.unchanged(), 3);
+    assert!(!report.has_changes());
+}
+
+#[test]
+fn test_replay_changed() {
+    let records = record_decisions();
+
+    let causaloid = get_low_threshold_causaloid();
+    let cs = CausalState::new(42, 2, 0.0, &causaloid);
+    let ca = get_test_action();
+    let state_actions = &[(&cs, &ca)];
+    let csm = CSM::new(state_actions);
+
+    let report = csm.replay(&records);
+    assert!(report.has_changes());
+    assert_eq!(report.unchanged(), 2);
+
+    let change = &report.changes()[0];
+    assert_eq!(*change.seq(), 2);
+    assert_eq!(*change.state_id(), 42);
+    assert_eq!(*change.recorded_version(), 1);
+    assert_eq!(*change.replayed_version(), Some(2));
+    assert_eq!(*change.recorded_decision(), Some(false));
+    assert_eq!(*change.replayed_decision(), Some(true));
+    assert!(change.replay_error().is_none());
+
+    // Replay must not record decisions
+    assert!(csm.audit_records().is_empty());
+}
+
+#[test]
+fn test_replay_missing_state() {
+    let records = record_decisions();
+
+    let causaloid = test_utils::get_test_causaloid();
+    let cs = CausalState::new(7, 1, 0.0, &causaloid);
+    let ca = get_test_action();
+    let state_actions = &[(&cs, &ca)];
+    let csm = CSM::new(state_actions);
+
+    let report = csm.replay(&records);
+    assert_eq!(report.changes().len(), 3);
+
+    let change = &report.changes()[0];
+    assert_eq!(*change.replayed_version(), None);
+    assert_eq!(*change.replayed_decision(), None);
+    assert!(change.replay_error().is_some());
+}
+
+#[test]
+fn test_replay_evaluation_error() {
+    let records = record_decisions();
+
+    let causaloid = test_utils::get_test_error_causaloid();
+    let cs = CausalState::new(42, 2, 0.0, &causaloid);
+    let ca = get_test_action();
+    let state_actions = &[(&cs, &ca)];
+    let csm = CSM::new(state_actions);
+
+    let report = csm.replay(&records);
+    assert_eq!(report.changes().len(), 3);
+    assert!(report.changes()[0].replay_error().is_some());
+    assert_eq!(
+        report.to_string(),
+        "CSMReplayReport: replayed: 3 unchanged: 0 changed: 3"
+    );
+}
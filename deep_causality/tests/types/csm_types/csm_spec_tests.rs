@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils_registry::*;
+
+#[test]
+fn test_csm_round_trip() {
+    let registry = get_test_registry();
+    let c1 = Causaloid::new(1, threshold_fn, "high").with_fn_name("threshold");
+    let c2 = Causaloid::new(2, inverse_threshold_fn, "low").with_fn_name("inverse_threshold");
+    let s1 = CausalState::new(1, 1, 0.9, &c1);
+    let s2 = CausalState::new(2, 3, 0.1, &c2);
+    let action = CausalAction::new(alert_action, "Raises an alert", 2).with_name("alert");
+    let state_actions = &[(&s1, &action), (&s2, &action)];
+    let csm = CSM::new(state_actions);
+
+    let spec = BaseCSMSpec::from_csm(&csm, &registry).unwrap();
+    assert_eq!(spec.states().len(), 2);
+    let state = &spec.states()[1];
+    assert_eq!(state.id(), 2);
+    assert_eq!(state.version(), 3);
+    assert_eq!(state.data(), 0.1);
+    assert_eq!(state.causaloid().description(), "low");
+    assert_eq!(state.action(), "alert");
+    assert_eq!(state.action_version(), 2);
+
+    let json = to_json(&spec).unwrap();
+    let from_json: BaseCSMSpec = from_json(&json).unwrap();
+    assert_eq!(from_json, spec);
+
+    let from_binary: BaseCSMSpec = from_binary(&to_binary(&spec).unwrap()).unwrap();
+    assert_eq!(from_binary, spec);
+
+    let built = from_binary.build(&registry, &[]).unwrap();
+    assert_eq!(built.len(), 2);
+    assert!(built.eval_single_state(1, 0.9).is_ok());
+    assert!(built.eval_all_states().is_ok());
+
+    // A rebuilt CSM describes itself the same way.
+    assert_eq!(BaseCSMSpec::from_csm(&built, &registry).unwrap(), spec);
+}
+
+#[test]
+fn test_csm_spec_errors() {
+    let registry = get_test_registry();
+    let c1 = Causaloid::new(1, threshold_fn, "high").with_fn_name("threshold");
+    let s1 = CausalState::new(1, 1, 0.9, &c1);
+
+    fn unregistered_action() -> Result<(), ActionError> {
+        Ok(())
+    }
+    let action = CausalAction::new(unregistered_action, "unregistered", 1);
+    let state_actions = &[(&s1, &action)];
+    let csm = CSM::new(state_actions);
+    assert_eq!(
+        BaseCSMSpec::from_csm(&csm, &registry)
+            .unwrap_err()
+            .to_string(),
+        "SerializationError: The action of state 1 is not registered"
+    );
+
+    let action =
+        CausalAction::new(unregistered_action, "unregistered", 1).with_name("unregistered");
+    let state_actions = &[(&s1, &action)];
+    let csm = CSM::new(state_actions);
+    assert_eq!(
+        BaseCSMSpec::from_csm(&csm, &registry)
+            .unwrap_err()
+            .to_string(),
+        "SerializationError: Unknown action 'unregistered' in state 1"
+    );
+
+    let causaloid = BaseCausaloidSpec::new(
+        1,
+        "",
+        None,
+        CausaloidSpecKind::Singleton("threshold".into()),
+    );
+    let spec = BaseCSMSpec::new(vec![CSMStateSpec::new(1, 1, 0.9, causaloid, "unknown", 1)]);
+    assert_eq!(
+        spec.build(&registry, &[]).err().unwrap().to_string(),
+        "SerializationError: Unknown action 'unknown' in state 1"
+    );
+}
@@ -116,8 +116,7 @@ fn update_single_state() {
     let csm = CSM::new(state_action);
     assert_eq!(csm.len(), 1);
 
-    let id = 44;
-    let version = 1;
+    let version = 2;
     let data = 0.7f64;
     let causaloid = &test_utils::get_test_causaloid();
 
@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::{ActionError, CausalAction, CausalState, CSM};
+
+use crate::utils::test_utils;
+
+fn state_action() -> Result<(), ActionError> {
+    Ok(())
+}
+
+fn get_test_action(version: usize) -> CausalAction {
+    CausalAction::new(state_action, "Test action that does nothing", version)
+}
+
+#[test]
+fn update_single_state_err_version_not_newer() {
+    let causaloid = test_utils::get_test_causaloid();
+    let cs1 = CausalState::new(42, 2, 0.23, &causaloid);
+    let ca = get_test_action(1);
+    let state_actions = &[(&cs1, &ca)];
+    let csm = CSM::new(state_actions);
+
+    let same = CausalState::new(42, 2, 0.5, &causaloid);
+    assert!(csm.update_single_state(42, (&same, &ca)).is_err());
+
+    let older = CausalState::new(42, 1, 0.5, &causaloid);
+    assert!(csm.update_single_state(42, (&older, &ca)).is_err());
+
+    assert_eq!(csm.state_versions(42), vec![2]);
+}
+
+#[test]
+fn update_single_state_err_id_mismatch() {
+    let causaloid = test_utils::get_test_causaloid();
+    let cs1 = CausalState::new(42, 1, 0.23, &causaloid);
+    let ca = get_test_action(1);
+    let state_actions = &[(&cs1, &ca)];
+    let csm = CSM::new(state_actions);
+
+    let other = CausalState::new(7, 2, 0.5, &causaloid);
+    let err = csm.update_single_state(42, (&other, &ca)).unwrap_err();
+    assert!(err.to_string().contains("id 7"));
+
+    assert_eq!(csm.state_versions(42), vec![1]);
+    assert!(csm.state_versions(7).is_empty());
+}
+
+#[test]
+fn update_single_state_err_action_version_older() {
+    let causaloid = test_utils::get_test_causaloid();
+    let cs1 = CausalState::new(42, 1, 0.23, &causaloid);
+    let ca2 = get_test_action(2);
+    let state_actions = &[(&cs1, &ca2)];
+    let csm = CSM::new(state_actions);
+
+    let cs2 = CausalState::new(42, 2, 0.23, &causaloid);
+    let ca1 = get_test_action(1);
+    assert!(csm.update_single_state(42, (&cs2, &ca1)).is_err());
+    assert!(csm.update_single_state(42, (&cs2, &ca2)).is_ok());
+}
+
+#[test]
+fn rollback() {
+    let causaloid = test_utils::get_test_causaloid();
+    let cs1 = CausalState::new(42, 1, 0.23, &causaloid);
+    let cs2 = CausalState::new(42, 2, 0.89, &causaloid);
+    let cs3 = CausalState::new(42, 3, 0.42, &causaloid);
+    let ca = get_test_action(1);
+    let state_actions = &[(&cs1, &ca)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.update_single_state(42, (&cs2, &ca)).is_ok());
+    assert!(csm.update_single_state(42, (&cs3, &ca)).is_ok());
+    assert_eq!(csm.state_versions(42), vec![1, 2, 3]);
+
+    assert!(csm.rollback(42).is_ok());
+    assert_eq!(csm.state_versions(42), vec![1, 2]);
+
+    // After a rollback, the discarded version may be applied again.
+    assert!(csm.update_single_state(42, (&cs3, &ca)).is_ok());
+    assert_eq!(csm.state_versions(42), vec![1, 2, 3]);
+
+    assert!(csm.rollback(42).is_ok());
+    assert!(csm.rollback(42).is_ok());
+    assert_eq!(csm.state_versions(42), vec![1]);
+    assert_eq!(csm.len(), 1);
+}
+
+#[test]
+fn rollback_err() {
+    let causaloid = test_utils::get_test_causaloid();
+    let cs1 = CausalState::new(42, 1, 0.23, &causaloid);
+    let ca = get_test_action(1);
+    let state_actions = &[(&cs1, &ca)];
+    let csm = CSM::new(state_actions);
+
+    // No previous version
+    assert!(csm.rollback(42).is_err());
+    // State does not exist
+    assert!(csm.rollback(99).is_err());
+    assert!(csm.state_versions(99).is_empty());
+}
+
+#[test]
+fn remove_single_state_drops_versions() {
+    let causaloid = test_utils::get_test_causaloid();
+    let cs1 = CausalState::new(42, 1, 0.23, &causaloid);
+    let cs2 = CausalState::new(42, 2, 0.89, &causaloid);
+    let ca = get_test_action(1);
+    let state_actions = &[(&cs1, &ca)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.update_single_state(42, (&cs2, &ca)).is_ok());
+    assert!(csm.remove_single_state(42).is_ok());
+    assert!(csm.add_single_state(42, (&cs1, &ca)).is_ok());
+    assert!(csm.rollback(42).is_err());
+}
+
+#[test]
+fn state_diff() {
+    let causaloid = test_utils::get_test_causaloid();
+    let error_causaloid = test_utils::get_test_error_causaloid();
+    let cs1 = CausalState::new(42, 1, 0.23, &causaloid);
+    let cs2 = CausalState::new(42, 2, 0.89, &error_causaloid);
+    let ca1 = get_test_action(1);
+    let ca2 = CausalAction::new(state_action, "Updated action", 2);
+    let state_actions = &[(&cs1, &ca1)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.update_single_state(42, (&cs2, &ca2)).is_ok());
+
+    let diff = csm.state_diff(42, 1, 2).expect("Failed to diff versions");
+    assert!(diff.has_changes());
+    assert_eq!(*diff.state_id(), 42);
+    assert_eq!(*diff.from_version(), 1);
+    assert_eq!(*diff.to_version(), 2);
+    assert_eq!(*diff.data(), Some((0.23, 0.89)));
+    // Both test causaloids share id and description
+    assert_eq!(*diff.causaloid_id(), None);
+    assert_eq!(*diff.causaloid_description(), None);
+    assert_eq!(*diff.action_version(), Some((1, 2)));
+    assert_eq!(
+        *diff.action_description(),
+        Some((
+            "Test action that does nothing".to_string(),
+            "Updated action".to_string()
+        ))
+    );
+
+    let expected = "CSMStateDiff: state: 42 version: 1 -> 2\n data: 0.23 -> 0.89\n action version: 1 -> 2\n action description: Test action that does nothing -> Updated action";
+    assert_eq!(diff.to_string(), expected);
+
+    let same = csm.state_diff(42, 2, 2).expect("Failed to diff versions");
+    assert!(!same.has_changes());
+}
+
+#[test]
+fn state_diff_err() {
+    let causaloid = test_utils::get_test_causaloid();
+    let cs1 = CausalState::new(42, 1, 0.23, &causaloid);
+    let ca = get_test_action(1);
+    let state_actions = &[(&cs1, &ca)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.state_diff(42, 1, 7).is_err());
+    assert!(csm.state_diff(99, 1, 1).is_err());
+}
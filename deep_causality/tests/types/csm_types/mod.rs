@@ -3,7 +3,17 @@
 
 #[cfg(test)]
 mod csm_action_tests;
+#[cfg(all(test, feature = "serde"))]
+mod csm_audit_json_tests;
+#[cfg(test)]
+mod csm_audit_tests;
+#[cfg(test)]
+mod csm_replay_tests;
+#[cfg(all(test, feature = "serde"))]
+mod csm_spec_tests;
 #[cfg(test)]
 mod csm_state_tests;
 #[cfg(test)]
 mod csm_tests;
+#[cfg(test)]
+mod csm_versioning_tests;
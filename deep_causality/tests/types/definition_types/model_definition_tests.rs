@@ -0,0 +1,376 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils_registry::*;
+
+const MODEL_JSON: &str = r#"{
+    "id": 1,
+    "author": "ops",
+    "description": "Detects overheating",
+    "causaloid": "overheating",
+    "causaloids": [
+        { "name": "high_temperature", "id": 1, "function": "threshold", "data_index": 0 },
+        { "name": "sustained", "id": 2, "contextual_function": "contextual_threshold", "data_index": 1 },
+        {
+            "name": "overheating",
+            "id": 3,
+            "description": "Overheating",
+            "graph": {
+                "root": "high_temperature",
+                "nodes": ["sustained"],
+                "edges": [{ "from": "high_temperature", "to": "sustained", "weight": 1 }]
+            }
+        },
+        { "name": "any", "id": 4, "collection": ["high_temperature", "sustained"] }
+    ],
+    "assumptions": [{ "id": 1, "description": "Data are not empty", "function": "has_data" }],
+    "context": {
+        "id": 7,
+        "name": "sensors",
+        "nodes": [
+            { "id": 1, "vertex_type": { "Datoid": { "id": 1, "data": 5 } } },
+            { "id": 2, "vertex_type": { "Datoid": { "id": 2, "data": 6 } } }
+        ],
+        "edges": [{ "from": 1, "to": 2, "relation": "Temporal" }]
+    },
+    "states": [
+        { "id": 1, "data": 0.9, "causaloid": "high_temperature", "action": "alert" },
+        { "id": 2, "version": 3, "data": 0.9, "causaloid": "any", "action": "alert", "action_version": 2 }
+    ]
+}"#;
+
+// Minimal model definition with the given causaloids in JSON.
+fn definition(causaloid: &str, causaloids: &str) -> String {
+    format!(
+        r#"{{ "id": 1, "causaloid": "{}", "causaloids": [{}] }}"#,
+        causaloid, causaloids
+    )
+}
+
+fn model_spec_error(json: &str) -> String {
+    let registry = get_test_registry();
+    BaseModelDefinition::from_json(json)
+        .unwrap()
+        .model_spec(&registry)
+        .err()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn test_model_from_json() {
+    let registry = get_test_registry();
+    let definition = BaseModelDefinition::from_json(MODEL_JSON).unwrap();
+    assert_eq!(*definition.id(), 1);
+    assert_eq!(definition.author(), "ops");
+    assert_eq!(definition.causaloids().len(), 4);
+    assert_eq!(definition.states().len(), 2);
+
+    let spec = definition.model_spec(&registry).unwrap();
+    assert_eq!(spec.id(), 1);
+    assert_eq!(spec.description(), "Detects overheating");
+    assert_eq!(spec.assumptions().unwrap().len(), 1);
+    assert_eq!(spec.causaloid().id(), 3);
+    assert_eq!(spec.causaloid().description(), "Overheating");
+    assert_eq!(spec.context().unwrap().id(), 7);
+    match spec.causaloid().kind() {
+        CausaloidSpecKind::Graph(graph) => {
+            assert_eq!(graph.root(), Some(0));
+            assert_eq!(graph.nodes().len(), 2);
+            assert_eq!(graph.edges(), &vec![(0, 1, 1)]);
+            // The contextual causaloid refers to the context of the model.
+            assert_eq!(graph.nodes()[1].1.context(), Some(7));
+        }
+        kind => panic!("Expected a graph causaloid, got {:?}", kind),
+    }
+
+    let model = spec.build(&registry).unwrap();
+    let context = model.context().unwrap();
+    assert_eq!(context.node_count(), 2);
+    assert_eq!(context.edge_count(), 1);
+    assert_eq!(model.causaloid().id(), 3);
+
+    let data_index = definition.data_index().unwrap();
+    assert_eq!(data_index, HashMap::from([(1, 0), (2, 1)]));
+    assert!(model.validate(&[0.9, 0.8], Some(&data_index)).is_valid());
+    assert!(model.evaluate(&[0.9, 0.8], Some(&data_index)).unwrap());
+}
+
+#[test]
+fn test_csm_from_json() {
+    let registry = get_test_registry();
+    let definition = BaseModelDefinition::from_json(MODEL_JSON).unwrap();
+    let model_spec = definition.model_spec(&registry).unwrap();
+    let model = model_spec.build(&registry).unwrap();
+
+    let spec = definition.csm_spec(&registry).unwrap();
+    assert_eq!(spec.states().len(), 2);
+    // The version of the action defaults to the version of the registered action.
+    assert_eq!(spec.states()[0].action_version(), 1);
+    assert_eq!(spec.states()[1].version(), 3);
+    assert_eq!(spec.states()[1].action_version(), 2);
+
+    let csm = spec.build(&registry, &[model.context().unwrap()]).unwrap();
+    assert_eq!(csm.len(), 2);
+    assert!(csm.eval_single_state(1, 0.9).is_ok());
+}
+
+#[test]
+fn test_model_definition_round_trip() {
+    let definition = BaseModelDefinition::from_json(MODEL_JSON).unwrap();
+    let json = to_json(&definition).unwrap();
+    assert_eq!(BaseModelDefinition::from_json(&json).unwrap(), definition);
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn test_model_from_toml() {
+    let registry = get_test_registry();
+    let toml = r#"
+        id = 1
+        author = "ops"
+        causaloid = "overheating"
+
+        [[causaloids]]
+        name = "high_temperature"
+        id = 1
+        function = "threshold"
+        data_index = 0
+
+        [[causaloids]]
+        name = "low_temperature"
+        id = 2
+        function = "inverse_threshold"
+        data_index = 1
+
+        [[causaloids]]
+        name = "overheating"
+        id = 3
+        graph = { root = "high_temperature", nodes = ["low_temperature"], edges = [{ from = "high_temperature", to = "low_temperature" }] }
+
+        [[states]]
+        id = 1
+        data = 0.9
+        causaloid = "high_temperature"
+        action = "alert"
+    "#;
+
+    let definition = BaseModelDefinition::from_toml(toml).unwrap();
+    let spec = definition.model_spec(&registry).unwrap();
+    assert!(spec.context().is_none());
+    let model = spec.build(&registry).unwrap();
+    let data_index = definition.data_index().unwrap();
+    assert!(!model.evaluate(&[0.9, 0.9], Some(&data_index)).unwrap());
+    assert!(definition.csm_spec(&registry).is_ok());
+
+    let toml = to_toml(&definition).unwrap();
+    assert_eq!(BaseModelDefinition::from_toml(&toml).unwrap(), definition);
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn test_model_from_yaml() {
+    let registry = get_test_registry();
+    let yaml = r#"
+id: 1
+causaloid: any
+causaloids:
+  - name: high_temperature
+    id: 1
+    function: threshold
+  - name: low_temperature
+    id: 2
+    function: inverse_threshold
+  - name: any
+    id: 3
+    collection: [high_temperature, low_temperature]
+"#;
+
+    let definition = BaseModelDefinition::from_yaml(yaml).unwrap();
+    let spec = definition.model_spec(&registry).unwrap();
+    match spec.causaloid().kind() {
+        CausaloidSpecKind::Collection(members) => assert_eq!(members.len(), 2),
+        kind => panic!("Expected a collection causaloid, got {:?}", kind),
+    }
+    assert!(spec.build(&registry).is_ok());
+
+    let yaml = to_yaml(&definition).unwrap();
+    assert_eq!(BaseModelDefinition::from_yaml(&yaml).unwrap(), definition);
+}
+
+#[test]
+fn test_invalid_definition() {
+    let err = BaseModelDefinition::from_json(r#"{ "id": 1, "causaloid": "a", "unknown": 1 }"#)
+        .err()
+        .unwrap();
+    assert!(err
+        .to_string()
+        .starts_with("DefinitionError: Invalid model definition: unknown field `unknown`"));
+}
+
+#[test]
+fn test_unknown_function() {
+    let json = definition("a", r#"{ "name": "a", "id": 1, "function": "missing" }"#);
+    assert_eq!(
+        model_spec_error(&json),
+        "DefinitionError: Causaloid 'a' uses unknown causal function 'missing'; \
+         registered causal functions: [\"inverse_threshold\", \"threshold\"]"
+    );
+}
+
+#[test]
+fn test_unknown_assumption_function() {
+    let json = r#"{
+        "id": 1,
+        "causaloid": "a",
+        "causaloids": [{ "name": "a", "id": 1, "function": "threshold" }],
+        "assumptions": [{ "id": 2, "function": "missing" }]
+    }"#;
+    assert_eq!(
+        model_spec_error(json),
+        "DefinitionError: Assumption 2 uses unknown assumption function 'missing'; \
+         registered assumption functions: [\"has_data\"]"
+    );
+}
+
+#[test]
+fn test_dangling_edge() {
+    let json = definition(
+        "g",
+        r#"{ "name": "a", "id": 1, "function": "threshold" },
+           { "name": "g", "id": 2, "graph": { "root": "a", "edges": [{ "from": "a", "to": "b" }] } }"#,
+    );
+    assert_eq!(
+        model_spec_error(&json),
+        "DefinitionError: Edge 'a' -> 'b' of causaloid 'g' refers to 'b', \
+         which is not a node of the graph"
+    );
+}
+
+#[test]
+fn test_unknown_causaloid() {
+    let json = definition("b", r#"{ "name": "a", "id": 1, "function": "threshold" }"#);
+    assert_eq!(
+        model_spec_error(&json),
+        "DefinitionError: Unknown causaloid 'b' referenced by the model"
+    );
+
+    let json = definition("c", r#"{ "name": "c", "id": 1, "collection": ["a"] }"#);
+    assert_eq!(
+        model_spec_error(&json),
+        "DefinitionError: Unknown causaloid 'a' referenced by causaloid 'c'"
+    );
+}
+
+#[test]
+fn test_causaloid_contains_itself() {
+    let json = definition(
+        "a",
+        r#"{ "name": "a", "id": 1, "collection": ["b"] },
+           { "name": "b", "id": 2, "graph": { "root": "a" } }"#,
+    );
+    assert_eq!(
+        model_spec_error(&json),
+        "DefinitionError: Causaloid 'a' contains itself: a -> b -> a"
+    );
+}
+
+#[test]
+fn test_invalid_causaloids() {
+    let json = definition(
+        "a",
+        r#"{ "name": "a", "id": 1, "function": "threshold", "collection": ["a"] }"#,
+    );
+    assert_eq!(
+        model_spec_error(&json),
+        "DefinitionError: Causaloid 'a' must have exactly one of function, \
+         contextual_function, collection, or graph"
+    );
+
+    let json = definition(
+        "a",
+        r#"{ "name": "a", "id": 1, "function": "threshold" },
+           { "name": "a", "id": 2, "function": "threshold" }"#,
+    );
+    assert_eq!(
+        model_spec_error(&json),
+        "DefinitionError: Duplicate causaloid name 'a'"
+    );
+
+    let json = definition("a", r#"{ "name": "a", "id": 1, "collection": [] }"#);
+    assert_eq!(
+        model_spec_error(&json),
+        "DefinitionError: Causaloid 'a' has an empty collection"
+    );
+
+    let json = definition(
+        "a",
+        r#"{ "name": "a", "id": 1, "contextual_function": "contextual_threshold" }"#,
+    );
+    assert_eq!(
+        model_spec_error(&json),
+        "DefinitionError: Causaloid 'a' uses a contextual causal function, \
+         but the model has no context"
+    );
+}
+
+#[test]
+fn test_unknown_contextoid() {
+    let json = r#"{
+        "id": 1,
+        "causaloid": "a",
+        "causaloids": [{ "name": "a", "id": 1, "function": "threshold" }],
+        "context": {
+            "id": 1,
+            "name": "ctx",
+            "nodes": [{ "id": 1, "vertex_type": { "Datoid": { "id": 1, "data": 5 } } }],
+            "edges": [{ "from": 1, "to": 9, "relation": "Temporal" }]
+        }
+    }"#;
+    assert_eq!(
+        model_spec_error(json),
+        "DefinitionError: Context edge 1 -> 9 refers to unknown contextoid 9"
+    );
+}
+
+#[test]
+fn test_unknown_action() {
+    let registry = get_test_registry();
+    let json = r#"{
+        "id": 1,
+        "causaloid": "a",
+        "causaloids": [{ "name": "a", "id": 1, "function": "threshold" }],
+        "states": [{ "id": 1, "data": 0.9, "causaloid": "a", "action": "missing" }]
+    }"#;
+    let err = BaseModelDefinition::from_json(json)
+        .unwrap()
+        .csm_spec(&registry)
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "DefinitionError: State 1 uses unknown action 'missing'; registered actions: [\"alert\"]"
+    );
+}
+
+#[test]
+fn test_conflicting_data_index() {
+    let json = definition(
+        "a",
+        r#"{ "name": "a", "id": 1, "function": "threshold", "data_index": 0 },
+           { "name": "b", "id": 1, "function": "threshold", "data_index": 1 }"#,
+    );
+    let err = BaseModelDefinition::from_json(&json)
+        .unwrap()
+        .data_index()
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "DefinitionError: Causaloid id 1 has the data indices 0 and 1"
+    );
+}
@@ -2,5 +2,8 @@
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 mod context_types;
 mod csm_types;
+#[cfg(all(test, feature = "serde"))]
+mod definition_types;
 mod model_types;
 mod reasoning_types;
+mod registry_types;
@@ -1,5 +1,11 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
+#[cfg(all(test, feature = "serde"))]
+mod model_spec_tests;
+
 #[cfg(test)]
 mod model_tests;
+
+#[cfg(test)]
+mod model_validation_tests;
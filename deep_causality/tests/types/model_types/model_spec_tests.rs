@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils::get_test_context;
+use crate::utils::test_utils_registry::*;
+
+#[test]
+fn test_model_round_trip() {
+    let registry = get_test_registry();
+    let context = get_test_context();
+    let assumption =
+        Assumption::new(1, "Data are not empty".into(), has_data_fn).with_fn_name("has_data");
+    let assumptions = vec![&assumption];
+    let causaloid =
+        Causaloid::new_with_context(2, contextual_threshold_fn, Some(&context), "contextual")
+            .with_fn_name("contextual_threshold");
+    let model = Model::new(
+        3,
+        "author",
+        "model",
+        Some(&assumptions),
+        &causaloid,
+        Some(&context),
+    );
+
+    let spec = BaseModelSpec::from_model(&model, &registry).unwrap();
+    assert_eq!(spec.id(), 3);
+    assert_eq!(spec.author(), "author");
+    assert_eq!(spec.description(), "model");
+    assert_eq!(spec.assumptions().unwrap().len(), 1);
+    assert_eq!(spec.causaloid().context(), Some(context.id()));
+    assert_eq!(spec.context().unwrap().id(), context.id());
+
+    let json = to_json_pretty(&spec).unwrap();
+    let from_json: BaseModelSpec = from_json(&json).unwrap();
+    assert_eq!(from_json, spec);
+
+    let from_binary: BaseModelSpec = from_binary(&to_binary(&spec).unwrap()).unwrap();
+    assert_eq!(from_binary, spec);
+
+    let built = from_binary.build(&registry).unwrap();
+    assert_eq!(built.id(), 3);
+    assert_eq!(*built.author(), "author");
+    assert_eq!(built.context().unwrap().node_count(), context.node_count());
+    // The causaloid uses the context of the model.
+    assert!(std::ptr::eq(
+        built.causaloid().context().unwrap(),
+        built.context().unwrap()
+    ));
+
+    let report = built.validate(&[0.9], None);
+    assert!(report.is_valid());
+    assert!(built.evaluate(&[0.9], None).unwrap());
+}
+
+#[test]
+fn test_model_without_context() {
+    let registry = get_test_registry();
+    let causaloid = Causaloid::new(1, threshold_fn, "threshold").with_fn_name("threshold");
+    let model = Model::new(1, "", "", None, &causaloid, None);
+
+    let spec = BaseModelSpec::from_model(&model, &registry).unwrap();
+    assert!(spec.assumptions().is_none());
+    assert!(spec.context().is_none());
+
+    let spec: BaseModelSpec = from_json(&to_json(&spec).unwrap()).unwrap();
+    let built = spec.build(&registry).unwrap();
+    assert!(built.assumptions().is_none());
+    assert!(built.context().is_none());
+    assert!(built.evaluate(&[0.9], None).unwrap());
+}
+
+#[test]
+fn test_model_spec_errors() {
+    let registry = get_test_registry();
+    let causaloid = BaseCausaloidSpec::new(
+        1,
+        "",
+        Some(42),
+        CausaloidSpecKind::Contextual("contextual_threshold".into()),
+    );
+    let spec = BaseModelSpec::new(1, "", "", None, causaloid, None);
+
+    assert_eq!(
+        spec.build(&registry).unwrap_err().to_string(),
+        "SerializationError: Causaloid 1 refers to unknown context 42"
+    );
+}
@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils::*;
+use crate::utils::test_utils_graph::*;
+
+fn contextual_causal_fn(obs: NumericalValue, _ctx: &BaseContext) -> Result<bool, CausalityError> {
+    Ok(obs >= 0.55)
+}
+
+fn get_failing_assumption() -> Assumption {
+    fn has_ten_values(data: &[NumericalValue]) -> bool {
+        data.len() == 10
+    }
+
+    Assumption::new(2, "Data have ten values".into(), has_ten_values)
+}
+
+#[test]
+fn test_validate() {
+    let a1 = get_test_assumption();
+    let assumptions = vec![&a1];
+    let causaloid = get_test_causaloid();
+
+    let model = Model::new(1, "", "", Some(&assumptions), &causaloid, None);
+    let report = model.validate(&get_test_num_array(), None);
+
+    assert!(report.is_valid());
+    assert_eq!(*report.model_id(), 1);
+    assert_eq!(*report.assumptions_verified(), 1);
+    assert_eq!(*report.assumption_confidence(), 1.0);
+    assert_eq!(*report.evaluation(), Some(true));
+    assert!(a1.assumption_valid());
+}
+
+#[test]
+fn test_validate_invalid_assumption() {
+    let a1 = get_test_assumption();
+    let a2 = get_failing_assumption();
+    let assumptions = vec![&a1, &a2];
+    let causaloid = get_test_causaloid();
+
+    let model = Model::new(1, "", "", Some(&assumptions), &causaloid, None);
+    let report = model.validate(&[0.1, 0.9], None);
+
+    assert!(!report.is_valid());
+    assert_eq!(
+        *report.issues(),
+        vec![ValidationIssue::InvalidAssumption(2)]
+    );
+    assert_eq!(*report.assumption_confidence(), 0.5);
+    assert_eq!(*report.evaluation(), Some(false));
+}
+
+#[test]
+fn test_validate_missing_context() {
+    let causaloid = Causaloid::new_with_context(3, contextual_causal_fn, None, "");
+
+    let model = Model::new(1, "", "", None, &causaloid, None);
+    let report = model.validate(&[0.9], None);
+
+    assert_eq!(*report.issues(), vec![ValidationIssue::MissingContext(3)]);
+    assert!(report.evaluation().is_none());
+}
+
+#[test]
+fn test_validate_collection() {
+    let coll = get_test_causality_vec();
+    let causaloid = Causaloid::from_causal_collection(4, &coll, "");
+    let model = Model::new(1, "", "", None, &causaloid, None);
+
+    let report = model.validate(&[0.9, 0.9, 0.9], None);
+    assert!(report.is_valid());
+    assert_eq!(*report.evaluation(), Some(true));
+
+    // The third causaloid has no data.
+    let report = model.validate(&[0.9, 0.9], None);
+    assert_eq!(*report.issues(), vec![ValidationIssue::UnresolvedData(1)]);
+
+    let empty: BaseCausaloidVec = Vec::new();
+    let causaloid = Causaloid::from_causal_collection(5, &empty, "");
+    let model = Model::new(1, "", "", None, &causaloid, None);
+    let report = model.validate(&[0.9], None);
+    assert_eq!(*report.issues(), vec![ValidationIssue::EmptyCollection(5)]);
+}
+
+#[test]
+fn test_validate_graph() {
+    let (graph, data) = get_small_linear_graph_and_data();
+    let causaloid = Causaloid::from_causal_graph(6, &graph, "");
+    let model = Model::new(1, "", "", None, &causaloid, None);
+
+    let report = model.validate(&data, None);
+    assert!(report.is_valid());
+    assert_eq!(*report.evaluation(), Some(true));
+
+    // All causaloids have id 1, so the data index must map 1.
+    let data_index = HashMap::from([(2, 0)]);
+    let report = model.validate(&data, Some(&data_index));
+    assert!(!report.is_valid());
+    assert!(report
+        .issues()
+        .iter()
+        .all(|i| *i == ValidationIssue::UnresolvedData(1)));
+
+    let data_index = HashMap::from([(1, 0)]);
+    let report = model.validate(&data, Some(&data_index));
+    assert!(report.is_valid());
+}
+
+#[test]
+fn test_validate_cyclic_graph() {
+    let mut graph = CausaloidGraph::new();
+    let a = graph.add_root_causaloid(get_test_causaloid());
+    let b = graph.add_causaloid(get_test_causaloid());
+    graph.add_edge(a, b).unwrap();
+    graph.add_edge(b, a).unwrap();
+
+    let causaloid = Causaloid::from_causal_graph(7, &graph, "");
+    let model = Model::new(1, "", "", None, &causaloid, None);
+
+    let report = model.validate(&[0.9, 0.9], None);
+    assert_eq!(*report.issues(), vec![ValidationIssue::CyclicGraph(7)]);
+    assert!(report.evaluation().is_none());
+    assert!(model.evaluate(&[0.9, 0.9], None).is_err());
+}
+
+#[test]
+fn test_validate_missing_root() {
+    let mut graph = CausaloidGraph::new();
+    graph.add_causaloid(get_test_causaloid());
+
+    let causaloid = Causaloid::from_causal_graph(8, &graph, "");
+    let model = Model::new(1, "", "", None, &causaloid, None);
+
+    let report = model.validate(&[0.9, 0.9], None);
+    assert_eq!(*report.issues(), vec![ValidationIssue::MissingRoot(8)]);
+}
+
+#[test]
+fn test_validate_evaluation_failed() {
+    let causaloid = get_test_error_causaloid();
+    let model = Model::new(1, "", "", None, &causaloid, None);
+
+    let report = model.validate(&[0.9], None);
+    assert_eq!(report.issues().len(), 1);
+    assert!(matches!(
+        report.issues()[0],
+        ValidationIssue::EvaluationFailed(_)
+    ));
+    assert!(report.evaluation().is_none());
+}
+
+#[test]
+fn test_evaluate() {
+    let a1 = get_test_assumption();
+    let assumptions = vec![&a1];
+    let causaloid = get_test_causaloid();
+    let model = Model::new(1, "", "", Some(&assumptions), &causaloid, None);
+
+    // Untested assumptions
+    let res = model.evaluate(&[0.9], None);
+    assert!(res.is_err());
+
+    model.validate(&[0.9], None);
+    assert!(model.evaluate(&[0.9], None).unwrap());
+    assert!(!model.evaluate(&[0.9, 0.1], None).unwrap());
+    assert!(model.evaluate(&[], None).is_err());
+
+    // A failed verification blocks evaluation.
+    a1.verify_assumption(&[]);
+    let res = model.evaluate(&[0.9], None);
+    assert!(res.unwrap_err().to_string().contains("[1]"));
+}
+
+#[test]
+fn test_report_display() {
+    let causaloid = get_test_causaloid();
+    let model = Model::new(1, "", "", None, &causaloid, None);
+    let report = model.validate(&[0.9], None);
+
+    assert_eq!(
+        report.to_string(),
+        "ModelValidationReport: model: 1 valid: true assumptions verified: 0 confidence: 1 issues: 0"
+    );
+    assert_eq!(
+        ValidationIssue::CyclicGraph(7).to_string(),
+        "Causaloid 7 has a cyclic graph"
+    );
+}
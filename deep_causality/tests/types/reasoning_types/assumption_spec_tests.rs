@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils::get_test_normal_series;
+use crate::utils::test_utils_registry::*;
+
+#[test]
+fn test_function_assumption_round_trip() {
+    let registry = get_test_registry();
+    let assumption =
+        Assumption::new(1, "Data are not empty".into(), has_data_fn).with_fn_name("has_data");
+    assumption.verify_assumption(&[0.1]);
+
+    let spec = AssumptionSpec::from_assumption(&assumption, &registry).unwrap();
+    assert_eq!(spec.id(), 1);
+    assert_eq!(spec.description(), "Data are not empty");
+    assert_eq!(
+        *spec.kind(),
+        AssumptionSpecKind::Function("has_data".to_string())
+    );
+
+    let spec: AssumptionSpec = from_json(&to_json(&spec).unwrap()).unwrap();
+    let built = spec.build(&registry).unwrap();
+    assert_eq!(built.id(), 1);
+    assert_eq!(built.description(), "Data are not empty");
+    assert_eq!(built.fn_name(), Some("has_data"));
+    // The verification state is not persisted.
+    assert!(!built.assumption_tested());
+    assert!(built.verify_assumption(&[0.1]));
+    assert!(!built.verify_assumption(&[]));
+}
+
+#[test]
+fn test_statistical_assumption_round_trip() {
+    let registry = get_test_registry();
+    let assumption = Assumption::new_statistical(
+        2,
+        "Data are normal".into(),
+        StatisticalTest::Normality,
+        0.01,
+    );
+
+    let spec = AssumptionSpec::from_assumption(&assumption, &registry).unwrap();
+    let spec: AssumptionSpec = from_binary(&to_binary(&spec).unwrap()).unwrap();
+    assert_eq!(
+        *spec.kind(),
+        AssumptionSpecKind::Statistical {
+            test: StatisticalTest::Normality,
+            significance: 0.01
+        }
+    );
+
+    let built = spec.build(&registry).unwrap();
+    assert_eq!(
+        built.statistical_test(),
+        Some((StatisticalTest::Normality, 0.01))
+    );
+    assert!(built.verify_assumption(&get_test_normal_series(200, 7)));
+}
+
+#[test]
+fn test_assumption_spec_errors() {
+    let registry = get_test_registry();
+
+    fn unregistered_fn(_data: &[NumericalValue]) -> bool {
+        true
+    }
+    let assumption = Assumption::new(3, "".into(), unregistered_fn);
+    let res = AssumptionSpec::from_assumption(&assumption, &registry);
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        "SerializationError: The assumption function of assumption 3 is not registered"
+    );
+
+    let assumption = Assumption::new(3, "".into(), unregistered_fn).with_fn_name("unregistered");
+    let res = AssumptionSpec::from_assumption(&assumption, &registry);
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        "SerializationError: Unknown assumption function 'unregistered' in assumption 3"
+    );
+
+    let spec = AssumptionSpec::new(4, "", AssumptionSpecKind::Function("unknown".into()));
+    assert_eq!(
+        spec.build(&registry).unwrap_err().to_string(),
+        "SerializationError: Unknown assumption function 'unknown' in assumption 4"
+    );
+}
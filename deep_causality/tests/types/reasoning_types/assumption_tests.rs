@@ -1,7 +1,10 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
-use deep_causality::prelude::{DescriptionValue, Identifiable};
+use deep_causality::prelude::{
+    Assumption, DescriptionValue, Identifiable, NumericalValue, StatisticalTest,
+    DEFAULT_SIGNIFICANCE,
+};
 use deep_causality::protocols::assumable::Assumable;
 
 use crate::utils::test_utils::*;
@@ -86,3 +89,66 @@ fn test_assumption_to_string() {
     let actual = assumption.to_string();
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_verify_assumption_invalidates() {
+    let assumption = get_test_assumption();
+
+    let data = get_test_num_array();
+    assert!(assumption.verify_assumption(&data));
+    assert!(assumption.assumption_valid());
+    assert_eq!(assumption.confidence(), 1.0);
+
+    // A later failure sets the assumption back to invalid.
+    assert!(!assumption.verify_assumption(&[]));
+    assert!(assumption.assumption_tested());
+    assert!(!assumption.assumption_valid());
+    assert_eq!(assumption.confidence(), 0.0);
+
+    let history = assumption.verification_history();
+    assert_eq!(history.len(), 2);
+    assert!(*history[0].valid());
+    assert!(!*history[1].valid());
+    assert!(history[1].test_result().is_none());
+}
+
+#[test]
+fn test_statistical_assumption() {
+    let description = "Residuals are normally distributed".to_string() as DescriptionValue;
+    let assumption = Assumption::new_statistical(2, description, StatisticalTest::Normality, 0.01);
+
+    assert_eq!(
+        assumption.statistical_test(),
+        Some((StatisticalTest::Normality, 0.01))
+    );
+    assert_eq!(assumption.confidence(), 0.0);
+
+    let normal = get_test_normal_series(500, 1);
+    assert!(assumption.verify_assumption(&normal));
+    assert!(assumption.assumption_valid());
+
+    let history = assumption.verification_history();
+    let result = history[0].test_result().unwrap();
+    assert_eq!(*history[0].confidence(), *result.p_value());
+    assert_eq!(*result.sample_size(), 500);
+
+    // The assumption function runs the test at the configured significance level.
+    assert!((assumption.assumption_fn())(&normal));
+    let strict = Assumption::new_statistical(
+        3,
+        "Residuals are strictly normal".into(),
+        StatisticalTest::Normality,
+        (*result.p_value() + DEFAULT_SIGNIFICANCE).min(1.0),
+    );
+    assert!(!(strict.assumption_fn())(&normal));
+    assert!(!strict.verify_assumption(&normal));
+
+    let skewed: Vec<NumericalValue> = normal.iter().map(|x| x.exp()).collect();
+    assert!(!assumption.verify_assumption(&skewed));
+    assert!(assumption.confidence() < 0.01);
+
+    // Too few observations
+    assert!(!assumption.verify_assumption(&[1.0, 2.0]));
+    assert_eq!(assumption.verification_history().len(), 3);
+    assert!(assumption.verification_history()[2].test_result().is_none());
+}
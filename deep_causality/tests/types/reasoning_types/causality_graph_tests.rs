@@ -4,7 +4,7 @@
 use deep_causality::prelude::*;
 use ultragraph::prelude::*;
 
-use crate::utils::test_utils;
+use crate::utils::{test_utils, test_utils_graph};
 
 // Custom type alias
 type CustomCausaloidGraph = CausaloidGraph<
@@ -436,3 +436,49 @@ fn test_get_graph() {
     assert_eq!(graph.number_edges(), 0);
     assert_eq!(graph.number_nodes(), 0);
 }
+
+#[test]
+fn test_get_predecessors() {
+    // root -> A, root -> B, A -> C, B -> C
+    let (g, _) = test_utils_graph::get_small_multi_cause_graph_and_data();
+
+    assert_eq!(g.get_predecessors(3).unwrap(), vec![1, 2]);
+    assert_eq!(g.get_predecessors(1).unwrap(), vec![0]);
+    assert!(g.get_predecessors(0).unwrap().is_empty());
+    assert!(g.get_predecessors(99).is_err());
+}
+
+#[test]
+fn test_get_degree() {
+    let (g, _) = test_utils_graph::get_small_multi_cause_graph_and_data();
+
+    assert_eq!(g.get_in_degree(3).unwrap(), 2);
+    assert_eq!(g.get_out_degree(3).unwrap(), 0);
+    assert_eq!(g.get_in_degree(0).unwrap(), 0);
+    assert_eq!(g.get_out_degree(0).unwrap(), 2);
+    assert!(g.get_in_degree(99).is_err());
+    assert!(g.get_out_degree(99).is_err());
+}
+
+#[test]
+fn test_get_ancestors_and_descendants() {
+    let (g, _) = test_utils_graph::get_small_multi_cause_graph_and_data();
+
+    assert_eq!(g.get_ancestors(3).unwrap(), vec![0, 1, 2]);
+    assert_eq!(g.get_ancestors(1).unwrap(), vec![0]);
+    assert_eq!(g.get_descendants(0).unwrap(), vec![1, 2, 3]);
+    assert!(g.get_descendants(3).unwrap().is_empty());
+    assert!(g.get_ancestors(99).is_err());
+    assert!(g.get_descendants(99).is_err());
+}
+
+#[test]
+fn test_get_sources_and_sinks() {
+    let (mut g, _) = test_utils_graph::get_small_multi_cause_graph_and_data();
+    assert_eq!(g.get_sources(), vec![0]);
+    assert_eq!(g.get_sinks(), vec![3]);
+
+    let index = g.add_causaloid(test_utils::get_test_causaloid());
+    assert_eq!(g.get_sources(), vec![0, index]);
+    assert_eq!(g.get_sinks(), vec![3, index]);
+}
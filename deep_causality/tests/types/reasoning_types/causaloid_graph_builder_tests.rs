@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use deep_causality::prelude::*;
+use ultragraph::prelude::*;
+
+use crate::utils::test_utils_registry::threshold_fn;
+
+fn causaloid<'l>(id: IdentificationValue) -> BaseCausaloid<'l> {
+    Causaloid::new(
+        id,
+        threshold_fn,
+        "tests whether data exceeds threshold of 0.55",
+    )
+}
+
+#[test]
+fn test_build() {
+    let mut builder = BaseCausaloidGraphBuilder::new();
+    let root = builder.root(causaloid(0));
+    let a = builder.add(causaloid(1));
+    let b = builder.add(causaloid(2));
+    let c = builder.add(causaloid(3));
+    builder
+        .cause(root)
+        .leads_to(a)
+        .cause(root)
+        .leads_to_with_weight(b, 7)
+        .cause(a)
+        .leads_to(c);
+    builder.cause(b).leads_to(c);
+
+    let g = builder.build().unwrap();
+    assert_eq!(g.number_nodes(), 4);
+    assert_eq!(g.number_edges(), 4);
+    assert_eq!(g.get_root_index(), Some(root.index()));
+    assert!(g.contains_edge(root.index(), a.index()));
+    assert!(g.contains_edge(a.index(), c.index()));
+    assert!(g.contains_edge(b.index(), c.index()));
+    assert_eq!(
+        g.get_graph().get_edge_weight(root.index(), b.index()),
+        Some(&7)
+    );
+    assert_eq!(g.get_causaloid(c.index()).unwrap().id(), 3);
+
+    assert!(g.reason_all_causes(&[0.9, 0.9, 0.9, 0.9], None).unwrap());
+}
+
+#[test]
+fn test_build_from_edge_lists() {
+    let mut builder = BaseCausaloidGraphBuilder::default();
+    let root = builder.root(causaloid(0));
+    let nodes = builder.add_all(vec![causaloid(1), causaloid(2), causaloid(3)]);
+    builder
+        .add_edges(&[(root, nodes[0]), (root, nodes[1])])
+        .add_weighted_edges(&[(nodes[0], nodes[2], 2), (nodes[1], nodes[2], 3)]);
+    builder.cause(root).leads_to_all(&[nodes[2]]);
+
+    let g = builder.build().unwrap();
+    assert_eq!(g.number_nodes(), 4);
+    assert_eq!(g.number_edges(), 5);
+    assert_eq!(
+        g.get_graph()
+            .get_edge_weight(nodes[1].index(), nodes[2].index()),
+        Some(&3)
+    );
+}
+
+#[test]
+fn test_data_index_coverage() {
+    let mut builder = BaseCausaloidGraphBuilder::new();
+    let root = builder.root(causaloid(10));
+    let a = builder.add(causaloid(11));
+    builder.cause(root).leads_to(a);
+    builder.data_index(HashMap::from([(10, 0), (11, 1)]));
+    let g = builder.build().unwrap();
+
+    let data_index = HashMap::from([(10, 0), (11, 1)]);
+    assert!(g.reason_all_causes(&[0.9, 0.9], Some(&data_index)).unwrap());
+
+    let mut builder = BaseCausaloidGraphBuilder::new();
+    let root = builder.root(causaloid(10));
+    let a = builder.add(causaloid(11));
+    builder.cause(root).leads_to(a);
+    builder.data_index(HashMap::from([(10, 0)]));
+    assert_eq!(
+        builder.build().err().unwrap().to_string(),
+        "BuildError: Invalid causaloid graph:\n  \
+         - Causaloids without data in the data index: 1 (id 11)"
+    );
+}
+
+#[test]
+fn test_no_root() {
+    let mut builder = BaseCausaloidGraphBuilder::new();
+    builder.add(causaloid(0));
+    assert_eq!(
+        builder.build().err().unwrap().to_string(),
+        "BuildError: Invalid causaloid graph:\n  - The graph has no root causaloid"
+    );
+}
+
+#[test]
+fn test_lists_every_problem() {
+    let mut builder = BaseCausaloidGraphBuilder::new();
+    let root = builder.root(causaloid(0));
+    let other_root = builder.root(causaloid(1));
+    let a = builder.add(causaloid(2));
+    let b = builder.add(causaloid(3));
+    builder.add(causaloid(4));
+    builder
+        .cause(root)
+        .leads_to(a)
+        .cause(a)
+        .leads_to(b)
+        .cause(b)
+        .leads_to(a)
+        .cause(root)
+        .leads_to(a);
+    builder.cause(other_root).leads_to(b);
+
+    let err = builder.build().err().unwrap().to_string();
+    assert_eq!(
+        err,
+        "BuildError: Invalid causaloid graph:\n  \
+         - Edge 0 (id 0) -> 2 (id 2) is added more than once\n  \
+         - The graph has 2 root causaloids: 0 (id 0), 1 (id 1)\n  \
+         - The graph has a cycle: 2 (id 2), 3 (id 3), 2 (id 2)\n  \
+         - Causaloids not reachable from the root: 1 (id 1), 4 (id 4)"
+    );
+}
+
+#[test]
+fn test_self_loop_is_a_cycle() {
+    let mut builder = BaseCausaloidGraphBuilder::new();
+    let root = builder.root(causaloid(0));
+    builder.cause(root).leads_to(root);
+    assert_eq!(
+        builder.build().err().unwrap().to_string(),
+        "BuildError: Invalid causaloid graph:\n  - The graph has a cycle: 0 (id 0), 0 (id 0)"
+    );
+}
+
+#[test]
+fn test_handle_of_another_builder() {
+    let mut other = BaseCausaloidGraphBuilder::new();
+    other.root(causaloid(0));
+    let foreign = other.add(causaloid(1));
+
+    let mut builder = BaseCausaloidGraphBuilder::new();
+    let root = builder.root(causaloid(0));
+    let a = builder.add(causaloid(1));
+    builder
+        .cause(root)
+        .leads_to(a)
+        .cause(root)
+        .leads_to(foreign);
+
+    // Handles of the same index from different builders are not equal.
+    assert_ne!(a, foreign);
+    assert_eq!(
+        builder.build().err().unwrap().to_string(),
+        "BuildError: Invalid causaloid graph:\n  - Edge 0 -> 1 uses a handle of another builder"
+    );
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils_registry::threshold_fn;
+
+// Builds the graph: root -(3)-> a -> b
+fn get_test_graph<'l>() -> BaseCausalGraph<'l> {
+    let mut g = CausaloidGraph::new();
+    let root = g.add_root_causaloid(Causaloid::new(0, threshold_fn, "root"));
+    let a = g.add_causaloid(Causaloid::new(1, threshold_fn, "a \"quoted\""));
+    let b = g.add_causaloid(Causaloid::new(2, threshold_fn, "b"));
+    g.add_edg_with_weight(root, a, 3).unwrap();
+    g.add_edge(a, b).unwrap();
+    g
+}
+
+#[test]
+fn test_to_dot() {
+    let g = get_test_graph();
+    let expected = r#"digraph causaloid_graph {
+    node [shape=box];
+    0 [label="id: 0\nroot\nactive: false", peripheries=2];
+    1 [label="id: 1\na \"quoted\"\nactive: false"];
+    2 [label="id: 2\nb\nactive: false"];
+    0 -> 1 [label="3"];
+    1 -> 2 [label="0"];
+}
+"#;
+    assert_eq!(g.to_dot(&GraphExportOptions::default()), expected);
+}
+
+#[test]
+fn test_to_dot_highlights_reasoning_path() {
+    let g = get_test_graph();
+    // b is not active, so reasoning stops at b.
+    assert!(!g.reason_all_causes(&[0.9, 0.9, 0.1], None).unwrap());
+
+    let expected = r#"digraph causaloid_graph {
+    node [shape=box];
+    0 [label="id: 0\nroot\nactive: true", peripheries=2, style=filled, fillcolor=palegreen];
+    1 [label="id: 1\na \"quoted\"\nactive: true", style=filled, fillcolor=palegreen];
+    2 [label="id: 2\nb\nactive: false"];
+    0 -> 1 [label="3", color=darkgreen, penwidth=2];
+    1 -> 2 [label="0"];
+}
+"#;
+    assert_eq!(g.to_dot(&GraphExportOptions::new(true)), expected);
+
+    // Without highlighting, only the labels show the activation.
+    assert!(!g
+        .to_dot(&GraphExportOptions::new(false))
+        .contains("palegreen"));
+}
+
+#[test]
+fn test_to_mermaid() {
+    let g = get_test_graph();
+    let expected = r#"flowchart TD
+    n0(["id: 0<br/>root<br/>active: false"])
+    n1["id: 1<br/>a #quot;quoted#quot;<br/>active: false"]
+    n2["id: 2<br/>b<br/>active: false"]
+    n0 -->|3| n1
+    n1 -->|0| n2
+"#;
+    assert_eq!(g.to_mermaid(&GraphExportOptions::new(true)), expected);
+}
+
+#[test]
+fn test_to_mermaid_highlights_reasoning_path() {
+    let g = get_test_graph();
+    assert!(!g.reason_all_causes(&[0.9, 0.9, 0.1], None).unwrap());
+
+    let expected = r#"flowchart TD
+    n0(["id: 0<br/>root<br/>active: true"])
+    n1["id: 1<br/>a #quot;quoted#quot;<br/>active: true"]
+    n2["id: 2<br/>b<br/>active: false"]
+    n0 -->|3| n1
+    n1 -->|0| n2
+    classDef active fill:#98fb98,stroke:#006400;
+    class n0,n1 active;
+    linkStyle 0 stroke:#006400,stroke-width:3px;
+"#;
+    assert_eq!(g.to_mermaid(&GraphExportOptions::new(true)), expected);
+    assert!(!g
+        .to_mermaid(&GraphExportOptions::default())
+        .contains("classDef"));
+}
+
+#[test]
+fn test_empty_graph() {
+    let g: BaseCausalGraph = CausaloidGraph::new();
+    assert_eq!(
+        g.to_dot(&GraphExportOptions::default()),
+        "digraph causaloid_graph {\n    node [shape=box];\n}\n"
+    );
+    assert_eq!(
+        g.to_mermaid(&GraphExportOptions::default()),
+        "flowchart TD\n"
+    );
+}
@@ -0,0 +1,249 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+use ultragraph::prelude::*;
+
+use crate::utils::test_utils::get_test_context;
+use crate::utils::test_utils_registry::*;
+
+// Root 0 -> 2 -> 3 with index 1 removed, so that indices have a gap.
+fn get_test_graph<'l>() -> BaseCausalGraph<'l> {
+    let mut graph = CausaloidGraph::new_with_capacity(10);
+    let root =
+        graph.add_root_causaloid(Causaloid::new(0, threshold_fn, "root").with_fn_name("threshold"));
+    let removed =
+        graph.add_causaloid(Causaloid::new(1, threshold_fn, "removed").with_fn_name("threshold"));
+    let a = graph.add_causaloid(Causaloid::new(2, threshold_fn, "a").with_fn_name("threshold"));
+    let b = graph.add_causaloid(
+        Causaloid::new(3, inverse_threshold_fn, "b").with_fn_name("inverse_threshold"),
+    );
+    graph.remove_causaloid(removed).unwrap();
+    graph.add_edg_with_weight(root, a, 7).unwrap();
+    graph.add_edge(a, b).unwrap();
+    graph
+}
+
+#[test]
+fn test_singleton_round_trip() {
+    let registry = get_test_registry();
+    let causaloid =
+        Causaloid::new(1, threshold_fn, "threshold causaloid").with_fn_name("threshold");
+
+    let spec = BaseCausaloidSpec::from_causaloid(&causaloid, &registry).unwrap();
+    assert_eq!(spec.id(), 1);
+    assert_eq!(spec.description(), "threshold causaloid");
+    assert_eq!(spec.context(), None);
+    assert_eq!(
+        *spec.kind(),
+        CausaloidSpecKind::Singleton("threshold".to_string())
+    );
+
+    let json = to_json(&spec).unwrap();
+    let from_json: BaseCausaloidSpec = from_json(&json).unwrap();
+    assert_eq!(from_json, spec);
+
+    let bytes = to_binary(&spec).unwrap();
+    let from_binary: BaseCausaloidSpec = from_binary(&bytes).unwrap();
+    assert_eq!(from_binary, spec);
+
+    let built = from_json.build(&registry, &[]).unwrap();
+    assert_eq!(built.id(), 1);
+    assert_eq!(built.description(), "threshold causaloid");
+    assert_eq!(built.fn_name(), Some("threshold"));
+    assert!(built.verify_single_cause(&0.9).unwrap());
+    assert!(!built.verify_single_cause(&0.1).unwrap());
+}
+
+#[test]
+fn test_contextual_round_trip() {
+    let registry = get_test_registry();
+    let context = get_test_context();
+    let causaloid =
+        Causaloid::new_with_context(2, contextual_threshold_fn, Some(&context), "contextual")
+            .with_fn_name("contextual_threshold");
+
+    let spec = BaseCausaloidSpec::from_causaloid(&causaloid, &registry).unwrap();
+    assert_eq!(spec.context(), Some(context.id()));
+
+    let spec: BaseCausaloidSpec = from_json(&to_json(&spec).unwrap()).unwrap();
+    let built = spec.build(&registry, &[&context]).unwrap();
+    assert_eq!(built.context().unwrap().id(), context.id());
+    assert!(built.verify_single_cause(&0.9).unwrap());
+
+    let res = spec.build(&registry, &[]);
+    assert!(res
+        .unwrap_err()
+        .to_string()
+        .contains("refers to unknown context 1"));
+}
+
+#[test]
+fn test_collection_round_trip() {
+    let registry = get_test_registry();
+    let coll = vec![
+        Causaloid::new(1, threshold_fn, "a").with_fn_name("threshold"),
+        Causaloid::new(2, inverse_threshold_fn, "b").with_fn_name("inverse_threshold"),
+    ];
+    let causaloid = Causaloid::from_causal_collection(3, &coll, "collection");
+
+    let spec = BaseCausaloidSpec::from_causaloid(&causaloid, &registry).unwrap();
+    let spec: BaseCausaloidSpec = from_binary(&to_binary(&spec).unwrap()).unwrap();
+    match spec.kind() {
+        CausaloidSpecKind::Collection(specs) => assert_eq!(specs.len(), 2),
+        kind => panic!("unexpected kind {:?}", kind),
+    }
+
+    let built = spec.build(&registry, &[]).unwrap();
+    let built_coll = built.causal_collection().unwrap();
+    assert_eq!(built_coll.len(), 2);
+    assert_eq!(built_coll[1].description(), "b");
+    assert!(built.verify_all_causes(&[0.9, 0.1], None).unwrap());
+    assert!(!built.verify_all_causes(&[0.1, 0.1], None).unwrap());
+}
+
+#[test]
+fn test_graph_round_trip() {
+    let registry = get_test_registry();
+    let graph = get_test_graph();
+    let causaloid = Causaloid::from_causal_graph(4, &graph, "graph");
+
+    let spec = BaseCausaloidSpec::from_causaloid(&causaloid, &registry).unwrap();
+    let spec: BaseCausaloidSpec = from_json(&to_json_pretty(&spec).unwrap()).unwrap();
+    match spec.kind() {
+        CausaloidSpecKind::Graph(graph_spec) => {
+            assert_eq!(graph_spec.root(), Some(0));
+            assert_eq!(graph_spec.nodes().len(), 3);
+            assert!(graph_spec.edges().contains(&(0, 2, 7)));
+        }
+        kind => panic!("unexpected kind {:?}", kind),
+    }
+
+    let built = spec.build(&registry, &[]).unwrap();
+    let built_graph = built.causal_graph().unwrap();
+    assert_eq!(built_graph.number_nodes(), 3);
+    assert_eq!(built_graph.number_edges(), 2);
+    assert_eq!(built_graph.get_root_index(), Some(0));
+    assert!(!built_graph.contains_causaloid(1));
+    assert_eq!(built_graph.get_causaloid(3).unwrap().description(), "b");
+    assert!(built_graph.contains_edge(2, 3));
+    assert_eq!(built_graph.get_graph().get_edge_weight(0, 2), Some(&7));
+
+    // Building again reuses the graph kept in the spec.
+    let again = spec.build(&registry, &[]).unwrap();
+    assert!(std::ptr::eq(
+        again.causal_graph().unwrap(),
+        built.causal_graph().unwrap()
+    ));
+}
+
+#[test]
+fn test_unregistered_function() {
+    let registry = get_test_registry();
+
+    fn unregistered_fn(_obs: NumericalValue) -> Result<bool, CausalityError> {
+        Ok(true)
+    }
+    let coll = vec![Causaloid::new(7, unregistered_fn, "unregistered")];
+    let causaloid = Causaloid::from_causal_collection(3, &coll, "collection");
+
+    let res = BaseCausaloidSpec::from_causaloid(&causaloid, &registry);
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        "SerializationError: The causal function of causaloid 7 is not registered"
+    );
+
+    let coll =
+        vec![Causaloid::new(7, unregistered_fn, "unregistered").with_fn_name("unregistered")];
+    let causaloid = Causaloid::from_causal_collection(3, &coll, "collection");
+
+    let res = BaseCausaloidSpec::from_causaloid(&causaloid, &registry);
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        "SerializationError: Unknown causal function 'unregistered' in causaloid 7"
+    );
+}
+
+#[test]
+fn test_unknown_function() {
+    let registry = get_test_registry();
+    let spec = BaseCausaloidSpec::new(
+        1,
+        "",
+        None,
+        CausaloidSpecKind::Singleton("unknown".to_string()),
+    );
+
+    let res = spec.build(&registry, &[]);
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        "SerializationError: Unknown causal function 'unknown' in causaloid 1"
+    );
+
+    // A causal function is not a contextual causal function.
+    let spec = BaseCausaloidSpec::new(
+        1,
+        "",
+        None,
+        CausaloidSpecKind::Contextual("threshold".to_string()),
+    );
+    assert!(spec.build(&registry, &[]).is_err());
+}
+
+#[test]
+fn test_invalid_graph() {
+    let registry = get_test_registry();
+    let node = |id| {
+        BaseCausaloidSpec::new(
+            id,
+            "",
+            None,
+            CausaloidSpecKind::Singleton("threshold".to_string()),
+        )
+    };
+
+    let dangling = CausaloidGraphSpec::new(Some(0), vec![(0, node(0))], vec![(0, 5, 0)]);
+    let spec = BaseCausaloidSpec::new(1, "", None, CausaloidSpecKind::Graph(dangling));
+    assert_eq!(
+        spec.build(&registry, &[]).unwrap_err().to_string(),
+        "SerializationError: Edge 0 -> 5 refers to a node that does not exist"
+    );
+
+    let duplicate = CausaloidGraphSpec::new(None, vec![(0, node(0)), (0, node(1))], vec![]);
+    let spec = BaseCausaloidSpec::new(1, "", None, CausaloidSpecKind::Graph(duplicate));
+    assert!(spec.build(&registry, &[]).is_err());
+
+    let missing_root = CausaloidGraphSpec::new(Some(3), vec![(0, node(0))], vec![]);
+    let spec = BaseCausaloidSpec::new(1, "", None, CausaloidSpecKind::Graph(missing_root));
+    assert!(spec.build(&registry, &[]).is_err());
+
+    // Gaps may not outnumber the nodes.
+    let gap = CausaloidGraphSpec::new(None, vec![(0, node(0)), (3, node(1))], vec![]);
+    let spec = BaseCausaloidSpec::new(1, "", None, CausaloidSpecKind::Graph(gap));
+    assert!(spec.build(&registry, &[]).is_ok());
+
+    let indices = [4, 1 << 40, usize::MAX];
+    let specs: Vec<BaseCausaloidSpec> = indices
+        .iter()
+        .map(|index| {
+            let huge = CausaloidGraphSpec::new(None, vec![(0, node(0)), (*index, node(1))], vec![]);
+            BaseCausaloidSpec::new(1, "", None, CausaloidSpecKind::Graph(huge))
+        })
+        .collect();
+
+    for (index, spec) in indices.iter().zip(&specs) {
+        assert_eq!(
+            spec.build(&registry, &[]).unwrap_err().to_string(),
+            format!(
+                "SerializationError: causaloid graph node index {} exceeds the limit of 4 for 2 nodes",
+                index
+            )
+        );
+    }
+}
+
+#[test]
+fn test_invalid_input() {
+    assert!(from_json::<BaseCausaloidSpec>("{").is_err());
+    assert!(from_binary::<BaseCausaloidSpec>(&[1, 2, 3]).is_err());
+}
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
+#[cfg(all(test, feature = "serde"))]
+mod assumption_spec_tests;
 #[cfg(test)]
 mod assumption_tests;
 #[cfg(test)]
@@ -10,8 +12,16 @@ mod causality_graph_reasoning_tests;
 #[cfg(test)]
 mod causality_graph_tests;
 #[cfg(test)]
+mod causaloid_graph_builder_tests;
+#[cfg(test)]
+mod causaloid_graph_export_tests;
+#[cfg(all(test, feature = "serde"))]
+mod causaloid_spec_tests;
+#[cfg(test)]
 mod causaloid_tests;
 #[cfg(test)]
 mod inference_tests;
 #[cfg(test)]
 mod observation_tests;
+#[cfg(test)]
+mod statistical_test_tests;
@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils::*;
+
+const ALPHA: NumericalValue = DEFAULT_SIGNIFICANCE;
+
+fn get_ar_series(phi: NumericalValue) -> Vec<NumericalValue> {
+    get_test_normal_series(300, 7)
+        .into_iter()
+        .scan(0.0, |last, e| {
+            *last = phi * *last + e;
+            Some(*last)
+        })
+        .collect()
+}
+
+#[test]
+fn test_normality() {
+    let test = StatisticalTest::Normality;
+
+    let normal = get_test_normal_series(500, 1);
+    let result = test.run(&normal).unwrap();
+    assert!(test.holds(&result, ALPHA));
+    assert_eq!(*result.sample_size(), 500);
+    assert!(*result.effect_size() < 0.3);
+
+    // Exponential noise is skewed.
+    let exponential: Vec<NumericalValue> = normal.iter().map(|x| x.exp()).collect();
+    let result = test.run(&exponential).unwrap();
+    assert!(!test.holds(&result, ALPHA));
+    assert!(*result.p_value() < 0.001);
+    assert!(*result.effect_size() > 1.0);
+}
+
+#[test]
+fn test_stationarity() {
+    let test = StatisticalTest::Stationarity;
+
+    let noise = get_test_normal_series(300, 2);
+    let result = test.run(&noise).unwrap();
+    assert!(test.holds(&result, ALPHA));
+    assert!(*result.statistic() < -2.86);
+
+    let walk = get_test_random_walk(300, 2);
+    let result = test.run(&walk).unwrap();
+    assert!(!test.holds(&result, ALPHA));
+    assert!(*result.p_value() > ALPHA);
+    assert!(test.confidence(&result) < 1.0 - ALPHA);
+}
+
+#[test]
+fn test_independence() {
+    let test = StatisticalTest::Independence;
+
+    let result = test.run(&get_ar_series(0.0)).unwrap();
+    assert!(test.holds(&result, ALPHA));
+
+    let result = test.run(&get_ar_series(0.8)).unwrap();
+    assert!(!test.holds(&result, ALPHA));
+    assert!(*result.effect_size() > 0.7);
+}
+
+#[test]
+fn test_homoscedasticity() {
+    let test = StatisticalTest::Homoscedasticity;
+
+    let noise = get_test_normal_series(400, 3);
+    let result = test.run(&noise).unwrap();
+    assert!(test.holds(&result, ALPHA));
+
+    // The spread grows over time.
+    let growing: Vec<NumericalValue> = noise
+        .iter()
+        .enumerate()
+        .map(|(t, x)| x * (1.0 + t as NumericalValue / 20.0))
+        .collect();
+    let result = test.run(&growing).unwrap();
+    assert!(!test.holds(&result, ALPHA));
+    assert!(*result.effect_size() > 0.0);
+}
+
+#[test]
+fn test_no_missing_values() {
+    let test = StatisticalTest::NoMissingValues;
+
+    let result = test.run(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+    assert!(test.holds(&result, ALPHA));
+    assert_eq!(*result.p_value(), 1.0);
+
+    let result = test.run(&[1.0, f64::NAN, 3.0, f64::INFINITY]).unwrap();
+    assert!(!test.holds(&result, ALPHA));
+    assert_eq!(*result.statistic(), 2.0);
+    assert_eq!(*result.effect_size(), 0.5);
+}
+
+#[test]
+fn test_run_err() {
+    for test in [
+        StatisticalTest::Normality,
+        StatisticalTest::Stationarity,
+        StatisticalTest::Independence,
+        StatisticalTest::Homoscedasticity,
+    ] {
+        // Too few observations
+        assert!(test.run(&[1.0, 2.0, 3.0]).is_err());
+        // Constant
+        assert!(test.run(&[1.0; 50]).is_err());
+        // Missing values
+        let mut data = get_test_normal_series(50, 4);
+        data[10] = f64::NAN;
+        assert!(test.run(&data).is_err());
+        assert!(!test.verify(&data, ALPHA));
+    }
+}
+
+#[test]
+fn test_eval_fn() {
+    let normal = get_test_normal_series(500, 1);
+    assert!((StatisticalTest::Normality.eval_fn(ALPHA))(&normal));
+    assert!(!(StatisticalTest::NoMissingValues.eval_fn(ALPHA))(&[
+        f64::NAN
+    ]));
+    assert!((StatisticalTest::Stationarity.eval_fn(ALPHA))(&normal));
+    assert!(!(StatisticalTest::Stationarity.eval_fn(ALPHA))(
+        &get_test_random_walk(300, 2)
+    ));
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(StatisticalTest::Normality.to_string(), "Normality");
+    assert_eq!(
+        StatisticalTest::NoMissingValues.to_string(),
+        "NoMissingValues"
+    );
+}
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils_registry::*;
+
+#[test]
+fn test_new() {
+    let registry: BaseCausalFunctionRegistry = CausalFunctionRegistry::new();
+    assert!(registry.is_empty());
+    assert_eq!(registry.len(), 0);
+
+    let registry: BaseCausalFunctionRegistry = CausalFunctionRegistry::default();
+    assert!(registry.is_empty());
+}
+
+#[test]
+fn test_register() {
+    let registry = get_test_registry();
+    assert!(!registry.is_empty());
+    assert_eq!(registry.len(), 5);
+
+    let f = registry.causal_fn("threshold").unwrap();
+    assert!(f(0.9).unwrap());
+    assert!(!f(0.1).unwrap());
+    assert!(registry.causal_fn("unknown").is_none());
+
+    let context = BaseContext::with_capacity(1, "empty", 1);
+    let f = registry
+        .contextual_causal_fn("contextual_threshold")
+        .unwrap();
+    assert!(f(0.9, &context).is_err());
+    assert!(registry.contextual_causal_fn("threshold").is_none());
+
+    let f = registry.eval_fn("has_data").unwrap();
+    assert!(f(&[0.1]));
+    assert!(registry.eval_fn("threshold").is_none());
+
+    let action = registry.action("alert").unwrap();
+    assert!(action.fire().is_ok());
+    assert_eq!(*action.descr(), "Raises an alert");
+    // Registered actions carry their name.
+    assert_eq!(action.name().as_deref(), Some("alert"));
+    assert!(registry.action("unknown").is_none());
+}
+
+#[test]
+fn test_register_duplicate() {
+    let mut registry = get_test_registry();
+
+    let res = registry.register_causal_fn("threshold", inverse_threshold_fn);
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        "RegistryError: A causal function named 'threshold' is already registered"
+    );
+    // The first registration is kept.
+    assert!(registry.causal_fn("threshold").unwrap()(0.9).unwrap());
+
+    assert!(registry
+        .register_contextual_causal_fn("contextual_threshold", contextual_threshold_fn)
+        .is_err());
+    assert!(registry.register_eval_fn("has_data", has_data_fn).is_err());
+    assert!(registry
+        .register_action("alert", get_test_alert_action())
+        .is_err());
+
+    // Names are unique per kind of function.
+    assert!(registry.register_eval_fn("threshold", has_data_fn).is_ok());
+    assert_eq!(registry.len(), 6);
+}
+
+#[test]
+fn test_names() {
+    let registry = get_test_registry();
+
+    assert_eq!(
+        registry.causal_fn_names(),
+        vec!["inverse_threshold", "threshold"]
+    );
+    assert_eq!(
+        registry.contextual_causal_fn_names(),
+        vec!["contextual_threshold"]
+    );
+    assert_eq!(registry.eval_fn_names(), vec!["has_data"]);
+    assert_eq!(registry.action_names(), vec!["alert"]);
+
+    let registry: BaseCausalFunctionRegistry = CausalFunctionRegistry::new();
+    assert!(registry.causal_fn_names().is_empty());
+}
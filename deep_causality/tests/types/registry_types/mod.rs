@@ -0,0 +1,5 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+#[cfg(test)]
+mod causal_function_registry_tests;
@@ -32,3 +32,83 @@ fn test_abs_num_pos() {
     let res = math_utils::abs_num(n);
     assert_eq!(res, 1.0);
 }
+
+#[test]
+fn test_euclidean_distance() {
+    assert_eq!(math_utils::euclidean_distance([0.0; 3], [0.0; 3]), 0.0);
+    assert_eq!(
+        math_utils::euclidean_distance([1.0, 2.0, 3.0], [4.0, 6.0, 3.0]),
+        5.0
+    );
+}
+
+#[test]
+fn test_geodesic_distance() {
+    assert_eq!(math_utils::geodesic_distance(10.0, 20.0, 10.0, 20.0), 0.0);
+
+    // One degree along the equator
+    let d = math_utils::geodesic_distance(0.0, 0.0, 0.0, 1.0);
+    assert!((d - 111_195.0).abs() < 1.0);
+
+    // Pole to pole
+    let d = math_utils::geodesic_distance(90.0, 0.0, -90.0, 0.0);
+    assert!((d - std::f64::consts::PI * math_utils::EARTH_RADIUS_METERS).abs() < 1e-6);
+
+    // London to Paris, about 343.5 km
+    let d = math_utils::geodesic_distance(51.5074, -0.1278, 48.8566, 2.3522);
+    assert!((d - 343_500.0).abs() < 1_000.0);
+}
+
+#[test]
+fn test_erf() {
+    assert!(math_utils::erf(0.0).abs() < 1e-8);
+    assert!((math_utils::erf(1.0) - 0.842700).abs() < 1e-6);
+    assert!((math_utils::erf(-1.0) + 0.842700).abs() < 1e-6);
+    assert!((math_utils::erf(3.0) - 0.999978).abs() < 1e-6);
+}
+
+#[test]
+fn test_normal_cdf() {
+    assert!((math_utils::normal_cdf(0.0, 0.0, 1.0) - 0.5).abs() < 1e-7);
+    assert!((math_utils::normal_cdf(12.0, 10.0, 2.0) - 0.841345).abs() < 1e-6);
+    assert!((math_utils::normal_cdf(8.0, 10.0, 2.0) - 0.158655).abs() < 1e-6);
+
+    // Without deviation, the cdf is a step at the mean.
+    assert_eq!(math_utils::normal_cdf(9.9, 10.0, 0.0), 0.0);
+    assert_eq!(math_utils::normal_cdf(10.0, 10.0, 0.0), 1.0);
+}
+
+#[test]
+fn test_ln_gamma() {
+    assert!((math_utils::ln_gamma(1.0)).abs() < 1e-9);
+    assert!((math_utils::ln_gamma(5.0) - 24.0_f64.ln()).abs() < 1e-9);
+    assert!((math_utils::ln_gamma(0.5) - std::f64::consts::PI.sqrt().ln()).abs() < 1e-9);
+}
+
+#[test]
+fn test_chi_squared_sf() {
+    assert_eq!(math_utils::chi_squared_sf(0.0, 3.0), 1.0);
+    assert!((math_utils::chi_squared_sf(3.841459, 1.0) - 0.05).abs() < 1e-6);
+    assert!((math_utils::chi_squared_sf(5.991465, 2.0) - 0.05).abs() < 1e-6);
+    assert!((math_utils::chi_squared_sf(18.307038, 10.0) - 0.05).abs() < 1e-6);
+    assert!((math_utils::chi_squared_sf(2.0, 10.0) - 0.996340).abs() < 1e-6);
+    assert!(math_utils::chi_squared_sf(200.0, 2.0) < 1e-40);
+}
+
+#[test]
+fn test_dickey_fuller_p_value() {
+    // Reference values of statsmodels mackinnonp with a constant.
+    assert!((math_utils::dickey_fuller_p_value(-3.0) - 0.034894).abs() < 1e-5);
+    assert!((math_utils::dickey_fuller_p_value(-2.0) - 0.286573).abs() < 1e-5);
+    assert!((math_utils::dickey_fuller_p_value(-1.0) - 0.753264).abs() < 1e-5);
+    assert!((math_utils::dickey_fuller_p_value(0.0) - 0.958532).abs() < 1e-5);
+
+    // Both response surfaces meet at tau = -1.61.
+    let below = math_utils::dickey_fuller_p_value(-1.61);
+    let above = math_utils::dickey_fuller_p_value(-1.61 + 1e-9);
+    assert!((below - above).abs() < 1e-3);
+    assert!((below - 0.478).abs() < 1e-3);
+
+    assert_eq!(math_utils::dickey_fuller_p_value(-20.0), 0.0);
+    assert_eq!(math_utils::dickey_fuller_p_value(3.0), 1.0);
+}
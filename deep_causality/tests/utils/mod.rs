@@ -4,5 +4,6 @@
 mod math_utils_tests;
 pub mod test_utils;
 pub mod test_utils_graph;
+pub mod test_utils_registry;
 #[cfg(test)]
 mod time_utils_tests;
@@ -114,9 +114,8 @@ pub fn get_test_observation() -> Observation {
 pub fn get_test_assumption() -> Assumption {
     let id: IdentificationValue = 1;
     let description: String = "Test assumption that data are there".to_string() as DescriptionValue;
-    let assumption_fn: EvalFn = test_has_data;
 
-    Assumption::new(id, description, assumption_fn)
+    Assumption::new(id, description, test_has_data)
 }
 
 fn test_has_data(data: &[NumericalValue]) -> bool {
@@ -126,3 +125,36 @@ fn test_has_data(data: &[NumericalValue]) -> bool {
 pub fn get_test_num_array() -> [NumericalValue; 10] {
     [8.4, 8.5, 9.1, 9.3, 9.4, 9.5, 9.7, 9.7, 9.9, 9.9]
 }
+
+// Deterministic pseudo random numbers in (0, 1).
+fn get_test_uniform_series(n: usize, seed: u64) -> Vec<NumericalValue> {
+    let mut state = seed;
+    (0..n)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 11) as NumericalValue + 0.5) / (1u64 << 53) as NumericalValue
+        })
+        .collect()
+}
+
+/// Returns deterministic standard normal noise, generated with the Box-Muller transform.
+pub fn get_test_normal_series(n: usize, seed: u64) -> Vec<NumericalValue> {
+    let uniform = get_test_uniform_series(2 * n, seed);
+    uniform
+        .chunks(2)
+        .map(|u| (-2.0 * u[0].ln()).sqrt() * (2.0 * std::f64::consts::PI * u[1]).cos())
+        .collect()
+}
+
+/// Returns a deterministic random walk, the cumulative sum of standard normal noise.
+pub fn get_test_random_walk(n: usize, seed: u64) -> Vec<NumericalValue> {
+    get_test_normal_series(n, seed)
+        .into_iter()
+        .scan(0.0, |sum, x| {
+            *sum += x;
+            Some(*sum)
+        })
+        .collect()
+}
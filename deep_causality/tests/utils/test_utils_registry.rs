@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+pub fn threshold_fn(obs: NumericalValue) -> Result<bool, CausalityError> {
+    Ok(obs >= 0.55)
+}
+
+pub fn inverse_threshold_fn(obs: NumericalValue) -> Result<bool, CausalityError> {
+    Ok(obs < 0.55)
+}
+
+pub fn contextual_threshold_fn(
+    obs: NumericalValue,
+    ctx: &BaseContext,
+) -> Result<bool, CausalityError> {
+    if ctx.node_count() == 0 {
+        return Err(CausalityError("Context is empty".into()));
+    }
+    Ok(obs >= 0.55)
+}
+
+pub fn has_data_fn(data: &[NumericalValue]) -> bool {
+    !data.is_empty()
+}
+
+pub fn alert_action() -> Result<(), ActionError> {
+    Ok(())
+}
+
+pub fn get_test_alert_action() -> CausalAction {
+    CausalAction::new(alert_action, "Raises an alert", 1)
+}
+
+// Registers the test functions under the names used in the serialization tests.
+pub fn get_test_registry<'l>() -> BaseCausalFunctionRegistry<'l> {
+    let mut registry = CausalFunctionRegistry::new();
+    registry
+        .register_causal_fn("threshold", threshold_fn)
+        .unwrap();
+    registry
+        .register_causal_fn("inverse_threshold", inverse_threshold_fn)
+        .unwrap();
+    registry
+        .register_contextual_causal_fn("contextual_threshold", contextual_threshold_fn)
+        .unwrap();
+    registry.register_eval_fn("has_data", has_data_fn).unwrap();
+    registry
+        .register_action("alert", get_test_alert_action())
+        .unwrap();
+    registry
+}
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
-use deep_causality::prelude::time_execution;
+use deep_causality::prelude::*;
 
 #[test]
 fn test_time() {
@@ -11,3 +11,166 @@ fn test_time() {
 fn run() {
     println!("Hello Run")
 }
+
+const NANOS: i64 = 1_000_000_000;
+
+// 2024-05-15T13:45:30Z, a Wednesday
+const TIMESTAMP: i64 = 1_715_780_730 * NANOS;
+
+#[test]
+fn test_civil_days() {
+    assert_eq!(days_from_civil(1970, 1, 1), Some(0));
+    assert_eq!(days_from_civil(2024, 5, 15), Some(19858));
+    assert_eq!(days_from_civil(1969, 12, 31), Some(-1));
+    assert_eq!(days_from_civil(2000, 3, 1), Some(11017));
+
+    assert_eq!(civil_from_days(0), (1970, 1, 1));
+    assert_eq!(civil_from_days(19858), (2024, 5, 15));
+    assert_eq!(civil_from_days(-1), (1969, 12, 31));
+
+    // Leap days round trip
+    for days in [days_from_civil(2024, 2, 29), days_from_civil(1600, 2, 29)] {
+        let days = days.unwrap();
+        let (y, m, d) = civil_from_days(days);
+        assert_eq!(days_from_civil(y, m, d), Some(days));
+        assert_eq!((m, d), (2, 29));
+    }
+
+    // Overflow
+    assert_eq!(days_from_civil(i64::MAX, 3, 1), None);
+    assert_eq!(days_from_civil(i64::MIN, 1, 1), None);
+    assert_eq!(days_from_civil(i64::MIN, 3, 1), None);
+}
+
+#[test]
+fn test_units_since_epoch() {
+    assert_eq!(
+        units_since_epoch(TIMESTAMP, TimeScale::Second).unwrap(),
+        1_715_780_730
+    );
+    assert_eq!(
+        units_since_epoch(TIMESTAMP, TimeScale::Millisecond).unwrap(),
+        1_715_780_730_000
+    );
+    assert_eq!(units_since_epoch(TIMESTAMP, TimeScale::Day).unwrap(), 19858);
+    assert_eq!(
+        units_since_epoch(TIMESTAMP, TimeScale::Month).unwrap(),
+        54 * 12 + 4
+    );
+    assert_eq!(
+        units_since_epoch(TIMESTAMP, TimeScale::Quarter).unwrap(),
+        54 * 4 + 1
+    );
+    assert_eq!(units_since_epoch(TIMESTAMP, TimeScale::Year).unwrap(), 54);
+
+    // The week of the epoch starts on Monday 1969-12-29.
+    assert_eq!(units_since_epoch(0, TimeScale::Week).unwrap(), 0);
+    assert_eq!(
+        units_since_epoch(-3 * 86400 * NANOS, TimeScale::Week).unwrap(),
+        0
+    );
+    assert_eq!(
+        units_since_epoch(-4 * 86400 * NANOS, TimeScale::Week).unwrap(),
+        -1
+    );
+
+    // Before the epoch
+    assert_eq!(
+        units_since_epoch(-3600 * NANOS, TimeScale::Day).unwrap(),
+        -1
+    );
+    assert_eq!(
+        units_since_epoch(-3600 * NANOS, TimeScale::Month).unwrap(),
+        -1
+    );
+    assert_eq!(
+        units_since_epoch(-3600 * NANOS, TimeScale::Year).unwrap(),
+        -1
+    );
+
+    assert!(units_since_epoch(TIMESTAMP, TimeScale::NoScale).is_err());
+}
+
+#[test]
+fn test_bucket_start_end() {
+    let cases = [
+        (TimeScale::Hour, 1_715_778_000, 1_715_781_600),
+        (TimeScale::Day, 1_715_731_200, 1_715_817_600),
+        (TimeScale::Week, 1_715_558_400, 1_716_163_200),
+        (TimeScale::Month, 1_714_521_600, 1_717_200_000),
+        (TimeScale::Quarter, 1_711_929_600, 1_719_792_000),
+        (TimeScale::Year, 1_704_067_200, 1_735_689_600),
+    ];
+
+    for (scale, start, end) in cases {
+        assert_eq!(
+            bucket_start(TIMESTAMP, scale).unwrap(),
+            start * NANOS,
+            "{}",
+            scale
+        );
+        assert_eq!(
+            bucket_end(TIMESTAMP, scale).unwrap(),
+            end * NANOS,
+            "{}",
+            scale
+        );
+    }
+
+    // Before the epoch
+    let t = -3600 * NANOS;
+    assert_eq!(bucket_start(t, TimeScale::Week).unwrap(), -259_200 * NANOS);
+    assert_eq!(
+        bucket_start(t, TimeScale::Month).unwrap(),
+        -2_678_400 * NANOS
+    );
+    assert_eq!(
+        bucket_start(t, TimeScale::Quarter).unwrap(),
+        -7_948_800 * NANOS
+    );
+    assert_eq!(bucket_end(t, TimeScale::Year).unwrap(), 0);
+
+    assert!(bucket_start(TIMESTAMP, TimeScale::NoScale).is_err());
+    assert!(unit_start(i64::MAX, TimeScale::Year).is_err());
+}
+
+#[test]
+fn test_unit_start_overflow() {
+    for scale in [
+        TimeScale::Second,
+        TimeScale::Day,
+        TimeScale::Week,
+        TimeScale::Month,
+        TimeScale::Quarter,
+        TimeScale::Year,
+    ] {
+        for units in [
+            i64::MAX,
+            i64::MAX / 2,
+            i64::MAX / 12,
+            i64::MIN,
+            i64::MIN / 2,
+        ] {
+            assert!(unit_start(units, scale).is_err(), "{} {}", scale, units);
+        }
+    }
+
+    assert_eq!(
+        unit_start(i64::MAX, TimeScale::Nanosecond).unwrap(),
+        i64::MAX
+    );
+    assert_eq!(
+        unit_start(i64::MIN, TimeScale::Nanosecond).unwrap(),
+        i64::MIN
+    );
+
+    // The bucket after the largest timestamp does not fit.
+    assert!(bucket_start(i64::MAX, TimeScale::Nanosecond).is_ok());
+    assert!(bucket_end(i64::MAX, TimeScale::Nanosecond).is_err());
+    assert!(bucket_end(i64::MAX, TimeScale::Year).is_err());
+    // The Monday before the smallest timestamp does not fit.
+    assert!(bucket_start(i64::MIN, TimeScale::Week).is_err());
+
+    let time = Time::new(1, TimeScale::Month, i64::MAX / 2);
+    assert!(time.unix_timestamp_nanos().is_err());
+}
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use crate::algorithms::CompactGraph;
+
+// Number of incoming and outgoing edges of each node divided by the number of other nodes.
+pub(crate) fn degree_centrality(graph: &CompactGraph) -> BTreeMap<usize, f64> {
+    let n = graph.len();
+    let mut degree = vec![0usize; n];
+    for position in 0..n {
+        degree[position] += graph.children(position).len();
+        for child in graph.children(position) {
+            degree[*child] += 1;
+        }
+    }
+
+    let scale = if n > 1 { 1.0 / (n - 1) as f64 } else { 0.0 };
+    (0..n)
+        .map(|p| (graph.index(p), degree[p] as f64 * scale))
+        .collect()
+}
+
+// Brandes' algorithm on the unweighted graph: the sum over all pairs of other nodes (s, t)
+// of the fraction of shortest paths from s to t that pass through the node.
+// If normalized, the sum is divided by the number of such pairs, (n - 1)(n - 2).
+pub(crate) fn betweenness_centrality(
+    graph: &CompactGraph,
+    normalized: bool,
+) -> BTreeMap<usize, f64> {
+    let n = graph.len();
+    let mut centrality = vec![0.0; n];
+
+    for source in 0..n {
+        let mut order = Vec::with_capacity(n);
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut paths = vec![0.0; n];
+        let mut distance: Vec<Option<usize>> = vec![None; n];
+        paths[source] = 1.0;
+        distance[source] = Some(0);
+
+        let mut queue = VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            let d = distance[node].expect("Visited nodes have a distance");
+            for child in graph.children(node) {
+                if distance[*child].is_none() {
+                    distance[*child] = Some(d + 1);
+                    queue.push_back(*child);
+                }
+                if distance[*child] == Some(d + 1) {
+                    paths[*child] += paths[node];
+                    predecessors[*child].push(node);
+                }
+            }
+        }
+
+        let mut dependency = vec![0.0; n];
+        for node in order.into_iter().rev() {
+            for predecessor in &predecessors[node] {
+                dependency[*predecessor] +=
+                    paths[*predecessor] / paths[node] * (1.0 + dependency[node]);
+            }
+            if node != source {
+                centrality[node] += dependency[node];
+            }
+        }
+    }
+
+    let scale = if normalized && n > 2 {
+        1.0 / ((n - 1) * (n - 2)) as f64
+    } else {
+        1.0
+    };
+    (0..n)
+        .map(|p| (graph.index(p), centrality[p] * scale))
+        .collect()
+}
+
+// Power iteration on the unweighted graph. The rank of nodes without outgoing edges is
+// spread evenly over all nodes. Stops after max_iterations or once the ranks change
+// by less than tolerance in total.
+pub(crate) fn pagerank(
+    graph: &CompactGraph,
+    damping: f64,
+    max_iterations: usize,
+    tolerance: f64,
+) -> BTreeMap<usize, f64> {
+    let n = graph.len();
+    if n == 0 {
+        return BTreeMap::new();
+    }
+
+    let mut rank = vec![1.0 / n as f64; n];
+    for _ in 0..max_iterations {
+        let dangling: f64 = (0..n)
+            .filter(|p| graph.children(*p).is_empty())
+            .map(|p| rank[p])
+            .sum();
+
+        let base = (1.0 - damping + damping * dangling) / n as f64;
+        let mut next = vec![base; n];
+        for (position, r) in rank.iter().enumerate() {
+            let children = graph.children(position);
+            for child in children {
+                next[*child] += damping * r / children.len() as f64;
+            }
+        }
+
+        let change: f64 = next.iter().zip(&rank).map(|(a, b)| (a - b).abs()).sum();
+        rank = next;
+        if change < tolerance {
+            break;
+        }
+    }
+
+    (0..n).map(|p| (graph.index(p), rank[p])).collect()
+}
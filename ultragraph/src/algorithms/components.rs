@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::algorithms::CompactGraph;
+
+// Tarjan's algorithm, iterative so that deep graphs do not overflow the stack.
+// Each component is sorted by index, and components are sorted by their smallest index.
+pub(crate) fn strongly_connected_components(graph: &CompactGraph) -> Vec<Vec<usize>> {
+    const UNVISITED: usize = usize::MAX;
+
+    let n = graph.len();
+    let mut order = vec![UNVISITED; n];
+    let mut low_link = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut next_order = 0;
+    let mut components = Vec::new();
+
+    for start in 0..n {
+        if order[start] != UNVISITED {
+            continue;
+        }
+
+        // Each frame holds a node and the position of the next child to visit.
+        let mut frames = vec![(start, 0)];
+        order[start] = next_order;
+        low_link[start] = next_order;
+        next_order += 1;
+        stack.push(start);
+        on_stack[start] = true;
+
+        while let Some((node, next_child)) = frames.last_mut() {
+            let node = *node;
+            match graph.children(node).get(*next_child) {
+                Some(&child) => {
+                    *next_child += 1;
+                    if order[child] == UNVISITED {
+                        order[child] = next_order;
+                        low_link[child] = next_order;
+                        next_order += 1;
+                        stack.push(child);
+                        on_stack[child] = true;
+                        frames.push((child, 0));
+                    } else if on_stack[child] {
+                        low_link[node] = low_link[node].min(order[child]);
+                    }
+                }
+                None => {
+                    frames.pop();
+                    if let Some((parent, _)) = frames.last() {
+                        low_link[*parent] = low_link[*parent].min(low_link[node]);
+                    }
+
+                    if low_link[node] == order[node] {
+                        let mut component = Vec::new();
+                        loop {
+                            let member = stack.pop().expect("Node is on the stack");
+                            on_stack[member] = false;
+                            component.push(graph.index(member));
+                            if member == node {
+                                break;
+                            }
+                        }
+                        component.sort_unstable();
+                        components.push(component);
+                    }
+                }
+            }
+        }
+    }
+
+    components.sort_unstable_by_key(|c| c[0]);
+    components
+}
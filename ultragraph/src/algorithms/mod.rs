@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+#![forbid(unsafe_code)]
+
+use ahash::AHashMap;
+
+use crate::prelude::GraphAlgorithms;
+
+pub(crate) mod centrality;
+pub(crate) mod components;
+pub(crate) mod paths;
+pub(crate) mod shortest_path;
+pub(crate) mod topological_sort;
+
+// Copy of the structure of a graph where nodes are numbered by their position 0..n
+// in ascending order of their index. Algorithms that visit every node keep their state
+// in vectors indexed by position instead of hash maps indexed by node index.
+pub(crate) struct CompactGraph {
+    nodes: Vec<usize>,
+    children: Vec<Vec<usize>>,
+}
+
+impl CompactGraph {
+    pub(crate) fn new<T, G>(graph: &G) -> Self
+    where
+        G: GraphAlgorithms<T> + ?Sized,
+    {
+        let nodes: Vec<usize> = graph
+            .get_all_indexed_nodes()
+            .into_iter()
+            .map(|(index, _)| index)
+            .collect();
+
+        let positions: AHashMap<usize, usize> =
+            nodes.iter().enumerate().map(|(p, i)| (*i, p)).collect();
+
+        let children = nodes
+            .iter()
+            .map(|index| {
+                let mut children: Vec<usize> = graph
+                    .outgoing_edges(*index)
+                    .expect("Node exists")
+                    .map(|child| positions[&child])
+                    .collect();
+                children.sort_unstable();
+                children
+            })
+            .collect();
+
+        Self { nodes, children }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    // Returns the node index at the position.
+    pub(crate) fn index(&self, position: usize) -> usize {
+        self.nodes[position]
+    }
+
+    pub(crate) fn children(&self, position: usize) -> &[usize] {
+        &self.children[position]
+    }
+}
+
+// Returns the weight of the edge from a to b; a and b must be connected.
+pub(crate) fn weight<T, G>(graph: &G, a: usize, b: usize) -> u64
+where
+    G: GraphAlgorithms<T> + ?Sized,
+{
+    *graph.get_edge_weight(a, b).expect("Edge exists")
+}
+
+// Returns the children of the node with the weights of the edges.
+pub(crate) fn weighted_children<T, G>(
+    graph: &G,
+    index: usize,
+) -> impl Iterator<Item = (usize, u64)> + '_
+where
+    G: GraphAlgorithms<T> + ?Sized,
+{
+    graph
+        .outgoing_edges(index)
+        .expect("Node exists")
+        .map(move |child| (child, weight(graph, index, child)))
+}
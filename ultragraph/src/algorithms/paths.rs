@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::BTreeSet;
+
+use ahash::AHashSet;
+
+use crate::algorithms::shortest_path::shortest_path;
+use crate::algorithms::{weight, weighted_children};
+use crate::prelude::GraphAlgorithms;
+
+// Depth first search that extends the current path with every child not on the path.
+// Paths are returned in lexicographic order.
+pub(crate) fn all_simple_paths<T, G>(graph: &G, start: usize, stop: usize) -> Vec<Vec<usize>>
+where
+    G: GraphAlgorithms<T> + ?Sized,
+{
+    if start == stop {
+        return vec![vec![start]];
+    }
+
+    let children = |index: usize| -> Vec<usize> {
+        let mut children: Vec<usize> = graph.outgoing_edges(index).expect("Node exists").collect();
+        children.sort_unstable();
+        children
+    };
+
+    let mut paths = Vec::new();
+    let mut path = vec![start];
+    let mut on_path: AHashSet<usize> = AHashSet::from_iter([start]);
+    // Each frame holds the children of the node at the same position of the path
+    // and the position of the next child to visit.
+    let mut frames = vec![(children(start), 0)];
+
+    while let Some((next, position)) = frames.last_mut() {
+        match next.get(*position).copied() {
+            Some(child) => {
+                *position += 1;
+                if child == stop {
+                    let mut found = path.clone();
+                    found.push(stop);
+                    paths.push(found);
+                } else if on_path.insert(child) {
+                    path.push(child);
+                    frames.push((children(child), 0));
+                }
+            }
+            None => {
+                frames.pop();
+                if let Some(node) = path.pop() {
+                    on_path.remove(&node);
+                }
+            }
+        }
+    }
+
+    paths
+}
+
+// Yen's algorithm for the k shortest paths without repeated nodes.
+// Each further path deviates from one of the paths found so far at a spur node: the root of
+// that path up to the spur node is kept and the rest is replaced with the shortest path from
+// the spur node that avoids the nodes of the root and the edges taken by known paths with the
+// same root.
+pub(crate) fn k_shortest_paths<T, G>(
+    graph: &G,
+    start: usize,
+    stop: usize,
+    k: usize,
+) -> Vec<(u64, Vec<usize>)>
+where
+    G: GraphAlgorithms<T> + ?Sized,
+{
+    let mut found: Vec<(u64, Vec<usize>)> = Vec::new();
+    if k == 0 {
+        return found;
+    }
+
+    match shortest_path(start, stop, |index| weighted_children(graph, index)) {
+        Some(path) => found.push(path),
+        None => return found,
+    }
+
+    let mut candidates: BTreeSet<(u64, Vec<usize>)> = BTreeSet::new();
+
+    while found.len() < k {
+        let (_, previous) = found.last().expect("At least one path was found").clone();
+
+        for i in 0..previous.len() - 1 {
+            let spur = previous[i];
+            let root = &previous[..=i];
+
+            let removed_edges: AHashSet<(usize, usize)> = found
+                .iter()
+                .filter(|(_, path)| path.len() > i + 1 && &path[..=i] == root)
+                .map(|(_, path)| (path[i], path[i + 1]))
+                .collect();
+            let removed_nodes: AHashSet<usize> = root[..i].iter().copied().collect();
+
+            let spur_path = shortest_path(spur, stop, |index| {
+                let removed_edges = &removed_edges;
+                let removed_nodes = &removed_nodes;
+                weighted_children(graph, index).filter(move |(child, _)| {
+                    !removed_nodes.contains(child) && !removed_edges.contains(&(index, *child))
+                })
+            });
+
+            if let Some((spur_length, spur_path)) = spur_path {
+                let root_length: u64 = root.windows(2).map(|w| weight(graph, w[0], w[1])).sum();
+                let mut path = root[..i].to_vec();
+                path.extend(spur_path);
+                candidates.insert((root_length + spur_length, path));
+            }
+        }
+
+        // The shortest candidate that was not found yet is the next path.
+        let next = loop {
+            match candidates.pop_first() {
+                Some(candidate) if found.contains(&candidate) => continue,
+                next => break next,
+            }
+        };
+
+        match next {
+            Some(path) => found.push(path),
+            None => break,
+        }
+    }
+
+    found
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+
+use ahash::AHashMap;
+
+// Dijkstra's algorithm over public node indices, shared by the storage backends that do not
+// wrap petgraph and by the graph analysis. Edge weights are the distances between nodes.
+// Returns the length of the shortest path from start to stop and the path, both included,
+// if stop is reachable from start.
+pub(crate) fn shortest_path<F, I>(
+    start: usize,
+    stop: usize,
+    neighbors: F,
+) -> Option<(u64, Vec<usize>)>
+where
+    F: Fn(usize) -> I,
+    I: Iterator<Item = (usize, u64)>,
+{
+    let (distances, previous) = search(start, Some(stop), neighbors);
+    let distance = *distances.get(&stop)?;
+
+    let mut path = vec![stop];
+    let mut current = stop;
+    while let Some(prev) = previous.get(&current) {
+        path.push(*prev);
+        current = *prev;
+    }
+    path.reverse();
+
+    Some((distance, path))
+}
+
+// Returns the length of the shortest path from start to every node reachable from start.
+pub(crate) fn distances<F, I>(start: usize, neighbors: F) -> BTreeMap<usize, u64>
+where
+    F: Fn(usize) -> I,
+    I: Iterator<Item = (usize, u64)>,
+{
+    search(start, None, neighbors).0.into_iter().collect()
+}
+
+type Distances = AHashMap<usize, u64>;
+type Previous = AHashMap<usize, usize>;
+
+// Runs until stop is settled or, without stop, until all reachable nodes are settled.
+fn search<F, I>(start: usize, stop: Option<usize>, neighbors: F) -> (Distances, Previous)
+where
+    F: Fn(usize) -> I,
+    I: Iterator<Item = (usize, u64)>,
+{
+    let mut distances: Distances = AHashMap::new();
+    let mut previous: Previous = AHashMap::new();
+    let mut queue = BinaryHeap::new();
+
+    distances.insert(start, 0);
+    queue.push(Reverse((0u64, start)));
+
+    while let Some(Reverse((distance, node))) = queue.pop() {
+        // Skip stale queue entries of nodes reached by a shorter path since.
+        if distances.get(&node).is_some_and(|d| *d < distance) {
+            continue;
+        }
+
+        if Some(node) == stop {
+            break;
+        }
+
+        for (next, weight) in neighbors(node) {
+            let next_distance = distance.saturating_add(weight);
+            if distances.get(&next).map_or(true, |d| next_distance < *d) {
+                distances.insert(next, next_distance);
+                previous.insert(next, node);
+                queue.push(Reverse((next_distance, next)));
+            }
+        }
+    }
+
+    (distances, previous)
+}
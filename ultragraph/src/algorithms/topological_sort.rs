@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::algorithms::CompactGraph;
+use crate::errors::UltraGraphError;
+
+// Kahn's algorithm. Among the nodes whose predecessors are all sorted,
+// the node with the smallest index comes first so that the order is deterministic.
+pub(crate) fn topological_sort(graph: &CompactGraph) -> Result<Vec<usize>, UltraGraphError> {
+    let mut in_degree = vec![0usize; graph.len()];
+    for position in 0..graph.len() {
+        for child in graph.children(position) {
+            in_degree[*child] += 1;
+        }
+    }
+
+    let mut ready: BinaryHeap<Reverse<usize>> = (0..graph.len())
+        .filter(|p| in_degree[*p] == 0)
+        .map(Reverse)
+        .collect();
+
+    let mut res = Vec::with_capacity(graph.len());
+    while let Some(Reverse(position)) = ready.pop() {
+        res.push(graph.index(position));
+        for child in graph.children(position) {
+            in_degree[*child] -= 1;
+            if in_degree[*child] == 0 {
+                ready.push(Reverse(*child));
+            }
+        }
+    }
+
+    if res.len() < graph.len() {
+        return Err(UltraGraphError(
+            "Graph contains a cycle and has no topological order".into(),
+        ));
+    }
+
+    Ok(res)
+}
@@ -1,8 +1,23 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
-use crate::prelude::{UltraGraphContainer, UltraMatrixGraph};
+use std::collections::BTreeMap;
+
+use crate::prelude::{
+    UltraAdjacencyListGraph, UltraCsrGraph, UltraGraphContainer, UltraMatrixGraph,
+};
 
 // Type alias for convenience and to shorten type annotations / inference.
 // This also allows for simple swapping of the underlying storage type.
-pub type UltraGraph<T> = UltraGraphContainer<UltraMatrixGraph<T>, T>;
+// E is the type of the edge payloads, see GraphEdgeData.
+pub type UltraGraph<T, E = ()> = UltraGraphContainer<UltraMatrixGraph<T, E>, T>;
+
+// UltraGraph with adjacency list storage for large, sparse graphs.
+pub type AdjacencyListGraph<T, E = ()> = UltraGraphContainer<UltraAdjacencyListGraph<T, E>, T>;
+
+// UltraGraph with read-only compressed sparse row storage for large, static graphs.
+pub type CsrGraph<T, E = ()> = UltraGraphContainer<UltraCsrGraph<T, E>, T>;
+
+// Subgraph copied from a graph, with the map from the indices in the graph
+// to the indices in the subgraph, see GraphTransform.
+pub type Subgraph<T, E = ()> = (UltraGraph<T, E>, BTreeMap<usize, usize>);
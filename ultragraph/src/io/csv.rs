@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphView, NodeFormat, UltraGraph};
+
+use super::{parse_weight, sorted_edges, GraphData};
+
+const HEADER: [&str; 5] = ["kind", "source", "target", "weight", "value"];
+
+/// Writes the graph as edge list CSV.
+///
+/// Each row is either a node, the root node, or an edge:
+///
+/// ```text
+/// kind,source,target,weight,value
+/// root,0,,,a
+/// node,1,,,b
+/// edge,0,1,3,
+/// ```
+///
+/// Fields are quoted as described in RFC 4180 if needed.
+/// Hyperedges and edge payloads are not written.
+///
+/// # Example:
+/// ```
+/// use ultragraph::io::{from_csv, to_csv};
+/// use ultragraph::prelude::*;
+///
+///  let mut g = ultragraph::new::<String>();
+///  let a = g.add_root_node("a".to_string());
+///  let b = g.add_node("b, c".to_string());
+///  g.add_edge_with_weight(a, b, 3).unwrap();
+///
+///  let csv = to_csv(&g);
+///  assert_eq!(csv, "kind,source,target,weight,value\nroot,0,,,a\nnode,1,,,\"b, c\"\nedge,0,1,3,\n");
+///
+///  let h: UltraGraph<String> = from_csv(&csv).unwrap();
+///  assert_eq!(h.get_node(b), Some(&"b, c".to_string()));
+///  assert_eq!(h.get_edge_weight(a, b), Some(&3));
+/// ```
+pub fn to_csv<T, G>(graph: &G) -> String
+where
+    T: NodeFormat,
+    G: GraphView<T>,
+{
+    let mut csv = String::new();
+    write_record(&mut csv, &HEADER);
+
+    let root = graph.get_root_index();
+    for (index, value) in graph.get_all_indexed_nodes() {
+        let kind = if root == Some(index) { "root" } else { "node" };
+        write_record(
+            &mut csv,
+            &[kind, &index.to_string(), "", "", &value.to_text()],
+        );
+    }
+
+    for (a, b, weight) in sorted_edges(graph) {
+        write_record(
+            &mut csv,
+            &[
+                "edge",
+                &a.to_string(),
+                &b.to_string(),
+                &weight.to_string(),
+                "",
+            ],
+        );
+    }
+
+    csv
+}
+
+/// Reads a graph from edge list CSV as written by to_csv.
+///
+/// The header is required, but its columns may be in any order, and the weight
+/// may be empty, in which case it is 0. Nodes get new indices in the order of the rows.
+///
+/// Returns UltraGraphError if the CSV is malformed, a value cannot be converted,
+/// or an edge refers to a node that does not exist.
+pub fn from_csv<T>(csv: &str) -> Result<UltraGraph<T>, UltraGraphError>
+where
+    T: NodeFormat,
+{
+    let mut records = parse_records(csv)?.into_iter();
+
+    let header = records
+        .next()
+        .ok_or_else(|| UltraGraphError("CSV has no header".to_string()))?;
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|h| h.trim() == name)
+            .ok_or_else(|| UltraGraphError(format!("CSV header has no {} column", name)))
+    };
+    let [kind, source, target, weight, value] = [
+        column(HEADER[0])?,
+        column(HEADER[1])?,
+        column(HEADER[2])?,
+        column(HEADER[3])?,
+        column(HEADER[4])?,
+    ];
+
+    let mut data = GraphData::new();
+    for (row, record) in records.enumerate() {
+        let field = |column: usize| record.get(column).map_or("", |f| f.as_str());
+
+        match field(kind).trim() {
+            kind @ ("node" | "root") => {
+                let id = field(source).trim().to_string();
+                if kind == "root" {
+                    if data.root.is_some() {
+                        return Err(UltraGraphError(format!(
+                            "CSV row {} adds a second root node",
+                            row + 2
+                        )));
+                    }
+                    data.root = Some(id.clone());
+                }
+                data.nodes.push((id, T::from_text(field(value))?));
+            }
+            "edge" => {
+                let w = match field(weight).trim() {
+                    "" => 0,
+                    w => parse_weight(w)?,
+                };
+                data.edges.push((
+                    field(source).trim().to_string(),
+                    field(target).trim().to_string(),
+                    w,
+                ));
+            }
+            other => {
+                return Err(UltraGraphError(format!(
+                    "CSV row {} has unknown kind {:?}",
+                    row + 2,
+                    other
+                )))
+            }
+        }
+    }
+
+    data.build()
+}
+
+fn write_record(csv: &mut String, fields: &[&str]) {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            csv.push(',');
+        }
+
+        if field.contains([',', '"', '\n', '\r']) {
+            csv.push('"');
+            csv.push_str(&field.replace('"', "\"\""));
+            csv.push('"');
+        } else {
+            csv.push_str(field);
+        }
+    }
+    csv.push('\n');
+}
+
+// Splits RFC 4180 CSV into records. Quoted fields may contain commas, quotes, and line breaks.
+// Empty lines are skipped.
+fn parse_records(csv: &str) -> Result<Vec<Vec<String>>, UltraGraphError> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = csv.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            (true, '"') => quoted = false,
+            (true, c) => field.push(c),
+            (false, '"') if field.is_empty() => quoted = true,
+            (false, ',') => record.push(std::mem::take(&mut field)),
+            (false, '\r') if chars.peek() == Some(&'\n') => {}
+            (false, '\n') => {
+                if !record.is_empty() || !field.is_empty() {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+            }
+            (false, c) => field.push(c),
+        }
+    }
+
+    if quoted {
+        return Err(UltraGraphError("CSV has an unterminated quote".to_string()));
+    }
+
+    if !record.is_empty() || !field.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    Ok(records)
+}
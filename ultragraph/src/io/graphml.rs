@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphView, NodeFormat, UltraGraph};
+
+use super::{parse_weight, sorted_edges, GraphData};
+
+/// Writes the graph as GraphML.
+///
+/// Node values are stored in the node attribute "value", the root node has the
+/// boolean attribute "root" set to true, and weights are stored in the edge attribute "weight".
+/// Nodes have the ids n0, n1, ... after their index.
+/// Hyperedges and edge payloads are not written.
+///
+/// # Example:
+/// ```
+/// use ultragraph::io::{from_graphml, to_graphml};
+/// use ultragraph::prelude::*;
+///
+///  let mut g = ultragraph::new::<String>();
+///  let a = g.add_root_node("<a>".to_string());
+///  let b = g.add_node("b".to_string());
+///  g.add_edge_with_weight(a, b, 3).unwrap();
+///
+///  let graphml = to_graphml(&g);
+///  assert!(graphml.contains("<data key=\"value\">&lt;a&gt;</data>"));
+///
+///  let h: UltraGraph<String> = from_graphml(&graphml).unwrap();
+///  assert_eq!(h.get_root_node(), Some(&"<a>".to_string()));
+///  assert_eq!(h.get_edge_weight(a, b), Some(&3));
+/// ```
+pub fn to_graphml<T, G>(graph: &G) -> String
+where
+    T: NodeFormat,
+    G: GraphView<T>,
+{
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n  \
+         <key id=\"value\" for=\"node\" attr.name=\"value\" attr.type=\"string\"/>\n  \
+         <key id=\"root\" for=\"node\" attr.name=\"root\" attr.type=\"boolean\"/>\n  \
+         <key id=\"weight\" for=\"edge\" attr.name=\"weight\" attr.type=\"long\"/>\n  \
+         <graph id=\"G\" edgedefault=\"directed\">\n",
+    );
+
+    let root = graph.get_root_index();
+    for (index, value) in graph.get_all_indexed_nodes() {
+        writeln!(xml, "    <node id=\"n{}\">", index).expect("Failed to write GraphML");
+        writeln!(
+            xml,
+            "      <data key=\"value\">{}</data>",
+            escape(value.to_text().as_str())
+        )
+        .expect("Failed to write GraphML");
+        if root == Some(index) {
+            xml.push_str("      <data key=\"root\">true</data>\n");
+        }
+        xml.push_str("    </node>\n");
+    }
+
+    for (a, b, weight) in sorted_edges(graph) {
+        writeln!(
+            xml,
+            "    <edge source=\"n{}\" target=\"n{}\">\n      \
+             <data key=\"weight\">{}</data>\n    </edge>",
+            a, b, weight
+        )
+        .expect("Failed to write GraphML");
+    }
+
+    xml.push_str("  </graph>\n</graphml>\n");
+    xml
+}
+
+// Element of the graph whose data is currently read.
+enum Element {
+    None,
+    Node {
+        id: String,
+        value: Option<String>,
+        root: bool,
+    },
+    Edge {
+        source: String,
+        target: String,
+        weight: u64,
+    },
+}
+
+/// Reads a graph from GraphML as written by to_graphml.
+///
+/// Attributes are matched by their attr.name, so files of other tools that declare
+/// the keys "value", "root", and "weight" under other ids can be read as well.
+/// Other attributes and hyperedges are ignored. A missing weight is 0.
+/// Nodes get new indices in the order of the file.
+///
+/// Returns UltraGraphError if the XML is malformed, the graph is undirected,
+/// a node has no value, a value cannot be converted, or an edge refers to a node
+/// that does not exist.
+pub fn from_graphml<T>(graphml: &str) -> Result<UltraGraph<T>, UltraGraphError>
+where
+    T: NodeFormat,
+{
+    let mut reader = Reader::from_str(graphml);
+
+    // Maps key ids to attribute names.
+    let mut keys: HashMap<String, String> = HashMap::new();
+    let mut data = GraphData::new();
+    let mut element = Element::None;
+    let mut key: Option<String> = None;
+    let mut text = String::new();
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| UltraGraphError(format!("Invalid GraphML: {}", e)))?;
+
+        match event {
+            Event::Start(ref e) | Event::Empty(ref e) => {
+                let empty = matches!(event, Event::Empty(_));
+                match e.local_name().as_ref() {
+                    b"key" => {
+                        let id = attribute(e, "id")?.unwrap_or_default();
+                        let name = attribute(e, "attr.name")?.unwrap_or_else(|| id.clone());
+                        keys.insert(id, name);
+                    }
+                    b"graph" if attribute(e, "edgedefault")?.as_deref() == Some("undirected") => {
+                        return Err(UltraGraphError(
+                            "Undirected graphs are not supported".to_string(),
+                        ));
+                    }
+                    b"node" => {
+                        element = Element::Node {
+                            id: required_attribute(e, "id")?,
+                            value: None,
+                            root: false,
+                        };
+                        if empty {
+                            end_element(&mut data, &mut element)?;
+                        }
+                    }
+                    b"edge" => {
+                        element = Element::Edge {
+                            source: required_attribute(e, "source")?,
+                            target: required_attribute(e, "target")?,
+                            weight: 0,
+                        };
+                        if empty {
+                            end_element(&mut data, &mut element)?;
+                        }
+                    }
+                    b"data" => {
+                        key = Some(required_attribute(e, "key")?);
+                        text.clear();
+                        if empty {
+                            set_data(&mut element, &keys, key.take(), &text)?;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Text(e) if key.is_some() => {
+                let unescaped = e
+                    .unescape()
+                    .map_err(|e| UltraGraphError(format!("Invalid GraphML: {}", e)))?;
+                text.push_str(&unescaped);
+            }
+            Event::CData(e) if key.is_some() => {
+                let decoded = e
+                    .decode()
+                    .map_err(|e| UltraGraphError(format!("Invalid GraphML: {}", e)))?;
+                text.push_str(&decoded);
+            }
+            Event::End(e) => match e.local_name().as_ref() {
+                b"data" => set_data(&mut element, &keys, key.take(), &text)?,
+                b"node" | b"edge" => end_element(&mut data, &mut element)?,
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    data.build()
+}
+
+fn set_data(
+    element: &mut Element,
+    keys: &HashMap<String, String>,
+    key: Option<String>,
+    text: &str,
+) -> Result<(), UltraGraphError> {
+    let Some(key) = key else {
+        return Ok(());
+    };
+    let name = keys.get(&key).map_or(key.as_str(), |name| name.as_str());
+
+    match (element, name) {
+        (Element::Node { value, .. }, "value") => *value = Some(text.to_string()),
+        (Element::Node { root, .. }, "root") => *root = text.trim() == "true",
+        (Element::Edge { weight, .. }, "weight") => *weight = parse_weight(text)?,
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn end_element<T>(data: &mut GraphData<T>, element: &mut Element) -> Result<(), UltraGraphError>
+where
+    T: NodeFormat,
+{
+    match std::mem::replace(element, Element::None) {
+        Element::Node { id, value, root } => {
+            let value =
+                value.ok_or_else(|| UltraGraphError(format!("Node {} has no value", id)))?;
+            if root {
+                if data.root.is_some() {
+                    return Err(UltraGraphError(format!(
+                        "Node {} is a second root node",
+                        id
+                    )));
+                }
+                data.root = Some(id.clone());
+            }
+            data.nodes.push((id, T::from_text(&value)?));
+        }
+        Element::Edge {
+            source,
+            target,
+            weight,
+        } => data.edges.push((source, target, weight)),
+        Element::None => {}
+    }
+
+    Ok(())
+}
+
+fn attribute(element: &BytesStart, name: &str) -> Result<Option<String>, UltraGraphError> {
+    for attribute in element.attributes() {
+        let attribute =
+            attribute.map_err(|e| UltraGraphError(format!("Invalid GraphML: {}", e)))?;
+        if attribute.key.as_ref() == name.as_bytes() {
+            let value = attribute
+                .unescape_value()
+                .map_err(|e| UltraGraphError(format!("Invalid GraphML: {}", e)))?;
+            return Ok(Some(value.into_owned()));
+        }
+    }
+
+    Ok(None)
+}
+
+fn required_attribute(element: &BytesStart, name: &str) -> Result<String, UltraGraphError> {
+    attribute(element, name)?.ok_or_else(|| {
+        UltraGraphError(format!(
+            "GraphML element {} has no attribute {}",
+            String::from_utf8_lossy(element.name().as_ref()),
+            name
+        ))
+    })
+}
@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use serde_json::{json, Map, Value};
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphView, NodeFormat, UltraGraph};
+
+use super::{sorted_edges, GraphData};
+
+/// Writes the graph as JSON in the node-link format used by NetworkX and D3.
+///
+/// ```text
+/// {
+///   "directed": true,
+///   "multigraph": false,
+///   "graph": {"root": 0},
+///   "nodes": [{"id": 0, "value": "a"}, {"id": 1, "value": "b"}],
+///   "links": [{"source": 0, "target": 1, "weight": 3}]
+/// }
+/// ```
+///
+/// The root is omitted if the graph has none. Hyperedges and edge payloads are not written.
+///
+/// # Example:
+/// ```
+/// use ultragraph::io::{from_json, to_json};
+/// use ultragraph::prelude::*;
+///
+///  let mut g = ultragraph::new::<u64>();
+///  let a = g.add_root_node(7);
+///  let b = g.add_node(8);
+///  g.add_edge_with_weight(a, b, 3).unwrap();
+///
+///  let h: UltraGraph<u64> = from_json(&to_json(&g)).unwrap();
+///  assert_eq!(h.get_root_node(), Some(&7));
+///  assert_eq!(h.get_edge_weight(a, b), Some(&3));
+/// ```
+pub fn to_json<T, G>(graph: &G) -> String
+where
+    T: NodeFormat,
+    G: GraphView<T>,
+{
+    let mut attributes = Map::new();
+    if let Some(root) = graph.get_root_index() {
+        attributes.insert("root".to_string(), json!(root));
+    }
+
+    let nodes: Vec<Value> = graph
+        .get_all_indexed_nodes()
+        .into_iter()
+        .map(|(index, value)| json!({"id": index, "value": value.to_text()}))
+        .collect();
+
+    let links: Vec<Value> = sorted_edges(graph)
+        .into_iter()
+        .map(|(a, b, weight)| json!({"source": a, "target": b, "weight": weight}))
+        .collect();
+
+    json!({
+        "directed": true,
+        "multigraph": false,
+        "graph": attributes,
+        "nodes": nodes,
+        "links": links,
+    })
+    .to_string()
+}
+
+/// Reads a graph from JSON in the node-link format.
+///
+/// Node ids may be numbers or strings. Edges are read from "links" or, as written by
+/// newer versions of NetworkX, from "edges". A missing weight is 0.
+/// Nodes get new indices in the order of the file.
+///
+/// Returns UltraGraphError if the JSON is malformed or describes an undirected graph
+/// or multigraph, a value cannot be converted, or an edge refers to a node that does not exist.
+pub fn from_json<T>(json: &str) -> Result<UltraGraph<T>, UltraGraphError>
+where
+    T: NodeFormat,
+{
+    let document: Value =
+        serde_json::from_str(json).map_err(|e| UltraGraphError(format!("Invalid JSON: {}", e)))?;
+
+    if document.get("directed") == Some(&Value::Bool(false)) {
+        return Err(UltraGraphError(
+            "Undirected graphs are not supported".to_string(),
+        ));
+    }
+
+    if document.get("multigraph") == Some(&Value::Bool(true)) {
+        return Err(UltraGraphError("Multigraphs are not supported".to_string()));
+    }
+
+    let mut data = GraphData::new();
+    data.root = document
+        .get("graph")
+        .and_then(|graph| graph.get("root"))
+        .map(id)
+        .transpose()?;
+
+    for node in array(&document, "nodes")? {
+        let value = node
+            .get("value")
+            .ok_or_else(|| UltraGraphError(format!("Node {} has no value", node)))?;
+        let value = match value {
+            Value::String(text) => T::from_text(text)?,
+            other => T::from_text(&other.to_string())?,
+        };
+        data.nodes.push((id(field(node, "id")?)?, value));
+    }
+
+    let links = match document.get("links") {
+        Some(_) => array(&document, "links")?,
+        None => array(&document, "edges")?,
+    };
+    for link in links {
+        let weight = match link.get("weight") {
+            None => 0,
+            Some(weight) => weight
+                .as_u64()
+                .ok_or_else(|| UltraGraphError(format!("Invalid edge weight {}", weight)))?,
+        };
+        data.edges.push((
+            id(field(link, "source")?)?,
+            id(field(link, "target")?)?,
+            weight,
+        ));
+    }
+
+    data.build()
+}
+
+fn array<'a>(value: &'a Value, key: &str) -> Result<&'a Vec<Value>, UltraGraphError> {
+    field(value, key)?
+        .as_array()
+        .ok_or_else(|| UltraGraphError(format!("{} is not an array", key)))
+}
+
+fn field<'a>(value: &'a Value, key: &str) -> Result<&'a Value, UltraGraphError> {
+    value
+        .get(key)
+        .ok_or_else(|| UltraGraphError(format!("{} has no {}", value, key)))
+}
+
+// Numbers and strings are both used as node ids.
+fn id(value: &Value) -> Result<String, UltraGraphError> {
+    match value {
+        Value::String(id) => Ok(id.clone()),
+        Value::Number(id) => Ok(id.to_string()),
+        other => Err(UltraGraphError(format!("Invalid node id {}", other))),
+    }
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+// Import and export of graphs in formats understood by other tools.
+//
+// All formats store the nodes with their index and value, the root node, and the edges
+// with their weight. Hyperedges and edge payloads are not exported. Node values are
+// converted to text with NodeFormat.
+//
+// Writers list nodes and edges sorted by index so that the output is deterministic.
+// Readers add the nodes in the order of the file, hence the indices of a graph with
+// removed nodes are renumbered when read back.
+//
+// The functions are not part of the prelude because names such as to_json are
+// commonly used by other crates; import them from ultragraph::io instead.
+
+use std::collections::HashMap;
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphLike, GraphRoot, GraphView, UltraGraph};
+
+mod csv;
+mod graphml;
+mod json;
+
+pub use csv::{from_csv, to_csv};
+pub use graphml::{from_graphml, to_graphml};
+pub use json::{from_json, to_json};
+
+// Returns all edges (a, b, weight) sorted by their nodes.
+fn sorted_edges<T, G>(graph: &G) -> Vec<(usize, usize, u64)>
+where
+    G: GraphView<T>,
+{
+    let mut edges: Vec<_> = graph.edges().map(|(a, b, w)| (a, b, *w)).collect();
+    edges.sort_unstable();
+    edges
+}
+
+// Nodes and edges as read from a file, where nodes are identified by the ids used in the file.
+struct GraphData<T> {
+    nodes: Vec<(String, T)>,
+    root: Option<String>,
+    edges: Vec<(String, String, u64)>,
+}
+
+impl<T> GraphData<T> {
+    fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            root: None,
+            edges: Vec::new(),
+        }
+    }
+
+    fn build(self) -> Result<UltraGraph<T>, UltraGraphError> {
+        let mut graph = crate::with_capacity(self.nodes.len());
+        let mut indices: HashMap<String, usize> = HashMap::with_capacity(self.nodes.len());
+
+        for (id, value) in self.nodes {
+            if indices.contains_key(&id) {
+                return Err(UltraGraphError(format!(
+                    "Node {} is given more than once",
+                    id
+                )));
+            }
+
+            let index = if self.root.as_ref() == Some(&id) {
+                graph.add_root_node(value)
+            } else {
+                graph.add_node(value)
+            };
+            indices.insert(id, index);
+        }
+
+        if let Some(root) = &self.root {
+            if !indices.contains_key(root) {
+                return Err(UltraGraphError(format!("root node {} not found", root)));
+            }
+        }
+
+        for (a, b, weight) in self.edges {
+            match (indices.get(&a), indices.get(&b)) {
+                (Some(a), Some(b)) => graph.add_edge_with_weight(*a, *b, weight)?,
+                _ => {
+                    return Err(UltraGraphError(format!(
+                        "Edge {} -> {} refers to a node that does not exist",
+                        a, b
+                    )))
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+}
+
+fn parse_weight(text: &str) -> Result<u64, UltraGraphError> {
+    text.trim()
+        .parse()
+        .map_err(|_| UltraGraphError(format!("Invalid edge weight {:?}", text)))
+}
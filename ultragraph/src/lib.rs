@@ -3,10 +3,17 @@
 
 #![forbid(unsafe_code)]
 
-use crate::prelude::{UltraGraph, UltraGraphContainer, UltraMatrixGraph};
+use crate::errors::UltraGraphError;
+use crate::prelude::{
+    AdjacencyListGraph, CsrGraph, GraphAlgorithms, GraphEdgeDataView, UltraAdjacencyListGraph,
+    UltraCsrGraph, UltraGraph, UltraGraphContainer, UltraMatrixGraph,
+};
 
+mod algorithms;
 pub mod alias;
 pub mod errors;
+#[cfg(feature = "io")]
+pub mod io;
 pub mod prelude;
 pub mod protocols;
 pub mod storage;
@@ -87,3 +94,105 @@ pub fn new_with_matrix_storage<T>(capacity: usize) -> UltraGraph<T> {
 pub fn default<T>() -> UltraGraph<T> {
     UltraGraphContainer::new(UltraMatrixGraph::<T>::default())
 }
+
+/// Returns a new UltraGraph with matrix storage backend and edge payloads of type E.
+///
+/// # Arguments
+/// * Capacity refers to the maximum number of nodes that fit into the graph before a resize occurs.
+///
+/// # Example:
+/// ```
+/// use ultragraph::prelude::*;
+///
+///  let mut g = ultragraph::with_edge_data::<u8, &str>(10);
+///  let a = g.add_root_node(1);
+///  let b = g.add_node(2);
+///
+///  g.add_edge_with_data(a, b, 1, "causes").unwrap();
+///  assert_eq!(g.get_edge_data(a, b), Some(&"causes"));
+///
+///  *g.get_edge_data_mut(a, b).unwrap() = "prevents";
+///  assert_eq!(g.get_edge_data(a, b), Some(&"prevents"));
+/// ```
+pub fn with_edge_data<T, E>(capacity: usize) -> UltraGraph<T, E> {
+    UltraGraphContainer::new(UltraMatrixGraph::<T, E>::new_with_capacity(capacity))
+}
+
+/// Returns a new graph with adjacency list storage backend.
+///
+/// Memory grows with the number of nodes and edges, which suits large, sparse graphs
+/// better than the matrix storage.
+///
+/// # Arguments
+/// * Capacity refers to the maximum number of nodes that fit into the graph before a resize occurs.
+///
+/// # Example:
+/// ```
+/// use ultragraph::prelude::*;
+///
+///  let mut g = ultragraph::new_with_adjacency_list_storage::<u8>(10);
+///  let root_index = g.add_root_node(1);
+///  let node_a_index = g.add_node(42);
+///
+///  let res = g.add_edge(root_index, node_a_index);
+///  assert!(res.is_ok());
+///  assert_eq!(g.outgoing_edges(root_index).unwrap().collect::<Vec<_>>(), vec![node_a_index]);
+/// ```
+pub fn new_with_adjacency_list_storage<T>(capacity: usize) -> AdjacencyListGraph<T> {
+    UltraGraphContainer::new(UltraAdjacencyListGraph::<T>::new_with_capacity(capacity))
+}
+
+/// Returns a new read-only graph with compressed sparse row storage backend
+/// built from nodes and weighted edges (a, b, weight).
+///
+/// Nodes get the indices 0..nodes.len() in the given order. Nodes can still be added
+/// and updated, but removing nodes and adding or removing edges returns an error.
+///
+/// Returns UltraGraphError if an edge or the root refers to a node that does not exist,
+/// or if an edge is given more than once.
+///
+/// # Example:
+/// ```
+/// use ultragraph::prelude::*;
+///
+///  let g = ultragraph::new_with_csr_storage(vec![1u8, 2, 3], &[(0, 1, 1), (1, 2, 1)], Some(0))
+///     .unwrap();
+///  assert_eq!(g.get_root_index(), Some(0));
+///  assert_eq!(g.shortest_path(0, 2), Some(vec![0, 1, 2]));
+/// ```
+pub fn new_with_csr_storage<T>(
+    nodes: Vec<T>,
+    edges: &[(usize, usize, u64)],
+    root_index: Option<usize>,
+) -> Result<CsrGraph<T>, UltraGraphError> {
+    Ok(UltraGraphContainer::new(UltraCsrGraph::from_edges(
+        nodes, edges, root_index,
+    )?))
+}
+
+/// Returns a read-only copy of the graph with compressed sparse row storage backend.
+///
+/// Node and hyperedge indices, edge weights and data, and the root node are preserved,
+/// so a graph can be built with a mutable storage and then frozen for fast traversal.
+///
+/// # Example:
+/// ```
+/// use ultragraph::prelude::*;
+///
+///  let mut g = ultragraph::new_with_adjacency_list_storage::<u8>(10);
+///  let a = g.add_root_node(1);
+///  let b = g.add_node(2);
+///  g.add_edge_with_weight(a, b, 3).unwrap();
+///
+///  let mut csr = ultragraph::new_with_csr_storage_from(&g);
+///  assert_eq!(csr.get_edge_weight(a, b), Some(&3));
+///  assert!(csr.remove_edge(a, b).is_err());
+/// ```
+pub fn new_with_csr_storage_from<T, E, G>(graph: &G) -> CsrGraph<T, E>
+where
+    G: GraphAlgorithms<T> + GraphEdgeDataView<E>,
+    T: Clone,
+    E: Clone,
+{
+    UltraGraphContainer::new(UltraCsrGraph::from_graph(graph))
+}
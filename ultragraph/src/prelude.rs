@@ -2,15 +2,28 @@
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
 // Alias type renames the container type to UltraGraph
-pub use crate::alias::UltraGraph;
+pub use crate::alias::{AdjacencyListGraph, CsrGraph, Subgraph, UltraGraph};
 // Errors
 pub use crate::errors::UltraGraphError;
 // Protocols
 pub use crate::protocols::graph_algorithms::GraphAlgorithms;
+pub use crate::protocols::graph_analysis::GraphAnalysis;
+pub use crate::protocols::graph_edge_data::GraphEdgeData;
+pub use crate::protocols::graph_edge_data::GraphEdgeDataView;
+pub use crate::protocols::graph_hyperedges::GraphHyperedges;
 pub use crate::protocols::graph_like::GraphLike;
 pub use crate::protocols::graph_root::GraphRoot;
 pub use crate::protocols::graph_storage::GraphStorage;
+pub use crate::protocols::graph_transform::GraphTransform;
+pub use crate::protocols::graph_view::GraphView;
+#[cfg(feature = "io")]
+pub use crate::protocols::node_format::NodeFormat;
 // Storage implementation
+pub use crate::storage::adjacency_graph::UltraAdjacencyListGraph;
+pub use crate::storage::csr_graph::UltraCsrGraph;
 pub use crate::storage::matrix_graph::UltraMatrixGraph;
 // Types
+pub use crate::types::filtered_graph::FilteredGraph;
+pub use crate::types::hyperedge::Hyperedge;
+pub use crate::types::reversed_graph::ReversedGraph;
 pub use crate::types::ultra_graph::UltraGraphContainer;
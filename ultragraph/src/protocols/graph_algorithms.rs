@@ -1,14 +1,79 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::collections::{BTreeSet, VecDeque};
 use std::vec::IntoIter;
 
 use crate::errors::UltraGraphError;
-use crate::prelude::GraphLike;
+use crate::prelude::GraphView;
 
-pub trait GraphAlgorithms<T>: GraphLike<T> {
+pub trait GraphAlgorithms<T>: GraphView<T> {
     /// Returns the path of subsequent NodeId from start to finish, if one was found.
     fn shortest_path(&self, start_index: usize, stop_index: usize) -> Option<Vec<usize>>;
 
     /// Returns all nodes with an outgoing edge starting from a.
     fn outgoing_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError>;
+
+    /// Returns all nodes with an incoming edge ending at a.
+    fn incoming_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError>;
+
+    /// Returns all nodes without incoming edges, sorted by index.
+    fn sources(&self) -> Vec<usize>;
+
+    /// Returns all nodes without outgoing edges, sorted by index.
+    fn sinks(&self) -> Vec<usize>;
+
+    /// Returns the direct predecessors of a, sorted by index.
+    fn predecessors(&self, a: usize) -> Result<Vec<usize>, UltraGraphError> {
+        let mut res: Vec<usize> = self.incoming_edges(a)?.collect();
+        res.sort_unstable();
+        Ok(res)
+    }
+
+    /// Returns the number of edges ending at a.
+    fn in_degree(&self, a: usize) -> Result<usize, UltraGraphError> {
+        Ok(self.incoming_edges(a)?.len())
+    }
+
+    /// Returns the number of edges starting from a.
+    fn out_degree(&self, a: usize) -> Result<usize, UltraGraphError> {
+        Ok(self.outgoing_edges(a)?.len())
+    }
+
+    /// Returns all nodes from which a can be reached, sorted by index.
+    /// a is only included if it lies on a cycle.
+    fn ancestors(&self, a: usize) -> Result<Vec<usize>, UltraGraphError> {
+        let start: Vec<usize> = self.incoming_edges(a)?.collect();
+        Ok(traverse(start, |i| {
+            self.incoming_edges(i).expect("Node exists")
+        }))
+    }
+
+    /// Returns all nodes that can be reached from a, sorted by index.
+    /// a is only included if it lies on a cycle.
+    fn descendants(&self, a: usize) -> Result<Vec<usize>, UltraGraphError> {
+        let start: Vec<usize> = self.outgoing_edges(a)?.collect();
+        Ok(traverse(start, |i| {
+            self.outgoing_edges(i).expect("Node exists")
+        }))
+    }
+}
+
+// Breadth first search from the start nodes; returns all visited nodes, sorted by index.
+fn traverse<F, I>(start: Vec<usize>, next: F) -> Vec<usize>
+where
+    F: Fn(usize) -> I,
+    I: Iterator<Item = usize>,
+{
+    let mut visited: BTreeSet<usize> = start.iter().copied().collect();
+    let mut queue: VecDeque<usize> = start.into();
+
+    while let Some(node) = queue.pop_front() {
+        for n in next(node) {
+            if visited.insert(n) {
+                queue.push_back(n);
+            }
+        }
+    }
+
+    visited.into_iter().collect()
 }
@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::collections::BTreeMap;
+
+use crate::algorithms::shortest_path::{distances, shortest_path};
+use crate::algorithms::{centrality, components, paths, topological_sort};
+use crate::algorithms::{weighted_children, CompactGraph};
+use crate::errors::UltraGraphError;
+use crate::prelude::GraphAlgorithms;
+
+/// Graph algorithms implemented once on top of GraphAlgorithms,
+/// so that every storage backend and every view provides them.
+///
+/// Algorithms on paths use the stored u64 edge weights as distances.
+/// Results are sorted by node index, so they do not depend on the backend.
+pub trait GraphAnalysis<T>: GraphAlgorithms<T> {
+    /// Returns all nodes so that every node comes before the nodes it has an edge to.
+    /// Among nodes that could come next, the node with the smallest index comes first.
+    ///
+    /// Returns UltraGraphError if the graph contains a cycle.
+    fn topological_sort(&self) -> Result<Vec<usize>, UltraGraphError> {
+        topological_sort::topological_sort(&CompactGraph::new(self))
+    }
+
+    /// Returns true if the graph contains no cycle.
+    fn is_acyclic(&self) -> bool {
+        self.topological_sort().is_ok()
+    }
+
+    /// Returns the strongly connected components of the graph, i.e. the largest
+    /// sets of nodes in which every node can be reached from every other node.
+    /// Each component is sorted, and components are sorted by their smallest index.
+    fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        components::strongly_connected_components(&CompactGraph::new(self))
+    }
+
+    /// Returns the length of the shortest path from start to every node reachable from start,
+    /// computed with Dijkstra's algorithm.
+    ///
+    /// Returns UltraGraphError if the graph does not contain start.
+    fn dijkstra(&self, start: usize) -> Result<BTreeMap<usize, u64>, UltraGraphError> {
+        check_node(self, start)?;
+        Ok(distances(start, |index| weighted_children(self, index)))
+    }
+
+    /// Returns the length of the shortest path from start to every node reachable from start,
+    /// computed with the Bellman-Ford algorithm. Weights are unsigned, so the result
+    /// equals the result of dijkstra; Bellman-Ford relaxes every edge in rounds instead
+    /// of keeping a priority queue.
+    ///
+    /// Returns UltraGraphError if the graph does not contain start.
+    fn bellman_ford(&self, start: usize) -> Result<BTreeMap<usize, u64>, UltraGraphError> {
+        check_node(self, start)?;
+
+        let edges: Vec<(usize, usize, u64)> = self
+            .get_all_edges()
+            .into_iter()
+            .map(|(a, b)| (a, b, *self.get_edge_weight(a, b).expect("Edge exists")))
+            .collect();
+
+        let mut res = BTreeMap::from([(start, 0u64)]);
+        for _ in 1..self.number_nodes().max(2) {
+            let mut changed = false;
+            for (a, b, weight) in &edges {
+                if let Some(distance) = res.get(a).map(|d| d.saturating_add(*weight)) {
+                    if res.get(b).map_or(true, |d| distance < *d) {
+                        res.insert(*b, distance);
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        Ok(res)
+    }
+
+    /// Returns the length and the nodes of the shortest path from start to stop
+    /// by edge weight, if stop can be reached from start.
+    fn weighted_shortest_path(&self, start: usize, stop: usize) -> Option<(u64, Vec<usize>)> {
+        if !self.contains_node(start) || !self.contains_node(stop) {
+            return None;
+        }
+
+        shortest_path(start, stop, |index| weighted_children(self, index))
+    }
+
+    /// Returns all paths from start to stop that do not visit a node twice,
+    /// in lexicographic order. If start equals stop, the only path is [start].
+    ///
+    /// The number of paths can grow exponentially with the size of the graph.
+    ///
+    /// Returns UltraGraphError if the graph does not contain start or stop.
+    fn all_simple_paths(
+        &self,
+        start: usize,
+        stop: usize,
+    ) -> Result<Vec<Vec<usize>>, UltraGraphError> {
+        check_node(self, start)?;
+        check_node(self, stop)?;
+        Ok(paths::all_simple_paths(self, start, stop))
+    }
+
+    /// Returns up to k shortest paths from start to stop that do not visit a node twice,
+    /// with their lengths, shortest first, computed with Yen's algorithm.
+    ///
+    /// Returns UltraGraphError if the graph does not contain start or stop.
+    fn k_shortest_paths(
+        &self,
+        start: usize,
+        stop: usize,
+        k: usize,
+    ) -> Result<Vec<(u64, Vec<usize>)>, UltraGraphError> {
+        check_node(self, start)?;
+        check_node(self, stop)?;
+        Ok(paths::k_shortest_paths(self, start, stop, k))
+    }
+
+    /// Returns true if stop can be reached from start. Every node can reach itself.
+    ///
+    /// Returns UltraGraphError if the graph does not contain start or stop.
+    fn is_reachable(&self, start: usize, stop: usize) -> Result<bool, UltraGraphError> {
+        check_node(self, stop)?;
+        Ok(start == stop || self.descendants(start)?.binary_search(&stop).is_ok())
+    }
+
+    /// Returns the degree centrality of every node: the number of its incoming and
+    /// outgoing edges divided by the number of other nodes.
+    fn degree_centrality(&self) -> BTreeMap<usize, f64> {
+        centrality::degree_centrality(&CompactGraph::new(self))
+    }
+
+    /// Returns the betweenness centrality of every node: the sum over all pairs of other
+    /// nodes of the fraction of shortest paths between them that pass through the node.
+    /// Paths are counted by number of edges. If normalized, the sum is divided by
+    /// the number of pairs of other nodes.
+    fn betweenness_centrality(&self, normalized: bool) -> BTreeMap<usize, f64> {
+        centrality::betweenness_centrality(&CompactGraph::new(self), normalized)
+    }
+
+    /// Returns the PageRank of every node, ignoring edge weights. The ranks sum to 1.
+    ///
+    /// # Arguments
+    /// * damping: probability of following an edge rather than jumping to a random node, usually 0.85.
+    /// * max_iterations: maximum number of iterations.
+    /// * tolerance: iteration stops once the ranks change by less than tolerance in total.
+    fn pagerank(
+        &self,
+        damping: f64,
+        max_iterations: usize,
+        tolerance: f64,
+    ) -> BTreeMap<usize, f64> {
+        centrality::pagerank(&CompactGraph::new(self), damping, max_iterations, tolerance)
+    }
+}
+
+impl<T, G> GraphAnalysis<T> for G where G: GraphAlgorithms<T> {}
+
+fn check_node<T, G>(graph: &G, index: usize) -> Result<(), UltraGraphError>
+where
+    G: GraphAlgorithms<T> + ?Sized,
+{
+    if graph.contains_node(index) {
+        Ok(())
+    } else {
+        Err(UltraGraphError(format!("index {} not found", index)))
+    }
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+
+/// Edges store a u64 weight, used as distance by the path algorithms,
+/// and optionally a payload of type E, e.g. the kind of relation between two nodes.
+/// Edges added with add_edge or add_edge_with_weight have no payload.
+pub trait GraphEdgeDataView<E> {
+    /// Returns the payload of the edge from a to b,
+    /// or None if there is no such edge or the edge has no payload.
+    fn get_edge_data(&self, a: usize, b: usize) -> Option<&E>;
+}
+
+/// Adds and modifies edge payloads. See GraphEdgeDataView.
+pub trait GraphEdgeData<E>: GraphEdgeDataView<E> {
+    /// Adds an edge from a to b with the given weight and payload.
+    ///
+    /// Returns UltraGraphError if a or b is not found or if the edge already exists.
+    fn add_edge_with_data(
+        &mut self,
+        a: usize,
+        b: usize,
+        weight: u64,
+        data: E,
+    ) -> Result<(), UltraGraphError>;
+
+    /// Returns a mutable reference to the payload of the edge from a to b,
+    /// or None if there is no such edge or the edge has no payload.
+    fn get_edge_data_mut(&mut self, a: usize, b: usize) -> Option<&mut E>;
+
+    /// Sets the payload of the edge from a to b.
+    ///
+    /// Returns UltraGraphError if there is no such edge.
+    fn update_edge_data(&mut self, a: usize, b: usize, data: E) -> Result<(), UltraGraphError>;
+}
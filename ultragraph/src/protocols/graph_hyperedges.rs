@@ -0,0 +1,14 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::GraphView;
+
+// Hyperedges connect any number of nodes at once, in addition to the regular
+// directed edges between two nodes. A hyperedge is identified by its own index,
+// which is independent of the node indices. GraphView reads the hyperedges.
+pub trait GraphHyperedges<T>: GraphView<T> {
+    fn add_hyperedge(&mut self, nodes: &[usize], weight: u64) -> Result<usize, UltraGraphError>;
+
+    fn remove_hyperedge(&mut self, index: usize) -> Result<(), UltraGraphError>;
+}
@@ -2,16 +2,17 @@
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
 use crate::errors::UltraGraphError;
+use crate::prelude::GraphView;
 
-pub trait GraphLike<T> {
+/// Adds, updates, and removes the nodes and edges of a graph.
+/// See GraphView for how nodes are addressed.
+pub trait GraphLike<T>: GraphView<T> {
     fn add_node(&mut self, value: T) -> usize;
 
-    fn contains_node(&self, index: usize) -> bool;
-
-    fn get_node(&self, index: usize) -> Option<&T>;
-
     fn remove_node(&mut self, index: usize) -> Result<(), UltraGraphError>;
 
+    fn update_node(&mut self, index: usize, value: T) -> Result<(), UltraGraphError>;
+
     fn add_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError>;
 
     fn add_edge_with_weight(
@@ -21,7 +22,5 @@ pub trait GraphLike<T> {
         weight: u64,
     ) -> Result<(), UltraGraphError>;
 
-    fn contains_edge(&self, a: usize, b: usize) -> bool;
-
     fn remove_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError>;
 }
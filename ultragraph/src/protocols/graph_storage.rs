@@ -1,22 +1,41 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
+use crate::errors::UltraGraphError;
 use crate::protocols::graph_algorithms::GraphAlgorithms;
+use crate::protocols::graph_hyperedges::GraphHyperedges;
 use crate::protocols::graph_like::GraphLike;
 use crate::protocols::graph_root::GraphRoot;
 
-pub trait GraphStorage<T>: GraphLike<T> + GraphRoot<T> + GraphAlgorithms<T> {
-    fn size(&self) -> usize;
-
-    fn is_empty(&self) -> bool;
-
-    fn number_nodes(&self) -> usize;
-
-    fn number_edges(&self) -> usize;
-
-    fn get_all_nodes(&self) -> Vec<&T>;
-
-    fn get_all_edges(&self) -> Vec<(usize, usize)>;
+pub trait GraphStorage<T>:
+    GraphLike<T> + GraphRoot<T> + GraphAlgorithms<T> + GraphHyperedges<T>
+{
+    /// Iterates over all nodes and their indices with mutable access to the nodes.
+    /// The order is unspecified.
+    fn nodes_mut<'a>(&'a mut self) -> impl Iterator<Item = (usize, &'a mut T)>
+    where
+        T: 'a;
+
+    /// Removes all nodes for which the predicate returns false,
+    /// together with their edges and hyperedges.
+    ///
+    /// Returns UltraGraphError if a node cannot be removed.
+    fn retain_nodes<F>(&mut self, mut predicate: F) -> Result<(), UltraGraphError>
+    where
+        F: FnMut(usize, &T) -> bool,
+    {
+        let removed: Vec<usize> = self
+            .nodes()
+            .filter(|(index, value)| !predicate(*index, value))
+            .map(|(index, _)| index)
+            .collect();
+
+        for index in removed {
+            self.remove_node(index)?;
+        }
+
+        Ok(())
+    }
 
     fn clear(&mut self);
 }
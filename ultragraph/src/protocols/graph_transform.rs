@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{
+    FilteredGraph, GraphAlgorithms, GraphEdgeData, GraphEdgeDataView, GraphStorage, ReversedGraph,
+    Subgraph,
+};
+
+/// Subgraphs, merging, and views implemented once on top of GraphAlgorithms,
+/// so that every storage backend and every view provides them. Merging modifies
+/// the graph and is therefore only available for graphs that implement GraphStorage.
+///
+/// Subgraphs and merges copy nodes, edges with their weights and payloads, and hyperedges
+/// into another graph, where the nodes get new indices. They return a map from the indices
+/// in the source graph to the indices in the new graph. Nodes are copied in ascending order
+/// of their index, so the new indices keep the order of the old ones.
+///
+/// Views borrow the graph and hide or reverse its structure without copying it.
+pub trait GraphTransform<T>: GraphAlgorithms<T> {
+    /// Returns a copy of the given nodes and of all edges and hyperedges between them,
+    /// together with the map from the indices in this graph to the indices in the subgraph.
+    /// The root node is kept if it is one of the given nodes. Duplicates are ignored.
+    ///
+    /// Returns UltraGraphError if the graph does not contain one of the nodes.
+    fn induced_subgraph<E>(&self, nodes: &[usize]) -> Result<Subgraph<T, E>, UltraGraphError>
+    where
+        Self: GraphEdgeDataView<E> + Sized,
+        T: Clone,
+        E: Clone,
+    {
+        for index in nodes {
+            if !self.contains_node(*index) {
+                return Err(UltraGraphError(format!("index {} not found", index)));
+            }
+        }
+
+        let nodes: BTreeSet<usize> = nodes.iter().copied().collect();
+        let mut res = crate::with_edge_data(nodes.len());
+        let map = copy_into(self, &mut res, &nodes)?;
+        Ok((res, map))
+    }
+
+    /// Returns the subgraph induced by start and all nodes that can be reached from start,
+    /// i.e. the part of the graph below start, together with the map from the indices
+    /// in this graph to the indices in the subgraph.
+    ///
+    /// Returns UltraGraphError if the graph does not contain start.
+    fn reachable_subgraph<E>(&self, start: usize) -> Result<Subgraph<T, E>, UltraGraphError>
+    where
+        Self: GraphEdgeDataView<E> + Sized,
+        T: Clone,
+        E: Clone,
+    {
+        let mut nodes = self.descendants(start)?;
+        nodes.push(start);
+        self.induced_subgraph(&nodes)
+    }
+
+    /// Adds all nodes, edges, and hyperedges of other to this graph and returns the map
+    /// from the indices in other to the indices in this graph. The root node of other
+    /// becomes the root node of this graph if this graph has none.
+    ///
+    /// Returns UltraGraphError if an edge or hyperedge cannot be added.
+    /// Nodes and edges added before the error remain in the graph.
+    fn merge<E, G>(&mut self, other: &G) -> Result<BTreeMap<usize, usize>, UltraGraphError>
+    where
+        Self: GraphStorage<T> + GraphEdgeData<E> + Sized,
+        G: GraphAlgorithms<T> + GraphEdgeDataView<E>,
+        T: Clone,
+        E: Clone,
+    {
+        let nodes: BTreeSet<usize> = other.nodes().map(|(index, _)| index).collect();
+        copy_into(other, self, &nodes)
+    }
+
+    /// Returns a view of the graph in which every edge points the other way.
+    fn reversed(&self) -> ReversedGraph<'_, Self>
+    where
+        Self: Sized,
+    {
+        ReversedGraph::new(self)
+    }
+
+    /// Returns a view of the graph that hides all nodes for which the predicate
+    /// returns false, together with their edges and hyperedges.
+    fn filter_nodes<'a, F>(&'a self, predicate: F) -> FilteredGraph<'a, T, Self>
+    where
+        Self: Sized,
+        F: Fn(usize, &T) -> bool + 'a,
+    {
+        FilteredGraph::new(self, predicate, |_, _, _| true)
+    }
+
+    /// Returns a view of the graph that hides all edges (from, to, weight)
+    /// for which the predicate returns false.
+    fn filter_edges<'a, F>(&'a self, predicate: F) -> FilteredGraph<'a, T, Self>
+    where
+        Self: Sized,
+        F: Fn(usize, usize, &u64) -> bool + 'a,
+    {
+        FilteredGraph::new(self, |_, _| true, predicate)
+    }
+}
+
+impl<T, G> GraphTransform<T> for G where G: GraphAlgorithms<T> {}
+
+// Copies the nodes of source, and the edges and hyperedges between them, into target.
+// Returns the map from the indices in source to the indices in target.
+fn copy_into<T, E, G, H>(
+    source: &G,
+    target: &mut H,
+    nodes: &BTreeSet<usize>,
+) -> Result<BTreeMap<usize, usize>, UltraGraphError>
+where
+    G: GraphAlgorithms<T> + GraphEdgeDataView<E>,
+    H: GraphStorage<T> + GraphEdgeData<E>,
+    T: Clone,
+    E: Clone,
+{
+    let root = source.get_root_index();
+    let mut map = BTreeMap::new();
+
+    for index in nodes {
+        let value = source.get_node(*index).expect("Node exists").clone();
+        let new_index = if root == Some(*index) && !target.contains_root_node() {
+            target.add_root_node(value)
+        } else {
+            target.add_node(value)
+        };
+        map.insert(*index, new_index);
+    }
+
+    // Edges are added in sorted order so that the result does not depend on the backend.
+    let mut edges: Vec<(usize, usize, u64)> = source
+        .edges()
+        .filter(|(a, b, _)| map.contains_key(a) && map.contains_key(b))
+        .map(|(a, b, weight)| (a, b, *weight))
+        .collect();
+    edges.sort_unstable();
+
+    for (a, b, weight) in edges {
+        match source.get_edge_data(a, b) {
+            Some(data) => target.add_edge_with_data(map[&a], map[&b], weight, data.clone())?,
+            None => target.add_edge_with_weight(map[&a], map[&b], weight)?,
+        }
+    }
+
+    let hyperedges: BTreeSet<usize> = nodes
+        .iter()
+        .flat_map(|index| source.get_hyperedges_of_node(*index))
+        .collect();
+
+    for index in hyperedges {
+        let hyperedge = source.get_hyperedge(index).expect("Hyperedge exists");
+        if hyperedge.nodes().iter().all(|node| map.contains_key(node)) {
+            let nodes: Vec<usize> = hyperedge.nodes().iter().map(|node| map[node]).collect();
+            target.add_hyperedge(&nodes, hyperedge.weight())?;
+        }
+    }
+
+    Ok(map)
+}
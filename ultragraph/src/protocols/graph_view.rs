@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::Hyperedge;
+
+/// Read-only access to the nodes, edges, root, and hyperedges of a graph.
+///
+/// Nodes are addressed by the index returned from add_node.
+/// Indices are never reused, so the index of a removed node stays invalid
+/// even after other nodes are added.
+///
+/// Storage backends implement GraphView together with the mutating traits
+/// GraphLike, GraphRoot, GraphHyperedges, and GraphStorage.
+/// ReversedGraph and FilteredGraph implement only GraphView.
+pub trait GraphView<T> {
+    fn contains_node(&self, index: usize) -> bool;
+
+    fn get_node(&self, index: usize) -> Option<&T>;
+
+    fn contains_edge(&self, a: usize, b: usize) -> bool;
+
+    fn get_edge_weight(&self, a: usize, b: usize) -> Option<&u64>;
+
+    fn contains_root_node(&self) -> bool;
+
+    fn get_root_node(&self) -> Option<&T>;
+
+    fn get_root_index(&self) -> Option<usize>;
+
+    fn get_last_index(&self) -> Result<usize, UltraGraphError>;
+
+    fn size(&self) -> usize;
+
+    fn is_empty(&self) -> bool;
+
+    fn number_nodes(&self) -> usize;
+
+    fn number_edges(&self) -> usize;
+
+    fn get_all_nodes(&self) -> Vec<&T>;
+
+    fn get_all_indexed_nodes(&self) -> Vec<(usize, &T)>;
+
+    fn get_all_edges(&self) -> Vec<(usize, usize)>;
+
+    /// Iterates over all nodes and their indices without allocating.
+    /// The order is unspecified; use get_all_indexed_nodes for nodes sorted by index.
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = (usize, &'a T)>
+    where
+        T: 'a;
+
+    /// Iterates over all edges (from, to, weight) without allocating.
+    /// The order is unspecified.
+    fn edges(&self) -> impl Iterator<Item = (usize, usize, &u64)>;
+
+    /// Iterates over the targets of the outgoing edges of a node without allocating.
+    /// The order is unspecified.
+    ///
+    /// Returns UltraGraphError if the node is not found.
+    fn neighbors(&self, a: usize) -> Result<impl Iterator<Item = usize>, UltraGraphError>;
+
+    fn contains_hyperedge(&self, index: usize) -> bool;
+
+    fn get_hyperedge(&self, index: usize) -> Option<&Hyperedge>;
+
+    fn get_hyperedges_of_node(&self, node_index: usize) -> Vec<usize>;
+
+    fn number_hyperedges(&self) -> usize;
+}
@@ -4,6 +4,13 @@
 #![forbid(unsafe_code)]
 
 pub mod graph_algorithms;
+pub mod graph_analysis;
+pub mod graph_edge_data;
+pub mod graph_hyperedges;
 pub mod graph_like;
 pub mod graph_root;
 pub mod graph_storage;
+pub mod graph_transform;
+pub mod graph_view;
+#[cfg(feature = "io")]
+pub mod node_format;
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+
+/// Converts node values to and from the text stored in CSV, GraphML, and JSON files.
+///
+/// Implemented for strings, chars, booleans, and all primitive numbers.
+/// For other node types, implement both methods so that from_text(&to_text()) returns
+/// an equal value, i.e. by formatting the fields of a struct with a separator.
+pub trait NodeFormat: Sized {
+    fn to_text(&self) -> String;
+
+    /// Returns UltraGraphError if the text does not describe a valid node value.
+    fn from_text(text: &str) -> Result<Self, UltraGraphError>;
+}
+
+macro_rules! impl_node_format {
+    ($($ty:ty),*) => {
+        $(
+            impl NodeFormat for $ty {
+                fn to_text(&self) -> String {
+                    self.to_string()
+                }
+
+                fn from_text(text: &str) -> Result<Self, UltraGraphError> {
+                    text.parse().map_err(|_| {
+                        UltraGraphError(format!(
+                            "Cannot convert {:?} to {}",
+                            text,
+                            stringify!($ty)
+                        ))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_node_format!(
+    String, char, bool, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
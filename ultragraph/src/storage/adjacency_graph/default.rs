@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use super::UltraAdjacencyListGraph;
+
+impl<T, E> Default for UltraAdjacencyListGraph<T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
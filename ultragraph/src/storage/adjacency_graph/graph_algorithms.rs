@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::vec::IntoIter;
+
+use crate::algorithms::shortest_path::shortest_path;
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphAlgorithms, GraphView};
+
+use super::UltraAdjacencyListGraph;
+
+impl<T, E> GraphAlgorithms<T> for UltraAdjacencyListGraph<T, E> {
+    fn shortest_path(&self, start_index: usize, stop_index: usize) -> Option<Vec<usize>> {
+        if !self.contains_node(start_index) || !self.contains_node(stop_index) {
+            return None;
+        }
+
+        shortest_path(start_index, stop_index, |index| {
+            self.nodes[&index]
+                .outgoing
+                .iter()
+                .map(|edge| (edge.target, edge.weight))
+        })
+        .map(|(_, path)| path)
+    }
+
+    fn outgoing_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError> {
+        match self.nodes.get(&a) {
+            Some(node) => {
+                let result: Vec<usize> = node.outgoing.iter().map(|edge| edge.target).collect();
+                Ok(result.into_iter())
+            }
+            None => Err(UltraGraphError("index a not found".into())),
+        }
+    }
+
+    fn incoming_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError> {
+        match self.nodes.get(&a) {
+            Some(node) => Ok(node.incoming.clone().into_iter()),
+            None => Err(UltraGraphError("index a not found".into())),
+        }
+    }
+
+    fn sources(&self) -> Vec<usize> {
+        let mut res: Vec<usize> = self
+            .nodes
+            .iter()
+            .filter(|(_, node)| node.incoming.is_empty())
+            .map(|(idx, _)| *idx)
+            .collect();
+
+        res.sort_unstable();
+        res
+    }
+
+    fn sinks(&self) -> Vec<usize> {
+        let mut res: Vec<usize> = self
+            .nodes
+            .iter()
+            .filter(|(_, node)| node.outgoing.is_empty())
+            .map(|(idx, _)| *idx)
+            .collect();
+
+        res.sort_unstable();
+        res
+    }
+
+    fn in_degree(&self, a: usize) -> Result<usize, UltraGraphError> {
+        match self.nodes.get(&a) {
+            Some(node) => Ok(node.incoming.len()),
+            None => Err(UltraGraphError("index a not found".into())),
+        }
+    }
+
+    fn out_degree(&self, a: usize) -> Result<usize, UltraGraphError> {
+        match self.nodes.get(&a) {
+            Some(node) => Ok(node.outgoing.len()),
+            None => Err(UltraGraphError("index a not found".into())),
+        }
+    }
+}
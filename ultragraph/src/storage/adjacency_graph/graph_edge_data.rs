@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphEdgeData, GraphEdgeDataView};
+
+use super::UltraAdjacencyListGraph;
+
+impl<T, E> GraphEdgeDataView<E> for UltraAdjacencyListGraph<T, E> {
+    fn get_edge_data(&self, a: usize, b: usize) -> Option<&E> {
+        self.nodes
+            .get(&a)?
+            .outgoing
+            .iter()
+            .find(|edge| edge.target == b)?
+            .data
+            .as_ref()
+    }
+}
+
+impl<T, E> GraphEdgeData<E> for UltraAdjacencyListGraph<T, E> {
+    fn add_edge_with_data(
+        &mut self,
+        a: usize,
+        b: usize,
+        weight: u64,
+        data: E,
+    ) -> Result<(), UltraGraphError> {
+        self.insert_edge(a, b, weight, Some(data))
+    }
+
+    fn get_edge_data_mut(&mut self, a: usize, b: usize) -> Option<&mut E> {
+        self.edge_mut(a, b)?.data.as_mut()
+    }
+
+    fn update_edge_data(&mut self, a: usize, b: usize, data: E) -> Result<(), UltraGraphError> {
+        match self.edge_mut(a, b) {
+            Some(edge) => {
+                edge.data = Some(data);
+                Ok(())
+            }
+            None => Err(UltraGraphError(format!(
+                "Edge does not exists between: {} and {}",
+                a, b
+            ))),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::GraphHyperedges;
+
+use super::UltraAdjacencyListGraph;
+
+impl<T, E> GraphHyperedges<T> for UltraAdjacencyListGraph<T, E> {
+    fn add_hyperedge(&mut self, nodes: &[usize], weight: u64) -> Result<usize, UltraGraphError> {
+        let node_map = &self.nodes;
+        self.hyperedges
+            .add(nodes, weight, |node| node_map.contains_key(&node))
+    }
+
+    fn remove_hyperedge(&mut self, index: usize) -> Result<(), UltraGraphError> {
+        self.hyperedges.remove(index)
+    }
+}
@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphLike, GraphView};
+
+use super::{AdjacencyEdge, AdjacencyNode, UltraAdjacencyListGraph};
+
+impl<T, E> GraphLike<T> for UltraAdjacencyListGraph<T, E> {
+    fn add_node(&mut self, value: T) -> usize {
+        let index = self.next_node_index;
+        self.next_node_index += 1;
+
+        self.nodes.insert(
+            index,
+            AdjacencyNode {
+                value,
+                outgoing: Vec::new(),
+                incoming: Vec::new(),
+            },
+        );
+        index
+    }
+
+    fn remove_node(&mut self, index: usize) -> Result<(), UltraGraphError> {
+        let node = match self.nodes.remove(&index) {
+            Some(node) => node,
+            None => return Err(UltraGraphError(format!("index {} not found", index))),
+        };
+
+        for edge in &node.outgoing {
+            if let Some(child) = self.nodes.get_mut(&edge.target) {
+                child.incoming.retain(|i| *i != index);
+            }
+        }
+
+        for parent in &node.incoming {
+            if let Some(parent) = self.nodes.get_mut(parent) {
+                parent.outgoing.retain(|edge| edge.target != index);
+            }
+        }
+
+        // A self loop is both an outgoing and an incoming edge but counts only once.
+        let self_loops = node.outgoing.iter().filter(|e| e.target == index).count();
+        self.number_edges -= node.outgoing.len() + node.incoming.len() - self_loops;
+
+        if self.root_index == Some(index) {
+            self.root_index = None;
+        }
+        self.hyperedges.remove_node(index);
+        Ok(())
+    }
+
+    fn update_node(&mut self, index: usize, value: T) -> Result<(), UltraGraphError> {
+        match self.nodes.get_mut(&index) {
+            Some(node) => {
+                node.value = value;
+                Ok(())
+            }
+            None => Err(UltraGraphError(format!("index {} not found", index))),
+        }
+    }
+
+    fn add_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError> {
+        self.add_edge_with_weight(a, b, 0)
+    }
+
+    fn add_edge_with_weight(
+        &mut self,
+        a: usize,
+        b: usize,
+        weight: u64,
+    ) -> Result<(), UltraGraphError> {
+        self.insert_edge(a, b, weight, None)
+    }
+
+    fn remove_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError> {
+        if !self.contains_node(a) {
+            return Err(UltraGraphError("index a not found".into()));
+        }
+
+        if !self.contains_node(b) {
+            return Err(UltraGraphError("index b not found".into()));
+        }
+
+        if !self.contains_edge(a, b) {
+            return Err(UltraGraphError(format!(
+                "Edge does not exists between: {} and {}",
+                a, b
+            )));
+        }
+
+        self.nodes
+            .get_mut(&a)
+            .expect("Node a exists")
+            .outgoing
+            .retain(|edge| edge.target != b);
+        self.nodes
+            .get_mut(&b)
+            .expect("Node b exists")
+            .incoming
+            .retain(|i| *i != a);
+        self.number_edges -= 1;
+        Ok(())
+    }
+}
+
+impl<T, E> UltraAdjacencyListGraph<T, E> {
+    // Adds an edge with or without payload.
+    pub(super) fn insert_edge(
+        &mut self,
+        a: usize,
+        b: usize,
+        weight: u64,
+        data: Option<E>,
+    ) -> Result<(), UltraGraphError> {
+        if !self.contains_node(a) {
+            return Err(UltraGraphError(format!("index a {} not found", a)));
+        }
+
+        if !self.contains_node(b) {
+            return Err(UltraGraphError(format!("index b {} not found", b)));
+        }
+
+        if self.contains_edge(a, b) {
+            return Err(UltraGraphError(format!(
+                "Edge already exists between: {} and {}",
+                a, b
+            )));
+        }
+
+        self.nodes
+            .get_mut(&a)
+            .expect("Node a exists")
+            .outgoing
+            .push(AdjacencyEdge {
+                target: b,
+                weight,
+                data,
+            });
+        self.nodes
+            .get_mut(&b)
+            .expect("Node b exists")
+            .incoming
+            .push(a);
+        self.number_edges += 1;
+        Ok(())
+    }
+
+    // Returns the edge from a to b, if any.
+    pub(super) fn edge_mut(&mut self, a: usize, b: usize) -> Option<&mut AdjacencyEdge<E>> {
+        self.nodes
+            .get_mut(&a)?
+            .outgoing
+            .iter_mut()
+            .find(|edge| edge.target == b)
+    }
+}
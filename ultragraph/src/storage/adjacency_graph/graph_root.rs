@@ -0,0 +1,14 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::prelude::{GraphLike, GraphRoot};
+
+use super::UltraAdjacencyListGraph;
+
+impl<T, E> GraphRoot<T> for UltraAdjacencyListGraph<T, E> {
+    fn add_root_node(&mut self, value: T) -> usize {
+        let index = self.add_node(value);
+        self.root_index = Some(index);
+        index
+    }
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::prelude::GraphStorage;
+
+use super::UltraAdjacencyListGraph;
+
+impl<T, E> GraphStorage<T> for UltraAdjacencyListGraph<T, E> {
+    fn nodes_mut<'a>(&'a mut self) -> impl Iterator<Item = (usize, &'a mut T)>
+    where
+        T: 'a,
+    {
+        self.nodes
+            .iter_mut()
+            .map(|(idx, node)| (*idx, &mut node.value))
+    }
+
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.number_edges = 0;
+        // Indices are not reset, so that indices from before remain invalid.
+        self.hyperedges.clear();
+        self.root_index = None;
+    }
+}
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphView, Hyperedge};
+
+use super::UltraAdjacencyListGraph;
+
+impl<T, E> GraphView<T> for UltraAdjacencyListGraph<T, E> {
+    fn contains_node(&self, index: usize) -> bool {
+        self.nodes.contains_key(&index)
+    }
+
+    fn get_node(&self, index: usize) -> Option<&T> {
+        self.nodes.get(&index).map(|node| &node.value)
+    }
+
+    fn contains_edge(&self, a: usize, b: usize) -> bool {
+        self.get_edge_weight(a, b).is_some()
+    }
+
+    fn get_edge_weight(&self, a: usize, b: usize) -> Option<&u64> {
+        self.nodes
+            .get(&a)?
+            .outgoing
+            .iter()
+            .find(|edge| edge.target == b)
+            .map(|edge| &edge.weight)
+    }
+
+    fn contains_root_node(&self) -> bool {
+        self.root_index.is_some()
+    }
+
+    fn get_root_node(&self) -> Option<&T> {
+        self.get_node(self.root_index?)
+    }
+
+    fn get_root_index(&self) -> Option<usize> {
+        self.root_index
+    }
+
+    fn get_last_index(&self) -> Result<usize, UltraGraphError> {
+        if !self.is_empty() {
+            Ok(self.nodes.len())
+        } else {
+            Err(UltraGraphError("Graph is empty".to_string()))
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    fn number_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn number_edges(&self) -> usize {
+        self.number_edges
+    }
+
+    fn get_all_nodes(&self) -> Vec<&T> {
+        self.get_all_indexed_nodes()
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect()
+    }
+
+    fn get_all_indexed_nodes(&self) -> Vec<(usize, &T)> {
+        let mut res: Vec<(usize, &T)> = self
+            .nodes
+            .iter()
+            .map(|(idx, node)| (*idx, &node.value))
+            .collect();
+
+        res.sort_unstable_by_key(|(idx, _)| *idx);
+        res
+    }
+
+    fn get_all_edges(&self) -> Vec<(usize, usize)> {
+        let mut edges = Vec::with_capacity(self.number_edges);
+
+        for (idx, node) in self.nodes.iter() {
+            for edge in &node.outgoing {
+                edges.push((*idx, edge.target));
+            }
+        }
+
+        edges
+    }
+
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = (usize, &'a T)>
+    where
+        T: 'a,
+    {
+        self.nodes.iter().map(|(idx, node)| (*idx, &node.value))
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (usize, usize, &u64)> {
+        self.nodes.iter().flat_map(|(idx, node)| {
+            node.outgoing
+                .iter()
+                .map(move |edge| (*idx, edge.target, &edge.weight))
+        })
+    }
+
+    fn neighbors(&self, a: usize) -> Result<impl Iterator<Item = usize>, UltraGraphError> {
+        match self.nodes.get(&a) {
+            Some(node) => Ok(node.outgoing.iter().map(|edge| edge.target)),
+            None => Err(UltraGraphError(format!("index a {} not found", a))),
+        }
+    }
+
+    fn contains_hyperedge(&self, index: usize) -> bool {
+        self.hyperedges.contains(index)
+    }
+
+    fn get_hyperedge(&self, index: usize) -> Option<&Hyperedge> {
+        self.hyperedges.get(index)
+    }
+
+    fn get_hyperedges_of_node(&self, node_index: usize) -> Vec<usize> {
+        self.hyperedges.of_node(node_index)
+    }
+
+    fn number_hyperedges(&self) -> usize {
+        self.hyperedges.len()
+    }
+}
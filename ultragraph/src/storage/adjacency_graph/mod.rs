@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use ahash::AHashMap;
+
+use crate::storage::hyperedge_store::HyperedgeStore;
+
+mod default;
+mod graph_algorithms;
+mod graph_edge_data;
+mod graph_hyperedges;
+mod graph_like;
+mod graph_root;
+mod graph_storage;
+mod graph_view;
+
+// Each node stores its value together with its outgoing edges and the sources of its
+// incoming edges. Memory grows with the number of edges rather than with the square of
+// the number of nodes as in the matrix graph, which suits large, sparse graphs.
+// Incoming edges make removing a node proportional to its degree.
+#[derive(Clone)]
+struct AdjacencyNode<T, E> {
+    value: T,
+    outgoing: Vec<AdjacencyEdge<E>>,
+    incoming: Vec<usize>,
+}
+
+// Each edge stores its weight and, optionally, a payload of any type.
+#[derive(Clone)]
+struct AdjacencyEdge<E> {
+    target: usize,
+    weight: u64,
+    data: Option<E>,
+}
+
+// Nodes are keyed by their public index directly. Indices are never reused,
+// so that an index of a removed node never refers to a node added later.
+type NodeMap<T, E> = AHashMap<usize, AdjacencyNode<T, E>>;
+
+#[derive(Clone)]
+pub struct UltraAdjacencyListGraph<T, E = ()> {
+    root_index: Option<usize>,
+    nodes: NodeMap<T, E>,
+    number_edges: usize,
+    next_node_index: usize,
+    hyperedges: HyperedgeStore,
+}
+
+impl<T, E> UltraAdjacencyListGraph<T, E> {
+    pub fn new() -> Self {
+        Self {
+            root_index: None,
+            nodes: AHashMap::new(),
+            number_edges: 0,
+            next_node_index: 0,
+            hyperedges: HyperedgeStore::new(),
+        }
+    }
+
+    pub fn new_with_capacity(capacity: usize) -> Self {
+        Self {
+            root_index: None,
+            nodes: AHashMap::with_capacity(capacity),
+            number_edges: 0,
+            next_node_index: 0,
+            hyperedges: HyperedgeStore::new(),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use super::UltraCsrGraph;
+
+impl<T, E> Default for UltraCsrGraph<T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::vec::IntoIter;
+
+use crate::algorithms::shortest_path::shortest_path;
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphAlgorithms, GraphView};
+
+use super::UltraCsrGraph;
+
+impl<T, E> GraphAlgorithms<T> for UltraCsrGraph<T, E> {
+    fn shortest_path(&self, start_index: usize, stop_index: usize) -> Option<Vec<usize>> {
+        if !self.contains_node(start_index) || !self.contains_node(stop_index) {
+            return None;
+        }
+
+        shortest_path(start_index, stop_index, |index| {
+            let range = self.edge_range(self.row(index).expect("Node exists"));
+            self.targets[range.clone()]
+                .iter()
+                .copied()
+                .zip(self.weights[range].iter().copied())
+        })
+        .map(|(_, path)| path)
+    }
+
+    fn outgoing_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError> {
+        match self.row(a) {
+            Some(row) => {
+                let result: Vec<usize> = self.targets[self.edge_range(row)].to_vec();
+                Ok(result.into_iter())
+            }
+            None => Err(UltraGraphError("index a not found".into())),
+        }
+    }
+
+    fn incoming_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError> {
+        match self.row(a) {
+            Some(row) => {
+                let result: Vec<usize> = self.in_sources[self.incoming_range(row)].to_vec();
+                Ok(result.into_iter())
+            }
+            None => Err(UltraGraphError("index a not found".into())),
+        }
+    }
+
+    fn sources(&self) -> Vec<usize> {
+        (0..self.rows.len())
+            .filter(|row| self.incoming_range(*row).is_empty())
+            .map(|row| self.rows[row])
+            .collect()
+    }
+
+    fn sinks(&self) -> Vec<usize> {
+        (0..self.rows.len())
+            .filter(|row| self.edge_range(*row).is_empty())
+            .map(|row| self.rows[row])
+            .collect()
+    }
+
+    fn in_degree(&self, a: usize) -> Result<usize, UltraGraphError> {
+        match self.row(a) {
+            Some(row) => Ok(self.incoming_range(row).len()),
+            None => Err(UltraGraphError("index a not found".into())),
+        }
+    }
+
+    fn out_degree(&self, a: usize) -> Result<usize, UltraGraphError> {
+        match self.row(a) {
+            Some(row) => Ok(self.edge_range(row).len()),
+            None => Err(UltraGraphError("index a not found".into())),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphEdgeData, GraphEdgeDataView};
+
+use super::UltraCsrGraph;
+
+impl<T, E> GraphEdgeDataView<E> for UltraCsrGraph<T, E> {
+    fn get_edge_data(&self, a: usize, b: usize) -> Option<&E> {
+        self.data.get(self.edge_position(a, b)?)?.as_ref()
+    }
+}
+
+// Payloads can be replaced because that does not change the structure of the graph.
+impl<T, E> GraphEdgeData<E> for UltraCsrGraph<T, E> {
+    fn add_edge_with_data(
+        &mut self,
+        a: usize,
+        b: usize,
+        _weight: u64,
+        _data: E,
+    ) -> Result<(), UltraGraphError> {
+        Err(Self::read_only(&format!("add edge {} -> {}", a, b)))
+    }
+
+    fn get_edge_data_mut(&mut self, a: usize, b: usize) -> Option<&mut E> {
+        let position = self.edge_position(a, b)?;
+        self.data.get_mut(position)?.as_mut()
+    }
+
+    fn update_edge_data(&mut self, a: usize, b: usize, data: E) -> Result<(), UltraGraphError> {
+        match self.edge_position(a, b) {
+            Some(position) => {
+                self.data[position] = Some(data);
+                Ok(())
+            }
+            None => Err(UltraGraphError(format!(
+                "Edge does not exists between: {} and {}",
+                a, b
+            ))),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::GraphHyperedges;
+
+use super::UltraCsrGraph;
+
+impl<T, E> GraphHyperedges<T> for UltraCsrGraph<T, E> {
+    fn add_hyperedge(&mut self, nodes: &[usize], weight: u64) -> Result<usize, UltraGraphError> {
+        let rows = &self.rows;
+        self.hyperedges
+            .add(nodes, weight, |node| rows.binary_search(&node).is_ok())
+    }
+
+    fn remove_hyperedge(&mut self, index: usize) -> Result<(), UltraGraphError> {
+        self.hyperedges.remove(index)
+    }
+}
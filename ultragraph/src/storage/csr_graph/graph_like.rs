@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphLike, GraphView};
+
+use super::UltraCsrGraph;
+
+impl<T, E> GraphLike<T> for UltraCsrGraph<T, E> {
+    // Appends a row without edges.
+    fn add_node(&mut self, value: T) -> usize {
+        let index = self.next_node_index;
+        self.next_node_index += 1;
+
+        self.rows.push(index);
+        self.values.push(value);
+        self.offsets.push(self.targets.len());
+        self.in_offsets.push(self.in_sources.len());
+        index
+    }
+
+    fn remove_node(&mut self, index: usize) -> Result<(), UltraGraphError> {
+        if !self.contains_node(index) {
+            return Err(UltraGraphError(format!("index {} not found", index)));
+        }
+
+        Err(Self::read_only(&format!("remove node {}", index)))
+    }
+
+    fn update_node(&mut self, index: usize, value: T) -> Result<(), UltraGraphError> {
+        let row = match self.row(index) {
+            Some(row) => row,
+            None => return Err(UltraGraphError(format!("index {} not found", index))),
+        };
+
+        self.values[row] = value;
+        Ok(())
+    }
+
+    fn add_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError> {
+        self.add_edge_with_weight(a, b, 0)
+    }
+
+    fn add_edge_with_weight(
+        &mut self,
+        a: usize,
+        b: usize,
+        _weight: u64,
+    ) -> Result<(), UltraGraphError> {
+        Err(Self::read_only(&format!("add edge {} -> {}", a, b)))
+    }
+
+    fn remove_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError> {
+        Err(Self::read_only(&format!("remove edge {} -> {}", a, b)))
+    }
+}
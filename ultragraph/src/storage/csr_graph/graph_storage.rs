@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::prelude::GraphStorage;
+
+use super::UltraCsrGraph;
+
+impl<T, E> GraphStorage<T> for UltraCsrGraph<T, E> {
+    fn nodes_mut<'a>(&'a mut self) -> impl Iterator<Item = (usize, &'a mut T)>
+    where
+        T: 'a,
+    {
+        self.rows.iter().copied().zip(self.values.iter_mut())
+    }
+
+    fn clear(&mut self) {
+        self.rows.clear();
+        self.values.clear();
+        self.offsets.clear();
+        self.offsets.push(0);
+        self.targets.clear();
+        self.weights.clear();
+        self.data.clear();
+        self.in_offsets.clear();
+        self.in_offsets.push(0);
+        self.in_sources.clear();
+        // Indices are not reset, so that indices from before remain invalid.
+        self.hyperedges.clear();
+        self.root_index = None;
+    }
+}
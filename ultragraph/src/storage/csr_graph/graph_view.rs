@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphView, Hyperedge};
+
+use super::UltraCsrGraph;
+
+impl<T, E> GraphView<T> for UltraCsrGraph<T, E> {
+    fn contains_node(&self, index: usize) -> bool {
+        self.row(index).is_some()
+    }
+
+    fn get_node(&self, index: usize) -> Option<&T> {
+        self.values.get(self.row(index)?)
+    }
+
+    fn contains_edge(&self, a: usize, b: usize) -> bool {
+        self.edge_position(a, b).is_some()
+    }
+
+    fn get_edge_weight(&self, a: usize, b: usize) -> Option<&u64> {
+        self.weights.get(self.edge_position(a, b)?)
+    }
+
+    fn contains_root_node(&self) -> bool {
+        self.root_index.is_some()
+    }
+
+    fn get_root_node(&self) -> Option<&T> {
+        self.get_node(self.root_index?)
+    }
+
+    fn get_root_index(&self) -> Option<usize> {
+        self.root_index
+    }
+
+    fn get_last_index(&self) -> Result<usize, UltraGraphError> {
+        if !self.is_empty() {
+            Ok(self.values.len())
+        } else {
+            Err(UltraGraphError("Graph is empty".to_string()))
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.values.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    fn number_nodes(&self) -> usize {
+        self.values.len()
+    }
+
+    fn number_edges(&self) -> usize {
+        self.targets.len()
+    }
+
+    fn get_all_nodes(&self) -> Vec<&T> {
+        self.values.iter().collect()
+    }
+
+    fn get_all_indexed_nodes(&self) -> Vec<(usize, &T)> {
+        self.rows.iter().copied().zip(self.values.iter()).collect()
+    }
+
+    fn get_all_edges(&self) -> Vec<(usize, usize)> {
+        let mut edges = Vec::with_capacity(self.targets.len());
+
+        for (row, idx) in self.rows.iter().enumerate() {
+            for e in &self.targets[self.edge_range(row)] {
+                edges.push((*idx, *e));
+            }
+        }
+
+        edges
+    }
+
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = (usize, &'a T)>
+    where
+        T: 'a,
+    {
+        self.rows.iter().copied().zip(self.values.iter())
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (usize, usize, &u64)> {
+        self.rows.iter().enumerate().flat_map(move |(row, idx)| {
+            self.edge_range(row)
+                .map(move |position| (*idx, self.targets[position], &self.weights[position]))
+        })
+    }
+
+    fn neighbors(&self, a: usize) -> Result<impl Iterator<Item = usize>, UltraGraphError> {
+        match self.row(a) {
+            Some(row) => Ok(self.targets[self.edge_range(row)].iter().copied()),
+            None => Err(UltraGraphError(format!("index a {} not found", a))),
+        }
+    }
+
+    fn contains_hyperedge(&self, index: usize) -> bool {
+        self.hyperedges.contains(index)
+    }
+
+    fn get_hyperedge(&self, index: usize) -> Option<&Hyperedge> {
+        self.hyperedges.get(index)
+    }
+
+    fn get_hyperedges_of_node(&self, node_index: usize) -> Vec<usize> {
+        self.hyperedges.of_node(node_index)
+    }
+
+    fn number_hyperedges(&self) -> usize {
+        self.hyperedges.len()
+    }
+}
@@ -0,0 +1,265 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphAlgorithms, GraphEdgeDataView};
+use crate::storage::hyperedge_store::HyperedgeStore;
+
+mod default;
+mod graph_algorithms;
+mod graph_edge_data;
+mod graph_hyperedges;
+mod graph_like;
+mod graph_root;
+mod graph_storage;
+mod graph_view;
+
+// Edge (a, b, weight, data) given to a CSR graph constructor.
+type CsrEdge<E> = (usize, usize, u64, Option<E>);
+
+// Compressed sparse row (CSR) storage for large, static graphs.
+//
+// Each node is a row. The edges of all rows are stored in flat arrays, targets, weights, and data,
+// and the edges of row r are at offsets[r]..offsets[r + 1], sorted by target. This is the most
+// compact layout and makes iterating the edges of a node a single slice access, at the cost of
+// making edge insertion and removal O(number of edges). The graph is therefore read-only:
+// nodes can be appended, since a new row without edges is cheap, and node values can be updated,
+// but removing nodes and adding or removing edges returns an error.
+//
+// Incoming edges are indexed the same way in in_offsets and in_sources, so that walking the
+// graph backwards is as fast as walking it forwards.
+//
+// rows holds the public index of each row in ascending order so that the indices of the graph
+// a CSR graph is built from are preserved, including gaps left by removed nodes.
+#[derive(Clone)]
+pub struct UltraCsrGraph<T, E = ()> {
+    root_index: Option<usize>,
+    rows: Vec<usize>,
+    values: Vec<T>,
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+    weights: Vec<u64>,
+    data: Vec<Option<E>>,
+    in_offsets: Vec<usize>,
+    in_sources: Vec<usize>,
+    next_node_index: usize,
+    hyperedges: HyperedgeStore,
+}
+
+impl<T, E> UltraCsrGraph<T, E> {
+    pub fn new() -> Self {
+        Self {
+            root_index: None,
+            rows: Vec::new(),
+            values: Vec::new(),
+            offsets: vec![0],
+            targets: Vec::new(),
+            weights: Vec::new(),
+            data: Vec::new(),
+            in_offsets: vec![0],
+            in_sources: Vec::new(),
+            next_node_index: 0,
+            hyperedges: HyperedgeStore::new(),
+        }
+    }
+
+    /// Builds a CSR graph from nodes and weighted edges (a, b, weight).
+    /// Nodes get the indices 0..nodes.len() in the given order.
+    ///
+    /// Returns UltraGraphError if an edge or the root refers to a node that does not exist,
+    /// or if an edge is given more than once.
+    pub fn from_edges(
+        nodes: Vec<T>,
+        edges: &[(usize, usize, u64)],
+        root_index: Option<usize>,
+    ) -> Result<Self, UltraGraphError> {
+        let edges = edges.iter().map(|(a, b, w)| (*a, *b, *w, None)).collect();
+        Self::build(nodes, edges, root_index)
+    }
+
+    /// Builds a CSR graph from nodes and weighted edges with data (a, b, weight, data).
+    /// Nodes get the indices 0..nodes.len() in the given order.
+    ///
+    /// Returns UltraGraphError if an edge or the root refers to a node that does not exist,
+    /// or if an edge is given more than once.
+    pub fn from_edges_with_data(
+        nodes: Vec<T>,
+        edges: Vec<(usize, usize, u64, E)>,
+        root_index: Option<usize>,
+    ) -> Result<Self, UltraGraphError> {
+        let edges = edges
+            .into_iter()
+            .map(|(a, b, w, data)| (a, b, w, Some(data)))
+            .collect();
+        Self::build(nodes, edges, root_index)
+    }
+
+    fn build(
+        nodes: Vec<T>,
+        mut edges: Vec<CsrEdge<E>>,
+        root_index: Option<usize>,
+    ) -> Result<Self, UltraGraphError> {
+        let number_nodes = nodes.len();
+
+        if let Some(root) = root_index {
+            if root >= number_nodes {
+                return Err(UltraGraphError(format!("root index {} not found", root)));
+            }
+        }
+
+        edges.sort_unstable_by_key(|(a, b, _, _)| (*a, *b));
+
+        for (i, (a, b, _, _)) in edges.iter().enumerate() {
+            if *a >= number_nodes {
+                return Err(UltraGraphError(format!("index a {} not found", a)));
+            }
+
+            if *b >= number_nodes {
+                return Err(UltraGraphError(format!("index b {} not found", b)));
+            }
+
+            if i > 0 && (edges[i - 1].0, edges[i - 1].1) == (*a, *b) {
+                return Err(UltraGraphError(format!(
+                    "Edge already exists between: {} and {}",
+                    a, b
+                )));
+            }
+        }
+
+        let mut offsets = vec![0; number_nodes + 1];
+        for (a, _, _, _) in &edges {
+            offsets[a + 1] += 1;
+        }
+        for row in 0..number_nodes {
+            offsets[row + 1] += offsets[row];
+        }
+
+        let mut res = Self {
+            root_index,
+            rows: (0..number_nodes).collect(),
+            values: nodes,
+            offsets,
+            targets: edges.iter().map(|(_, b, _, _)| *b).collect(),
+            weights: edges.iter().map(|(_, _, w, _)| *w).collect(),
+            data: Vec::with_capacity(edges.len()),
+            in_offsets: Vec::new(),
+            in_sources: Vec::new(),
+            next_node_index: number_nodes,
+            hyperedges: HyperedgeStore::new(),
+        };
+        res.data
+            .extend(edges.into_iter().map(|(_, _, _, data)| data));
+        res.index_incoming_edges();
+        Ok(res)
+    }
+
+    /// Builds a read-only CSR copy of any graph. Node and hyperedge indices,
+    /// edge weights and data, and the root node are preserved.
+    pub fn from_graph<G>(graph: &G) -> Self
+    where
+        G: GraphAlgorithms<T> + GraphEdgeDataView<E>,
+        T: Clone,
+        E: Clone,
+    {
+        let nodes = graph.get_all_indexed_nodes();
+
+        let mut res = Self::new();
+        res.rows.reserve(nodes.len());
+        res.values.reserve(nodes.len());
+        res.offsets.reserve(nodes.len());
+        res.targets.reserve(graph.number_edges());
+        res.weights.reserve(graph.number_edges());
+        res.data.reserve(graph.number_edges());
+
+        for (index, value) in &nodes {
+            let mut edges: Vec<usize> =
+                graph.outgoing_edges(*index).expect("Node exists").collect();
+            edges.sort_unstable();
+
+            res.rows.push(*index);
+            res.values.push((*value).clone());
+            for b in edges {
+                res.targets.push(b);
+                res.weights
+                    .push(*graph.get_edge_weight(*index, b).unwrap_or(&0));
+                res.data.push(graph.get_edge_data(*index, b).cloned());
+            }
+            res.offsets.push(res.targets.len());
+
+            for hyperedge in graph.get_hyperedges_of_node(*index) {
+                if !res.hyperedges.contains(hyperedge) {
+                    let edge = graph.get_hyperedge(hyperedge).expect("Hyperedge exists");
+                    res.hyperedges.insert(hyperedge, edge.clone());
+                }
+            }
+        }
+
+        res.next_node_index = nodes.last().map_or(0, |(index, _)| index + 1);
+        res.root_index = graph.get_root_index();
+        res.index_incoming_edges();
+        res
+    }
+
+    // Builds in_offsets and in_sources from the outgoing edges.
+    fn index_incoming_edges(&mut self) {
+        let number_rows = self.rows.len();
+        let target_rows: Vec<usize> = self
+            .targets
+            .iter()
+            .map(|b| self.row(*b).expect("Edge target exists"))
+            .collect();
+
+        let mut in_offsets = vec![0; number_rows + 1];
+        for row in &target_rows {
+            in_offsets[row + 1] += 1;
+        }
+        for row in 0..number_rows {
+            in_offsets[row + 1] += in_offsets[row];
+        }
+
+        // Rows are visited in ascending order, so the sources of each row end up sorted.
+        let mut next = in_offsets.clone();
+        let mut in_sources = vec![0; self.targets.len()];
+        for row in 0..number_rows {
+            for position in self.edge_range(row) {
+                let target_row = target_rows[position];
+                in_sources[next[target_row]] = self.rows[row];
+                next[target_row] += 1;
+            }
+        }
+
+        self.in_offsets = in_offsets;
+        self.in_sources = in_sources;
+    }
+}
+
+impl<T, E> UltraCsrGraph<T, E> {
+    // Returns the row of the public index.
+    fn row(&self, index: usize) -> Option<usize> {
+        self.rows.binary_search(&index).ok()
+    }
+
+    // Returns the range of the edges of the row in targets, weights, and data.
+    fn edge_range(&self, row: usize) -> std::ops::Range<usize> {
+        self.offsets[row]..self.offsets[row + 1]
+    }
+
+    // Returns the range of the incoming edges of the row in in_sources.
+    fn incoming_range(&self, row: usize) -> std::ops::Range<usize> {
+        self.in_offsets[row]..self.in_offsets[row + 1]
+    }
+
+    // Returns the position of the edge in targets, weights, and data.
+    fn edge_position(&self, a: usize, b: usize) -> Option<usize> {
+        let range = self.edge_range(self.row(a)?);
+        let start = range.start;
+        self.targets[range]
+            .binary_search(&b)
+            .ok()
+            .map(|position| start + position)
+    }
+
+    fn read_only(operation: &str) -> UltraGraphError {
+        UltraGraphError(format!("Cannot {}: CSR graph is read-only", operation))
+    }
+}
@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::BTreeSet;
+
+use ahash::AHashMap;
+
+use crate::errors::UltraGraphError;
+use crate::prelude::Hyperedge;
+
+// Hyperedges are stored separately from the edges because they connect any number of nodes.
+// The store is shared by all storage backends. Hyperedge indices are never reused.
+// The incidence map lists the hyperedges of every node, so that removing a node
+// or querying its hyperedges does not scan all hyperedges.
+#[derive(Clone)]
+pub(crate) struct HyperedgeStore {
+    hyperedges: AHashMap<usize, Hyperedge>,
+    incidence: AHashMap<usize, BTreeSet<usize>>,
+    next_index: usize,
+}
+
+impl HyperedgeStore {
+    pub(crate) fn new() -> Self {
+        Self {
+            hyperedges: AHashMap::new(),
+            incidence: AHashMap::new(),
+            next_index: 0,
+        }
+    }
+
+    pub(crate) fn add<F>(
+        &mut self,
+        nodes: &[usize],
+        weight: u64,
+        contains_node: F,
+    ) -> Result<usize, UltraGraphError>
+    where
+        F: Fn(usize) -> bool,
+    {
+        if nodes.len() < 2 {
+            return Err(UltraGraphError(
+                "Hyperedge requires at least two nodes".into(),
+            ));
+        }
+
+        for (i, node) in nodes.iter().enumerate() {
+            if !contains_node(*node) {
+                return Err(UltraGraphError(format!("index {} not found", node)));
+            }
+
+            if nodes[..i].contains(node) {
+                return Err(UltraGraphError(format!(
+                    "Hyperedge contains index {} more than once",
+                    node
+                )));
+            }
+        }
+
+        let index = self.next_index;
+        self.next_index += 1;
+        self.insert(index, Hyperedge::new(nodes.to_vec(), weight));
+
+        Ok(index)
+    }
+
+    // Inserts a hyperedge under the given index, used when copying hyperedges between graphs.
+    pub(crate) fn insert(&mut self, index: usize, hyperedge: Hyperedge) {
+        self.next_index = self.next_index.max(index + 1);
+        if let Some(previous) = self.hyperedges.remove(&index) {
+            self.unlink(index, &previous);
+        }
+        for node in hyperedge.nodes() {
+            self.incidence.entry(*node).or_default().insert(index);
+        }
+        self.hyperedges.insert(index, hyperedge);
+    }
+
+    pub(crate) fn contains(&self, index: usize) -> bool {
+        self.hyperedges.contains_key(&index)
+    }
+
+    pub(crate) fn get(&self, index: usize) -> Option<&Hyperedge> {
+        self.hyperedges.get(&index)
+    }
+
+    pub(crate) fn remove(&mut self, index: usize) -> Result<(), UltraGraphError> {
+        match self.hyperedges.remove(&index) {
+            Some(edge) => {
+                self.unlink(index, &edge);
+                Ok(())
+            }
+            None => Err(UltraGraphError(format!("hyperedge {} not found", index))),
+        }
+    }
+
+    // Hyperedges cannot exist without all of their nodes.
+    pub(crate) fn remove_node(&mut self, node_index: usize) {
+        let Some(indices) = self.incidence.remove(&node_index) else {
+            return;
+        };
+
+        for index in indices {
+            if let Some(edge) = self.hyperedges.remove(&index) {
+                self.unlink(index, &edge);
+            }
+        }
+    }
+
+    pub(crate) fn of_node(&self, node_index: usize) -> Vec<usize> {
+        self.incidence
+            .get(&node_index)
+            .map(|indices| indices.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.hyperedges.len()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.hyperedges.clear();
+        self.incidence.clear();
+    }
+
+    // Removes the hyperedge from the incidence lists of its nodes.
+    fn unlink(&mut self, index: usize, hyperedge: &Hyperedge) {
+        for node in hyperedge.nodes() {
+            if let Some(indices) = self.incidence.get_mut(node) {
+                indices.remove(&index);
+                if indices.is_empty() {
+                    self.incidence.remove(node);
+                }
+            }
+        }
+    }
+}
@@ -3,7 +3,7 @@
 
 use super::UltraMatrixGraph;
 
-impl<T> Default for UltraMatrixGraph<T> {
+impl<T, E> Default for UltraMatrixGraph<T, E> {
     fn default() -> Self {
         Self::new()
     }
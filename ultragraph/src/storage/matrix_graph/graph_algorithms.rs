@@ -4,33 +4,28 @@ use std::vec::IntoIter;
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 use petgraph::algo::astar;
 use petgraph::prelude::EdgeRef;
+use petgraph::Direction;
 
 use crate::errors::UltraGraphError;
-use crate::prelude::{GraphAlgorithms, GraphLike, UltraMatrixGraph};
-use crate::storage::matrix_graph::NodeIndex;
+use crate::prelude::{GraphAlgorithms, UltraMatrixGraph};
 
-impl<T> GraphAlgorithms<T> for UltraMatrixGraph<T> {
+impl<T, E> GraphAlgorithms<T> for UltraMatrixGraph<T, E> {
     fn shortest_path(&self, start_index: usize, stop_index: usize) -> Option<Vec<usize>> {
-        if !self.contains_node(start_index) {
-            return None;
-        };
-
-        if !self.contains_node(stop_index) {
-            return None;
-        };
+        let start = self.node_index(start_index)?;
+        let stop = self.node_index(stop_index)?;
 
         let mut result: Vec<usize> = Vec::new();
 
         // A* algorithm https://docs.rs/petgraph/latest/petgraph/algo/astar/fn.astar.html
         if let Some((_, path)) = astar(
             &self.graph,
-            NodeIndex::new(start_index),
-            |finish| finish == NodeIndex::new(stop_index),
-            |e| *e.weight(),
+            start,
+            |finish| finish == stop,
+            |e| e.weight().0,
             |_| 0,
         ) {
             for node in path {
-                result.push(node.index());
+                result.push(self.public_index(node));
             }
             Some(result)
         } else {
@@ -39,18 +34,63 @@ impl<T> GraphAlgorithms<T> for UltraMatrixGraph<T> {
     }
 
     fn outgoing_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError> {
-        if !self.contains_node(a) {
-            return Err(UltraGraphError("index a not found".into()));
+        let k = match self.node_index(a) {
+            Some(k) => k,
+            None => return Err(UltraGraphError("index a not found".into())),
+        };
+
+        let mut result: Vec<usize> = Vec::new();
+
+        let neighbors = self.graph.neighbors(k);
+
+        for node in neighbors {
+            result.push(self.public_index(node));
+        }
+
+        Ok(result.into_iter())
+    }
+
+    fn incoming_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError> {
+        let k = match self.node_index(a) {
+            Some(k) => k,
+            None => return Err(UltraGraphError("index a not found".into())),
         };
 
         let mut result: Vec<usize> = Vec::new();
 
-        let neighbors = self.graph.neighbors(NodeIndex::new(a));
+        let neighbors = self.graph.neighbors_directed(k, Direction::Incoming);
 
         for node in neighbors {
-            result.push(node.index());
+            result.push(self.public_index(node));
         }
 
         Ok(result.into_iter())
     }
+
+    fn sources(&self) -> Vec<usize> {
+        self.nodes_without_edges(Direction::Incoming)
+    }
+
+    fn sinks(&self) -> Vec<usize> {
+        self.nodes_without_edges(Direction::Outgoing)
+    }
+}
+
+impl<T, E> UltraMatrixGraph<T, E> {
+    fn nodes_without_edges(&self, direction: Direction) -> Vec<usize> {
+        let mut res: Vec<usize> = self
+            .node_map
+            .keys()
+            .filter(|k| {
+                self.graph
+                    .neighbors_directed(**k, direction)
+                    .next()
+                    .is_none()
+            })
+            .map(|k| self.public_index(*k))
+            .collect();
+
+        res.sort_unstable();
+        res
+    }
 }
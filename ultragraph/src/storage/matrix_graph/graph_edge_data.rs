@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphEdgeData, GraphEdgeDataView, GraphView};
+
+use super::UltraMatrixGraph;
+
+impl<T, E> GraphEdgeDataView<E> for UltraMatrixGraph<T, E> {
+    fn get_edge_data(&self, a: usize, b: usize) -> Option<&E> {
+        if !self.contains_edge(a, b) {
+            return None;
+        };
+
+        let k = self.node_index(a)?;
+        let l = self.node_index(b)?;
+        self.graph.edge_weight(k, l).1.as_ref()
+    }
+}
+
+impl<T, E> GraphEdgeData<E> for UltraMatrixGraph<T, E> {
+    fn add_edge_with_data(
+        &mut self,
+        a: usize,
+        b: usize,
+        weight: u64,
+        data: E,
+    ) -> Result<(), UltraGraphError> {
+        self.insert_edge(a, b, weight, Some(data))
+    }
+
+    fn get_edge_data_mut(&mut self, a: usize, b: usize) -> Option<&mut E> {
+        if !self.contains_edge(a, b) {
+            return None;
+        };
+
+        let k = self.node_index(a)?;
+        let l = self.node_index(b)?;
+        self.graph.edge_weight_mut(k, l).1.as_mut()
+    }
+
+    fn update_edge_data(&mut self, a: usize, b: usize, data: E) -> Result<(), UltraGraphError> {
+        if !self.contains_edge(a, b) {
+            return Err(UltraGraphError(format!(
+                "Edge does not exists between: {} and {}",
+                a, b
+            )));
+        };
+
+        let k = self.node_index(a).expect("Node a exists");
+        let l = self.node_index(b).expect("Node b exists");
+        self.graph.edge_weight_mut(k, l).1 = Some(data);
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::GraphHyperedges;
+
+use super::UltraMatrixGraph;
+
+impl<T, E> GraphHyperedges<T> for UltraMatrixGraph<T, E> {
+    fn add_hyperedge(&mut self, nodes: &[usize], weight: u64) -> Result<usize, UltraGraphError> {
+        let index_map = &self.index_map;
+        self.hyperedges
+            .add(nodes, weight, |node| index_map.contains_key(&node))
+    }
+
+    fn remove_hyperedge(&mut self, index: usize) -> Result<(), UltraGraphError> {
+        self.hyperedges.remove(index)
+    }
+}
@@ -6,59 +6,59 @@ use crate::prelude::GraphLike;
 
 use super::UltraMatrixGraph;
 
-impl<T> GraphLike<T> for UltraMatrixGraph<T> {
+impl<T, E> GraphLike<T> for UltraMatrixGraph<T, E> {
     fn add_node(&mut self, value: T) -> usize {
         let node_index = self.graph.add_node(true);
+        let index = self.next_node_index;
+        self.next_node_index += 1;
+
         self.node_map.insert(node_index, value);
-        self.index_map.insert(node_index.index(), node_index);
-        node_index.index()
+        self.index_map.insert(index, node_index);
+        self.node_ids.insert(node_index, index);
+        index
     }
 
-    fn contains_node(&self, index: usize) -> bool {
-        self.index_map.get(&index).is_some()
-    }
+    fn remove_node(&mut self, index: usize) -> Result<(), UltraGraphError> {
+        let k = match self.node_index(index) {
+            Some(k) => k,
+            None => return Err(UltraGraphError(format!("index {} not found", index))),
+        };
+
+        // petgraph removes the edges of the node without updating its edge count,
+        // so the edges are removed one by one before the node.
+        let nodes: Vec<_> = self.node_map.keys().copied().collect();
+        for other in nodes {
+            if self.graph.has_edge(k, other) {
+                self.graph.remove_edge(k, other);
+            }
+            if other != k && self.graph.has_edge(other, k) {
+                self.graph.remove_edge(other, k);
+            }
+        }
 
-    fn get_node(&self, index: usize) -> Option<&T> {
-        if !self.contains_node(index) {
-            None
-        } else {
-            let k = self.index_map.get(&index).expect("index not found");
-            self.node_map.get(k)
+        self.graph.remove_node(k);
+        self.node_map.remove(&k);
+        self.index_map.remove(&index);
+        self.node_ids.remove(&k);
+        if self.root_index == Some(k) {
+            self.root_index = None;
         }
+        self.hyperedges.remove_node(index);
+        Ok(())
     }
 
-    fn remove_node(&mut self, index: usize) -> Result<(), UltraGraphError> {
-        if !self.contains_node(index) {
-            return Err(UltraGraphError(format!("index {} not found", index)));
+    fn update_node(&mut self, index: usize, value: T) -> Result<(), UltraGraphError> {
+        let k = match self.node_index(index) {
+            Some(k) => k,
+            None => return Err(UltraGraphError(format!("index {} not found", index))),
         };
 
-        let k = self.index_map.get(&index).unwrap();
-        self.graph.remove_node(*k);
-        self.node_map.remove(k);
-        self.index_map.remove(&k.index());
+        self.node_map.insert(k, value);
         Ok(())
     }
 
     fn add_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError> {
-        if !self.contains_node(a) {
-            return Err(UltraGraphError(format!("index a {} not found", a)));
-        };
-
-        if !self.contains_node(b) {
-            return Err(UltraGraphError(format!("index b {} not found", b)));
-        };
-
-        if self.contains_edge(a, b) {
-            return Err(UltraGraphError(format!(
-                "Edge already exists between: {} and {}",
-                a, b
-            )));
-        }
-
-        let k = self.index_map.get(&a).expect("index not found");
-        let l = self.index_map.get(&b).expect("index not found");
-        self.graph.add_edge(*k, *l, 0);
-        Ok(())
+        self.add_edge_with_weight(a, b, 0)
     }
 
     fn add_edge_with_weight(
@@ -67,60 +67,59 @@ impl<T> GraphLike<T> for UltraMatrixGraph<T> {
         b: usize,
         weight: u64,
     ) -> Result<(), UltraGraphError> {
-        if !self.contains_node(a) {
-            return Err(UltraGraphError(format!("index a {} not found", a)));
+        self.insert_edge(a, b, weight, None)
+    }
+
+    fn remove_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError> {
+        let k = match self.node_index(a) {
+            Some(k) => k,
+            None => return Err(UltraGraphError("index a not found".into())),
         };
 
-        if !self.contains_node(b) {
-            return Err(UltraGraphError(format!("index b {} not found", b)));
+        let l = match self.node_index(b) {
+            Some(l) => l,
+            None => return Err(UltraGraphError("index b not found".into())),
         };
 
-        if self.contains_edge(a, b) {
+        if !self.graph.has_edge(k, l) {
             return Err(UltraGraphError(format!(
-                "Edge already exists between: {} and {}",
+                "Edge does not exists between: {} and {}",
                 a, b
             )));
         }
 
-        let k = self.index_map.get(&a).expect("index not found");
-        let l = self.index_map.get(&b).expect("index not found");
-        self.graph.add_edge(*k, *l, weight);
+        self.graph.remove_edge(k, l);
         Ok(())
     }
+}
 
-    fn contains_edge(&self, a: usize, b: usize) -> bool {
-        if !self.contains_node(a) || !self.contains_node(b) {
-            return false;
-        };
-
-        let k = self.index_map.get(&a).expect("index not found");
-        let l = self.index_map.get(&b).expect("index not found");
-        self.graph.has_edge(*k, *l)
-    }
-
-    fn remove_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError> {
-        if !self.contains_node(a) {
-            return Err(UltraGraphError("index a not found".into()));
+impl<T, E> UltraMatrixGraph<T, E> {
+    // Adds an edge with or without payload.
+    pub(super) fn insert_edge(
+        &mut self,
+        a: usize,
+        b: usize,
+        weight: u64,
+        data: Option<E>,
+    ) -> Result<(), UltraGraphError> {
+        let k = match self.node_index(a) {
+            Some(k) => k,
+            None => return Err(UltraGraphError(format!("index a {} not found", a))),
         };
 
-        if !self.contains_node(b) {
-            return Err(UltraGraphError("index b not found".into()));
+        let l = match self.node_index(b) {
+            Some(l) => l,
+            None => return Err(UltraGraphError(format!("index b {} not found", b))),
         };
 
-        if !self.contains_edge(a, b) {
+        if self.graph.has_edge(k, l) {
             return Err(UltraGraphError(format!(
-                "Edge does not exists between: {} and {}",
+                "Edge already exists between: {} and {}",
                 a, b
             )));
         }
 
-        let k = self.index_map.get(&a).expect("index not found");
-        let l = self.index_map.get(&b).expect("index not found");
-
-        self.graph.remove_edge(*k, *l);
-        self.index_map.remove(&a);
-        self.index_map.remove(&b);
-
+        self.graph.add_edge(k, l, (weight, data));
         Ok(())
     }
 }
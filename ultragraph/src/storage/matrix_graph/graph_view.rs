@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphView, Hyperedge};
+
+use super::UltraMatrixGraph;
+
+impl<T, E> GraphView<T> for UltraMatrixGraph<T, E> {
+    fn contains_node(&self, index: usize) -> bool {
+        self.index_map.contains_key(&index)
+    }
+
+    fn get_node(&self, index: usize) -> Option<&T> {
+        let k = self.node_index(index)?;
+        self.node_map.get(&k)
+    }
+
+    fn contains_edge(&self, a: usize, b: usize) -> bool {
+        match (self.node_index(a), self.node_index(b)) {
+            (Some(k), Some(l)) => self.graph.has_edge(k, l),
+            _ => false,
+        }
+    }
+
+    fn get_edge_weight(&self, a: usize, b: usize) -> Option<&u64> {
+        if !self.contains_edge(a, b) {
+            return None;
+        };
+
+        let k = self.node_index(a)?;
+        let l = self.node_index(b)?;
+        Some(&self.graph.edge_weight(k, l).0)
+    }
+
+    fn contains_root_node(&self) -> bool {
+        self.root_index.is_some()
+    }
+
+    fn get_root_node(&self) -> Option<&T> {
+        if self.contains_root_node() {
+            self.node_map.get(&self.root_index.unwrap())
+        } else {
+            None
+        }
+    }
+
+    fn get_root_index(&self) -> Option<usize> {
+        if self.contains_root_node() {
+            Some(self.public_index(self.root_index.unwrap()))
+        } else {
+            None
+        }
+    }
+
+    fn get_last_index(&self) -> Result<usize, UltraGraphError> {
+        if !self.is_empty() {
+            Ok(self.node_map.len())
+        } else {
+            Err(UltraGraphError("Graph is empty".to_string()))
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.graph.node_count() == 0
+    }
+
+    fn number_nodes(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    fn number_edges(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    fn get_all_nodes(&self) -> Vec<&T> {
+        let mut res = Vec::with_capacity(self.graph.node_count());
+
+        for val in self.node_map.values() {
+            res.push(val);
+        }
+
+        res
+    }
+
+    fn get_all_indexed_nodes(&self) -> Vec<(usize, &T)> {
+        let mut res = Vec::with_capacity(self.graph.node_count());
+
+        for (idx, val) in self.node_map.iter() {
+            res.push((self.public_index(*idx), val));
+        }
+
+        res.sort_unstable_by_key(|(idx, _)| *idx);
+        res
+    }
+
+    fn get_all_edges(&self) -> Vec<(usize, usize)> {
+        let mut edges = Vec::with_capacity(self.node_map.len());
+
+        for idx in self.node_map.keys() {
+            for e in self.graph.neighbors(*idx) {
+                edges.push((self.public_index(*idx), self.public_index(e)));
+            }
+        }
+
+        edges
+    }
+
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = (usize, &'a T)>
+    where
+        T: 'a,
+    {
+        self.node_map
+            .iter()
+            .map(|(idx, val)| (self.public_index(*idx), val))
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (usize, usize, &u64)> {
+        self.node_map.keys().flat_map(move |k| {
+            self.graph.neighbors(*k).map(move |l| {
+                let weight = &self.graph.edge_weight(*k, l).0;
+                (self.public_index(*k), self.public_index(l), weight)
+            })
+        })
+    }
+
+    fn neighbors(&self, a: usize) -> Result<impl Iterator<Item = usize>, UltraGraphError> {
+        match self.node_index(a) {
+            Some(k) => Ok(self.graph.neighbors(k).map(|l| self.public_index(l))),
+            None => Err(UltraGraphError(format!("index a {} not found", a))),
+        }
+    }
+
+    fn contains_hyperedge(&self, index: usize) -> bool {
+        self.hyperedges.contains(index)
+    }
+
+    fn get_hyperedge(&self, index: usize) -> Option<&Hyperedge> {
+        self.hyperedges.get(index)
+    }
+
+    fn get_hyperedges_of_node(&self, node_index: usize) -> Vec<usize> {
+        self.hyperedges.of_node(node_index)
+    }
+
+    fn number_hyperedges(&self) -> usize {
+        self.hyperedges.len()
+    }
+}
@@ -6,16 +6,22 @@ use petgraph::graph::NodeIndex as GraphNodeIndex;
 use petgraph::matrix_graph::MatrixGraph;
 use petgraph::Directed;
 
+use crate::storage::hyperedge_store::HyperedgeStore;
+
 mod default;
 mod graph_algorithms;
+mod graph_edge_data;
+mod graph_hyperedges;
 mod graph_like;
 mod graph_root;
 mod graph_storage;
+mod graph_view;
 
 type DefaultIx = u32;
 type NodeIndex<Ix = DefaultIx> = GraphNodeIndex<Ix>;
 
 // Edge weights need to be numerical (u64) to make the shortest path algo work.
+// Any other data about an edge is stored in its optional payload, see Edge.
 // Also, u32 is used as custom node node index type to bypass the fairly ancient 65k node limit
 // coming from the u16 default node index default type in petgraph. The u16 default index limit
 // was handled with a wrap-around meaning, after adding 65k nodes to the graph, the index counter
@@ -28,11 +34,17 @@ type NodeIndex<Ix = DefaultIx> = GraphNodeIndex<Ix>;
 // Graph is directed by default because otherwise neighbors would also return all incoming edges
 // and that is undesirable in the current use case in causality and context graphs.
 // These graphs are always directed graphs and therefore neighbors should only return outgoing edges.
-type HyperGraph<T> = MatrixGraph<T, u64, Directed, Option<u64>, u32>;
+type HyperGraph<T, E> = MatrixGraph<T, Edge<E>, Directed, Option<Edge<E>>, u32>;
+
+// Each edge stores its weight and, optionally, a payload of any type.
+type Edge<E> = (u64, Option<E>);
 
 // IndexMap literally maps between the usize index used in the public API and the
-// stable node index used in petgraph.
+// node index used in petgraph. petgraph reuses the indices of removed nodes whereas
+// the public indices are never reused, so that an index of a removed node never
+// refers to a node added later. NodeIdMap maps back from petgraph to the public index.
 type IndexMap = AHashMap<usize, NodeIndex>;
+type NodeIdMap = AHashMap<NodeIndex, usize>;
 
 // node_map stores the actual nodes in a hashmap betcause
 // petgraph does not have a good way to retrieve a specific node from the graph.
@@ -54,20 +66,26 @@ type NodeMap<T> = AHashMap<NodeIndex, T>;
 type RootIndex = Option<NodeIndex>;
 
 #[derive(Clone)]
-pub struct UltraMatrixGraph<T> {
+pub struct UltraMatrixGraph<T, E = ()> {
     root_index: RootIndex,
-    graph: HyperGraph<bool>,
+    graph: HyperGraph<bool, E>,
     node_map: NodeMap<T>,
     index_map: IndexMap,
+    node_ids: NodeIdMap,
+    next_node_index: usize,
+    hyperedges: HyperedgeStore,
 }
 
-impl<T> UltraMatrixGraph<T> {
+impl<T, E> UltraMatrixGraph<T, E> {
     pub fn new() -> Self {
         Self {
             root_index: None,
             graph: MatrixGraph::default(),
             node_map: AHashMap::new(),
             index_map: AHashMap::new(),
+            node_ids: AHashMap::new(),
+            next_node_index: 0,
+            hyperedges: HyperedgeStore::new(),
         }
     }
 
@@ -77,6 +95,24 @@ impl<T> UltraMatrixGraph<T> {
             graph: MatrixGraph::with_capacity(capacity),
             node_map: AHashMap::with_capacity(capacity),
             index_map: AHashMap::with_capacity(capacity),
+            node_ids: AHashMap::with_capacity(capacity),
+            next_node_index: 0,
+            hyperedges: HyperedgeStore::new(),
         }
     }
 }
+
+impl<T, E> UltraMatrixGraph<T, E> {
+    // Returns the petgraph node index of the public index.
+    fn node_index(&self, index: usize) -> Option<NodeIndex> {
+        self.index_map.get(&index).copied()
+    }
+
+    // Returns the public index of the petgraph node index.
+    fn public_index(&self, node_index: NodeIndex) -> usize {
+        *self
+            .node_ids
+            .get(&node_index)
+            .expect("Node index has no public index")
+    }
+}
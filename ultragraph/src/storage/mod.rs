@@ -3,4 +3,7 @@
 
 #![forbid(unsafe_code)]
 
+pub mod adjacency_graph;
+pub mod csr_graph;
+mod hyperedge_store;
 pub mod matrix_graph;
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::vec::IntoIter;
+
+use crate::algorithms::shortest_path::shortest_path;
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphAlgorithms, GraphView};
+
+use super::FilteredGraph;
+
+impl<T, G> GraphAlgorithms<T> for FilteredGraph<'_, T, G>
+where
+    G: GraphAlgorithms<T>,
+{
+    fn shortest_path(&self, start_index: usize, stop_index: usize) -> Option<Vec<usize>> {
+        if !self.contains_node(start_index) || !self.contains_node(stop_index) {
+            return None;
+        }
+
+        shortest_path(start_index, stop_index, |index| {
+            self.graph
+                .outgoing_edges(index)
+                .expect("Node exists")
+                .filter_map(move |child| Some((child, *self.get_edge_weight(index, child)?)))
+        })
+        .map(|(_, path)| path)
+    }
+
+    fn outgoing_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError> {
+        if !self.contains_node(a) {
+            return Err(UltraGraphError("index a not found".into()));
+        }
+
+        let result: Vec<usize> = self
+            .graph
+            .outgoing_edges(a)?
+            .filter(|b| self.contains_edge(a, *b))
+            .collect();
+        Ok(result.into_iter())
+    }
+
+    fn incoming_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError> {
+        if !self.contains_node(a) {
+            return Err(UltraGraphError("index a not found".into()));
+        }
+
+        let result: Vec<usize> = self
+            .graph
+            .incoming_edges(a)?
+            .filter(|b| self.contains_edge(*b, a))
+            .collect();
+        Ok(result.into_iter())
+    }
+
+    fn sources(&self) -> Vec<usize> {
+        self.get_all_indexed_nodes()
+            .into_iter()
+            .map(|(index, _)| index)
+            .filter(|index| self.in_degree(*index).expect("Node exists") == 0)
+            .collect()
+    }
+
+    fn sinks(&self) -> Vec<usize> {
+        self.get_all_indexed_nodes()
+            .into_iter()
+            .map(|(index, _)| index)
+            .filter(|index| self.out_degree(*index).expect("Node exists") == 0)
+            .collect()
+    }
+}
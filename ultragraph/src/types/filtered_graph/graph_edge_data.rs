@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::prelude::{GraphAlgorithms, GraphEdgeDataView, GraphView};
+
+use super::FilteredGraph;
+
+impl<T, E, G> GraphEdgeDataView<E> for FilteredGraph<'_, T, G>
+where
+    G: GraphAlgorithms<T> + GraphEdgeDataView<E>,
+{
+    fn get_edge_data(&self, a: usize, b: usize) -> Option<&E> {
+        if self.contains_edge(a, b) {
+            self.graph.get_edge_data(a, b)
+        } else {
+            None
+        }
+    }
+}
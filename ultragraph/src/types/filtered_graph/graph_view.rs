@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::BTreeSet;
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphAlgorithms, GraphView, Hyperedge};
+
+use super::FilteredGraph;
+
+impl<T, G> GraphView<T> for FilteredGraph<'_, T, G>
+where
+    G: GraphAlgorithms<T>,
+{
+    fn contains_node(&self, index: usize) -> bool {
+        self.is_visible_node(index)
+    }
+
+    fn get_node(&self, index: usize) -> Option<&T> {
+        self.graph
+            .get_node(index)
+            .filter(|value| (self.node_filter)(index, value))
+    }
+
+    fn contains_edge(&self, a: usize, b: usize) -> bool {
+        self.get_edge_weight(a, b).is_some()
+    }
+
+    fn get_edge_weight(&self, a: usize, b: usize) -> Option<&u64> {
+        self.graph
+            .get_edge_weight(a, b)
+            .filter(|weight| self.is_visible_edge(a, b, weight))
+    }
+
+    // A hidden root node is treated as if the graph had no root.
+    fn contains_root_node(&self) -> bool {
+        self.get_root_index().is_some()
+    }
+
+    fn get_root_node(&self) -> Option<&T> {
+        self.get_node(self.get_root_index()?)
+    }
+
+    fn get_root_index(&self) -> Option<usize> {
+        self.graph
+            .get_root_index()
+            .filter(|index| self.is_visible_node(*index))
+    }
+
+    fn get_last_index(&self) -> Result<usize, UltraGraphError> {
+        if !self.is_empty() {
+            Ok(self.number_nodes())
+        } else {
+            Err(UltraGraphError("Graph is empty".to_string()))
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.number_nodes()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.nodes().next().is_none()
+    }
+
+    fn number_nodes(&self) -> usize {
+        self.nodes().count()
+    }
+
+    fn number_edges(&self) -> usize {
+        self.edges().count()
+    }
+
+    fn get_all_nodes(&self) -> Vec<&T> {
+        self.nodes().map(|(_, value)| value).collect()
+    }
+
+    fn get_all_indexed_nodes(&self) -> Vec<(usize, &T)> {
+        self.graph
+            .get_all_indexed_nodes()
+            .into_iter()
+            .filter(|(index, value)| (self.node_filter)(*index, value))
+            .collect()
+    }
+
+    fn get_all_edges(&self) -> Vec<(usize, usize)> {
+        self.edges().map(|(a, b, _)| (a, b)).collect()
+    }
+
+    fn nodes<'b>(&'b self) -> impl Iterator<Item = (usize, &'b T)>
+    where
+        T: 'b,
+    {
+        self.graph
+            .nodes()
+            .filter(|(index, value)| (self.node_filter)(*index, value))
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (usize, usize, &u64)> {
+        self.graph
+            .edges()
+            .filter(|(a, b, weight)| self.is_visible_edge(*a, *b, weight))
+    }
+
+    fn neighbors(&self, a: usize) -> Result<impl Iterator<Item = usize>, UltraGraphError> {
+        if !self.contains_node(a) {
+            return Err(UltraGraphError(format!("index a {} not found", a)));
+        }
+
+        Ok(self
+            .graph
+            .neighbors(a)?
+            .filter(move |b| self.contains_edge(a, *b)))
+    }
+
+    fn contains_hyperedge(&self, index: usize) -> bool {
+        self.get_hyperedge(index).is_some()
+    }
+
+    fn get_hyperedge(&self, index: usize) -> Option<&Hyperedge> {
+        self.graph
+            .get_hyperedge(index)
+            .filter(|hyperedge| hyperedge.nodes().iter().all(|n| self.contains_node(*n)))
+    }
+
+    fn get_hyperedges_of_node(&self, node_index: usize) -> Vec<usize> {
+        if !self.contains_node(node_index) {
+            return Vec::new();
+        }
+
+        self.graph
+            .get_hyperedges_of_node(node_index)
+            .into_iter()
+            .filter(|index| self.contains_hyperedge(*index))
+            .collect()
+    }
+
+    fn number_hyperedges(&self) -> usize {
+        let hyperedges: BTreeSet<usize> = self
+            .nodes()
+            .flat_map(|(index, _)| self.get_hyperedges_of_node(index))
+            .collect();
+        hyperedges.len()
+    }
+}
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use crate::prelude::GraphView;
+
+mod graph_algorithms;
+mod graph_edge_data;
+mod graph_view;
+
+type NodeFilter<'a, T> = Box<dyn Fn(usize, &T) -> bool + 'a>;
+type EdgeFilter<'a> = Box<dyn Fn(usize, usize, &u64) -> bool + 'a>;
+
+// View of a graph that hides nodes and edges for which a filter returns false.
+//
+// A node is visible if the node filter accepts it. An edge is visible if both of its nodes
+// are visible and the edge filter accepts it, and a hyperedge is visible if all of its nodes
+// are visible. Hidden nodes, edges, and hyperedges behave as if they did not exist,
+// while visible ones keep their indices, so indices of the view are valid in the graph.
+//
+// The view only borrows the graph and does not copy it. The filters are evaluated on every
+// access, hence counting nodes or edges takes linear time. Views can be nested, e.g. a
+// filtered view of a filtered view hides what either filter hides. The view is read-only,
+// hence it implements GraphView, GraphAlgorithms, and GraphEdgeDataView, but none of the
+// traits that modify a graph.
+pub struct FilteredGraph<'a, T, G> {
+    graph: &'a G,
+    node_filter: NodeFilter<'a, T>,
+    edge_filter: EdgeFilter<'a>,
+}
+
+impl<'a, T, G> FilteredGraph<'a, T, G>
+where
+    G: GraphView<T>,
+{
+    /// Returns a view of the graph that shows the nodes (index, value) accepted by node_filter
+    /// and the edges (from, to, weight) between them accepted by edge_filter.
+    pub fn new<N, M>(graph: &'a G, node_filter: N, edge_filter: M) -> Self
+    where
+        N: Fn(usize, &T) -> bool + 'a,
+        M: Fn(usize, usize, &u64) -> bool + 'a,
+    {
+        Self {
+            graph,
+            node_filter: Box::new(node_filter),
+            edge_filter: Box::new(edge_filter),
+        }
+    }
+
+    /// Returns the graph this view is based on.
+    pub fn graph(&self) -> &'a G {
+        self.graph
+    }
+
+    fn is_visible_node(&self, index: usize) -> bool {
+        self.graph
+            .get_node(index)
+            .is_some_and(|value| (self.node_filter)(index, value))
+    }
+
+    fn is_visible_edge(&self, a: usize, b: usize, weight: &u64) -> bool {
+        self.is_visible_node(a) && self.is_visible_node(b) && (self.edge_filter)(a, b, weight)
+    }
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+// A hyperedge connects two or more distinct nodes and carries a numerical weight.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Hyperedge {
+    nodes: Vec<usize>,
+    weight: u64,
+}
+
+impl Hyperedge {
+    pub fn new(nodes: Vec<usize>, weight: u64) -> Self {
+        Self { nodes, weight }
+    }
+
+    pub fn nodes(&self) -> &[usize] {
+        &self.nodes
+    }
+
+    pub fn weight(&self) -> u64 {
+        self.weight
+    }
+
+    pub fn contains(&self, node_index: usize) -> bool {
+        self.nodes.contains(&node_index)
+    }
+}
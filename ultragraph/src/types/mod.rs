@@ -3,4 +3,7 @@
 
 #![forbid(unsafe_code)]
 
+pub mod filtered_graph;
+pub mod hyperedge;
+pub mod reversed_graph;
 pub mod ultra_graph;
@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::vec::IntoIter;
+
+use crate::errors::UltraGraphError;
+use crate::prelude::GraphAlgorithms;
+
+use super::ReversedGraph;
+
+impl<T, G> GraphAlgorithms<T> for ReversedGraph<'_, G>
+where
+    G: GraphAlgorithms<T>,
+{
+    // The shortest path from start to stop in the view is the
+    // shortest path from stop to start in the graph, walked backwards.
+    fn shortest_path(&self, start_index: usize, stop_index: usize) -> Option<Vec<usize>> {
+        let mut path = self.graph.shortest_path(stop_index, start_index)?;
+        path.reverse();
+        Some(path)
+    }
+
+    fn outgoing_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError> {
+        self.graph.incoming_edges(a)
+    }
+
+    fn incoming_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError> {
+        self.graph.outgoing_edges(a)
+    }
+
+    fn sources(&self) -> Vec<usize> {
+        self.graph.sinks()
+    }
+
+    fn sinks(&self) -> Vec<usize> {
+        self.graph.sources()
+    }
+
+    fn in_degree(&self, a: usize) -> Result<usize, UltraGraphError> {
+        self.graph.out_degree(a)
+    }
+
+    fn out_degree(&self, a: usize) -> Result<usize, UltraGraphError> {
+        self.graph.in_degree(a)
+    }
+}
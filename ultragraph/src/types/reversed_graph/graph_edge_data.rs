@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::prelude::GraphEdgeDataView;
+
+use super::ReversedGraph;
+
+impl<E, G> GraphEdgeDataView<E> for ReversedGraph<'_, G>
+where
+    G: GraphEdgeDataView<E>,
+{
+    fn get_edge_data(&self, a: usize, b: usize) -> Option<&E> {
+        self.graph.get_edge_data(b, a)
+    }
+}
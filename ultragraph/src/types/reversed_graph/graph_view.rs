@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphAlgorithms, GraphView, Hyperedge};
+
+use super::ReversedGraph;
+
+impl<T, G> GraphView<T> for ReversedGraph<'_, G>
+where
+    G: GraphAlgorithms<T>,
+{
+    fn contains_node(&self, index: usize) -> bool {
+        self.graph.contains_node(index)
+    }
+
+    fn get_node(&self, index: usize) -> Option<&T> {
+        self.graph.get_node(index)
+    }
+
+    fn contains_edge(&self, a: usize, b: usize) -> bool {
+        self.graph.contains_edge(b, a)
+    }
+
+    fn get_edge_weight(&self, a: usize, b: usize) -> Option<&u64> {
+        self.graph.get_edge_weight(b, a)
+    }
+
+    fn contains_root_node(&self) -> bool {
+        self.graph.contains_root_node()
+    }
+
+    fn get_root_node(&self) -> Option<&T> {
+        self.graph.get_root_node()
+    }
+
+    fn get_root_index(&self) -> Option<usize> {
+        self.graph.get_root_index()
+    }
+
+    fn get_last_index(&self) -> Result<usize, UltraGraphError> {
+        self.graph.get_last_index()
+    }
+
+    fn size(&self) -> usize {
+        self.graph.size()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+
+    fn number_nodes(&self) -> usize {
+        self.graph.number_nodes()
+    }
+
+    fn number_edges(&self) -> usize {
+        self.graph.number_edges()
+    }
+
+    fn get_all_nodes(&self) -> Vec<&T> {
+        self.graph.get_all_nodes()
+    }
+
+    fn get_all_indexed_nodes(&self) -> Vec<(usize, &T)> {
+        self.graph.get_all_indexed_nodes()
+    }
+
+    fn get_all_edges(&self) -> Vec<(usize, usize)> {
+        self.edges().map(|(a, b, _)| (a, b)).collect()
+    }
+
+    fn nodes<'b>(&'b self) -> impl Iterator<Item = (usize, &'b T)>
+    where
+        T: 'b,
+    {
+        self.graph.nodes()
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (usize, usize, &u64)> {
+        self.graph.edges().map(|(a, b, weight)| (b, a, weight))
+    }
+
+    fn neighbors(&self, a: usize) -> Result<impl Iterator<Item = usize>, UltraGraphError> {
+        self.graph
+            .incoming_edges(a)
+            .map_err(|_| UltraGraphError(format!("index a {} not found", a)))
+    }
+
+    // Hyperedges have no direction, hence they are the same as in the graph.
+    fn contains_hyperedge(&self, index: usize) -> bool {
+        self.graph.contains_hyperedge(index)
+    }
+
+    fn get_hyperedge(&self, index: usize) -> Option<&Hyperedge> {
+        self.graph.get_hyperedge(index)
+    }
+
+    fn get_hyperedges_of_node(&self, node_index: usize) -> Vec<usize> {
+        self.graph.get_hyperedges_of_node(node_index)
+    }
+
+    fn number_hyperedges(&self) -> usize {
+        self.graph.number_hyperedges()
+    }
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+mod graph_algorithms;
+mod graph_edge_data;
+mod graph_view;
+
+// View of a graph in which every edge points the other way, i.e. the edge from a to b
+// of the graph is the edge from b to a of the view, with the same weight and payload.
+// Nodes, indices, the root, and hyperedges are the same as in the graph.
+//
+// The view only borrows the graph and does not copy it, which makes it cheap to walk a
+// graph backwards, i.e. to run the algorithms of GraphAnalysis against the direction
+// of the edges. The view is read-only, hence it implements GraphView, GraphAlgorithms,
+// and GraphEdgeDataView, but none of the traits that modify a graph.
+pub struct ReversedGraph<'a, G> {
+    graph: &'a G,
+}
+
+impl<'a, G> ReversedGraph<'a, G> {
+    pub fn new(graph: &'a G) -> Self {
+        Self { graph }
+    }
+
+    /// Returns the graph this view is based on.
+    pub fn graph(&self) -> &'a G {
+        self.graph
+    }
+}
@@ -16,4 +16,36 @@ where
     fn outgoing_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError> {
         self.storage.outgoing_edges(a)
     }
+
+    fn incoming_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError> {
+        self.storage.incoming_edges(a)
+    }
+
+    fn sources(&self) -> Vec<usize> {
+        self.storage.sources()
+    }
+
+    fn sinks(&self) -> Vec<usize> {
+        self.storage.sinks()
+    }
+
+    fn predecessors(&self, a: usize) -> Result<Vec<usize>, UltraGraphError> {
+        self.storage.predecessors(a)
+    }
+
+    fn in_degree(&self, a: usize) -> Result<usize, UltraGraphError> {
+        self.storage.in_degree(a)
+    }
+
+    fn out_degree(&self, a: usize) -> Result<usize, UltraGraphError> {
+        self.storage.out_degree(a)
+    }
+
+    fn ancestors(&self, a: usize) -> Result<Vec<usize>, UltraGraphError> {
+        self.storage.ancestors(a)
+    }
+
+    fn descendants(&self, a: usize) -> Result<Vec<usize>, UltraGraphError> {
+        self.storage.descendants(a)
+    }
 }
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use crate::prelude::{
+    GraphEdgeData, GraphEdgeDataView, GraphStorage, UltraGraphContainer, UltraGraphError,
+};
+
+impl<S, T, E> GraphEdgeDataView<E> for UltraGraphContainer<S, T>
+where
+    S: GraphStorage<T> + GraphEdgeDataView<E>,
+{
+    fn get_edge_data(&self, a: usize, b: usize) -> Option<&E> {
+        self.storage.get_edge_data(a, b)
+    }
+}
+
+impl<S, T, E> GraphEdgeData<E> for UltraGraphContainer<S, T>
+where
+    S: GraphStorage<T> + GraphEdgeData<E>,
+{
+    fn add_edge_with_data(
+        &mut self,
+        a: usize,
+        b: usize,
+        weight: u64,
+        data: E,
+    ) -> Result<(), UltraGraphError> {
+        self.storage.add_edge_with_data(a, b, weight, data)
+    }
+
+    fn get_edge_data_mut(&mut self, a: usize, b: usize) -> Option<&mut E> {
+        self.storage.get_edge_data_mut(a, b)
+    }
+
+    fn update_edge_data(&mut self, a: usize, b: usize, data: E) -> Result<(), UltraGraphError> {
+        self.storage.update_edge_data(a, b, data)
+    }
+}
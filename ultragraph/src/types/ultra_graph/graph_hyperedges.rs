@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::prelude::{GraphHyperedges, GraphStorage, UltraGraphContainer, UltraGraphError};
+
+impl<S, T> GraphHyperedges<T> for UltraGraphContainer<S, T>
+where
+    S: GraphStorage<T>,
+{
+    fn add_hyperedge(&mut self, nodes: &[usize], weight: u64) -> Result<usize, UltraGraphError> {
+        self.storage.add_hyperedge(nodes, weight)
+    }
+
+    fn remove_hyperedge(&mut self, index: usize) -> Result<(), UltraGraphError> {
+        self.storage.remove_hyperedge(index)
+    }
+}
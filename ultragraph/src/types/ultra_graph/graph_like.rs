@@ -10,18 +10,14 @@ where
         self.storage.add_node(value)
     }
 
-    fn contains_node(&self, index: usize) -> bool {
-        self.storage.contains_node(index)
-    }
-
-    fn get_node(&self, index: usize) -> Option<&T> {
-        self.storage.get_node(index)
-    }
-
     fn remove_node(&mut self, index: usize) -> Result<(), UltraGraphError> {
         self.storage.remove_node(index)
     }
 
+    fn update_node(&mut self, index: usize, value: T) -> Result<(), UltraGraphError> {
+        self.storage.update_node(index, value)
+    }
+
     fn add_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError> {
         self.storage.add_edge(a, b)
     }
@@ -35,10 +31,6 @@ where
         self.storage.add_edge_with_weight(a, b, weight)
     }
 
-    fn contains_edge(&self, a: usize, b: usize) -> bool {
-        self.storage.contains_edge(a, b)
-    }
-
     fn remove_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError> {
         self.storage.remove_edge(a, b)
     }
@@ -1,34 +1,24 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
-use crate::prelude::{GraphStorage, UltraGraphContainer};
+use crate::prelude::{GraphStorage, UltraGraphContainer, UltraGraphError};
 
 impl<S, T> GraphStorage<T> for UltraGraphContainer<S, T>
 where
     S: GraphStorage<T>,
 {
-    fn size(&self) -> usize {
-        self.storage.size()
+    fn nodes_mut<'a>(&'a mut self) -> impl Iterator<Item = (usize, &'a mut T)>
+    where
+        T: 'a,
+    {
+        self.storage.nodes_mut()
     }
 
-    fn is_empty(&self) -> bool {
-        self.storage.is_empty()
-    }
-
-    fn number_nodes(&self) -> usize {
-        self.storage.number_nodes()
-    }
-
-    fn number_edges(&self) -> usize {
-        self.storage.number_edges()
-    }
-
-    fn get_all_nodes(&self) -> Vec<&T> {
-        self.storage.get_all_nodes()
-    }
-
-    fn get_all_edges(&self) -> Vec<(usize, usize)> {
-        self.storage.get_all_edges()
+    fn retain_nodes<F>(&mut self, predicate: F) -> Result<(), UltraGraphError>
+    where
+        F: FnMut(usize, &T) -> bool,
+    {
+        self.storage.retain_nodes(predicate)
     }
 
     fn clear(&mut self) {
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::prelude::{GraphStorage, GraphView, Hyperedge, UltraGraphContainer, UltraGraphError};
+
+impl<S, T> GraphView<T> for UltraGraphContainer<S, T>
+where
+    S: GraphStorage<T>,
+{
+    fn contains_node(&self, index: usize) -> bool {
+        self.storage.contains_node(index)
+    }
+
+    fn get_node(&self, index: usize) -> Option<&T> {
+        self.storage.get_node(index)
+    }
+
+    fn contains_edge(&self, a: usize, b: usize) -> bool {
+        self.storage.contains_edge(a, b)
+    }
+
+    fn get_edge_weight(&self, a: usize, b: usize) -> Option<&u64> {
+        self.storage.get_edge_weight(a, b)
+    }
+
+    fn contains_root_node(&self) -> bool {
+        self.storage.contains_root_node()
+    }
+
+    fn get_root_node(&self) -> Option<&T> {
+        self.storage.get_root_node()
+    }
+
+    fn get_root_index(&self) -> Option<usize> {
+        self.storage.get_root_index()
+    }
+
+    fn get_last_index(&self) -> Result<usize, UltraGraphError> {
+        self.storage.get_last_index()
+    }
+
+    fn size(&self) -> usize {
+        self.storage.size()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    fn number_nodes(&self) -> usize {
+        self.storage.number_nodes()
+    }
+
+    fn number_edges(&self) -> usize {
+        self.storage.number_edges()
+    }
+
+    fn get_all_nodes(&self) -> Vec<&T> {
+        self.storage.get_all_nodes()
+    }
+
+    fn get_all_indexed_nodes(&self) -> Vec<(usize, &T)> {
+        self.storage.get_all_indexed_nodes()
+    }
+
+    fn get_all_edges(&self) -> Vec<(usize, usize)> {
+        self.storage.get_all_edges()
+    }
+
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = (usize, &'a T)>
+    where
+        T: 'a,
+    {
+        self.storage.nodes()
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (usize, usize, &u64)> {
+        self.storage.edges()
+    }
+
+    fn neighbors(&self, a: usize) -> Result<impl Iterator<Item = usize>, UltraGraphError> {
+        self.storage.neighbors(a)
+    }
+
+    fn contains_hyperedge(&self, index: usize) -> bool {
+        self.storage.contains_hyperedge(index)
+    }
+
+    fn get_hyperedge(&self, index: usize) -> Option<&Hyperedge> {
+        self.storage.get_hyperedge(index)
+    }
+
+    fn get_hyperedges_of_node(&self, node_index: usize) -> Vec<usize> {
+        self.storage.get_hyperedges_of_node(node_index)
+    }
+
+    fn number_hyperedges(&self) -> usize {
+        self.storage.number_hyperedges()
+    }
+}
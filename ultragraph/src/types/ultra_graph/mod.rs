@@ -9,9 +9,12 @@ use crate::prelude::GraphStorage;
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 mod graph_algorithms;
+mod graph_edge_data;
+mod graph_hyperedges;
 mod graph_like;
 mod graph_root;
 pub mod graph_storage;
+mod graph_view;
 
 #[derive(Constructor, Debug, Copy, Clone)]
 pub struct UltraGraphContainer<S, T>
@@ -26,3 +26,27 @@ fn test_default() {
     let g = ultragraph::default::<u8>();
     assert!(g.is_empty());
 }
+
+#[test]
+fn test_new_with_adjacency_list_storage() {
+    let g = ultragraph::new_with_adjacency_list_storage::<u8>(100);
+    assert!(g.is_empty());
+}
+
+#[test]
+fn test_new_with_csr_storage() {
+    let g = ultragraph::new_with_csr_storage::<u8>(vec![1, 2], &[(0, 1, 0)], None).unwrap();
+    assert_eq!(g.number_nodes(), 2);
+    assert_eq!(g.number_edges(), 1);
+}
+
+#[test]
+fn test_new_with_csr_storage_from() {
+    let mut g = ultragraph::new::<u8>();
+    let a = g.add_node(1);
+    let b = g.add_node(2);
+    g.add_edge(a, b).unwrap();
+
+    let csr = ultragraph::new_with_csr_storage_from(&g);
+    assert_eq!(csr.get_all_edges(), vec![(a, b)]);
+}
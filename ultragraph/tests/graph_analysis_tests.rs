@@ -0,0 +1,245 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::collections::BTreeMap;
+
+use proptest::prelude::*;
+use ultragraph::prelude::*;
+
+#[derive(Default, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Data {
+    pub x: u8,
+}
+
+// Builds a graph with nodes 0..n and the weighted edges with each backend.
+macro_rules! analysis_tests {
+    ($name:ident, $build:expr) => {
+        mod $name {
+            use super::*;
+
+            fn build(n: usize, edges: &[(usize, usize, u64)]) -> impl GraphStorage<Data> {
+                $build(n, edges)
+            }
+
+            #[test]
+            fn test_topological_sort() {
+                let g = build(5, &[(3, 1, 0), (1, 0, 0), (4, 0, 0), (2, 4, 0)]);
+                assert_eq!(g.topological_sort().unwrap(), vec![2, 3, 1, 4, 0]);
+                assert!(g.is_acyclic());
+
+                let g = build(3, &[(0, 1, 0), (1, 2, 0), (2, 1, 0)]);
+                assert!(g.topological_sort().is_err());
+                assert!(!g.is_acyclic());
+            }
+
+            #[test]
+            fn test_strongly_connected_components() {
+                let g = build(
+                    6,
+                    &[(0, 1, 0), (1, 2, 0), (2, 0, 0), (2, 3, 0), (3, 4, 0), (4, 3, 0), (5, 5, 0)],
+                );
+                assert_eq!(
+                    g.strongly_connected_components(),
+                    vec![vec![0, 1, 2], vec![3, 4], vec![5]]
+                );
+            }
+
+            #[test]
+            fn test_dijkstra_and_bellman_ford() {
+                let g = build(5, &[(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 1), (2, 3, 5)]);
+                let expected = BTreeMap::from([(0, 0), (1, 3), (2, 1), (3, 4)]);
+                assert_eq!(g.dijkstra(0).unwrap(), expected);
+                assert_eq!(g.bellman_ford(0).unwrap(), expected);
+                assert!(g.dijkstra(99).is_err());
+                assert!(g.bellman_ford(99).is_err());
+
+                assert_eq!(g.weighted_shortest_path(0, 3), Some((4, vec![0, 2, 1, 3])));
+                assert_eq!(g.weighted_shortest_path(3, 0), None);
+                assert_eq!(g.weighted_shortest_path(0, 99), None);
+            }
+
+            #[test]
+            fn test_all_simple_paths() {
+                let g = build(4, &[(0, 1, 0), (0, 2, 0), (1, 2, 0), (2, 1, 0), (1, 3, 0), (2, 3, 0)]);
+                assert_eq!(
+                    g.all_simple_paths(0, 3).unwrap(),
+                    vec![vec![0, 1, 2, 3], vec![0, 1, 3], vec![0, 2, 1, 3], vec![0, 2, 3]]
+                );
+                assert_eq!(g.all_simple_paths(3, 0).unwrap(), Vec::<Vec<usize>>::new());
+                assert_eq!(g.all_simple_paths(1, 1).unwrap(), vec![vec![1]]);
+                assert!(g.all_simple_paths(0, 99).is_err());
+            }
+
+            #[test]
+            fn test_k_shortest_paths() {
+                let g = build(
+                    6,
+                    &[
+                        (0, 1, 3),
+                        (0, 2, 2),
+                        (1, 3, 4),
+                        (2, 1, 1),
+                        (2, 3, 2),
+                        (2, 4, 3),
+                        (3, 4, 2),
+                        (3, 5, 1),
+                        (4, 5, 2),
+                    ],
+                );
+                let paths = g.k_shortest_paths(0, 5, 3).unwrap();
+                assert_eq!(
+                    paths,
+                    vec![
+                        (5, vec![0, 2, 3, 5]),
+                        (7, vec![0, 2, 4, 5]),
+                        (8, vec![0, 1, 3, 5]),
+                    ]
+                );
+
+                assert_eq!(g.k_shortest_paths(0, 5, 100).unwrap().len(), 7);
+                assert!(g.k_shortest_paths(0, 5, 0).unwrap().is_empty());
+                assert!(g.k_shortest_paths(5, 0, 3).unwrap().is_empty());
+                assert!(g.k_shortest_paths(0, 99, 3).is_err());
+            }
+
+            #[test]
+            fn test_is_reachable() {
+                let g = build(3, &[(0, 1, 0)]);
+                assert!(g.is_reachable(0, 1).unwrap());
+                assert!(!g.is_reachable(1, 0).unwrap());
+                assert!(g.is_reachable(2, 2).unwrap());
+                assert!(g.is_reachable(0, 99).is_err());
+                assert!(g.is_reachable(99, 0).is_err());
+            }
+
+            #[test]
+            fn test_degree_centrality() {
+                let g = build(3, &[(0, 1, 0), (0, 2, 0)]);
+                assert_eq!(
+                    g.degree_centrality(),
+                    BTreeMap::from([(0, 1.0), (1, 0.5), (2, 0.5)])
+                );
+            }
+
+            #[test]
+            fn test_betweenness_centrality() {
+                // 0 -> 1 -> 2 and 0 -> 3 -> 2: each of 1 and 3 is on one of two shortest paths.
+                let g = build(5, &[(0, 1, 0), (1, 2, 0), (0, 3, 0), (3, 2, 0), (2, 4, 0)]);
+                let centrality = g.betweenness_centrality(false);
+                assert_eq!(centrality[&0], 0.0);
+                assert_eq!(centrality[&1], 1.0);
+                assert_eq!(centrality[&3], 1.0);
+                // 2 is on every path from 0, 1, and 3 to 4.
+                assert_eq!(centrality[&2], 3.0);
+                assert_eq!(centrality[&4], 0.0);
+
+                let normalized = g.betweenness_centrality(true);
+                assert_eq!(normalized[&2], 3.0 / 12.0);
+            }
+
+            #[test]
+            fn test_pagerank() {
+                let g = build(3, &[(0, 1, 0), (1, 2, 0), (2, 0, 0)]);
+                let rank = g.pagerank(0.85, 100, 1e-9);
+                for r in rank.values() {
+                    assert!((r - 1.0 / 3.0).abs() < 1e-6);
+                }
+
+                // Node 2 has no outgoing edges; ranks still sum to 1.
+                let g = build(3, &[(0, 2, 0), (1, 2, 0)]);
+                let rank = g.pagerank(0.85, 100, 1e-9);
+                assert!((rank.values().sum::<f64>() - 1.0).abs() < 1e-6);
+                assert!(rank[&2] > rank[&0]);
+                assert!((rank[&0] - rank[&1]).abs() < 1e-9);
+            }
+
+            proptest! {
+                #[test]
+                fn test_algorithms_agree(edges in prop::collection::vec((0..8usize, 0..8usize, 0..10u64), 0..24)) {
+                    let mut unique = BTreeMap::new();
+                    for (a, b, w) in edges {
+                        unique.entry((a, b)).or_insert(w);
+                    }
+                    let edges: Vec<(usize, usize, u64)> =
+                        unique.into_iter().map(|((a, b), w)| (a, b, w)).collect();
+                    let g = build(8, &edges);
+
+                    let components = g.strongly_connected_components();
+                    assert_eq!(components.iter().map(|c| c.len()).sum::<usize>(), 8);
+
+                    match g.topological_sort() {
+                        Ok(order) => {
+                            let position: BTreeMap<usize, usize> =
+                                order.iter().enumerate().map(|(p, i)| (*i, p)).collect();
+                            for (a, b, _) in &edges {
+                                assert!(position[a] < position[b]);
+                            }
+                            assert!(components.iter().all(|c| c.len() == 1));
+                        }
+                        Err(_) => assert!(
+                            components.iter().any(|c| c.len() > 1)
+                                || edges.iter().any(|(a, b, _)| a == b)
+                        ),
+                    }
+
+                    for start in 0..8 {
+                        let distances = g.dijkstra(start).unwrap();
+                        assert_eq!(distances, g.bellman_ford(start).unwrap());
+
+                        for stop in 0..8 {
+                            assert_eq!(
+                                g.is_reachable(start, stop).unwrap(),
+                                distances.contains_key(&stop)
+                            );
+
+                            let paths = g.k_shortest_paths(start, stop, 3).unwrap();
+                            let simple = g.all_simple_paths(start, stop).unwrap();
+                            assert_eq!(paths.len(), simple.len().min(3));
+                            if let Some((length, path)) = paths.first() {
+                                assert_eq!(Some(length), distances.get(&stop));
+                                assert_eq!(
+                                    g.weighted_shortest_path(start, stop).map(|(l, _)| l),
+                                    Some(*length)
+                                );
+                                assert!(simple.contains(path));
+                            }
+                            for pair in paths.windows(2) {
+                                assert!(pair[0].0 <= pair[1].0);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+fn build_matrix(n: usize, edges: &[(usize, usize, u64)]) -> UltraGraph<Data> {
+    let mut g = ultragraph::new_with_matrix_storage(n);
+    for x in 0..n {
+        g.add_node(Data { x: x as u8 });
+    }
+    for (a, b, weight) in edges {
+        g.add_edge_with_weight(*a, *b, *weight).unwrap();
+    }
+    g
+}
+
+fn build_adjacency_list(n: usize, edges: &[(usize, usize, u64)]) -> AdjacencyListGraph<Data> {
+    let mut g = ultragraph::new_with_adjacency_list_storage(n);
+    for x in 0..n {
+        g.add_node(Data { x: x as u8 });
+    }
+    for (a, b, weight) in edges {
+        g.add_edge_with_weight(*a, *b, *weight).unwrap();
+    }
+    g
+}
+
+fn build_csr(n: usize, edges: &[(usize, usize, u64)]) -> CsrGraph<Data> {
+    let nodes = (0..n).map(|x| Data { x: x as u8 }).collect();
+    ultragraph::new_with_csr_storage(nodes, edges, None).unwrap()
+}
+
+analysis_tests!(matrix_storage, build_matrix);
+analysis_tests!(adjacency_list_storage, build_adjacency_list);
+analysis_tests!(csr_storage, build_csr);
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use ultragraph::prelude::*;
+
+#[derive(Default, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Data {
+    pub x: u8,
+}
+
+fn get_ultra_graph() -> UltraGraph<Data> {
+    ultragraph::with_capacity::<Data>(10)
+}
+
+fn get_populated_graph() -> (UltraGraph<Data>, Vec<usize>) {
+    let mut g = get_ultra_graph();
+    let nodes = (0..5).map(|x| g.add_node(Data { x })).collect();
+    (g, nodes)
+}
+
+#[test]
+fn test_add_hyperedge() {
+    let (mut g, n) = get_populated_graph();
+    assert_eq!(g.number_hyperedges(), 0);
+
+    let e = g.add_hyperedge(&[n[0], n[2], n[4]], 7).unwrap();
+    assert!(g.contains_hyperedge(e));
+    assert_eq!(g.number_hyperedges(), 1);
+
+    let edge = g.get_hyperedge(e).unwrap();
+    assert_eq!(edge.nodes(), &[n[0], n[2], n[4]]);
+    assert_eq!(edge.weight(), 7);
+    assert!(edge.contains(n[2]));
+    assert!(!edge.contains(n[1]));
+
+    // Regular edges are not affected
+    assert_eq!(g.number_edges(), 0);
+}
+
+#[test]
+fn test_add_hyperedge_error() {
+    let (mut g, n) = get_populated_graph();
+
+    assert!(g.add_hyperedge(&[], 0).is_err());
+    assert!(g.add_hyperedge(&[n[0]], 0).is_err());
+    assert!(g.add_hyperedge(&[n[0], 99], 0).is_err());
+    assert!(g.add_hyperedge(&[n[0], n[1], n[0]], 0).is_err());
+    assert_eq!(g.number_hyperedges(), 0);
+}
+
+#[test]
+fn test_get_hyperedges_of_node() {
+    let (mut g, n) = get_populated_graph();
+
+    let a = g.add_hyperedge(&[n[0], n[1], n[2]], 0).unwrap();
+    let b = g.add_hyperedge(&[n[2], n[3]], 0).unwrap();
+
+    assert_eq!(g.get_hyperedges_of_node(n[0]), vec![a]);
+    assert_eq!(g.get_hyperedges_of_node(n[2]), vec![a, b]);
+    assert_eq!(g.get_hyperedges_of_node(n[3]), vec![b]);
+    assert!(g.get_hyperedges_of_node(n[4]).is_empty());
+    assert!(g.get_hyperedges_of_node(99).is_empty());
+}
+
+#[test]
+fn test_remove_hyperedge() {
+    let (mut g, n) = get_populated_graph();
+
+    let a = g.add_hyperedge(&[n[0], n[1]], 0).unwrap();
+    assert!(g.remove_hyperedge(a).is_ok());
+    assert!(!g.contains_hyperedge(a));
+    assert!(g.get_hyperedge(a).is_none());
+    assert!(g.remove_hyperedge(a).is_err());
+    assert!(g.get_hyperedges_of_node(n[0]).is_empty());
+
+    // Indices are not reused
+    let b = g.add_hyperedge(&[n[0], n[1]], 0).unwrap();
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_remove_node_removes_hyperedges() {
+    let (mut g, n) = get_populated_graph();
+
+    let a = g.add_hyperedge(&[n[0], n[1], n[2]], 0).unwrap();
+    let b = g.add_hyperedge(&[n[3], n[4]], 0).unwrap();
+
+    g.remove_node(n[1]).unwrap();
+    assert!(!g.contains_hyperedge(a));
+    assert!(g.contains_hyperedge(b));
+
+    // The remaining nodes of a no longer refer to it.
+    assert!(g.get_hyperedges_of_node(n[0]).is_empty());
+    assert!(g.get_hyperedges_of_node(n[2]).is_empty());
+    assert_eq!(g.get_hyperedges_of_node(n[3]), vec![b]);
+
+    g.clear();
+    assert_eq!(g.number_hyperedges(), 0);
+}
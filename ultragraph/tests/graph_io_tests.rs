@@ -0,0 +1,305 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+#![cfg(feature = "io")]
+
+use ultragraph::io::*;
+use ultragraph::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    pub name: String,
+    pub x: i32,
+}
+
+impl NodeFormat for Data {
+    fn to_text(&self) -> String {
+        format!("{}:{}", self.x, self.name)
+    }
+
+    fn from_text(text: &str) -> Result<Self, UltraGraphError> {
+        let (x, name) = text
+            .split_once(':')
+            .ok_or_else(|| UltraGraphError(format!("Invalid data {}", text)))?;
+        Ok(Data {
+            name: name.to_string(),
+            x: i32::from_text(x)?,
+        })
+    }
+}
+
+fn data(name: &str, x: i32) -> Data {
+    Data {
+        name: name.to_string(),
+        x,
+    }
+}
+
+// The root is not the first node, one node has no edges, and the names
+// contain the characters each format needs to escape.
+fn get_graph() -> UltraGraph<Data> {
+    let mut g = ultragraph::new::<Data>();
+    let a = g.add_node(data("a, \"quoted\"", 1));
+    let root = g.add_root_node(data("<root> & co", -2));
+    let b = g.add_node(data("line\nbreak", 3));
+    g.add_node(data("", 4));
+    g.add_edge_with_weight(root, a, 5).unwrap();
+    g.add_edge_with_weight(a, b, 0).unwrap();
+    g.add_edge_with_weight(b, b, u64::MAX).unwrap();
+    g
+}
+
+fn assert_same_graph(a: &UltraGraph<Data>, b: &UltraGraph<Data>) {
+    assert_eq!(a.get_all_indexed_nodes(), b.get_all_indexed_nodes());
+    assert_eq!(a.get_root_index(), b.get_root_index());
+
+    let mut edges_a: Vec<_> = a.edges().collect();
+    let mut edges_b: Vec<_> = b.edges().collect();
+    edges_a.sort_unstable();
+    edges_b.sort_unstable();
+    assert_eq!(edges_a, edges_b);
+}
+
+macro_rules! round_trip_tests {
+    ($name:ident, $write:path, $read:path) => {
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn test_round_trip() {
+                let g = get_graph();
+                let h: UltraGraph<Data> = $read(&$write(&g)).unwrap();
+                assert_same_graph(&g, &h);
+                assert_eq!(h.get_edge_weight(2, 2), Some(&u64::MAX));
+
+                // Writing the graph read back gives the same output.
+                assert_eq!($write(&h), $write(&g));
+            }
+
+            #[test]
+            fn test_round_trip_empty() {
+                let g = ultragraph::new::<Data>();
+                let h: UltraGraph<Data> = $read(&$write(&g)).unwrap();
+                assert!(h.is_empty());
+                assert_eq!(h.get_root_index(), None);
+            }
+
+            #[test]
+            fn test_round_trip_without_root() {
+                let mut g = ultragraph::new::<u8>();
+                let a = g.add_node(1);
+                let b = g.add_node(2);
+                g.add_edge(a, b).unwrap();
+
+                let h: UltraGraph<u8> = $read(&$write(&g)).unwrap();
+                assert_eq!(h.get_root_index(), None);
+                assert_eq!(h.get_node(a), Some(&1));
+                assert_eq!(h.get_node(b), Some(&2));
+                assert_eq!(h.get_edge_weight(a, b), Some(&0));
+            }
+
+            #[test]
+            fn test_round_trip_renumbers_removed_nodes() {
+                let mut g = ultragraph::new_with_adjacency_list_storage::<String>(10);
+                let a = g.add_node("a".to_string());
+                let b = g.add_node("b".to_string());
+                let c = g.add_root_node("c".to_string());
+                g.add_edge_with_weight(c, a, 2).unwrap();
+                g.remove_node(b).unwrap();
+
+                let h: UltraGraph<String> = $read(&$write(&g)).unwrap();
+                assert_eq!(h.get_node(0), Some(&"a".to_string()));
+                assert_eq!(h.get_node(1), Some(&"c".to_string()));
+                assert_eq!(h.get_root_index(), Some(1));
+                assert_eq!(h.get_edge_weight(1, 0), Some(&2));
+                assert_eq!(h.number_edges(), 1);
+            }
+
+            #[test]
+            fn test_round_trip_csr() {
+                let g = get_graph();
+                let csr = ultragraph::new_with_csr_storage_from(&g);
+                let h: UltraGraph<Data> = $read(&$write(&csr)).unwrap();
+                assert_same_graph(&g, &h);
+            }
+
+            #[test]
+            fn test_read_invalid_value() {
+                let mut g = ultragraph::new::<String>();
+                g.add_node("not a number".to_string());
+                let h: Result<UltraGraph<u8>, _> = $read(&$write(&g));
+                assert!(h.is_err());
+            }
+        }
+    };
+}
+
+round_trip_tests!(csv, to_csv, from_csv);
+round_trip_tests!(graphml, to_graphml, from_graphml);
+round_trip_tests!(json, to_json, from_json);
+
+#[test]
+fn test_csv_format() {
+    let mut g = ultragraph::new::<String>();
+    let a = g.add_root_node("a".to_string());
+    let b = g.add_node("say \"b\"".to_string());
+    g.add_edge_with_weight(a, b, 7).unwrap();
+
+    assert_eq!(
+        to_csv(&g),
+        "kind,source,target,weight,value\n\
+         root,0,,,a\n\
+         node,1,,,\"say \"\"b\"\"\"\n\
+         edge,0,1,7,\n"
+    );
+}
+
+#[test]
+fn test_read_csv_with_other_column_order() {
+    let csv = "value,kind,source,target,weight\r\n\
+               x,node,a,,\r\n\
+               y,root,b,,\r\n\
+               \r\n\
+               ,edge,b,a,\r\n";
+    let g: UltraGraph<String> = from_csv(csv).unwrap();
+
+    assert_eq!(g.get_node(0), Some(&"x".to_string()));
+    assert_eq!(g.get_root_index(), Some(1));
+    assert_eq!(g.get_edge_weight(1, 0), Some(&0));
+}
+
+#[test]
+fn test_read_csv_errors() {
+    let header = "kind,source,target,weight,value\n";
+
+    assert!(from_csv::<u8>("").is_err());
+    assert!(from_csv::<u8>("kind,source,target,weight\n").is_err());
+    assert!(from_csv::<u8>(&format!("{}node,0,,,\"1\n", header)).is_err());
+    assert!(from_csv::<u8>(&format!("{}vertex,0,,,1\n", header)).is_err());
+    assert!(from_csv::<u8>(&format!("{}node,0,,,1\nnode,0,,,2\n", header)).is_err());
+    assert!(from_csv::<u8>(&format!("{}root,0,,,1\nroot,1,,,2\n", header)).is_err());
+    assert!(from_csv::<u8>(&format!("{}node,0,,,1\nedge,0,1,1,\n", header)).is_err());
+    assert!(from_csv::<u8>(&format!("{}node,0,,,1\nedge,0,0,-1,\n", header)).is_err());
+    assert!(from_csv::<u8>(&format!("{}node,0,,,1\nedge,0,0,1,\nedge,0,0,2,\n", header)).is_err());
+}
+
+#[test]
+fn test_read_graphml_of_other_tools() {
+    let graphml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="node" attr.name="value" attr.type="string"/>
+  <key id="d1" for="edge" attr.name="weight" attr.type="long"/>
+  <key id="d2" for="node" attr.name="color" attr.type="string"/>
+  <graph id="G" edgedefault="directed">
+    <node id="first"><data key="d2">red</data><data key="d0"><![CDATA[1 < 2]]></data></node>
+    <node id="second"><data key="d0">x &amp; y</data></node>
+    <edge source="first" target="second"><data key="d1">4</data></edge>
+    <edge source="second" target="first"/>
+  </graph>
+</graphml>"#;
+    let g: UltraGraph<String> = from_graphml(graphml).unwrap();
+
+    assert_eq!(g.get_node(0), Some(&"1 < 2".to_string()));
+    assert_eq!(g.get_node(1), Some(&"x & y".to_string()));
+    assert_eq!(g.get_root_index(), None);
+    assert_eq!(g.get_edge_weight(0, 1), Some(&4));
+    assert_eq!(g.get_edge_weight(1, 0), Some(&0));
+}
+
+#[test]
+fn test_read_graphml_errors() {
+    let graph = |edgedefault: &str, content: &str| {
+        format!(
+            "<graphml><graph edgedefault=\"{}\">{}</graph></graphml>",
+            edgedefault, content
+        )
+    };
+    let node = |id: &str| format!("<node id=\"{}\"><data key=\"value\">1</data></node>", id);
+
+    assert!(from_graphml::<u8>(&graph("directed", &node("a"))).is_ok());
+    assert!(from_graphml::<u8>(&graph("undirected", &node("a"))).is_err());
+    assert!(from_graphml::<u8>(&graph("directed", "<node id=\"a\"/>")).is_err());
+    assert!(from_graphml::<u8>(&graph(
+        "directed",
+        "<node><data key=\"value\">1</data></node>"
+    ))
+    .is_err());
+    assert!(
+        from_graphml::<u8>(&graph("directed", &format!("{}{}", node("a"), node("a")))).is_err()
+    );
+    assert!(from_graphml::<u8>(&graph(
+        "directed",
+        &format!("{}<edge source=\"a\" target=\"b\"/>", node("a"))
+    ))
+    .is_err());
+    assert!(from_graphml::<u8>("<graphml><graph></node></graphml>").is_err());
+}
+
+#[test]
+fn test_json_format() {
+    let mut g = ultragraph::new::<u8>();
+    let a = g.add_root_node(1);
+    let b = g.add_node(2);
+    g.add_edge_with_weight(a, b, 3).unwrap();
+
+    let json: serde_json::Value = serde_json::from_str(&to_json(&g)).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "directed": true,
+            "multigraph": false,
+            "graph": {"root": 0},
+            "nodes": [{"id": 0, "value": "1"}, {"id": 1, "value": "2"}],
+            "links": [{"source": 0, "target": 1, "weight": 3}],
+        })
+    );
+}
+
+#[test]
+fn test_read_json_of_other_tools() {
+    // As written by NetworkX with edges="edges", with string ids and numeric values.
+    let json = r#"{
+        "directed": true,
+        "multigraph": false,
+        "graph": {"root": "b", "name": "example"},
+        "nodes": [{"id": "a", "value": 1.5}, {"id": "b", "value": "2", "color": "red"}],
+        "edges": [{"source": "b", "target": "a", "weight": 4}, {"source": "a", "target": "b"}]
+    }"#;
+    let g: UltraGraph<f64> = from_json(json).unwrap();
+
+    assert_eq!(g.get_node(0), Some(&1.5));
+    assert_eq!(g.get_root_node(), Some(&2.0));
+    assert_eq!(g.get_edge_weight(1, 0), Some(&4));
+    assert_eq!(g.get_edge_weight(0, 1), Some(&0));
+}
+
+#[test]
+fn test_read_json_errors() {
+    let json = |graph: &str, nodes: &str, links: &str| {
+        format!(
+            "{{\"directed\": true, \"graph\": {}, \"nodes\": {}, \"links\": {}}}",
+            graph, nodes, links
+        )
+    };
+
+    assert!(from_json::<u8>(&json("{}", "[{\"id\": 0, \"value\": 1}]", "[]")).is_ok());
+    assert!(from_json::<u8>("{").is_err());
+    assert!(from_json::<u8>("{\"directed\": false, \"nodes\": [], \"links\": []}").is_err());
+    assert!(from_json::<u8>("{\"multigraph\": true, \"nodes\": [], \"links\": []}").is_err());
+    assert!(from_json::<u8>("{\"links\": []}").is_err());
+    assert!(from_json::<u8>("{\"nodes\": []}").is_err());
+    assert!(from_json::<u8>(&json("{\"root\": 1}", "[{\"id\": 0, \"value\": 1}]", "[]")).is_err());
+    assert!(from_json::<u8>(&json("{}", "[{\"id\": 0}]", "[]")).is_err());
+    assert!(from_json::<u8>(&json("{}", "[{\"id\": [0], \"value\": 1}]", "[]")).is_err());
+    assert!(from_json::<u8>(&json(
+        "{}",
+        "[{\"id\": 0, \"value\": 1}]",
+        "[{\"source\": 0, \"target\": 1}]"
+    ))
+    .is_err());
+    assert!(from_json::<u8>(&json(
+        "{}",
+        "[{\"id\": 0, \"value\": 1}]",
+        "[{\"source\": 0, \"target\": 0, \"weight\": -1}]"
+    ))
+    .is_err());
+}
@@ -110,6 +110,26 @@ fn test_get_node() {
     assert_eq!(expected, actual);
 }
 
+#[test]
+fn test_update_node() {
+    let mut g = get_ultra_graph();
+
+    let index = g.add_node(Data { x: 1 });
+    let other = g.add_node(Data { x: 2 });
+    g.add_edge(index, other).expect("Failed to add edge");
+
+    let res = g.update_node(index, Data { x: 42 });
+    assert!(res.is_ok());
+    assert_eq!(g.get_node(index), Some(&Data { x: 42 }));
+
+    // Edges are not affected
+    assert!(g.contains_edge(index, other));
+    assert_eq!(g.number_nodes(), 2);
+
+    let res = g.update_node(99, Data { x: 42 });
+    assert!(res.is_err());
+}
+
 #[test]
 fn test_get_node_error() {
     let mut g = get_ultra_graph();
@@ -238,6 +258,25 @@ fn test_add_edge() {
     assert!(res.is_ok());
 }
 
+#[test]
+fn test_get_edge_weight() {
+    let mut g = get_ultra_graph();
+
+    let root_index = g.add_root_node(Data { x: 1 });
+    let node_a_index = g.add_node(Data { x: 42 });
+    let node_b_index = g.add_node(Data { x: 23 });
+
+    let res = g.add_edge(root_index, node_a_index);
+    assert!(res.is_ok());
+    let res = g.add_edge_with_weight(node_a_index, node_b_index, 7);
+    assert!(res.is_ok());
+
+    assert_eq!(g.get_edge_weight(root_index, node_a_index), Some(&0));
+    assert_eq!(g.get_edge_weight(node_a_index, node_b_index), Some(&7));
+    assert_eq!(g.get_edge_weight(node_b_index, node_a_index), None);
+    assert_eq!(g.get_edge_weight(root_index, 99), None);
+}
+
 #[test]
 fn test_add_edge_error() {
     let mut g = get_ultra_graph();
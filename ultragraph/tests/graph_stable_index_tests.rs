@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use ultragraph::prelude::*;
+
+#[derive(Default, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Data {
+    pub x: u8,
+}
+
+fn get_ultra_graph() -> UltraGraph<Data> {
+    ultragraph::with_capacity::<Data>(10)
+}
+
+#[test]
+fn test_indices_are_not_reused() {
+    let mut g = get_ultra_graph();
+    let a = g.add_node(Data { x: 1 });
+    let b = g.add_node(Data { x: 2 });
+    g.remove_node(a).unwrap();
+
+    let c = g.add_node(Data { x: 3 });
+    assert_ne!(a, c);
+    assert!(!g.contains_node(a));
+    assert_eq!(g.get_node(a), None);
+    assert_eq!(g.get_node(b), Some(&Data { x: 2 }));
+    assert_eq!(g.get_node(c), Some(&Data { x: 3 }));
+    assert_eq!(
+        g.get_all_indexed_nodes(),
+        vec![(b, &Data { x: 2 }), (c, &Data { x: 3 })]
+    );
+}
+
+#[test]
+fn test_indices_are_not_reused_after_clear() {
+    let mut g = get_ultra_graph();
+    let a = g.add_node(Data { x: 1 });
+    g.clear();
+
+    let b = g.add_node(Data { x: 2 });
+    assert_ne!(a, b);
+    assert!(!g.contains_node(a));
+}
+
+#[test]
+fn test_remove_edge_keeps_nodes() {
+    let mut g = get_ultra_graph();
+    let a = g.add_root_node(Data { x: 1 });
+    let b = g.add_node(Data { x: 2 });
+    g.add_edge(a, b).unwrap();
+    g.remove_edge(a, b).unwrap();
+
+    assert!(g.contains_node(a));
+    assert!(g.contains_node(b));
+    assert_eq!(g.get_node(b), Some(&Data { x: 2 }));
+    assert!(g.add_edge(a, b).is_ok());
+    assert_eq!(g.outgoing_edges(a).unwrap().collect::<Vec<_>>(), vec![b]);
+}
+
+#[test]
+fn test_remove_node_removes_its_edges() {
+    let mut g = get_ultra_graph();
+    let a = g.add_node(Data { x: 1 });
+    let b = g.add_node(Data { x: 2 });
+    let c = g.add_node(Data { x: 3 });
+    g.add_edge(a, b).unwrap();
+    g.add_edge(b, c).unwrap();
+    g.add_edge(b, b).unwrap();
+    g.add_edge(a, c).unwrap();
+
+    g.remove_node(b).unwrap();
+    assert_eq!(g.number_edges(), 1);
+    assert_eq!(g.get_all_edges(), vec![(a, c)]);
+
+    // The new node takes the place of b in the matrix but has no edges.
+    let d = g.add_node(Data { x: 4 });
+    assert_eq!(g.outgoing_edges(a).unwrap().collect::<Vec<_>>(), vec![c]);
+    assert_eq!(g.outgoing_edges(d).unwrap().count(), 0);
+    assert_eq!(g.shortest_path(a, c), Some(vec![a, c]));
+}
+
+#[test]
+fn test_remove_root_node() {
+    let mut g = get_ultra_graph();
+    let root = g.add_root_node(Data { x: 1 });
+    g.remove_node(root).unwrap();
+    assert!(!g.contains_root_node());
+    assert_eq!(g.get_root_index(), None);
+
+    let a = g.add_node(Data { x: 2 });
+    assert_eq!(g.get_root_index(), None);
+    assert_eq!(g.get_root_node(), None);
+
+    let new_root = g.add_root_node(Data { x: 3 });
+    assert_ne!(a, new_root);
+    assert_eq!(g.get_root_index(), Some(new_root));
+}
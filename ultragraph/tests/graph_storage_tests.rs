@@ -47,6 +47,23 @@ fn test_get_all_nodes_empty() {
     assert_eq!(expected, actual);
 }
 
+#[test]
+fn test_get_all_indexed_nodes() {
+    let mut g = get_ultra_graph();
+    assert!(g.get_all_indexed_nodes().is_empty());
+
+    let _ = g.add_root_node(Data { x: 3 });
+    let _ = g.add_node(Data { x: 7 });
+    let _ = g.add_node(Data { x: 9 });
+
+    let res = g.remove_node(1);
+    assert!(res.is_ok());
+
+    let expected = vec![(0, &Data { x: 3 }), (2, &Data { x: 9 })];
+    let actual = g.get_all_indexed_nodes();
+    assert_eq!(expected, actual);
+}
+
 #[test]
 fn test_get_all_nodes() {
     let mut g = get_ultra_graph();
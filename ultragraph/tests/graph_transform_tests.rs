@@ -0,0 +1,318 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::collections::BTreeMap;
+
+use ultragraph::prelude::*;
+
+#[derive(Default, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Data {
+    pub x: u8,
+}
+
+fn sorted_edges<T>(g: &impl GraphView<T>) -> Vec<(usize, usize, u64)> {
+    let mut edges: Vec<_> = g.edges().map(|(a, b, w)| (a, b, *w)).collect();
+    edges.sort_unstable();
+    edges
+}
+
+fn sorted<I: Iterator<Item = usize>>(iter: I) -> Vec<usize> {
+    let mut res: Vec<usize> = iter.collect();
+    res.sort_unstable();
+    res
+}
+
+// Builds the graph
+//   0 (root) -> 1 -> 2 -> 3
+//               1 -> 4
+//   5 -> 3
+// with node values x = 10 * index, edge weights a + b, and a hyperedge over 1, 2, 4.
+macro_rules! transform_tests {
+    ($name:ident, $graph:expr) => {
+        mod $name {
+            use super::*;
+
+            fn build() -> impl GraphStorage<Data> + GraphEdgeData<&'static str> {
+                let mut g = $graph;
+                g.add_root_node(Data { x: 0 });
+                for x in 1..6 {
+                    g.add_node(Data { x: 10 * x });
+                }
+                for (a, b) in [(0, 1), (1, 2), (2, 3), (5, 3)] {
+                    g.add_edge_with_weight(a, b, (a + b) as u64).unwrap();
+                }
+                g.add_edge_with_data(1, 4, 5, "causes").unwrap();
+                g.add_hyperedge(&[1, 2, 4], 9).unwrap();
+                g
+            }
+
+            #[test]
+            fn test_induced_subgraph() {
+                let g = build();
+                let (s, map) = g.induced_subgraph(&[4, 1, 2, 1]).unwrap();
+
+                assert_eq!(map, BTreeMap::from([(1, 0), (2, 1), (4, 2)]));
+                assert_eq!(s.number_nodes(), 3);
+                assert_eq!(s.get_node(2), Some(&Data { x: 40 }));
+                assert_eq!(s.get_root_index(), None);
+                assert_eq!(sorted_edges(&s), vec![(0, 1, 3), (0, 2, 5)]);
+                assert_eq!(s.get_edge_data(0, 2), Some(&"causes"));
+                assert_eq!(s.number_hyperedges(), 1);
+                assert_eq!(s.get_hyperedge(0).unwrap().nodes(), &[0, 1, 2]);
+
+                // The hyperedge is dropped if one of its nodes is missing.
+                let (s, _) = g.induced_subgraph(&[0, 1, 2]).unwrap();
+                assert_eq!(s.get_root_index(), Some(0));
+                assert_eq!(s.number_hyperedges(), 0);
+
+                assert!(g.induced_subgraph(&[1, 99]).is_err());
+            }
+
+            #[test]
+            fn test_reachable_subgraph() {
+                let g = build();
+                let (s, map) = g.reachable_subgraph(1).unwrap();
+
+                assert_eq!(map, BTreeMap::from([(1, 0), (2, 1), (3, 2), (4, 3)]));
+                assert_eq!(sorted_edges(&s), vec![(0, 1, 3), (0, 3, 5), (1, 2, 5)]);
+                assert_eq!(s.number_hyperedges(), 1);
+
+                let (s, map) = g.reachable_subgraph(0).unwrap();
+                assert_eq!(map.len(), 5);
+                assert!(!map.contains_key(&5));
+                assert_eq!(s.get_root_node(), Some(&Data { x: 0 }));
+
+                let (s, _) = g.reachable_subgraph(3).unwrap();
+                assert_eq!(s.number_nodes(), 1);
+                assert_eq!(s.number_edges(), 0);
+
+                assert!(g.reachable_subgraph(99).is_err());
+            }
+
+            #[test]
+            fn test_merge() {
+                let mut g = build();
+                let other = build();
+                let map = g.merge(&other).unwrap();
+
+                assert_eq!(map, (0..6).map(|i| (i, i + 6)).collect());
+                assert_eq!(g.number_nodes(), 12);
+                assert_eq!(g.number_edges(), 10);
+                assert_eq!(g.number_hyperedges(), 2);
+                // The graph keeps its own root.
+                assert_eq!(g.get_root_index(), Some(0));
+                assert_eq!(g.get_node(10), Some(&Data { x: 40 }));
+                assert_eq!(g.get_edge_weight(11, 9), Some(&8));
+                assert_eq!(g.get_edge_data(7, 10), Some(&"causes"));
+                assert!(!g.contains_edge(1, 7));
+            }
+
+            #[test]
+            fn test_merge_into_graph_without_root() {
+                let mut g = ultragraph::with_edge_data::<Data, &str>(10);
+                let a = g.add_node(Data { x: 99 });
+
+                let map = g.merge(&build()).unwrap();
+                assert_eq!(map[&0], 1);
+                assert_eq!(g.get_root_index(), Some(1));
+                assert_eq!(g.get_node(a), Some(&Data { x: 99 }));
+            }
+
+            #[test]
+            fn test_reversed() {
+                let g = build();
+                let r = g.reversed();
+
+                assert_eq!(r.number_nodes(), 6);
+                assert_eq!(r.number_edges(), 5);
+                assert_eq!(r.get_root_index(), Some(0));
+                assert!(r.contains_edge(1, 0));
+                assert!(!r.contains_edge(0, 1));
+                assert_eq!(r.get_edge_weight(3, 2), Some(&5));
+                assert_eq!(r.get_edge_data(4, 1), Some(&"causes"));
+                assert_eq!(r.get_edge_data(1, 4), None);
+                assert_eq!(
+                    sorted_edges(&r),
+                    vec![(1, 0, 1), (2, 1, 3), (3, 2, 5), (3, 5, 8), (4, 1, 5)]
+                );
+                assert_eq!(r.get_all_edges().len(), 5);
+
+                assert_eq!(sorted(r.neighbors(3).unwrap()), vec![2, 5]);
+                assert!(r.neighbors(99).is_err());
+                assert_eq!(sorted(r.outgoing_edges(1).unwrap()), vec![0]);
+                assert_eq!(sorted(r.incoming_edges(1).unwrap()), vec![2, 4]);
+                assert_eq!(r.in_degree(1).unwrap(), 2);
+                assert_eq!(r.out_degree(3).unwrap(), 2);
+                assert_eq!(r.sources(), vec![3, 4]);
+                assert_eq!(r.sinks(), vec![0, 5]);
+
+                assert_eq!(r.shortest_path(3, 0), Some(vec![3, 2, 1, 0]));
+                assert_eq!(r.shortest_path(0, 3), None);
+                assert_eq!(r.descendants(3).unwrap(), g.ancestors(3).unwrap());
+                assert_eq!(r.ancestors(1).unwrap(), g.descendants(1).unwrap());
+                assert_eq!(r.dijkstra(3).unwrap()[&0], 9);
+                assert_eq!(r.topological_sort().unwrap(), vec![3, 2, 4, 1, 0, 5]);
+
+                assert_eq!(r.get_hyperedges_of_node(2), vec![0]);
+                assert_eq!(r.number_hyperedges(), 1);
+
+                // Reversing twice gives the graph back.
+                let rr = r.reversed();
+                assert_eq!(sorted_edges(&rr), sorted_edges(&g));
+                assert_eq!(rr.shortest_path(0, 3), g.shortest_path(0, 3));
+            }
+
+            #[test]
+            fn test_reversed_subgraph() {
+                // The reachable subgraph of the reversed graph contains the ancestors.
+                let g = build();
+                let (s, map) = g.reversed().reachable_subgraph(3).unwrap();
+
+                assert_eq!(
+                    map,
+                    BTreeMap::from([(0, 0), (1, 1), (2, 2), (3, 3), (5, 4)])
+                );
+                assert!(s.contains_edge(1, 0));
+                assert!(s.contains_edge(3, 4));
+                assert_eq!(s.get_root_index(), Some(0));
+            }
+
+            #[test]
+            fn test_filter_nodes() {
+                let g = build();
+                let f = g.filter_nodes(|index, _| index != 2);
+
+                assert_eq!(f.number_nodes(), 5);
+                assert_eq!(f.size(), 5);
+                assert!(!f.is_empty());
+                assert!(!f.contains_node(2));
+                assert_eq!(f.get_node(2), None);
+                assert_eq!(f.get_node(3), Some(&Data { x: 30 }));
+                assert_eq!(f.get_all_nodes().len(), 5);
+                assert_eq!(
+                    f.get_all_indexed_nodes()
+                        .into_iter()
+                        .map(|(i, _)| i)
+                        .collect::<Vec<_>>(),
+                    vec![0, 1, 3, 4, 5]
+                );
+                assert_eq!(f.get_last_index().unwrap(), 5);
+
+                assert_eq!(f.number_edges(), 3);
+                assert!(!f.contains_edge(1, 2));
+                assert_eq!(f.get_edge_weight(2, 3), None);
+                assert_eq!(sorted_edges(&f), vec![(0, 1, 1), (1, 4, 5), (5, 3, 8)]);
+                assert_eq!(sorted(f.neighbors(1).unwrap()), vec![4]);
+                assert!(f.neighbors(2).is_err());
+                assert!(f.outgoing_edges(2).is_err());
+                assert!(f.incoming_edges(2).is_err());
+                assert_eq!(sorted(f.incoming_edges(3).unwrap()), vec![5]);
+                assert_eq!(f.sources(), vec![0, 5]);
+                assert_eq!(f.sinks(), vec![3, 4]);
+
+                assert_eq!(f.shortest_path(0, 3), None);
+                assert_eq!(f.shortest_path(0, 4), Some(vec![0, 1, 4]));
+                assert_eq!(f.shortest_path(0, 2), None);
+                assert_eq!(f.descendants(0).unwrap(), vec![1, 4]);
+                assert_eq!(f.strongly_connected_components().len(), 5);
+
+                // The hyperedge over 1, 2, 4 is hidden with node 2.
+                assert_eq!(f.number_hyperedges(), 0);
+                assert!(!f.contains_hyperedge(0));
+                assert_eq!(f.get_hyperedges_of_node(1), Vec::<usize>::new());
+
+                // The root node is hidden with its node.
+                assert_eq!(f.get_root_index(), Some(0));
+                let f = g.filter_nodes(|_, value| value.x > 0);
+                assert!(!f.contains_root_node());
+                assert_eq!(f.get_root_node(), None);
+                assert_eq!(f.get_root_index(), None);
+                assert_eq!(f.number_hyperedges(), 1);
+                assert_eq!(f.get_hyperedges_of_node(4), vec![0]);
+                assert!(f.get_hyperedges_of_node(0).is_empty());
+
+                let f = g.filter_nodes(|_, _| false);
+                assert!(f.is_empty());
+                assert!(f.get_last_index().is_err());
+            }
+
+            #[test]
+            fn test_filter_edges() {
+                let g = build();
+                let f = g.filter_edges(|_, _, weight| *weight != 3);
+
+                assert_eq!(f.number_nodes(), 6);
+                assert_eq!(f.number_edges(), 4);
+                assert!(!f.contains_edge(1, 2));
+                assert_eq!(f.get_edge_data(1, 4), Some(&"causes"));
+                assert_eq!(f.get_all_edges().len(), 4);
+                assert_eq!(f.descendants(0).unwrap(), vec![1, 4]);
+                assert_eq!(f.sources(), vec![0, 2, 5]);
+                assert_eq!(f.shortest_path(0, 3), None);
+                assert_eq!(f.number_hyperedges(), 1);
+
+                // Filters can be combined by nesting views.
+                let ff = f.filter_nodes(|index, _| index != 4);
+                assert_eq!(ff.number_nodes(), 5);
+                assert_eq!(sorted_edges(&ff), vec![(0, 1, 1), (2, 3, 5), (5, 3, 8)]);
+                assert_eq!(ff.get_edge_data(1, 4), None);
+
+                let f = FilteredGraph::new(&g, |index, _| index < 4, |a, _, _| a != 0);
+                assert_eq!(sorted_edges(&f), vec![(1, 2, 3), (2, 3, 5)]);
+                assert!(std::ptr::eq(f.graph(), &g));
+            }
+
+            #[test]
+            fn test_filtered_subgraph() {
+                let g = build();
+                let f = g.filter_edges(|_, b, _| b != 4);
+                let (s, map) = f.reachable_subgraph(0).unwrap();
+
+                assert_eq!(map, BTreeMap::from([(0, 0), (1, 1), (2, 2), (3, 3)]));
+                assert_eq!(s.number_edges(), 3);
+
+                // A view can be merged into another graph.
+                let mut h = ultragraph::with_edge_data::<Data, &str>(10);
+                let map = h.merge(&g.filter_nodes(|index, _| index >= 3)).unwrap();
+                assert_eq!(map, BTreeMap::from([(3, 0), (4, 1), (5, 2)]));
+                assert_eq!(sorted_edges(&h), vec![(2, 0, 8)]);
+                assert_eq!(h.get_root_index(), None);
+            }
+        }
+    };
+}
+
+transform_tests!(
+    matrix_storage,
+    ultragraph::with_edge_data::<Data, &'static str>(10)
+);
+transform_tests!(
+    adjacency_list_storage,
+    AdjacencyListGraph::new(UltraAdjacencyListGraph::<Data, &'static str>::new_with_capacity(10))
+);
+
+#[test]
+fn test_csr_storage() {
+    let mut g = ultragraph::new::<Data>();
+    let a = g.add_root_node(Data { x: 1 });
+    let b = g.add_node(Data { x: 2 });
+    let c = g.add_node(Data { x: 3 });
+    g.add_edge_with_weight(a, b, 4).unwrap();
+    g.add_edge_with_weight(b, c, 5).unwrap();
+    let csr = ultragraph::new_with_csr_storage_from(&g);
+
+    let (s, map) = csr.reachable_subgraph(b).unwrap();
+    assert_eq!(map, BTreeMap::from([(b, 0), (c, 1)]));
+    assert_eq!(s.get_edge_weight(0, 1), Some(&5));
+
+    let r = csr.reversed();
+    assert_eq!(r.shortest_path(c, a), Some(vec![c, b, a]));
+    assert_eq!(r.sources(), vec![c]);
+
+    let f = csr.filter_nodes(|index, _| index != b);
+    assert_eq!(f.number_edges(), 0);
+    assert_eq!(f.sinks(), vec![a, c]);
+
+    // Edges cannot be added to a read-only graph.
+    let mut h = ultragraph::new_with_csr_storage_from(&g);
+    assert!(h.merge(&g).is_err());
+}
@@ -6,6 +6,12 @@ mod constructor_tests;
 #[cfg(test)]
 mod error_tests;
 #[cfg(test)]
+mod graph_analysis_tests;
+#[cfg(test)]
+mod graph_hyperedges_tests;
+#[cfg(all(test, feature = "io"))]
+mod graph_io_tests;
+#[cfg(test)]
 mod graph_like_tests;
 #[cfg(test)]
 mod graph_outgoing_edges_tests;
@@ -14,4 +20,10 @@ mod graph_root_tests;
 #[cfg(test)]
 mod graph_shortest_path_tests;
 #[cfg(test)]
+mod graph_stable_index_tests;
+#[cfg(test)]
 mod graph_storage_tests;
+#[cfg(test)]
+mod graph_transform_tests;
+#[cfg(test)]
+mod storage_conformance_tests;
@@ -0,0 +1,696 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+// Tests shared by all storage backends. Each mutable backend runs the same deterministic
+// tests and the same model based property test. The read-only CSR backend is built from
+// a graph that went through the same operations and must answer every query the same way.
+use std::collections::{BTreeMap, BTreeSet};
+
+use proptest::prelude::*;
+use ultragraph::prelude::*;
+
+#[derive(Default, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Data {
+    pub x: u8,
+}
+
+#[derive(Debug, Clone)]
+enum Op {
+    AddNode(u8),
+    AddRootNode(u8),
+    RemoveNode(usize),
+    UpdateNode(usize, u8),
+    AddEdge(usize, usize, u64),
+    RemoveEdge(usize, usize),
+    AddHyperedge(usize, usize, usize, u64),
+    Clear,
+}
+
+// Node arguments select one of the indices issued so far, including removed ones,
+// or an index that was never issued.
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        4 => any::<u8>().prop_map(Op::AddNode),
+        1 => any::<u8>().prop_map(Op::AddRootNode),
+        2 => any::<usize>().prop_map(Op::RemoveNode),
+        1 => (any::<usize>(), any::<u8>()).prop_map(|(a, x)| Op::UpdateNode(a, x)),
+        5 => (any::<usize>(), any::<usize>(), 0..10u64).prop_map(|(a, b, w)| Op::AddEdge(a, b, w)),
+        2 => (any::<usize>(), any::<usize>()).prop_map(|(a, b)| Op::RemoveEdge(a, b)),
+        1 => (any::<usize>(), any::<usize>(), any::<usize>(), 0..10u64)
+            .prop_map(|(a, b, c, w)| Op::AddHyperedge(a, b, c, w)),
+        1 => Just(Op::Clear),
+    ]
+}
+
+// Reference model of the graph.
+#[derive(Default)]
+struct Model {
+    nodes: BTreeMap<usize, Data>,
+    edges: BTreeMap<(usize, usize), u64>,
+    hyperedges: BTreeMap<usize, Vec<usize>>,
+    root: Option<usize>,
+    issued: Vec<usize>,
+}
+
+impl Model {
+    fn select(&self, selector: usize) -> usize {
+        match selector % (self.issued.len() + 1) {
+            i if i < self.issued.len() => self.issued[i],
+            // An index that was never issued.
+            _ => usize::MAX,
+        }
+    }
+
+    fn add_node(&mut self, index: usize, data: Data) {
+        assert!(
+            !self.issued.contains(&index),
+            "index {} was issued before",
+            index
+        );
+        self.issued.push(index);
+        self.nodes.insert(index, data);
+    }
+
+    fn remove_node(&mut self, index: usize) {
+        self.nodes.remove(&index);
+        self.edges.retain(|(a, b), _| *a != index && *b != index);
+        self.hyperedges.retain(|_, nodes| !nodes.contains(&index));
+        if self.root == Some(index) {
+            self.root = None;
+        }
+    }
+
+    // Nodes reachable from a by following edges forward or, if reverse, backward.
+    fn reachable(&self, a: usize, reverse: bool) -> BTreeSet<usize> {
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![a];
+        while let Some(node) = stack.pop() {
+            for (x, y) in self.edges.keys() {
+                let (from, to) = if reverse { (*y, *x) } else { (*x, *y) };
+                if from == node && visited.insert(to) {
+                    stack.push(to);
+                }
+            }
+        }
+        visited
+    }
+
+    // Length of the shortest path from a to b, computed with Bellman-Ford on the model.
+    fn distance(&self, a: usize, b: usize) -> Option<u64> {
+        let mut distances = BTreeMap::from([(a, 0u64)]);
+        for _ in 0..self.nodes.len() {
+            for ((x, y), w) in &self.edges {
+                if let Some(d) = distances.get(x).map(|d| d + w) {
+                    if distances.get(y).map_or(true, |e| d < *e) {
+                        distances.insert(*y, d);
+                    }
+                }
+            }
+        }
+        distances.get(&b).copied()
+    }
+}
+
+fn apply<G: GraphStorage<Data>>(g: &mut G, model: &mut Model, op: &Op) {
+    match *op {
+        Op::AddNode(x) => {
+            let index = g.add_node(Data { x });
+            model.add_node(index, Data { x });
+        }
+        Op::AddRootNode(x) => {
+            let index = g.add_root_node(Data { x });
+            model.add_node(index, Data { x });
+            model.root = Some(index);
+        }
+        Op::RemoveNode(a) => {
+            let a = model.select(a);
+            let res = g.remove_node(a);
+            assert_eq!(res.is_ok(), model.nodes.contains_key(&a));
+            model.remove_node(a);
+        }
+        Op::UpdateNode(a, x) => {
+            let a = model.select(a);
+            let res = g.update_node(a, Data { x });
+            assert_eq!(res.is_ok(), model.nodes.contains_key(&a));
+            if let Some(data) = model.nodes.get_mut(&a) {
+                *data = Data { x };
+            }
+        }
+        Op::AddEdge(a, b, weight) => {
+            let (a, b) = (model.select(a), model.select(b));
+            let res = g.add_edge_with_weight(a, b, weight);
+            let valid = model.nodes.contains_key(&a)
+                && model.nodes.contains_key(&b)
+                && !model.edges.contains_key(&(a, b));
+            assert_eq!(res.is_ok(), valid);
+            if valid {
+                model.edges.insert((a, b), weight);
+            }
+        }
+        Op::RemoveEdge(a, b) => {
+            let (a, b) = (model.select(a), model.select(b));
+            let res = g.remove_edge(a, b);
+            assert_eq!(res.is_ok(), model.edges.remove(&(a, b)).is_some());
+        }
+        Op::AddHyperedge(a, b, c, weight) => {
+            let nodes = [model.select(a), model.select(b), model.select(c)];
+            let res = g.add_hyperedge(&nodes, weight);
+            let valid = nodes.iter().all(|n| model.nodes.contains_key(n))
+                && nodes[0] != nodes[1]
+                && nodes[0] != nodes[2]
+                && nodes[1] != nodes[2];
+            assert_eq!(res.is_ok(), valid);
+            if let Ok(index) = res {
+                assert!(!model.hyperedges.contains_key(&index));
+                model.hyperedges.insert(index, nodes.to_vec());
+            }
+        }
+        Op::Clear => {
+            g.clear();
+            model.nodes.clear();
+            model.edges.clear();
+            model.hyperedges.clear();
+            model.root = None;
+        }
+    }
+}
+
+fn check_invariants<G: GraphStorage<Data>>(g: &G, model: &Model) {
+    assert_eq!(g.number_nodes(), model.nodes.len());
+    assert_eq!(g.number_edges(), model.edges.len());
+    assert_eq!(g.number_hyperedges(), model.hyperedges.len());
+    assert_eq!(g.is_empty(), model.nodes.is_empty());
+
+    let nodes: Vec<(usize, &Data)> = model.nodes.iter().map(|(i, d)| (*i, d)).collect();
+    assert_eq!(g.get_all_indexed_nodes(), nodes);
+
+    let mut edges = g.get_all_edges();
+    edges.sort_unstable();
+    assert_eq!(edges, model.edges.keys().copied().collect::<Vec<_>>());
+
+    let mut iterated: Vec<(usize, &Data)> = g.nodes().collect();
+    iterated.sort_unstable_by_key(|(i, _)| *i);
+    assert_eq!(iterated, nodes);
+    let iterated: BTreeMap<(usize, usize), u64> = g.edges().map(|(a, b, w)| ((a, b), *w)).collect();
+    assert_eq!(iterated, model.edges);
+
+    for index in &model.issued {
+        match model.nodes.get(index) {
+            Some(data) => {
+                assert!(g.contains_node(*index));
+                assert_eq!(g.get_node(*index), Some(data));
+
+                let children: BTreeSet<usize> = g.outgoing_edges(*index).unwrap().collect();
+                let neighbors: BTreeSet<usize> = g.neighbors(*index).unwrap().collect();
+                assert_eq!(neighbors, children);
+                let expected: BTreeSet<usize> = model
+                    .edges
+                    .keys()
+                    .filter(|(a, _)| a == index)
+                    .map(|(_, b)| *b)
+                    .collect();
+                assert_eq!(children, expected);
+                assert_eq!(g.out_degree(*index).unwrap(), expected.len());
+
+                let parents: Vec<usize> = model
+                    .edges
+                    .keys()
+                    .filter(|(_, b)| b == index)
+                    .map(|(a, _)| *a)
+                    .collect();
+                assert_eq!(g.predecessors(*index).unwrap(), parents);
+                assert_eq!(g.in_degree(*index).unwrap(), parents.len());
+
+                let ancestors: Vec<usize> = model.reachable(*index, true).into_iter().collect();
+                assert_eq!(g.ancestors(*index).unwrap(), ancestors);
+                let descendants: Vec<usize> = model.reachable(*index, false).into_iter().collect();
+                assert_eq!(g.descendants(*index).unwrap(), descendants);
+
+                let hyperedges: Vec<usize> = model
+                    .hyperedges
+                    .iter()
+                    .filter(|(_, nodes)| nodes.contains(index))
+                    .map(|(i, _)| *i)
+                    .collect();
+                assert_eq!(g.get_hyperedges_of_node(*index), hyperedges);
+            }
+            None => {
+                assert!(!g.contains_node(*index));
+                assert_eq!(g.get_node(*index), None);
+                assert!(g.outgoing_edges(*index).is_err());
+                assert!(g.neighbors(*index).is_err());
+                assert!(g.incoming_edges(*index).is_err());
+                assert!(g.in_degree(*index).is_err());
+                assert!(g.ancestors(*index).is_err());
+            }
+        }
+    }
+
+    let sources: Vec<usize> = model
+        .nodes
+        .keys()
+        .filter(|n| !model.edges.keys().any(|(_, b)| b == *n))
+        .copied()
+        .collect();
+    assert_eq!(g.sources(), sources);
+    let sinks: Vec<usize> = model
+        .nodes
+        .keys()
+        .filter(|n| !model.edges.keys().any(|(a, _)| a == *n))
+        .copied()
+        .collect();
+    assert_eq!(g.sinks(), sinks);
+
+    for ((a, b), weight) in &model.edges {
+        assert!(g.contains_edge(*a, *b));
+        assert_eq!(g.get_edge_weight(*a, *b), Some(weight));
+    }
+
+    for (index, nodes) in &model.hyperedges {
+        assert_eq!(g.get_hyperedge(*index).unwrap().nodes(), nodes.as_slice());
+    }
+
+    assert_eq!(g.get_root_index(), model.root);
+    assert_eq!(g.get_root_node(), model.root.map(|r| &model.nodes[&r]));
+}
+
+// Backends may break ties between paths of equal length differently,
+// so paths are checked for validity and length instead of compared directly.
+fn check_shortest_paths<G: GraphStorage<Data>>(g: &G, model: &Model) {
+    for a in model.nodes.keys() {
+        for b in model.nodes.keys() {
+            match (g.shortest_path(*a, *b), model.distance(*a, *b)) {
+                (Some(path), Some(distance)) => {
+                    assert_eq!(path.first(), Some(a));
+                    assert_eq!(path.last(), Some(b));
+                    let length: u64 = path.windows(2).map(|w| model.edges[&(w[0], w[1])]).sum();
+                    assert_eq!(length, distance);
+                }
+                (None, None) => {}
+                (path, distance) => panic!(
+                    "Path {:?} from {} to {} does not match distance {:?}",
+                    path, a, b, distance
+                ),
+            }
+        }
+    }
+}
+
+fn check_read_only(g: &mut CsrGraph<Data>, model: &Model) {
+    for index in model.nodes.keys() {
+        assert!(g.remove_node(*index).is_err());
+        assert!(g.add_edge(*index, *index).is_err());
+    }
+    for (a, b) in model.edges.keys() {
+        assert!(g.remove_edge(*a, *b).is_err());
+    }
+    assert!(g.retain_nodes(|_, _| true).is_ok());
+    if !model.nodes.is_empty() {
+        assert!(g.retain_nodes(|_, _| false).is_err());
+    }
+    check_invariants(g, model);
+}
+
+macro_rules! conformance_tests {
+    ($name:ident, $graph:expr) => {
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn test_add_and_get_nodes() {
+                let mut g = $graph;
+                assert!(g.is_empty());
+                assert!(g.get_last_index().is_err());
+
+                let root = g.add_root_node(Data { x: 1 });
+                let a = g.add_node(Data { x: 2 });
+                assert_ne!(root, a);
+                assert_eq!(g.get_root_index(), Some(root));
+                assert_eq!(g.get_root_node(), Some(&Data { x: 1 }));
+                assert_eq!(g.get_node(a), Some(&Data { x: 2 }));
+                assert_eq!(g.number_nodes(), 2);
+                assert_eq!(g.size(), 2);
+                assert_eq!(g.get_last_index().unwrap(), 2);
+                assert_eq!(g.get_all_nodes().len(), 2);
+
+                assert!(g.update_node(a, Data { x: 3 }).is_ok());
+                assert_eq!(g.get_node(a), Some(&Data { x: 3 }));
+                assert!(g.update_node(99, Data { x: 3 }).is_err());
+                assert_eq!(g.get_node(99), None);
+            }
+
+            #[test]
+            fn test_edges() {
+                let mut g = $graph;
+                let a = g.add_node(Data { x: 1 });
+                let b = g.add_node(Data { x: 2 });
+
+                assert!(g.add_edge(a, b).is_ok());
+                assert!(g.add_edge(a, b).is_err());
+                assert!(g.add_edge(a, 99).is_err());
+                assert!(g.add_edge(99, b).is_err());
+                assert!(g.add_edge_with_weight(b, b, 4).is_ok());
+
+                assert!(g.contains_edge(a, b));
+                assert!(!g.contains_edge(b, a));
+                assert_eq!(g.get_edge_weight(a, b), Some(&0));
+                assert_eq!(g.get_edge_weight(b, b), Some(&4));
+                assert_eq!(g.number_edges(), 2);
+
+                assert!(g.remove_edge(b, a).is_err());
+                assert!(g.remove_edge(a, b).is_ok());
+                assert!(!g.contains_edge(a, b));
+                assert_eq!(g.number_edges(), 1);
+
+                assert!(g.remove_node(b).is_ok());
+                assert!(g.remove_node(b).is_err());
+                assert_eq!(g.number_edges(), 0);
+            }
+
+            #[test]
+            fn test_shortest_path() {
+                let mut g = $graph;
+                let n: Vec<usize> = (0..5).map(|x| g.add_node(Data { x })).collect();
+                g.add_edge_with_weight(n[0], n[1], 1).unwrap();
+                g.add_edge_with_weight(n[1], n[4], 10).unwrap();
+                g.add_edge_with_weight(n[0], n[2], 2).unwrap();
+                g.add_edge_with_weight(n[2], n[3], 2).unwrap();
+                g.add_edge_with_weight(n[3], n[4], 2).unwrap();
+
+                assert_eq!(g.shortest_path(n[0], n[4]), Some(vec![n[0], n[2], n[3], n[4]]));
+                assert_eq!(g.shortest_path(n[0], n[0]), Some(vec![n[0]]));
+                assert_eq!(g.shortest_path(n[4], n[0]), None);
+                assert_eq!(g.shortest_path(n[0], 99), None);
+            }
+
+            #[test]
+            fn test_outgoing_edges() {
+                let mut g = $graph;
+                let a = g.add_node(Data { x: 1 });
+                let b = g.add_node(Data { x: 2 });
+                let c = g.add_node(Data { x: 3 });
+                g.add_edge(a, b).unwrap();
+                g.add_edge(a, c).unwrap();
+
+                let mut children: Vec<usize> = g.outgoing_edges(a).unwrap().collect();
+                children.sort_unstable();
+                assert_eq!(children, vec![b, c]);
+                assert_eq!(g.outgoing_edges(b).unwrap().count(), 0);
+                assert!(g.outgoing_edges(99).is_err());
+            }
+
+            #[test]
+            fn test_incoming_edges_and_closures() {
+                let mut g = $graph;
+                let n: Vec<usize> = (0..5).map(|x| g.add_node(Data { x })).collect();
+                g.add_edge(n[0], n[2]).unwrap();
+                g.add_edge(n[1], n[2]).unwrap();
+                g.add_edge(n[2], n[3]).unwrap();
+
+                let mut parents: Vec<usize> = g.incoming_edges(n[2]).unwrap().collect();
+                parents.sort_unstable();
+                assert_eq!(parents, vec![n[0], n[1]]);
+                assert_eq!(g.predecessors(n[2]).unwrap(), vec![n[0], n[1]]);
+                assert_eq!(g.in_degree(n[2]).unwrap(), 2);
+                assert_eq!(g.out_degree(n[2]).unwrap(), 1);
+                assert!(g.incoming_edges(99).is_err());
+
+                assert_eq!(g.ancestors(n[3]).unwrap(), vec![n[0], n[1], n[2]]);
+                assert_eq!(g.descendants(n[0]).unwrap(), vec![n[2], n[3]]);
+                assert_eq!(g.ancestors(n[0]).unwrap(), Vec::<usize>::new());
+                assert_eq!(g.sources(), vec![n[0], n[1], n[4]]);
+                assert_eq!(g.sinks(), vec![n[3], n[4]]);
+
+                // A node on a cycle is its own ancestor.
+                g.add_edge(n[3], n[0]).unwrap();
+                assert_eq!(g.ancestors(n[0]).unwrap(), vec![n[0], n[1], n[2], n[3]]);
+            }
+
+            #[test]
+            fn test_hyperedges() {
+                let mut g = $graph;
+                let n: Vec<usize> = (0..3).map(|x| g.add_node(Data { x })).collect();
+
+                let e = g.add_hyperedge(&n, 7).unwrap();
+                assert!(g.contains_hyperedge(e));
+                assert_eq!(g.get_hyperedge(e).unwrap().weight(), 7);
+                assert_eq!(g.get_hyperedges_of_node(n[1]), vec![e]);
+                assert!(g.add_hyperedge(&[n[0]], 1).is_err());
+
+                g.remove_node(n[1]).unwrap();
+                assert!(!g.contains_hyperedge(e));
+                assert_eq!(g.number_hyperedges(), 0);
+            }
+
+            #[test]
+            fn test_iterators() {
+                let mut g = $graph;
+                let n: Vec<usize> = (0..4).map(|x| g.add_node(Data { x })).collect();
+                g.add_edge_with_weight(n[0], n[1], 1).unwrap();
+                g.add_edge_with_weight(n[0], n[2], 2).unwrap();
+                g.add_edge_with_weight(n[2], n[3], 3).unwrap();
+
+                let mut nodes: Vec<(usize, Data)> = g.nodes().map(|(i, d)| (i, *d)).collect();
+                nodes.sort_unstable_by_key(|(i, _)| *i);
+                assert_eq!(nodes, (0..4).map(|x| (n[x as usize], Data { x })).collect::<Vec<_>>());
+
+                for (_, data) in g.nodes_mut() {
+                    data.x *= 10;
+                }
+                assert_eq!(g.get_node(n[3]), Some(&Data { x: 30 }));
+
+                let mut edges: Vec<(usize, usize, u64)> = g.edges().map(|(a, b, w)| (a, b, *w)).collect();
+                edges.sort_unstable();
+                assert_eq!(edges, vec![(n[0], n[1], 1), (n[0], n[2], 2), (n[2], n[3], 3)]);
+
+                let mut neighbors: Vec<usize> = g.neighbors(n[0]).unwrap().collect();
+                neighbors.sort_unstable();
+                assert_eq!(neighbors, vec![n[1], n[2]]);
+                assert_eq!(g.neighbors(n[3]).unwrap().count(), 0);
+                assert!(g.neighbors(99).is_err());
+            }
+
+            #[test]
+            fn test_retain_nodes() {
+                let mut g = $graph;
+                let root = g.add_root_node(Data { x: 0 });
+                let n: Vec<usize> = (1..5).map(|x| g.add_node(Data { x })).collect();
+                g.add_edge(root, n[0]).unwrap();
+                g.add_edge(n[0], n[1]).unwrap();
+                g.add_edge(n[1], n[2]).unwrap();
+                g.add_hyperedge(&[n[0], n[2]], 1).unwrap();
+                let e = g.add_hyperedge(&[root, n[3]], 1).unwrap();
+
+                // Removes the nodes with odd values and the root.
+                g.retain_nodes(|_, data| data.x % 2 == 0 && data.x > 0).unwrap();
+
+                assert_eq!(g.number_nodes(), 2);
+                assert!(!g.contains_node(root));
+                assert!(!g.contains_node(n[0]));
+                assert!(g.contains_node(n[1]));
+                assert!(!g.contains_node(n[2]));
+                assert!(g.contains_node(n[3]));
+                assert_eq!(g.number_edges(), 0);
+                assert_eq!(g.get_root_index(), None);
+                assert!(!g.contains_hyperedge(e));
+                assert_eq!(g.number_hyperedges(), 0);
+
+                g.retain_nodes(|index, _| index != n[3]).unwrap();
+                assert_eq!(g.get_all_indexed_nodes(), vec![(n[1], &Data { x: 2 })]);
+            }
+
+            #[test]
+            fn test_clear() {
+                let mut g = $graph;
+                let a = g.add_root_node(Data { x: 1 });
+                let b = g.add_node(Data { x: 2 });
+                g.add_edge(a, b).unwrap();
+
+                g.clear();
+                assert!(g.is_empty());
+                assert_eq!(g.number_edges(), 0);
+                assert_eq!(g.get_root_index(), None);
+                assert!(!g.contains_node(a));
+
+                let c = g.add_node(Data { x: 3 });
+                assert_ne!(a, c);
+                assert_ne!(b, c);
+            }
+
+            proptest! {
+                #[test]
+                fn test_interleaved_operations_match_model(ops in prop::collection::vec(op(), 1..64)) {
+                    let mut g = $graph;
+                    let mut model = Model::default();
+
+                    for op in &ops {
+                        apply(&mut g, &mut model, op);
+                        check_invariants(&g, &model);
+                    }
+                    check_shortest_paths(&g, &model);
+
+                    let mut csr = ultragraph::new_with_csr_storage_from(&g);
+                    check_invariants(&csr, &model);
+                    check_shortest_paths(&csr, &model);
+                    check_read_only(&mut csr, &model);
+                }
+            }
+        }
+    };
+}
+
+conformance_tests!(
+    matrix_storage,
+    ultragraph::new_with_matrix_storage::<Data>(10)
+);
+conformance_tests!(
+    adjacency_list_storage,
+    ultragraph::new_with_adjacency_list_storage::<Data>(10)
+);
+
+#[test]
+fn test_csr_from_edges() {
+    let nodes = vec![Data { x: 0 }, Data { x: 1 }, Data { x: 2 }];
+    let mut g =
+        ultragraph::new_with_csr_storage(nodes, &[(1, 2, 5), (0, 2, 1), (0, 1, 2)], Some(0))
+            .unwrap();
+
+    assert_eq!(g.number_nodes(), 3);
+    assert_eq!(g.number_edges(), 3);
+    assert_eq!(g.get_root_node(), Some(&Data { x: 0 }));
+    assert_eq!(g.get_all_edges(), vec![(0, 1), (0, 2), (1, 2)]);
+    assert_eq!(g.get_edge_weight(1, 2), Some(&5));
+    assert_eq!(g.outgoing_edges(0).unwrap().collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(g.shortest_path(0, 2), Some(vec![0, 2]));
+    assert_eq!(g.incoming_edges(2).unwrap().collect::<Vec<_>>(), vec![0, 1]);
+    assert_eq!(
+        g.edges().collect::<Vec<_>>(),
+        vec![(0, 1, &2), (0, 2, &1), (1, 2, &5)]
+    );
+    assert_eq!(g.neighbors(0).unwrap().collect::<Vec<_>>(), vec![1, 2]);
+
+    // Nodes can be appended and updated, but edges are read-only.
+    let c = g.add_node(Data { x: 3 });
+    assert_eq!(c, 3);
+    assert!(g.update_node(c, Data { x: 4 }).is_ok());
+    assert_eq!(g.in_degree(c).unwrap(), 0);
+    assert_eq!(g.sinks(), vec![2, c]);
+    assert_eq!(g.get_node(c), Some(&Data { x: 4 }));
+    assert!(g.add_edge(0, c).is_err());
+    assert!(g.remove_edge(0, 1).is_err());
+    assert!(g.remove_node(0).is_err());
+}
+
+#[test]
+fn test_csr_from_edges_invalid() {
+    let nodes = || vec![Data { x: 0 }, Data { x: 1 }];
+
+    assert!(ultragraph::new_with_csr_storage(nodes(), &[(0, 2, 0)], None).is_err());
+    assert!(ultragraph::new_with_csr_storage(nodes(), &[(2, 0, 0)], None).is_err());
+    assert!(ultragraph::new_with_csr_storage(nodes(), &[(0, 1, 0), (0, 1, 1)], None).is_err());
+    assert!(ultragraph::new_with_csr_storage(nodes(), &[], Some(2)).is_err());
+    assert!(ultragraph::new_with_csr_storage(nodes(), &[], None).is_ok());
+}
+
+#[test]
+fn test_csr_from_graph_preserves_indices() {
+    let mut g = ultragraph::new_with_matrix_storage::<Data>(10);
+    let a = g.add_node(Data { x: 1 });
+    let b = g.add_root_node(Data { x: 2 });
+    let c = g.add_node(Data { x: 3 });
+    let d = g.add_node(Data { x: 4 });
+    g.add_edge_with_weight(b, d, 3).unwrap();
+    g.add_hyperedge(&[a, b, d], 1).unwrap();
+    let e = g.add_hyperedge(&[b, d], 2).unwrap();
+    g.remove_node(a).unwrap();
+
+    let mut csr = ultragraph::new_with_csr_storage_from(&g);
+    assert!(!csr.contains_node(a));
+    assert_eq!(csr.get_node(c), Some(&Data { x: 3 }));
+    assert_eq!(csr.get_root_index(), Some(b));
+    assert_eq!(csr.get_edge_weight(b, d), Some(&3));
+    assert_eq!(csr.get_hyperedges_of_node(d), vec![e]);
+
+    // New hyperedges and nodes do not reuse indices of the copied graph.
+    assert!(csr.add_hyperedge(&[b, c], 1).unwrap() > e);
+    assert!(csr.add_node(Data { x: 5 }) > d);
+}
+
+macro_rules! edge_data_tests {
+    ($name:ident, $graph:expr) => {
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn test_edge_data() {
+                let mut g = $graph;
+                let a = g.add_node(Data { x: 1 });
+                let b = g.add_node(Data { x: 2 });
+                let c = g.add_node(Data { x: 3 });
+
+                assert!(g.add_edge_with_data(a, b, 2, "causes").is_ok());
+                assert!(g.add_edge_with_data(a, b, 2, "causes").is_err());
+                assert!(g.add_edge_with_data(a, 99, 2, "causes").is_err());
+                assert!(g.add_edge(b, c).is_ok());
+
+                assert_eq!(g.get_edge_data(a, b), Some(&"causes"));
+                assert_eq!(g.get_edge_weight(a, b), Some(&2));
+                assert_eq!(g.get_edge_data(b, c), None);
+                assert_eq!(g.get_edge_data(a, c), None);
+                assert_eq!(g.shortest_path(a, c), Some(vec![a, b, c]));
+
+                *g.get_edge_data_mut(a, b).unwrap() = "prevents";
+                assert_eq!(g.get_edge_data(a, b), Some(&"prevents"));
+                assert!(g.get_edge_data_mut(b, c).is_none());
+
+                assert!(g.update_edge_data(b, c, "enables").is_ok());
+                assert_eq!(g.get_edge_data(b, c), Some(&"enables"));
+                assert!(g.update_edge_data(c, a, "enables").is_err());
+
+                let mut csr = ultragraph::new_with_csr_storage_from(&g);
+                assert_eq!(csr.get_edge_data(a, b), Some(&"prevents"));
+                assert_eq!(csr.get_edge_data(b, c), Some(&"enables"));
+                assert!(csr.update_edge_data(a, b, "causes").is_ok());
+                assert_eq!(csr.get_edge_data(a, b), Some(&"causes"));
+                assert!(csr.add_edge_with_data(c, a, 1, "causes").is_err());
+
+                // Removing an edge removes its payload.
+                g.remove_edge(a, b).unwrap();
+                g.add_edge(a, b).unwrap();
+                assert_eq!(g.get_edge_data(a, b), None);
+
+                g.remove_node(b).unwrap();
+                assert_eq!(g.get_edge_data(b, c), None);
+            }
+        }
+    };
+}
+
+edge_data_tests!(
+    matrix_storage_edge_data,
+    ultragraph::with_edge_data::<Data, &str>(10)
+);
+edge_data_tests!(
+    adjacency_list_storage_edge_data,
+    AdjacencyListGraph::new(UltraAdjacencyListGraph::<Data, &str>::new_with_capacity(10))
+);
+
+#[test]
+fn test_csr_from_edges_with_data() {
+    let nodes = vec![Data { x: 0 }, Data { x: 1 }, Data { x: 2 }];
+    let storage =
+        UltraCsrGraph::from_edges_with_data(nodes, vec![(1, 2, 5, "b"), (0, 1, 2, "a")], None)
+            .unwrap();
+    let g: CsrGraph<Data, &str> = UltraGraphContainer::new(storage);
+
+    assert_eq!(g.get_edge_data(0, 1), Some(&"a"));
+    assert_eq!(g.get_edge_data(1, 2), Some(&"b"));
+    assert_eq!(g.get_edge_weight(1, 2), Some(&5));
+    assert_eq!(g.get_edge_data(0, 2), None);
+    assert!(UltraCsrGraph::<Data, &str>::from_edges_with_data(
+        vec![Data { x: 0 }],
+        vec![(0, 0, 1, "a"), (0, 0, 2, "b")],
+        None
+    )
+    .is_err());
+}